@@ -0,0 +1,184 @@
+//! Throughput and accuracy of E1 in each piece of its domain, and across all of them at once.
+//!
+//! `cargo bench` times this crate (with whichever features are enabled, e.g. `fast-poly`)
+//! alongside `fast::Ei`, and prints how far the latter strays from the former in each piece.
+//! With GSL installed, `RUSTFLAGS="--cfg gsl" cargo bench` also times `gsl_sf_expint_E1_e`
+//! through a minimal FFI binding, and prints how far this crate strays from it.
+
+#![expect(
+    unused_crate_dependencies,
+    reason = "benchmarks won't necessarily use each dev-dependency"
+)]
+#![expect(clippy::print_stdout, reason = "executable, not a library")]
+#![expect(
+    unused_results,
+    reason = "`criterion` returns `&mut Self` from every builder method, and `black_box` its argument"
+)]
+
+/// The one function this benchmark needs from GSL.
+#[cfg(gsl)]
+mod gsl {
+    use core::ffi::{c_int, c_void};
+
+    /// `gsl_sf_result`.
+    #[repr(C)]
+    struct SfResult {
+        /// Value.
+        val: f64,
+        /// Error estimate.
+        err: f64,
+    }
+
+    #[link(name = "gsl")]
+    #[link(name = "gslcblas")]
+    unsafe extern "C" {
+        /// $E_{1}(x)$, returning nonzero on failure.
+        fn gsl_sf_expint_E1_e(x: f64, result: *mut SfResult) -> c_int;
+        /// Report errors by return value alone, instead of aborting.
+        fn gsl_set_error_handler_off() -> *mut c_void;
+    }
+
+    /// $E_{1}(x)$ from GSL, or `None` wherever it reports an error.
+    #[inline]
+    #[expect(non_snake_case, reason = "Proper mathematical names")]
+    pub(crate) fn E1(x: f64) -> Option<f64> {
+        let mut result = SfResult {
+            val: 0_f64,
+            err: 0_f64,
+        };
+        // SAFETY:
+        // Turning off the error handler only changes global state GSL owns.
+        unsafe {
+            gsl_set_error_handler_off();
+        }
+        // SAFETY:
+        // `result` is a valid `gsl_sf_result` for the duration of the call.
+        let status = unsafe { gsl_sf_expint_E1_e(x, &raw mut result) };
+        (status == 0).then_some(result.val)
+    }
+}
+
+use {
+    core::hint::black_box,
+    criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main},
+    exponential_integral::{E1, Precision, consts::EI_ZERO, fast},
+    sigma_types::{Finite, NonZero},
+};
+
+/// Points sampled from each piece.
+const POINTS: u32 = 1024;
+
+/// Each piece of `E1`, by name, as an interval of its argument.
+/// `le_pos_1` also covers $(-1, 0)$ away from the root, which GSL handles just the same.
+const REGIONS: [(&str, f64, f64); 7] = [
+    ("le_neg_10", -700_f64, -10_f64),
+    ("le_neg_4", -10_f64, -4_f64),
+    ("le_neg_1", -4_f64, -1_f64),
+    ("near_root", -EI_ZERO - 0.0625_f64, -EI_ZERO + 0.0625_f64),
+    ("le_pos_1", 0_f64, 1_f64),
+    ("le_pos_4", 1_f64, 4_f64),
+    ("le_pos_max", 4_f64, 700_f64),
+];
+
+/// Evenly spaced points strictly inside `(lo, hi)`, at the midpoint of each of `POINTS` subintervals.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "called once per region")]
+fn sample(lo: f64, hi: f64) -> Vec<NonZero<Finite<f64>>> {
+    (0..POINTS)
+        .filter_map(|k| {
+            let t = (f64::from(k) + 0.5_f64) / f64::from(POINTS);
+            Finite::try_new(lo.mul_add(1_f64 - t, hi * t)).and_then(NonZero::try_new)
+        })
+        .collect()
+}
+
+/// $E_{1}(x)$ from this crate, at full precision.
+#[inline]
+fn precise(x: NonZero<Finite<f64>>) -> Option<f64> {
+    E1(x, Precision::Full).ok().map(|approx| *approx.value)
+}
+
+/// Largest relative difference between `reference` and `candidate` over `xs`.
+#[inline]
+#[cfg_attr(
+    not(gsl),
+    expect(clippy::single_call_fn, reason = "also compares against GSL")
+)]
+fn worst(
+    xs: &[NonZero<Finite<f64>>],
+    reference: impl Fn(NonZero<Finite<f64>>) -> Option<f64>,
+    candidate: impl Fn(NonZero<Finite<f64>>) -> Option<f64>,
+) -> f64 {
+    xs.iter()
+        .filter_map(|&x| {
+            let expected = reference(x)?;
+            let actual = candidate(x)?;
+            Some(((actual - expected) / expected).abs())
+        })
+        .fold(0_f64, f64::max)
+}
+
+/// Time every implementation on `xs`, as a group called `name`, after printing how closely they agree.
+#[inline]
+fn compare(c: &mut Criterion, name: &str, xs: &[NonZero<Finite<f64>>]) {
+    // $E_{1}(x) = -\text{Ei}(-x)$:
+    let reduced = |x: NonZero<Finite<f64>>| Some(-fast::Ei(-**x));
+    println!(
+        "{name}: `fast` differs from this crate by at most {:e}",
+        worst(xs, precise, reduced),
+    );
+    #[cfg(gsl)]
+    println!(
+        "{name}: this crate differs from GSL by at most {:e}",
+        worst(xs, |x| gsl::E1(**x), precise),
+    );
+
+    let mut group = c.benchmark_group(name);
+    #[expect(
+        clippy::as_conversions,
+        reason = "a few thousand points, far below where `u64` overflows"
+    )]
+    group.throughput(Throughput::Elements(xs.len() as u64));
+    group.bench_with_input(BenchmarkId::new("crate", xs.len()), xs, |b, points| {
+        b.iter(|| {
+            for &x in points {
+                black_box(precise(black_box(x)));
+            }
+        });
+    });
+    group.bench_with_input(BenchmarkId::new("fast", xs.len()), xs, |b, points| {
+        b.iter(|| {
+            for &x in points {
+                black_box(reduced(black_box(x)));
+            }
+        });
+    });
+    #[cfg(gsl)]
+    group.bench_with_input(BenchmarkId::new("gsl", xs.len()), xs, |b, points| {
+        b.iter(|| {
+            for &x in points {
+                black_box(gsl::E1(black_box(**x)));
+            }
+        });
+    });
+    group.finish();
+}
+
+/// One group per piece, then one with every piece shuffled together,
+/// where which piece comes next is as unpredictable as it gets.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "registered with `criterion_group`")]
+fn regions(c: &mut Criterion) {
+    let mut mixed = vec![];
+    for (name, lo, hi) in REGIONS {
+        let xs = sample(lo, hi);
+        compare(c, name, &xs);
+        mixed.extend(xs);
+    }
+    // A fixed but thorough shuffle, by hashing each point's bits (as in `cache::CachedEi`):
+    mixed.sort_by_key(|x| x.to_bits().wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    compare(c, "mixed", &mixed);
+}
+
+criterion_group!(benches, regions);
+criterion_main!(benches);
@@ -0,0 +1,118 @@
+//! With the `tuned` feature, cut each table behind the double-precision pieces of E1
+//! from the double-double fits in `src/constants/quad.rs`,
+//! keeping the fewest terms whose truncation error stays under `EXPONENTIAL_INTEGRAL_TOLERANCE`,
+//! a relative error (e.g. `1e-8` for speed, or `1e-17` to go past GSL's own tables),
+//! defaulting to $2^{-52}$, about where GSL's tables stop.
+//! The tables are written to `tuned.rs` in `OUT_DIR`, which `constants::piecewise` includes.
+//! Without `tuned`, this does nothing, and the pieces keep GSL's tables.
+//! A tolerance that some table can't meet without every last one of its terms fails the build,
+//! since nothing bounds what the table itself leaves out.
+//!
+//! Alongside the orders, this picks where the Taylor series about the zero of Ei takes over from `E12`
+//! (`constants::piecewise::ROOT_RADIUS`), since how close in `E12` holds up, and how far out the Taylor series does,
+//! both depend on the tolerance.
+//! The other split points, at -10, -4, -1, 1, and 4, stay GSL's along with each piece's change of variables,
+//! since the double-double fits only hold on their own intervals, and moving them would take fitting new ones.
+//! Every other backend keeps GSL's tables, so it keeps `constants::ROOT_RADIUS` too.
+//!
+//! A Chebyshev series cut after $n$ terms is off by at most $\sum_{k \geq n} |c_{k}|$, since $|T_{k}| \leq 1$,
+//! and the Taylor series about the zero of Ei by at most $\sum_{k \geq n} |a_{k}| r^{k}$ within radius $r$.
+//! Each piece's result is proportional to whatever its series is added to,
+//! so the bound is divided by the smallest that gets over the piece, sampled on a fine grid.
+
+#![expect(clippy::single_call_fn, reason = "one step each, for readability")]
+
+/// The double-double fits, as the `quad` backend has them.
+#[expect(
+    clippy::unreadable_literal,
+    dead_code,
+    reason = "copy & paste, and only the tables the double-precision pieces share"
+)]
+mod quad {
+    include!("src/constants/quad.rs");
+}
+
+/// Choosing each table's order, shared with the tests.
+mod tuning {
+    include!("src/tuning.rs");
+}
+
+use {
+    core::fmt::Write as _,
+    std::{env, fs, path::PathBuf},
+    tuning::Shape,
+};
+
+/// Every table the double-precision pieces share, by name, with its double-double fit and its shape.
+const TABLES: [Table; 7] = [
+    ("AE11", &quad::AE11, Shape::Decaying),
+    ("AE12", &quad::AE12, Shape::Decaying),
+    ("AE13", &quad::AE13, Shape::Decaying),
+    ("AE14", &quad::AE14, Shape::Decaying),
+    ("E11", &quad::E11, Shape::LogShifted),
+    ("E12", &quad::E12, Shape::Log),
+    ("EI_ROOT", &quad::EI_ROOT, Shape::Taylor),
+];
+
+/// The environment variable holding the tolerance.
+const TOLERANCE: &str = "EXPONENTIAL_INTEGRAL_TOLERANCE";
+
+/// A table's name, its double-double fit, and its shape.
+type Table = (&'static str, &'static [(f64, f64)], Shape);
+
+fn main() -> Result<(), String> {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src/constants/quad.rs");
+    println!("cargo::rerun-if-changed=src/tuning.rs");
+    println!("cargo::rerun-if-env-changed={TOLERANCE}");
+    if env::var_os("CARGO_FEATURE_TUNED").is_none() {
+        return Ok(());
+    }
+
+    let tolerance = match env::var(TOLERANCE) {
+        Err(env::VarError::NotPresent) => f64::EPSILON,
+        Err(env::VarError::NotUnicode(raw)) => {
+            return Err(format!("`{TOLERANCE}` isn't Unicode: {raw:?}"));
+        }
+        Ok(raw) => match raw.trim().parse::<f64>() {
+            Ok(tolerance) if tolerance > 0_f64 && tolerance.is_finite() => tolerance,
+            _ => {
+                return Err(format!(
+                    "`{TOLERANCE}` should be a positive number, like `1e-8`, but it's `{raw}`"
+                ));
+            }
+        },
+    };
+
+    let mut generated = format!(
+        "// Generated by `build.rs` for `{TOLERANCE}={tolerance:e}`: edit that, not this.\n"
+    );
+    let radius = tuning::root_radius(&quad::E12, &quad::EI_ROOT, tolerance);
+    _ = write!(
+        generated,
+        "\n/// Half-width of the neighbourhood of the zero of Ei that `EI_ROOT` covers instead of `E12`.\n\
+         pub(crate) const ROOT_RADIUS: f64 = {radius:e};\n",
+    );
+    for (name, coefficients, shape) in TABLES {
+        let all = coefficients.len();
+        let (terms, bound) = tuning::order(coefficients, shape, radius, tolerance).map_err(|closest| {
+            format!(
+                "`{TOLERANCE}={tolerance:e}` is out of reach: \
+                 {name} can't be cut short of all {all} terms for a relative truncation error under {closest:e}"
+            )
+        })?;
+        _ = write!(
+            generated,
+            "\n/// `{name}`, cut to {terms} of {all} terms, for a relative truncation error of at most {bound:e}.\n\
+             pub(crate) const {name}: [f64; {terms}] = [\n",
+        );
+        for &(hi, _) in coefficients.iter().take(terms) {
+            _ = writeln!(generated, "    {hi:e},");
+        }
+        generated.push_str("];\n");
+    }
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").ok_or("Cargo didn't set `OUT_DIR`")?);
+    fs::write(out.join("tuned.rs"), generated)
+        .map_err(|e| format!("Couldn't write the tuned tables: {e}"))
+}
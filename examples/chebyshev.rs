@@ -16,7 +16,6 @@ use {
     sigma_types::Finite,
 };
 
-#[cfg(feature = "precision")]
 use sigma_types::usize::LessThan;
 
 fn main() {
@@ -28,29 +27,17 @@ fn main() {
     let (coefficient_tuple, x): ((f64, f64, f64, f64, f64, f64, f64, f64), Finite<f64>) =
         Arbitrary::arbitrary(&mut g);
 
-    #[cfg(feature = "precision")]
     let raw_order = usize::arbitrary(&mut g);
 
     let raw_coefficients: [_; N] = coefficient_tuple.into();
     let coefficients = Finite::all(&raw_coefficients);
 
-    #[cfg(feature = "precision")]
     #[expect(clippy::integer_division_remainder_used, reason = "not cryptographic")]
     let order = LessThan::new(raw_order % N);
 
-    let evaluated = chebyshev::eval(
-        coefficients,
-        x,
-        #[cfg(feature = "precision")]
-        order,
-    );
+    let evaluated = chebyshev::eval(coefficients, x, order);
     println!(
         "chebyshev::eval{:#?} = {evaluated:#?}",
-        (
-            coefficients,
-            x,
-            #[cfg(feature = "precision")]
-            order,
-        ),
+        (coefficients, x, order,),
     );
 }
@@ -0,0 +1,57 @@
+//! Time $\text{Ei}$ on arguments scattered across every piece of its domain,
+//! so that which piece comes next is as unpredictable as possible.
+
+#![expect(
+    unused_crate_dependencies,
+    reason = "examples won't necessarily use each dev-dependency"
+)]
+#![expect(clippy::print_stdout, reason = "executable, not a library")]
+#![expect(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    reason = "not worth sacrificing clarity to eliminate a one-in-a-million scenario for a one-off example"
+)]
+
+use {
+    core::hint::black_box,
+    exponential_integral::{Ei, Precision},
+    quickcheck::{Arbitrary, Gen},
+    sigma_types::{Finite, NonZero},
+    std::time::Instant,
+};
+
+/// Distinct arguments, enough to defeat any pattern the branch predictor could learn.
+const POINTS: usize = 1 << 16;
+
+/// Passes over every argument, to average out noise.
+const PASSES: usize = 64;
+
+fn main() {
+    let mut g = Gen::new(256);
+    // Uniform on $(-16, 16)$, which covers every piece but the last with room to spare:
+    let xs: Vec<NonZero<Finite<f64>>> = (0..POINTS)
+        .filter_map(|_| {
+            let u: usize = Arbitrary::arbitrary(&mut g);
+            let on_unit = (u as f64) / (usize::MAX as f64);
+            Finite::try_new(on_unit.mul_add(32_f64, -16_f64)).and_then(NonZero::try_new)
+        })
+        .collect();
+
+    // Summed and printed so that no evaluation can be optimized away:
+    let mut checksum = 0_f64;
+    let start = Instant::now();
+    for _ in 0..PASSES {
+        for &x in &xs {
+            if let Ok(approx) = black_box(Ei(black_box(x), Precision::Full)) {
+                checksum += *approx.value;
+            }
+        }
+    }
+    let elapsed = start.elapsed();
+
+    let evaluations = xs.len().wrapping_mul(PASSES) as f64;
+    println!(
+        "{:.2} ns per evaluation (checksum {checksum})",
+        elapsed.as_secs_f64() * 1e9_f64 / evaluations,
+    );
+}
@@ -12,7 +12,7 @@
 )]
 
 use {
-    exponential_integral::Ei,
+    exponential_integral::{Ei, Precision},
     quickcheck::{Arbitrary, Gen},
     sigma_types::{Finite, NonZero},
 };
@@ -35,11 +35,7 @@ fn main() {
     let mut g = Gen::new(256);
     let x = in_range(-16_f64, 16_f64, &mut g);
     println!("x = {x}");
-    let ei = Ei(
-        x,
-        #[cfg(feature = "precision")]
-        Arbitrary::arbitrary(&mut g),
-    );
+    let ei = Ei(x, Precision::Terms(Arbitrary::arbitrary(&mut g)));
     match ei {
         Ok(ok) => println!("Ei({x}) = {ok}"),
         Err(e) => println!("Ei({x}) = [ERROR: {e}]"),
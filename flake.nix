@@ -33,6 +33,8 @@
           root = ./.;
           include = [
             ./Cargo.lock
+            ./benches
+            ./build.rs
             ./src
           ];
         };
@@ -78,6 +80,11 @@
           };
         };
         dev-dependencies = {
+          criterion = {
+            features = [
+              "cargo_bench_support"
+            ];
+          };
           quickcheck = {
             features = [ ];
             # git = "https://github.com/neithernut/quickcheck.git";
@@ -88,25 +95,120 @@
           };
         };
         features = {
+          alloc = {
+            dependencies = { };
+            other-features = [ ];
+          };
+          arrow = {
+            dependencies = {
+              arrow-array = {
+                features = [ ];
+              };
+            };
+            other-features = [ ];
+          };
+          compensated = {
+            dependencies = { };
+            other-features = [
+              "quad"
+            ];
+          };
+          double-double = {
+            dependencies = { };
+            other-features = [
+              "quad"
+            ];
+          };
           error = {
             dependencies = { };
             other-features = [ ];
           };
+          fast-poly = {
+            dependencies = { };
+            other-features = [ ];
+          };
+          interval = {
+            dependencies = { };
+            other-features = [ ];
+          };
+          mp = {
+            dependencies = {
+              dashu-float = {
+                features = [ ];
+              };
+            };
+            other-features = [ ];
+          };
+          num-complex = {
+            dependencies = {
+              num-complex = {
+                features = [
+                  "libm"
+                ];
+              };
+            };
+            other-features = [ ];
+          };
+          opt-size = {
+            dependencies = { };
+            other-features = [ ];
+          };
           precision = {
             dependencies = { };
             other-features = [ ];
           };
+          quad = {
+            dependencies = { };
+            other-features = [ ];
+          };
+          rigorous-error = {
+            dependencies = { };
+            other-features = [
+              "error"
+              "quad"
+            ];
+          };
+          simd = {
+            dependencies = { };
+            other-features = [ ];
+          };
+          std = {
+            dependencies = {
+              rayon = {
+                features = [ ];
+              };
+            };
+            other-features = [
+              "alloc"
+            ];
+          };
+          tuned = {
+            dependencies = { };
+            other-features = [ ];
+          };
         };
         feature-dependencies = builtins.foldl' (
           acc: { dependencies, other-features }: acc // dependencies
         ) { } (builtins.attrValues features);
 
+        toml-value =
+          v:
+          if builtins.isAttrs v then
+            "{ ${
+              pkgs.lib.strings.concatStringsSep ", " (
+                builtins.attrValues (builtins.mapAttrs (k: v: "${k} = ${toml-value v}") v)
+              )
+            } }"
+          else if builtins.isList v then
+            "[ ${pkgs.lib.strings.concatStringsSep ", " (builtins.map toml-value v)} ]"
+          else
+            "\"${v}\"";
         tomlize =
           set:
           pkgs.lib.strings.concatLines (
             builtins.filter (s: !builtins.isNull s) (
               builtins.attrValues (
-                builtins.mapAttrs (k: v: if builtins.isNull v then null else "${k} = \"${v}\"") set
+                builtins.mapAttrs (k: v: if builtins.isNull v then null else "${k} = ${toml-value v}") set
               )
             )
           );
@@ -154,6 +256,7 @@
           single-char-lifetime-names = "allow";
           tail-expr-drop-order = "warn";
           unknown-lints = "allow";
+          unexpected-cfgs = null; # replaced by `unexpected_cfgs` in `rust-lint-tables`
           unneeded-field-pattern = "allow";
           unqualified-local-imports = null;
           unsafe-code = "allow";
@@ -161,6 +264,13 @@
           warnings = "warn";
           wildcard-dependencies = "allow";
         };
+        rust-lint-tables = {
+          # `cfg(gsl)`: benchmark against GSL itself (see `benches/regions.rs`)
+          unexpected_cfgs = {
+            level = "deny";
+            check-cfg = [ "cfg(gsl)" ];
+          };
+        };
         cargo-toml-contents = ''
           [package]
           name = "${pname}"
@@ -239,11 +349,16 @@
               ) features
             )
           )}
+          [[bench]]
+          name = "regions"
+          harness = false
+
           [lints.rust]
           ${tomlize (
             builtins.mapAttrs (
               k: v: if builtins.hasAttr k override-lints then override-lints.${k} else "deny"
             ) (import "${self.packages.${system}.all-lints}/rustc.nix")
+            // rust-lint-tables
           )}
           [lints.clippy]
           ${tomlize (
@@ -0,0 +1,377 @@
+//! Alternative algorithms, independent of the Chebyshev tables,
+//! for cross-checking or for trading speed against controllable accuracy.
+
+pub mod asymptotic {
+    //! The divergent asymptotic expansion for large inputs,
+    //! $E_{1}(x) \sim \frac{e^{-x}}{x} \sum_{k=0}^{\infty} \frac{(-1)^k k!}{x^k}$,
+    //! summed up to its smallest term.
+    //!
+    //! For inputs greater than 0 the series alternates,
+    //! so the first term left out bounds the truncation error,
+    //! which at the optimal point is roughly $\sqrt{2 \pi x} e^{-x}$ relative to the result.
+
+    use {
+        crate::{Approx, algorithms::Converged, constants, pos::HugeArgument},
+        sigma_types::{Finite, Positive},
+    };
+
+    #[cfg(feature = "error")]
+    use sigma_types::NonNegative;
+
+    /// $e^{x} E_{1}(x) \approx \frac{1}{x} \sum_{k} \frac{(-1)^k k!}{x^k}$, summed up to its smallest term.
+    #[inline]
+    fn optimally_truncated(x: f64) -> Converged {
+        let mut term = 1_f64;
+        let mut k = 0_f64;
+        let mut sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_sum = 0_f64;
+        #[cfg(feature = "error")]
+        let omitted: f64;
+        let mut terms = 0_usize;
+        loop {
+            sum += term;
+            #[cfg(feature = "error")]
+            {
+                abs_sum += term.abs();
+            }
+            terms = terms.saturating_add(1);
+            k += 1_f64;
+            let next = -term * k / x;
+            // Past the smallest term, the series only diverges:
+            if next.abs() >= term.abs() || term.abs() <= f64::EPSILON * sum.abs() {
+                #[cfg(feature = "error")]
+                {
+                    omitted = next.abs();
+                }
+                break;
+            }
+            term = next;
+        }
+
+        let value = sum / x;
+        Converged {
+            approx: Approx {
+                value: Finite::new(value),
+                #[cfg(feature = "error")]
+                // The first term left out, plus rounding:
+                error: NonNegative::new(Finite::new(
+                    (omitted + 2_f64 * constants::GSL_DBL_EPSILON * abs_sum) / x,
+                )),
+            },
+            terms,
+        }
+    }
+
+    /// E1 via its asymptotic expansion, truncated at the smallest term.
+    /// Accurate to double precision only for `x` past about 40.
+    /// # Errors
+    /// If `x` is so large that floating-point operations will fail down the line (just over 700).
+    #[inline]
+    pub fn E1(x: Positive<Finite<f64>>) -> Result<Converged, HugeArgument> {
+        if **x >= constants::XMAX {
+            return HugeArgument::fail(x);
+        }
+        let mut converged = optimally_truncated(**x);
+        let exponential = libm::exp(-**x);
+        converged.approx.value = Finite::new(*converged.approx.value * exponential);
+        #[cfg(feature = "error")]
+        {
+            converged.approx.error = NonNegative::new(Finite::new(
+                **converged.approx.error * exponential
+                    + 2_f64
+                        * constants::GSL_DBL_EPSILON
+                        * (1_f64 + **x)
+                        * converged.approx.value.abs(),
+            ));
+        }
+        Ok(converged)
+    }
+
+    /// `E1` scaled by $e^{x}$, i.e. $e^{x} E_{1}(x)$, via its asymptotic expansion,
+    /// which never underflows and so has no `XMAX` ceiling.
+    #[inline]
+    #[must_use]
+    pub fn E1_scaled(x: Positive<Finite<f64>>) -> Converged {
+        optimally_truncated(**x)
+    }
+}
+
+pub mod continued_fraction {
+    //! The continued fraction for inputs greater than 0, evaluated by the modified Lentz method.
+    //!
+    //! $E_{n}(x) = e^{-x} \left( \frac{1}{x + n -} \frac{1 \cdot n}{x + n + 2 -} \frac{2 (n + 1)}{x + n + 4 -} \cdots \right)$
+    //!
+    //! It converges for every input greater than 0, but only quickly once `x + n` is past about 1.
+
+    use {
+        crate::{Error, algorithms::Converged, constants, implementation::en},
+        sigma_types::{Finite, Positive},
+    };
+
+    /// E1 via the continued fraction.
+    /// # Errors
+    /// If `x` is so large that floating-point operations will fail down the line (just over 700).
+    #[inline]
+    pub fn E1(x: Positive<Finite<f64>>) -> Result<Converged, Error> {
+        En(1, x)
+    }
+
+    /// En via the continued fraction, for any order `n`.
+    /// # Errors
+    /// If `x` is so large that floating-point operations will fail down the line (just over 700).
+    #[inline]
+    pub fn En(n: usize, x: Positive<Finite<f64>>) -> Result<Converged, Error> {
+        if **x >= constants::XMAX {
+            return Err(Error::ArgumentTooPositive(x));
+        }
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "orders past 2^53 are indistinguishable in the result anyway"
+        )]
+        let order = n as f64;
+        en::continued_fraction(order, x, false)
+    }
+}
+
+pub mod ramanujan {
+    //! Ramanujan's rapidly converging series for Ei on inputs greater than 0.
+    //!
+    //! $\text{Ei}(x) = \gamma + \ln x + e^{x/2} \sum_{n=1}^{\infty} \frac{(-1)^{n-1} x^n}{n! \, 2^{n-1}} \sum_{k=0}^{\lfloor (n-1)/2 \rfloor} \frac{1}{2k+1}$
+
+    use {
+        crate::{Approx, algorithms::Converged, constants, consts, pos::HugeArgument},
+        sigma_types::{Finite, Positive},
+    };
+
+    #[cfg(feature = "error")]
+    use sigma_types::NonNegative;
+
+    /// Ei on inputs greater than 0 via Ramanujan's series,
+    /// summing at most `max_terms` terms but stopping early
+    /// as soon as a term no longer changes the sum.
+    /// # Errors
+    /// If `x` is so large that floating-point operations will fail down the line (just over 710).
+    #[inline]
+    pub fn Ei(x: Positive<Finite<f64>>, max_terms: usize) -> Result<Converged, HugeArgument> {
+        if **x >= constants::XMAX {
+            return HugeArgument::fail(x);
+        }
+        let raw = **x;
+
+        // `x^n / (n! 2^(n-1))`, starting at `n = 1`:
+        let mut power = raw;
+        // `sum_{k=0}^{floor((n-1)/2)} 1/(2k+1)`:
+        let mut odd_harmonic = 1_f64;
+        let mut n = 1_f64;
+        let mut n_is_odd = true;
+        let mut sign = 1_f64;
+
+        let mut sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_last = 0_f64;
+        #[cfg(feature = "error")]
+        let mut converged = false;
+
+        let mut terms = 0_usize;
+        while terms < max_terms {
+            let term = sign * power * odd_harmonic;
+            sum += term;
+            #[cfg(feature = "error")]
+            {
+                abs_last = term.abs();
+                abs_sum += abs_last;
+            }
+            terms = terms.saturating_add(1);
+            if term.abs() <= f64::EPSILON * sum.abs() {
+                #[cfg(feature = "error")]
+                {
+                    converged = true;
+                }
+                break;
+            }
+
+            n += 1_f64;
+            n_is_odd = !n_is_odd;
+            sign = -sign;
+            power *= raw / (2_f64 * n);
+            if n_is_odd {
+                odd_harmonic += n.recip();
+            }
+        }
+
+        let half_exp = libm::exp(0.5_f64 * raw);
+        let log_part = consts::EULER_GAMMA + libm::log(raw);
+        let value = log_part + half_exp * sum;
+
+        #[cfg(feature = "error")]
+        let error = {
+            let rounding = constants::GSL_DBL_EPSILON
+                * (half_exp * abs_sum + log_part.abs() + 2_f64 * value.abs());
+            // The terms alternate in sign, so once their magnitudes only shrink,
+            // what's left out is at most the first term left out.
+            // Each ratio of magnitudes is at most `x (n + 2) / 2 (n + 1)^2`, which falls as `n` grows.
+            // Before that, each magnitude is at most `x (x/2)^(n-1) / (n-1)!`, so all of them sum to at most `x e^(x/2)`:
+            let truncation = if converged {
+                half_exp * abs_last
+            } else if raw * (n + 2_f64) <= 2_f64 * (n + 1_f64) * (n + 1_f64) {
+                half_exp * power * odd_harmonic
+            } else {
+                half_exp * raw * half_exp
+            };
+            NonNegative::new(Finite::new(rounding + truncation))
+        };
+
+        Ok(Converged {
+            approx: Approx {
+                value: Finite::new(value),
+                #[cfg(feature = "error")]
+                error,
+            },
+            terms,
+        })
+    }
+}
+
+pub mod series {
+    //! The power series about 0,
+    //! $\text{Ei}(x) = \gamma + \ln |x| + \sum_{k=1}^{\infty} \frac{x^k}{k \cdot k!}$,
+    //! with $E_{1}(x) = -\text{Ei}(-x)$.
+    //!
+    //! It converges everywhere, but its terms cancel for large negative arguments to Ei
+    //! (i.e. large positive arguments to E1), so it's best for inputs at most a few in magnitude.
+
+    use {
+        crate::{Approx, Error, algorithms::Converged, constants, consts},
+        sigma_types::{Finite, Negative, NonZero, Positive},
+    };
+
+    #[cfg(feature = "error")]
+    use sigma_types::NonNegative;
+
+    /// Sum the series for Ei at `x`, whose magnitude is assumed to be below `XMAX`.
+    #[inline]
+    fn ei_unchecked(x: f64, max_terms: usize) -> Converged {
+        // `x^k / k!`, starting at `k = 1`:
+        let mut power = x;
+        let mut k = 1_f64;
+
+        let mut sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_last = 0_f64;
+        #[cfg(feature = "error")]
+        let mut converged = false;
+
+        let mut terms = 0_usize;
+        while terms < max_terms {
+            let term = power / k;
+            sum += term;
+            #[cfg(feature = "error")]
+            {
+                abs_last = term.abs();
+                abs_sum += abs_last;
+            }
+            terms = terms.saturating_add(1);
+            if term.abs() <= f64::EPSILON * sum.abs() {
+                #[cfg(feature = "error")]
+                {
+                    converged = true;
+                }
+                break;
+            }
+
+            k += 1_f64;
+            power *= x / k;
+        }
+
+        let log_part = consts::EULER_GAMMA + libm::log(x.abs());
+        let value = log_part + sum;
+
+        #[cfg(feature = "error")]
+        let error = {
+            let rounding =
+                constants::GSL_DBL_EPSILON * (abs_sum + log_part.abs() + 2_f64 * value.abs());
+            // Cut short, what's left out starts at `x^k / (k k!)`, and each magnitude after it
+            // is `|x| j / (j + 1)^2 < |x| / (j + 1)` times the one before, which falls as `j` grows.
+            // Sum the magnitudes one by one until that ratio is below a half, then bound the rest geometrically:
+            let truncation = if converged {
+                abs_last
+            } else {
+                let magnitude = x.abs();
+                let mut next = (power / k).abs();
+                let mut j = k;
+                let mut tail = 0_f64;
+                loop {
+                    let ratio = magnitude / (j + 1_f64);
+                    if ratio < 0.5_f64 {
+                        break tail + next / (1_f64 - ratio);
+                    }
+                    tail += next;
+                    next *= ratio * j / (j + 1_f64);
+                    j += 1_f64;
+                }
+            };
+            NonNegative::new(Finite::new(rounding + truncation))
+        };
+
+        Converged {
+            approx: Approx {
+                value: Finite::new(value),
+                #[cfg(feature = "error")]
+                error,
+            },
+            terms,
+        }
+    }
+
+    /// E1 via the power series,
+    /// summing at most `max_terms` terms but stopping early
+    /// as soon as a term no longer changes the sum.
+    /// # Errors
+    /// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 700).
+    #[inline]
+    pub fn E1(x: NonZero<Finite<f64>>, max_terms: usize) -> Result<Converged, Error> {
+        if **x <= constants::NXMAX {
+            return Err(Error::ArgumentTooNegative(Negative::new(*x)));
+        }
+        if **x >= constants::XMAX {
+            return Err(Error::ArgumentTooPositive(Positive::new(*x)));
+        }
+        let mut converged = ei_unchecked(-**x, max_terms);
+        converged.approx.value = Finite::new(-*converged.approx.value);
+        Ok(converged)
+    }
+
+    /// Ei via the power series,
+    /// summing at most `max_terms` terms but stopping early
+    /// as soon as a term no longer changes the sum.
+    /// # Errors
+    /// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 700).
+    #[inline]
+    pub fn Ei(x: NonZero<Finite<f64>>, max_terms: usize) -> Result<Converged, Error> {
+        if **x <= constants::NXMAX {
+            return Err(Error::ArgumentTooNegative(Negative::new(*x)));
+        }
+        if **x >= constants::XMAX {
+            return Err(Error::ArgumentTooPositive(Positive::new(*x)));
+        }
+        Ok(ei_unchecked(**x, max_terms))
+    }
+}
+
+use crate::Approx;
+
+/// An approximation alongside the number of terms it took to compute.
+#[expect(clippy::exhaustive_structs, reason = "Simple structure")]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Converged {
+    /// The approximation itself.
+    pub approx: Approx,
+    /// Number of terms summed.
+    pub terms: usize,
+}
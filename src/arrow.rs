@@ -0,0 +1,105 @@
+//! Column-wise evaluation over Apache Arrow arrays,
+//! e.g. as a vectorized UDF in `polars` or `datafusion`.
+
+use {
+    crate::Precision,
+    arrow_array::Float64Array,
+    core::{error, fmt},
+    sigma_types::{Finite, NonZero},
+};
+
+/// Why a valid (non-null) entry couldn't be evaluated.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[expect(
+    clippy::error_impl_error,
+    reason = "the name every caller already uses, qualified as `exponential_integral::arrow::Error`"
+)]
+pub enum Error {
+    /// Input was zero, infinite, or NaN.
+    Domain(f64),
+    /// Input was finite and nonzero, but too large in magnitude.
+    Range(crate::Error),
+}
+
+impl fmt::Display for Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Domain(arg) => write!(f, "Argument outside the domain: {arg}"),
+            Self::Range(ref e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "`Display` already includes any wrapped `crate::Error`, so there's no separate cause, and the rest are deprecated, unstable, or sealed"
+)]
+impl error::Error for Error {}
+
+/// Check that an entry is finite and nonzero.
+#[inline]
+fn check(x: f64) -> Result<NonZero<Finite<f64>>, Error> {
+    Finite::try_new(x)
+        .and_then(NonZero::try_new)
+        .ok_or(Error::Domain(x))
+}
+
+/// E1 over every entry of an array.
+/// Null entries stay null, and entries that can't be evaluated
+/// (zero, non-finite, or too large in magnitude) become null.
+#[inline]
+#[must_use]
+pub fn E1(array: &Float64Array, max_precision: Precision) -> Float64Array {
+    array.unary_opt(|x| {
+        let approx = crate::E1(check(x).ok()?, max_precision).ok()?;
+        Some(*approx.value)
+    })
+}
+
+/// E1 over every entry of an array, reusing its buffer if it isn't shared.
+/// Null entries are left untouched.
+/// # Errors
+/// `Err(array)` (the original array) if its buffer is shared with another array;
+/// `Ok(Err(..))` if any valid entry can't be evaluated.
+#[inline]
+pub fn E1_in_place(
+    array: Float64Array,
+    max_precision: Precision,
+) -> Result<Result<Float64Array, Error>, Float64Array> {
+    array.try_unary_mut(|x| {
+        crate::E1(check(x)?, max_precision)
+            .map(|approx| *approx.value)
+            .map_err(Error::Range)
+    })
+}
+
+/// Ei over every entry of an array.
+/// Null entries stay null, and entries that can't be evaluated
+/// (zero, non-finite, or too large in magnitude) become null.
+#[inline]
+#[must_use]
+pub fn Ei(array: &Float64Array, max_precision: Precision) -> Float64Array {
+    array.unary_opt(|x| {
+        let approx = crate::Ei(check(x).ok()?, max_precision).ok()?;
+        Some(*approx.value)
+    })
+}
+
+/// Ei over every entry of an array, reusing its buffer if it isn't shared.
+/// Null entries are left untouched.
+/// # Errors
+/// `Err(array)` (the original array) if its buffer is shared with another array;
+/// `Ok(Err(..))` if any valid entry can't be evaluated.
+#[inline]
+pub fn Ei_in_place(
+    array: Float64Array,
+    max_precision: Precision,
+) -> Result<Result<Float64Array, Error>, Float64Array> {
+    array.try_unary_mut(|x| {
+        crate::Ei(check(x)?, max_precision)
+            .map(|approx| *approx.value)
+            .map_err(Error::Range)
+    })
+}
@@ -0,0 +1,86 @@
+//! Ei with a memory, e.g. for Monte Carlo codes that keep landing on the same arguments.
+//!
+//! A direct-mapped cache: each argument's bit pattern is hashed (Fibonacci hashing, i.e. multiplied by $2^{64} / \varphi$)
+//! to exactly one slot, which holds the last argument hashed there alongside its result.
+//! A lookup is one multiplication and one comparison,
+//! and a new argument simply replaces whatever was in its slot,
+//! so there's no bookkeeping to slow down a hit the way an LRU list would.
+//! Results are exactly what `Ei` returns, errors included, to the bit.
+//!
+//! No original C code: GSL evaluates from scratch every time.
+
+extern crate alloc;
+
+use {
+    crate::{Approx, Error, Precision},
+    alloc::{boxed::Box, vec},
+    core::num::NonZeroUsize,
+    sigma_types::{Finite, NonZero},
+};
+
+/// $\frac{2^{64}}{\varphi}$, rounded to an odd number,
+/// which scatters nearby bit patterns (e.g. points on a grid) across the whole cache.
+const FIBONACCI: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The bit pattern of the last argument hashed to a slot, alongside `Ei` there, if any has been.
+type Slot = Option<(u64, Result<Approx, Error>)>;
+
+/// Ei, remembering the most recent result in each of a fixed number of slots.
+#[derive(Clone, Debug)]
+pub struct CachedEi {
+    /// As in `Ei`, fixed for the cache's lifetime so that every result in it agrees.
+    max_precision: Precision,
+    /// How far to shift a hash down to leave a slot's index.
+    shift: u32,
+    /// One entry per possible hash.
+    slots: Box<[Slot]>,
+}
+
+impl CachedEi {
+    /// $\text{Ei}(x)$, exactly as `Ei` at the crate root would give, but from the cache if it's there.
+    /// # Errors
+    /// See `Ei` at the crate root.
+    #[inline]
+    pub fn Ei(&mut self, x: NonZero<Finite<f64>>) -> Result<Approx, Error> {
+        let bits = (**x).to_bits();
+        let hash = bits
+            .wrapping_mul(FIBONACCI)
+            .checked_shr(self.shift)
+            .unwrap_or(0);
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            reason = "under the number of slots, which is a `usize`"
+        )]
+        let index = hash as usize;
+        let Some(slot) = self.slots.get_mut(index) else {
+            return crate::Ei(x, self.max_precision);
+        };
+        if let Some((cached, result)) = *slot
+            && cached == bits
+        {
+            return result;
+        }
+        let result = crate::Ei(x, self.max_precision);
+        *slot = Some((bits, result));
+        result
+    }
+
+    /// Forget every result, e.g. to start over with a fresh working set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.slots.fill(None);
+    }
+
+    /// An empty cache of at least `capacity` slots (rounded up to a power of two).
+    #[inline]
+    #[must_use]
+    pub fn new(capacity: NonZeroUsize, max_precision: Precision) -> Self {
+        let slots = capacity.get().next_power_of_two();
+        Self {
+            max_precision,
+            shift: u64::BITS.wrapping_sub(slots.trailing_zeros()),
+            slots: vec![None; slots].into_boxed_slice(),
+        }
+    }
+}
@@ -1,14 +1,930 @@
 //! Chebyshev series/polynomial approximation.
 
+pub mod gsl {
+    //! GSL's Chebyshev fits behind E1 in double precision.
+    //!
+    //! Copied from
+    //! [`specfunc/expint.c`](https://github.com/ampl/gsl/blob/ff49e28bdffb893a1c0f6e3eff151296e0e71f82/specfunc/expint.c),
+    //! which took them from W. Fullerton's SLATEC routine `e1.f`, each good to about 16 significant digits.
+    //!
+    //! Each is on $[-1, 1]$, as in GSL, so each piece of E1 first maps its own argument onto it
+    //! (given alongside each fit below), and, as GSL sums them, the first coefficient counts half.
+    //! These are always GSL's own tables, even with the `tuned` feature
+    //! (which only changes the copies the pieces of E1 themselves sum).
+    //!
+    //! They're here to inspect, to cut short (e.g. with `Series::truncate_to_tolerance`,
+    //! or at runtime with `Series::eval_to_tolerance`),
+    //! or to build on (scaled variants, say) without copying them out of GSL.
+
+    use crate::{chebyshev::Series, constants};
+
+    /// `AE11_cs`, for $x \leq -10$:
+    /// $E_{1}(x) = \frac{e^{-x}}{x} \left( 1 + \text{AE11}\left( \frac{20}{x} + 1 \right) \right)$.
+    pub const AE11: Series<{ constants::size::AE11 }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: constants::AE11,
+        order_sp: 20,
+    };
+
+    /// `AE12_cs`, for $-10 < x \leq -4$:
+    /// $E_{1}(x) = \frac{e^{-x}}{x} \left( 1 + \text{AE12}\left( \frac{40 / x + 7}{3} \right) \right)$.
+    pub const AE12: Series<{ constants::size::AE12 }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: constants::AE12,
+        order_sp: 15,
+    };
+
+    /// `AE13_cs`, for $1 < x \leq 4$:
+    /// $E_{1}(x) = \frac{e^{-x}}{x} \left( 1 + \text{AE13}\left( \frac{8 / x - 5}{3} \right) \right)$.
+    pub const AE13: Series<{ constants::size::AE13 }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: constants::AE13,
+        order_sp: 15,
+    };
+
+    /// `AE14_cs`, for $x > 4$:
+    /// $E_{1}(x) = \frac{e^{-x}}{x} \left( 1 + \text{AE14}\left( \frac{8}{x} - 1 \right) \right)$.
+    pub const AE14: Series<{ constants::size::AE14 }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: constants::AE14,
+        order_sp: 13,
+    };
+
+    /// `E11_cs`, for $-4 < x \leq -1$:
+    /// $E_{1}(x) = -\ln |x| + \text{E11}\left( \frac{2 x + 5}{3} \right)$.
+    pub const E11: Series<{ constants::size::E11 }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: constants::E11,
+        order_sp: 13,
+    };
+
+    /// `E12_cs`, for $-1 < x \leq 1$ (but not 0):
+    /// $E_{1}(x) = -\ln |x| - 0.6875 + x + \text{E12}(x)$.
+    pub const E12: Series<{ constants::size::E12 }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: constants::E12,
+        order_sp: 10,
+    };
+}
+
 use {
-    crate::Approx,
-    sigma_types::{Finite, Zero as _, usize::LessThan},
+    crate::{Approx, BatchError, Error, Precision, real::Real as _},
+    core::{
+        f64::consts::PI,
+        mem,
+        ops::{Add, Mul, Sub},
+    },
+    sigma_types::{Finite, Zero, usize::LessThan},
 };
 
 #[cfg(feature = "error")]
 use {crate::constants, sigma_types::NonNegative};
 
+#[cfg(feature = "quad")]
+use crate::double_double::DoubleDouble;
+
+#[cfg(any(feature = "compensated", feature = "rigorous-error"))]
+use crate::double_double::{two_prod, two_sum};
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+#[cfg(feature = "simd")]
+use core::simd::Simd;
+
+/// How many points `eval_many` steps through the recurrence together:
+/// one 256-bit vector of `f64`s, or two 128-bit ones.
+pub(crate) const LANES: usize = 4;
+
+/// A type a Chebyshev series can be summed in: real or complex, at any precision.
+///
+/// Implemented for `f32`, `f64`, and `Finite<f64>`,
+/// for `DoubleDouble` with the `quad` feature,
+/// for `Complex` numbers of any of these with the `num-complex` feature
+/// (wherever `num-complex` itself provides the arithmetic),
+/// and for SIMD vectors of `f64` with the `simd` feature.
+pub trait Scalar: Add<Output = Self> + Copy + Mul<Output = Self> + Sub<Output = Self> {
+    /// Zero.
+    const ZERO: Self;
+
+    /// How each coefficient is stored.
+    type Coefficient: Copy;
+
+    /// A coefficient, as this type.
+    #[must_use]
+    fn coefficient(c: Self::Coefficient) -> Self;
+
+    /// This value times a power of two (exactly, barring overflow and underflow).
+    #[must_use]
+    fn scale(self, power_of_two: f64) -> Self;
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0_f32;
+
+    type Coefficient = f64;
+
+    #[inline]
+    fn coefficient(c: f64) -> Self {
+        Self::from_f64(c)
+    }
+
+    #[inline]
+    fn scale(self, power_of_two: f64) -> Self {
+        Self::from_f64(power_of_two) * self
+    }
+}
+
+impl Scalar for f64 {
+    const ZERO: Self = 0_f64;
+
+    type Coefficient = Self;
+
+    #[inline]
+    fn coefficient(c: Self) -> Self {
+        c
+    }
+
+    #[inline]
+    fn scale(self, power_of_two: Self) -> Self {
+        power_of_two * self
+    }
+}
+
+impl Scalar for Finite<f64> {
+    const ZERO: Self = <Self as Zero>::ZERO;
+
+    type Coefficient = Self;
+
+    #[inline]
+    fn coefficient(c: Self) -> Self {
+        c
+    }
+
+    #[inline]
+    fn scale(self, power_of_two: f64) -> Self {
+        self.map(|value| power_of_two * value)
+    }
+}
+
+#[cfg(feature = "quad")]
+impl Scalar for DoubleDouble {
+    const ZERO: Self = Self {
+        hi: 0_f64,
+        lo: 0_f64,
+    };
+
+    type Coefficient = (f64, f64);
+
+    #[inline]
+    fn coefficient((hi, lo): (f64, f64)) -> Self {
+        Self { hi, lo }
+    }
+
+    #[inline]
+    fn scale(self, power_of_two: f64) -> Self {
+        Self {
+            hi: power_of_two * self.hi,
+            lo: power_of_two * self.lo,
+        }
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<S: Scalar> Scalar for Complex<S>
+where
+    Self: Add<Output = Self> + Mul<Output = Self> + Sub<Output = Self>,
+{
+    const ZERO: Self = Self::new(S::ZERO, S::ZERO);
+
+    type Coefficient = S::Coefficient;
+
+    #[inline]
+    fn coefficient(c: S::Coefficient) -> Self {
+        Self::new(S::coefficient(c), S::ZERO)
+    }
+
+    #[inline]
+    fn scale(self, power_of_two: f64) -> Self {
+        Self::new(self.re.scale(power_of_two), self.im.scale(power_of_two))
+    }
+}
+
+#[cfg(feature = "simd")]
+impl<const N: usize> Scalar for Simd<f64, N> {
+    const ZERO: Self = Self::from_array([0_f64; N]);
+
+    type Coefficient = f64;
+
+    #[inline]
+    fn coefficient(c: f64) -> Self {
+        Self::splat(c)
+    }
+
+    #[inline]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "floating-point arithmetic saturates instead of panicking"
+    )]
+    fn scale(self, power_of_two: f64) -> Self {
+        Self::splat(power_of_two) * self
+    }
+}
+
+/// A sum cut as short as a tolerance allows, by `Series::eval_to_tolerance`,
+/// alongside how close it came and what it cost.
+#[expect(clippy::exhaustive_structs, reason = "Simple structure")]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Adaptive {
+    /// The sum, whose error estimate (with the `error` feature) counts `bound` as well as the rounding.
+    pub approx: Approx,
+    /// The most the terms left out could have added, anywhere on the interval (at most the tolerance).
+    pub bound: f64,
+    /// How many leading coefficients were summed.
+    pub terms: usize,
+}
+
+/// How quickly a series' coefficients fall off, for `Precision::RelTolerance` to pick where to stop it.
+pub(crate) struct Decay<const N_COEFFICIENTS: usize> {
+    /// The smallest that the result gets relative to the series' own weight in it, anywhere the series is used,
+    /// so that dividing a truncation error in the series by this bounds the relative error it causes.
+    floor: f64,
+    /// The most that stopping at each order $k$ can change the sum by, $\sum_{j > k} |c_{j}|$, since $|T_{j}| \leq 1$.
+    tails: [f64; N_COEFFICIENTS],
+}
+
+impl<const N_COEFFICIENTS: usize> Decay<N_COEFFICIENTS> {
+    /// The tails of `coefficients`, alongside the `floor` of whatever function sums them.
+    #[inline]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub(crate) const fn new(coefficients: &[f64; N_COEFFICIENTS], floor: f64) -> Self {
+        let mut tails = [0_f64; N_COEFFICIENTS];
+        let mut k = N_COEFFICIENTS.saturating_sub(1);
+        while k > 0 {
+            tails[k.wrapping_sub(1)] = tails[k] + coefficients[k].abs();
+            k = k.wrapping_sub(1);
+        }
+        Self { floor, tails }
+    }
+
+    /// The highest order to sum for `precision`: at most the last, and for a tolerance,
+    /// the lowest whose tail keeps the relative error under it.
+    #[inline]
+    pub(crate) const fn order(&self, precision: Precision) -> usize {
+        let last = N_COEFFICIENTS.saturating_sub(1);
+        match precision {
+            Precision::Full => last,
+            Precision::RelTolerance(tolerance) => {
+                let allowed = tolerance * self.floor;
+                let mut order = 0;
+                let mut remaining = self.tails.as_slice();
+                while let [tail, ref rest @ ..] = *remaining {
+                    if tail <= allowed {
+                        return order;
+                    }
+                    order = order.wrapping_add(1);
+                    remaining = rest;
+                }
+                last
+            }
+            Precision::Terms(order) => min(order, last),
+        }
+    }
+
+    /// `sum(order)`, the series summed through the `order` for `precision`, `widen`ed to count what it leaves out.
+    #[inline]
+    pub(crate) fn sum(&self, precision: Precision, sum: impl FnOnce(usize) -> Approx) -> Approx {
+        let order = self.order(precision);
+        self.widen(sum(order), order)
+    }
+
+    /// `approx`, the series summed through `order`, with its error estimate (with the `error` feature)
+    /// also counting every coefficient past `order`, since GSL's last coefficient only stands in for what the full table leaves out.
+    #[inline]
+    #[cfg_attr(
+        feature = "error",
+        expect(
+            clippy::arithmetic_side_effects,
+            reason = "property-based testing ensures this never happens"
+        )
+    )]
+    #[cfg_attr(
+        not(feature = "error"),
+        expect(
+            clippy::missing_const_for_fn,
+            clippy::unused_self,
+            unused_mut,
+            unused_variables,
+            reason = "no estimate to widen without the `error` feature"
+        )
+    )]
+    pub(crate) fn widen(&self, mut approx: Approx, order: usize) -> Approx {
+        #[cfg(feature = "error")]
+        {
+            approx.error +=
+                NonNegative::new(Finite::new(self.tails.get(order).copied().unwrap_or(0_f64)));
+        }
+        approx
+    }
+}
+
+/// A Chebyshev series on the interval $[a, b]$, as GSL's `cheb_series` has it.
+///
+/// `coefficients` must all be finite, which is checked whenever debug assertions are.
+#[expect(clippy::exhaustive_structs, reason = "Simple structure")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Series<const N_COEFFICIENTS: usize> {
+    /// Lower end of the interval.
+    pub a: f64,
+    /// Upper end of the interval.
+    pub b: f64,
+    /// Coefficients of $T_{0}$ (halved in the sum, as in GSL) through $T_{N - 1}$.
+    pub coefficients: [f64; N_COEFFICIENTS],
+    /// The order GSL stops at in its single-precision mode.
+    pub order_sp: usize,
+}
+
+impl<const N_COEFFICIENTS: usize> Series<N_COEFFICIENTS> {
+    /// The series of the derivative with respect to `x` (not the mapped argument), on the same interval.
+    ///
+    /// Works down from the top through $c'_{k - 1} = c'_{k + 1} + 2 k c_{k}$ (starting from $c'_{N - 1} = c'_{N} = 0$),
+    /// then scales by $\frac{2}{b - a}$ for the mapping, so the last coefficient is always zero.
+    /// Meant for compile time, e.g. to hand a Newton solver its derivative for free.
+    ///
+    /// Differentiating amplifies the later coefficients (by up to $2 N$), and with them the truncation error,
+    /// so expect a few digits fewer than the original series.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn derivative(&self) -> Self {
+        let mut coefficients = [0_f64; N_COEFFICIENTS];
+        let mut k = N_COEFFICIENTS.saturating_sub(1);
+        while k > 0 {
+            let above = if k.wrapping_add(1) < N_COEFFICIENTS {
+                coefficients[k.wrapping_add(1)]
+            } else {
+                0_f64
+            };
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_precision_loss,
+                reason = "far fewer than $2^{52}$ coefficients"
+            )]
+            let multiple = k as f64;
+            coefficients[k.wrapping_sub(1)] = above + 2_f64 * multiple * self.coefficients[k];
+            k = k.wrapping_sub(1);
+        }
+        let scale = 2_f64 / (self.b - self.a);
+        let mut i = 0;
+        while i < N_COEFFICIENTS {
+            coefficients[i] *= scale;
+            i = i.wrapping_add(1);
+        }
+        Self {
+            a: self.a,
+            b: self.b,
+            coefficients,
+            order_sp: self.order_sp.saturating_sub(1),
+        }
+    }
+
+    /// Sum the series at `x`, mapped from $[a, b]$ onto $[-1, 1]$,
+    /// or `Error::Domain` if `x` is outside $[a, b]$.
+    ///
+    /// Stops at `order` (or the last coefficient, if that's lower).
+    /// # Errors
+    /// If `x` is outside $[a, b]$.
+    #[inline]
+    pub const fn eval(&self, x: Finite<f64>, order: usize) -> Result<Approx, Error> {
+        if *x.get_ref() < self.a || *x.get_ref() > self.b {
+            return Err(Error::Domain(x));
+        }
+        Ok(self.eval_mapped(self.map(x), order))
+    }
+
+    /// `eval` at every entry of `xs` into the same position in `out`, through `eval_many`'s lockstep recurrence
+    /// (so with the same bits `eval` would give each on its own).
+    /// Every entry is checked up front, so nothing is written unless everything can be.
+    /// # Errors
+    /// If the lengths differ, or at the first entry outside $[a, b]$.
+    #[inline]
+    pub fn eval_many(
+        &self,
+        xs: &[Finite<f64>],
+        out: &mut [Approx],
+        order: usize,
+    ) -> Result<(), BatchError> {
+        if xs.len() != out.len() {
+            return Err(BatchError::LengthMismatch {
+                inputs: xs.len(),
+                outputs: out.len(),
+            });
+        }
+        if let Some((index, x)) = xs
+            .iter()
+            .enumerate()
+            .find(|&(_, x)| !(self.a..=self.b).contains(&**x))
+        {
+            return Err(BatchError::Domain { index, value: **x });
+        }
+        let all: &[Finite<f64>] = Finite::all(&self.coefficients);
+        let used = all.get(..=order).unwrap_or(all);
+        for (chunk, results) in xs.chunks(LANES).zip(out.chunks_mut(LANES)) {
+            let mut ys = [<Finite<f64> as Zero>::ZERO; LANES];
+            for (y, &x) in ys.iter_mut().zip(chunk) {
+                *y = self.map(x);
+            }
+            lockstep(
+                used,
+                #[cfg(feature = "error")]
+                Finite::new(self.coefficients.last().copied().unwrap_or(0_f64)),
+                ys.get(..chunk.len()).unwrap_or(&ys),
+                results,
+            );
+        }
+        Ok(())
+    }
+
+    /// Sum the series at `y`, already mapped onto $[-1, 1]$, as `eval` does after checking and mapping `x`.
+    ///
+    /// For pieces that work out `y` themselves (as GSL's do, on series over $[-1, 1]$).
+    /// Outside $[-1, 1]$, the sum means nothing.
+    #[inline]
+    #[must_use]
+    pub const fn eval_mapped(&self, y: Finite<f64>, order: usize) -> Approx {
+        eval(
+            finite_all(&self.coefficients),
+            y,
+            less_than(min(order, const { N_COEFFICIENTS - 1 })),
+        )
+    }
+
+    /// Sum the series at `x`, mapped from $[a, b]$ onto $[-1, 1]$, over only as many terms as it takes
+    /// to keep the truncation error under `tolerance`, or `Error::Domain` if `x` is outside $[a, b]$.
+    ///
+    /// Clenshaw's recurrence runs from the last term down, so it can't simply stop once the sum looks close enough.
+    /// Instead, the terms left out are chosen first, as in `truncate_to_tolerance`,
+    /// from how quickly the coefficients fall off: dropping $c_{k}$ onward changes the sum by at most $\sum_{j \geq k} |c_{j}|$.
+    /// Only the rest are summed, so a looser tolerance costs fewer steps, and `terms` says how many.
+    ///
+    /// With the `error` feature, the error estimate is that bound, in place of GSL's last coefficient, plus the rounding.
+    /// There's no `order` to give: the tolerance decides.
+    /// # Errors
+    /// If `x` is outside $[a, b]$.
+    #[inline]
+    pub const fn eval_to_tolerance(
+        &self,
+        x: Finite<f64>,
+        tolerance: f64,
+    ) -> Result<Adaptive, Error> {
+        if *x.get_ref() < self.a || *x.get_ref() > self.b {
+            return Err(Error::Domain(x));
+        }
+        let Truncated { bound, terms, .. } = self.truncate_to_tolerance(tolerance);
+        let (used, _) = finite_all(&self.coefficients).split_at(terms);
+        Ok(Adaptive {
+            approx: series(
+                used,
+                self.map(x),
+                #[cfg(feature = "error")]
+                finite(bound),
+            ),
+            bound,
+            terms,
+        })
+    }
+
+    /// `eval` alongside the derivative with respect to `x` (not the mapped argument),
+    /// from the one pass of `eval_with_derivative`, scaled by $\frac{2}{b - a}$ for the mapping.
+    /// # Errors
+    /// If `x` is outside $[a, b]$.
+    #[inline]
+    pub fn eval_with_derivative(
+        &self,
+        x: Finite<f64>,
+        order: usize,
+    ) -> Result<(Approx, Approx), Error> {
+        if !(self.a..=self.b).contains(&*x) {
+            return Err(Error::Domain(x));
+        }
+        let (value, derivative) = eval_with_derivative(
+            Finite::all(&self.coefficients),
+            self.map(x),
+            LessThan::new(order.min(const { N_COEFFICIENTS - 1 })),
+        );
+        let scale = 2_f64 / (self.b - self.a);
+        Ok((
+            value,
+            Approx {
+                value: Finite::new(scale * *derivative.value),
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(scale.abs() * **derivative.error)),
+            },
+        ))
+    }
+
+    /// The series interpolating `f` at the $N$ extrema of $T_{N - 1}$ on $[a, b]$,
+    /// i.e. $x_{j} = \frac{a + b}{2} + \frac{b - a}{2} \cos \frac{\pi j}{N - 1}$,
+    /// by the discrete cosine transform
+    /// $c_{k} = \frac{2}{N - 1} {\sum_{j}}'' f(x_{j}) \cos \frac{\pi j k}{N - 1}$
+    /// (halving the first and last terms), with the last coefficient halved too,
+    /// since $T_{N - 1}$ can't be told apart from its own alias at these points.
+    ///
+    /// For smooth `f`, this is within a small factor of the best approximation of its order,
+    /// and its coefficients fall off just as fast, so `truncate` and the error estimate in `eval` mean what they do for GSL's own tables.
+    /// `f` must be finite everywhere on $[a, b]$, which is checked whenever debug assertions are.
+    #[inline]
+    #[must_use]
+    pub fn fit<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> Self {
+        #![expect(
+            clippy::arithmetic_side_effects,
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            clippy::indexing_slicing,
+            reason = "far fewer than $2^{52}$ coefficients, and every index is below `N`"
+        )]
+
+        let mut coefficients = [0_f64; N_COEFFICIENTS];
+        let (middle, half_width) = (0.5_f64 * (a + b), 0.5_f64 * (b - a));
+        let Some(intervals) = N_COEFFICIENTS.checked_sub(1).filter(|&n| n > 0) else {
+            if let Some(only) = coefficients.first_mut() {
+                // Halved in the sum, so doubled here:
+                *only = 2_f64 * f(middle);
+            }
+            return Self {
+                a,
+                b,
+                coefficients,
+                order_sp: 0,
+            };
+        };
+        let angle = |multiple: usize| {
+            // Reduced modulo a full turn first, so the angle stays exact:
+            libm::cos(PI * multiple.rem_euclid(2 * intervals) as f64 / intervals as f64)
+        };
+        let weight = 2_f64 / intervals as f64;
+        for j in 0..=intervals {
+            let mut sample = weight * f(middle + half_width * angle(j));
+            if j == 0 || j == intervals {
+                sample *= 0.5_f64;
+            }
+            for (k, coefficient) in coefficients.iter_mut().enumerate() {
+                *coefficient += sample * angle(j * k);
+            }
+        }
+        coefficients[intervals] *= 0.5_f64;
+        let _: &[Finite<f64>; N_COEFFICIENTS] = Finite::all(&coefficients);
+        Self {
+            a,
+            b,
+            coefficients,
+            order_sp: intervals,
+        }
+    }
+
+    /// The series of the antiderivative with respect to `x` that equals `constant` at $a$, on the same interval.
+    ///
+    /// Each coefficient is $C_{k} = \frac{b - a}{4} \frac{c_{k - 1} - c_{k + 1}}{k}$ (taking $c_{N} = 0$),
+    /// and $C_{0}$ is whatever puts the sum at $a$ on `constant`.
+    /// The one term that would land past the end, $\frac{b - a}{4 N} c_{N - 1} T_{N}$, is dropped,
+    /// which changes the result by at most that much anywhere on the interval.
+    /// Meant for compile time, e.g. to integrate an approximation cheaply without a new table.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn integral(&self, constant: f64) -> Self {
+        let mut coefficients = [0_f64; N_COEFFICIENTS];
+        let scale = 0.25_f64 * (self.b - self.a);
+        // $\sum_{k \geq 1} C_{k} T_{k}(-1)$, with $T_{k}(-1) = (-1)^{k}$:
+        let mut at_a = 0_f64;
+        let mut sign = -1_f64;
+        let mut k = 1;
+        while k < N_COEFFICIENTS {
+            let below = self.coefficients[k.wrapping_sub(1)];
+            let above = if k.wrapping_add(1) < N_COEFFICIENTS {
+                self.coefficients[k.wrapping_add(1)]
+            } else {
+                0_f64
+            };
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_precision_loss,
+                reason = "far fewer than $2^{52}$ coefficients"
+            )]
+            let multiple = k as f64;
+            coefficients[k] = scale * (below - above) / multiple;
+            at_a += sign * coefficients[k];
+            sign = -sign;
+            k = k.wrapping_add(1);
+        }
+        if N_COEFFICIENTS > 0 {
+            // Halved in the sum, so doubled here:
+            coefficients[0] = 2_f64 * (constant - at_a);
+        }
+        Self {
+            a: self.a,
+            b: self.b,
+            coefficients,
+            order_sp: min(
+                self.order_sp.saturating_add(1),
+                N_COEFFICIENTS.saturating_sub(1),
+            ),
+        }
+    }
+
+    /// `x` mapped from $[a, b]$ onto $[-1, 1]$, exactly as GSL maps it
+    /// (so, on $[-1, 1]$ itself, not at all).
+    #[inline]
+    #[must_use]
+    pub const fn map(&self, x: Finite<f64>) -> Finite<f64> {
+        finite((2_f64 * *x.get_ref() - self.a - self.b) / (self.b - self.a))
+    }
+
+    /// The product of two series on the same interval, by $T_{m} T_{n} = \frac{T_{m + n} + T_{|m - n|}}{2}$,
+    /// cut back to `N` coefficients, or `None` if the intervals differ.
+    ///
+    /// The bound adds up every piece of every term that lands past the end,
+    /// so it never underestimates what cutting the product short changed.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn product(&self, other: &Self) -> Option<Truncated<N_COEFFICIENTS>> {
+        if !self.same_interval(other) {
+            return None;
+        }
+        let mut coefficients = [0_f64; N_COEFFICIENTS];
+        let mut bound = 0_f64;
+        let mut m = 0;
+        while m < N_COEFFICIENTS {
+            // Halved in the sum, so halved here to work with the terms themselves:
+            let left = if m == 0 {
+                0.5_f64 * self.coefficients[0]
+            } else {
+                self.coefficients[m]
+            };
+            let mut n = 0;
+            while n < N_COEFFICIENTS {
+                let right = if n == 0 {
+                    0.5_f64 * other.coefficients[0]
+                } else {
+                    other.coefficients[n]
+                };
+                let half = 0.5_f64 * left * right;
+                let high = m.wrapping_add(n);
+                if high < N_COEFFICIENTS {
+                    coefficients[high] += half;
+                } else {
+                    bound += half.abs();
+                }
+                coefficients[m.abs_diff(n)] += half;
+                n = n.wrapping_add(1);
+            }
+            m = m.wrapping_add(1);
+        }
+        if N_COEFFICIENTS > 0 {
+            // Halved in the sum, so doubled back:
+            coefficients[0] *= 2_f64;
+        }
+        Some(Truncated {
+            bound,
+            series: Self {
+                a: self.a,
+                b: self.b,
+                coefficients,
+                order_sp: min(
+                    self.order_sp.saturating_add(other.order_sp),
+                    N_COEFFICIENTS.saturating_sub(1),
+                ),
+            },
+            terms: N_COEFFICIENTS,
+        })
+    }
+
+    /// Whether `other` is on exactly the same interval.
+    #[inline]
+    #[expect(clippy::float_cmp, reason = "exactly the same interval")]
+    const fn same_interval(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b
+    }
+
+    /// The series times `factor`.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn scale(&self, factor: f64) -> Self {
+        let mut coefficients = self.coefficients;
+        let mut k = 0;
+        while k < N_COEFFICIENTS {
+            coefficients[k] *= factor;
+            k = k.wrapping_add(1);
+        }
+        Self {
+            coefficients,
+            ..*self
+        }
+    }
+
+    /// How far a sum in `f32` should go: through the last coefficient bigger than `f32::EPSILON`,
+    /// past which no term can move a sum of order one, but never past `order_sp`.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn single_order(&self) -> usize {
+        /// `f32::EPSILON`, widened.
+        #[expect(clippy::as_conversions, reason = "lossless, but `From` isn't `const`")]
+        const EPSILON: f64 = f32::EPSILON as f64;
+
+        let mut order = min(self.order_sp, N_COEFFICIENTS.saturating_sub(1));
+        while order > 0 && self.coefficients[order].abs() <= EPSILON {
+            order = order.wrapping_sub(1);
+        }
+        order
+    }
+
+    /// The sum of two series on the same interval, or `None` if the intervals differ.
+    /// To subtract, `scale` one by $-1$ first.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn sum(&self, other: &Self) -> Option<Self> {
+        if !self.same_interval(other) {
+            return None;
+        }
+        let mut coefficients = self.coefficients;
+        let mut k = 0;
+        while k < N_COEFFICIENTS {
+            coefficients[k] += other.coefficients[k];
+            k = k.wrapping_add(1);
+        }
+        Some(Self {
+            a: self.a,
+            b: self.b,
+            coefficients,
+            order_sp: if self.order_sp > other.order_sp {
+                self.order_sp
+            } else {
+                other.order_sp
+            },
+        })
+    }
+
+    /// The first `M` coefficients, each rounded to `f32` (padded with zeros if there aren't that many).
+    /// With `M` one past `single_order`, a copy to sum in single precision, built at compile time.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N` or `M`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn to_f32<const M: usize>(&self) -> [f32; M] {
+        let mut coefficients = [0_f32; M];
+        let mut k = 0;
+        while k < M && k < N_COEFFICIENTS {
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                reason = "rounding is the point"
+            )]
+            let rounded = self.coefficients[k] as f32;
+            coefficients[k] = rounded;
+            k = k.wrapping_add(1);
+        }
+        coefficients
+    }
+
+    /// The first `M` coefficients (padded with zeros if there aren't that many),
+    /// alongside $\sum_{k \geq M} |c_{k}|$, the most that dropping the rest can change the sum anywhere on the interval.
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N` or `M`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn truncate<const M: usize>(&self) -> Truncated<M> {
+        let mut coefficients = [0_f64; M];
+        let mut bound = 0_f64;
+        let mut k = 0;
+        while k < N_COEFFICIENTS {
+            if k < M {
+                coefficients[k] = self.coefficients[k];
+            } else {
+                bound += self.coefficients[k].abs();
+            }
+            k = k.wrapping_add(1);
+        }
+        Truncated {
+            bound,
+            series: Series {
+                a: self.a,
+                b: self.b,
+                coefficients,
+                order_sp: min(self.order_sp, M.saturating_sub(1)),
+            },
+            terms: min(N_COEFFICIENTS, M),
+        }
+    }
+
+    /// The fewest leading coefficients (at least one) whose dropped tail, $\sum_{k \geq \text{terms}} |c_{k}|$,
+    /// adds up to no more than `tolerance`, alongside that sum,
+    /// the most that dropping them can change the sum anywhere on the interval.
+    ///
+    /// The length stays `N`, with every dropped coefficient zeroed,
+    /// so that a tolerance chosen at runtime can still shorten a table:
+    /// hand `terms - 1` to `eval` as the order,
+    /// or `&coefficients[..terms]` to `eval_slice`, to skip the zeros.
+    /// At compile time, `truncate` with `terms` as `M` shortens the array itself (e.g. for `f32` tables).
+    #[inline]
+    #[must_use]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+    )]
+    pub const fn truncate_to_tolerance(&self, tolerance: f64) -> Truncated<N_COEFFICIENTS> {
+        let mut coefficients = self.coefficients;
+        let mut bound = 0_f64;
+        let mut terms = N_COEFFICIENTS;
+        while terms > 1 {
+            let last = terms.wrapping_sub(1);
+            let with_last = bound + coefficients[last].abs();
+            if with_last > tolerance {
+                break;
+            }
+            bound = with_last;
+            coefficients[last] = 0_f64;
+            terms = last;
+        }
+        Truncated {
+            bound,
+            series: Self {
+                a: self.a,
+                b: self.b,
+                coefficients,
+                order_sp: min(self.order_sp, terms.saturating_sub(1)),
+            },
+            terms,
+        }
+    }
+}
+
+/// A series cut short, alongside a bound on how much cutting it changed its sum anywhere on its interval.
+#[expect(clippy::exhaustive_structs, reason = "Simple structure")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Truncated<const N_COEFFICIENTS: usize> {
+    /// The most the sum moved, anywhere on the interval.
+    pub bound: f64,
+    /// What's left.
+    pub series: Series<N_COEFFICIENTS>,
+    /// How many leading coefficients are left, with any after them zero.
+    pub terms: usize,
+}
+
 /// Chebyshev series/polynomial approximation.
+///
+/// With the `compensated` feature, the rounding error of every step of the recurrence is found exactly
+/// and carried through a second recurrence of its own, then added back at the end,
+/// so the sum is about as accurate as the recurrence run in twice the precision.
+/// That matters most for long tables like `AE11` (39 terms), whose rounding error can otherwise reach a few units in the last place,
+/// and costs about three times as many operations (far more without a fused multiply-add instruction, which is then emulated).
+/// The error estimate is GSL's either way, so it's only more conservative.
+///
+/// GSL's error estimate is a heuristic: `GSL_DBL_EPSILON` times the magnitudes of every term summed.
+/// With the `rigorous-error` feature, every step's rounding errors are found exactly (as `compensated` finds them),
+/// and their magnitudes, weighted by how far each can move the sum (see `growth`), are added up instead,
+/// so the estimate is a guaranteed bound on the rounding in the sum, at any `x`, compensated or not.
+/// The last coefficient is still added on top, as GSL's stand-in for the terms the table leaves out.
+/// That costs about as much again as the recurrence itself.
+///
+/// A `const fn` (as are `Series::eval` and `Series::eval_mapped`), since the recurrence needs no `libm`,
+/// so a fixed argument can be summed at compile time, with the same bits as at runtime.
+/// Building `Finite` and `LessThan` arguments in a `const` takes `sigma_types`' own constructors,
+/// which are only `const` in release builds.
 /// # Original C code
 /// ```c
 /// struct cheb_series_struct {
@@ -57,91 +973,668 @@ use {crate::constants, sigma_types::NonNegative};
 /// ```
 #[inline]
 #[must_use]
-pub fn eval<const N_COEFFICIENTS: usize>(
+pub const fn eval<const N_COEFFICIENTS: usize>(
     coefficients: &[Finite<f64>; N_COEFFICIENTS],
     x: Finite<f64>,
-    #[cfg(feature = "precision")] order: LessThan<{ N_COEFFICIENTS }>,
+    order: LessThan<{ N_COEFFICIENTS }>,
 ) -> Approx {
-    #![expect(
-        clippy::arithmetic_side_effects,
-        reason = "property-based testing ensures this never happens"
-    )]
-
     debug_assert!(
         N_COEFFICIENTS > 0,
         "Chebyshev series without any coefficients",
     );
 
-    let two_x: Finite<f64> = Finite::new(2_f64) * x;
+    // SAFETY:
+    // Sigma types ensure validity.
+    let (used, _) = unsafe { coefficients.split_at_unchecked(order.get_ref().wrapping_add(1)) };
 
+    series(
+        used,
+        x,
+        #[cfg(feature = "error")]
+        coefficients[const { N_COEFFICIENTS - 1 }],
+    )
+}
+
+/// The same as `eval`, over coefficients known only at runtime (loaded or generated, say),
+/// or `None` if there are fewer than `order + 1`.
+///
+/// The error estimate still counts the last coefficient given, whatever the order.
+#[inline]
+#[must_use]
+pub const fn eval_slice(
+    coefficients: &[Finite<f64>],
+    x: Finite<f64>,
+    order: usize,
+) -> Option<Approx> {
+    if order >= coefficients.len() {
+        return None;
+    }
+    let (used, _) = coefficients.split_at(order.wrapping_add(1));
     #[cfg(feature = "error")]
-    let mut e = NonNegative::<Finite<f64>>::ZERO;
+    let [.., last_coefficient] = *coefficients else {
+        return None;
+    };
 
-    let mut d = Finite::<f64>::ZERO;
-    let mut dd = Finite::<f64>::ZERO;
+    Some(series(
+        used,
+        x,
+        #[cfg(feature = "error")]
+        last_coefficient,
+    ))
+}
 
-    {
-        let mut j: LessThan<{ N_COEFFICIENTS }> = {
-            #[cfg(feature = "precision")]
-            {
-                order
-            }
-            #[cfg(not(feature = "precision"))]
-            {
-                LessThan::new(const { N_COEFFICIENTS - 1 })
-            }
-        };
-        while *j >= 1 {
-            // SAFETY:
-            // See the `debug_assert` above.
-            let coefficient = *unsafe { coefficients.get_unchecked(*j) };
-            let tmp = d;
-            d = ((two_x * d) - dd) + coefficient;
-            #[cfg(feature = "error")]
-            {
-                e += NonNegative::<Finite<f64>>::new((two_x * tmp).map(f64::abs))
-                    + NonNegative::<Finite<f64>>::new(dd.map(f64::abs))
-                    + NonNegative::<Finite<f64>>::new(coefficient.map(f64::abs));
-            }
-            dd = tmp;
+/// The same series as `eval`, already converted to a polynomial in `x` by `monomial`, by Estrin's scheme.
+///
+/// Adjacent coefficients are paired off as $a_{2i} + a_{2i + 1} x$,
+/// then adjacent pairs as $b_{2i} + b_{2i + 1} x^{2}$, and so on,
+/// so the longest chain of dependent operations grows with $\log_{2} N$ rather than $N$ as in Clenshaw's recurrence.
+/// Each step is a fused multiply-add wherever the target has one.
+///
+/// Only ever uses the full series, so any lower `order` falls back to `eval` on `coefficients`, the original Chebyshev form.
+/// For `x` in $[-1, 1]$, the rounding error is at most about $2 \lceil \log_{2} N \rceil \epsilon \sum_{i} |a_{i}|$,
+/// so the error estimate is that plus the last Chebyshev coefficient, in place of the running sum in `eval`.
+#[inline]
+#[must_use]
+#[cfg(feature = "fast-poly")]
+pub fn eval_estrin<const N_COEFFICIENTS: usize>(
+    coefficients: &[Finite<f64>; N_COEFFICIENTS],
+    monomials: &[f64; N_COEFFICIENTS],
+    x: Finite<f64>,
+    order: LessThan<{ N_COEFFICIENTS }>,
+) -> Approx {
+    if *order < const { N_COEFFICIENTS - 1 } {
+        return eval(coefficients, x, order);
+    }
 
-            j.map_mut(|u| *u -= 1);
+    let mut terms = *monomials;
+    let mut len = N_COEFFICIENTS;
+    let mut power = *x;
+    #[cfg(feature = "error")]
+    let mut levels = 0_u32;
+    while len > 1 {
+        let mut pairs = [0_f64; N_COEFFICIENTS];
+        for (pair, term) in pairs.iter_mut().zip(terms.chunks(2).take(len.div_ceil(2))) {
+            *pair = match *term {
+                // Rounded once where the target has a fused multiply-add instruction,
+                // rather than falling back to a much slower exact emulation where it doesn't:
+                #[cfg(target_feature = "fma")]
+                [lo, hi] => hi.mul_add(power, lo),
+                #[cfg(not(target_feature = "fma"))]
+                [lo, hi] => hi * power + lo,
+                [lo] => lo,
+                _ => 0_f64,
+            };
+        }
+        terms = pairs;
+        len = len.div_ceil(2);
+        power *= power;
+        #[cfg(feature = "error")]
+        {
+            levels = levels.wrapping_add(1);
         }
     }
+    let value = Finite::new(terms.first().copied().unwrap_or(0_f64));
 
+    #[cfg(not(feature = "error"))]
     {
-        #[cfg(feature = "error")]
-        let tmp = d;
+        Approx { value }
+    }
+
+    #[cfg(feature = "error")]
+    {
+        let magnitude: f64 = monomials.iter().map(|a| a.abs()).sum();
+        let last_coefficient = coefficients.last().map_or(0_f64, |c| c.abs());
+        Approx {
+            value,
+            error: NonNegative::new(Finite::new(
+                2_f64 * f64::from(levels) * constants::GSL_DBL_EPSILON * magnitude
+                    + last_coefficient,
+            )),
+        }
+    }
+}
+
+/// `eval` at every entry of `ys` into the same position in `out`.
+///
+/// Rather than summing the whole series at one point before starting the next,
+/// `LANES` points step through the recurrence together,
+/// so each coefficient is loaded once per group, and each step is the same operation on independent lanes,
+/// which the compiler can keep in one vector register apiece.
+/// The arithmetic in each lane is exactly `eval`'s, so every result has the same bits `eval` would give.
+/// # Errors
+/// If the lengths differ, in which case nothing is written.
+#[inline]
+pub fn eval_many<const N_COEFFICIENTS: usize>(
+    coefficients: &[Finite<f64>; N_COEFFICIENTS],
+    ys: &[Finite<f64>],
+    out: &mut [Approx],
+    order: LessThan<{ N_COEFFICIENTS }>,
+) -> Result<(), BatchError> {
+    if ys.len() != out.len() {
+        return Err(BatchError::LengthMismatch {
+            inputs: ys.len(),
+            outputs: out.len(),
+        });
+    }
+    let used = coefficients.get(..=*order).unwrap_or(coefficients);
+    lockstep(
+        used,
         // SAFETY:
-        // Sigma types ensure validity.
-        let coefficient = *unsafe { coefficients.get_unchecked(0) };
-        let half_coefficient = coefficient.map(|c| 0.5_f64 * c);
-        d = x * d - dd + half_coefficient;
+        // Evaluating `N_COEFFICIENTS - 1` at compile time rejects an empty table.
+        #[cfg(feature = "error")]
+        *unsafe { coefficients.get_unchecked(const { N_COEFFICIENTS - 1 }) },
+        ys,
+        out,
+    );
+    Ok(())
+}
+
+/// `eval` alongside the derivative of the series with respect to `x`, as `(value, derivative)`, in one pass.
+///
+/// Differentiating Clenshaw's recurrence $d_{k} = 2 x d_{k + 1} - d_{k + 2} + c_{k}$ term by term
+/// gives $d'_{k} = 2 d_{k + 1} + 2 x d'_{k + 1} - d'_{k + 2}$, which runs right alongside it,
+/// so there's no second pass and no derivative series (as `Series::derivative` builds) to store.
+/// The value has the same bits as `eval`'s (unless `eval` is compensated).
+///
+/// The derivative's error estimate is built the same way as `eval`'s,
+/// with the last coefficient weighted by $(N - 1)^{2}$, the steepest $T_{N - 1}$ gets on $[-1, 1]$.
+#[inline]
+#[must_use]
+#[expect(
+    clippy::similar_names,
+    reason = "each running term beside its own derivative"
+)]
+pub fn eval_with_derivative<const N_COEFFICIENTS: usize>(
+    coefficients: &[Finite<f64>; N_COEFFICIENTS],
+    x: Finite<f64>,
+    order: LessThan<{ N_COEFFICIENTS }>,
+) -> (Approx, Approx) {
+    let used = coefficients.get(..=*order).unwrap_or(coefficients);
+
+    let two_x = 2_f64 * *x;
+    let mut d = 0_f64;
+    let mut dd = 0_f64;
+    let mut d_prime = 0_f64;
+    let mut dd_prime = 0_f64;
+    #[cfg(feature = "error")]
+    let mut e = 0_f64;
+    #[cfg(feature = "error")]
+    let mut e_prime = 0_f64;
+    if let Some((&first, rest)) = used.split_first() {
+        for &c in rest.iter().rev() {
+            #[cfg(feature = "error")]
+            {
+                e += ((two_x * d).abs() + dd.abs()) + c.abs();
+                e_prime += ((2_f64 * d).abs() + (two_x * d_prime).abs()) + dd_prime.abs();
+            }
+            (dd_prime, d_prime) = (d_prime, (2_f64 * d + two_x * d_prime) - dd_prime);
+            (dd, d) = (d, ((two_x * d) - dd) + *c);
+        }
+        let half = 0.5_f64 * *first;
         #[cfg(feature = "error")]
         {
-            e += NonNegative::<Finite<f64>>::new((x * tmp).map(f64::abs))
-                + NonNegative::<Finite<f64>>::new(dd.map(f64::abs))
-                + NonNegative::<Finite<f64>>::new(half_coefficient.map(f64::abs));
+            e += ((*x * d).abs() + dd.abs()) + half.abs();
+            e_prime += (d.abs() + (*x * d_prime).abs()) + dd_prime.abs();
         }
+        (d_prime, d) = ((d + *x * d_prime) - dd_prime, *x * d - dd + half);
     }
 
     #[cfg(feature = "error")]
     // SAFETY:
-    // See `debug_assert`s above.
-    let last_coefficient = *unsafe { coefficients.get_unchecked(const { N_COEFFICIENTS - 1 }) };
+    // Evaluating `N_COEFFICIENTS - 1` at compile time rejects an empty table.
+    let last = unsafe { coefficients.get_unchecked(const { N_COEFFICIENTS - 1 }) }.abs();
+    #[cfg(feature = "error")]
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "far fewer than $2^{52}$ coefficients"
+    )]
+    let steepest = const { N_COEFFICIENTS - 1 } as f64;
+    (
+        Approx {
+            value: Finite::new(d),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(constants::GSL_DBL_EPSILON * e + last)),
+        },
+        Approx {
+            value: Finite::new(d_prime),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                constants::GSL_DBL_EPSILON * e_prime + steepest * steepest * last,
+            )),
+        },
+    )
+}
+
+/// The same recurrence as `eval`, in any `Scalar` type and without an error estimate,
+/// through every coefficient given (so truncate the slice to evaluate to a lower order).
+#[inline]
+#[must_use]
+pub fn eval_scalar<S: Scalar>(coefficients: &[S::Coefficient], x: S) -> S {
+    recurrence(coefficients, x, S::coefficient)
+}
+
+/// The same recurrence as `eval_scalar`, at every lane of `x` at once,
+/// each lane stepping through the coefficients in lockstep with the others.
+#[inline]
+#[must_use]
+#[cfg(feature = "simd")]
+pub fn eval_simd<const N: usize>(coefficients: &[f64], x: Simd<f64, N>) -> Simd<f64, N> {
+    eval_scalar(coefficients, x)
+}
+
+/// Clenshaw's recurrence in any `Scalar` type, the one implementation behind `eval_scalar`,
+/// over coefficients stored however `coefficient` reads them
+/// (as `real` reads its single-precision tables, which aren't any type's `Scalar::Coefficient`).
+#[inline]
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "property-based testing ensures this never happens"
+)]
+pub(crate) fn recurrence<S: Scalar, C: Copy>(
+    coefficients: &[C],
+    x: S,
+    coefficient: impl Fn(C) -> S,
+) -> S {
+    let two_x = x.scale(2_f64);
+    let mut d = S::ZERO;
+    let mut dd = S::ZERO;
+    let Some((&first, rest)) = coefficients.split_first() else {
+        return d;
+    };
+    for &c in rest.iter().rev() {
+        let tmp = d;
+        d = ((two_x * d) - dd) + coefficient(c);
+        dd = tmp;
+    }
+    x * d - dd + coefficient(first).scale(0.5_f64)
+}
+
+/// An $r$ with $|T_{k}(x)| \leq r^{k}$ for every $k$: 1 on $[-1, 1]$, and $2 |x|$ outside it.
+///
+/// Rounding $\delta_{k}$ at step $k$ of Clenshaw's recurrence leaves every later step exactly as if $c_{k}$ had been $c_{k} + \delta_{k}$,
+/// so it moves the sum by exactly $\delta_{k} T_{k}(x)$, and by no more than $|\delta_{k}| r^{k}$.
+/// Working down from the top, `rigorous-error` adds these up by Horner's scheme in $r$.
+#[inline]
+#[cfg(feature = "rigorous-error")]
+const fn growth(x: f64) -> f64 {
+    if x.abs() <= 1_f64 {
+        1_f64
+    } else {
+        2_f64 * x.abs()
+    }
+}
+
+/// The most one step of Clenshaw's recurrence, $m d - d' + c$, is off by:
+/// the magnitudes of its three rounding errors (from `step_with_errors`), added up.
+#[inline]
+#[cfg(feature = "rigorous-error")]
+const fn step_error(multiplier: f64, d: f64, dd: f64, c: f64) -> f64 {
+    let (_, [product_error, difference_error, sum_error]) = step_with_errors(multiplier, d, dd, c);
+    (product_error.abs() + difference_error.abs()) + sum_error.abs()
+}
+
+/// One step of Clenshaw's recurrence, $m d - d' + c$, rounded exactly as the plain recurrence rounds it,
+/// alongside the sum of its three rounding errors
+/// (exact to within a rounding of their own, which is all compensation needs).
+#[inline]
+#[cfg(feature = "compensated")]
+const fn step_exactly(multiplier: f64, d: f64, dd: f64, c: f64) -> (f64, f64) {
+    let (sum, [product_error, difference_error, sum_error]) =
+        step_with_errors(multiplier, d, dd, c);
+    (sum, (product_error + difference_error) + sum_error)
+}
+
+/// One step of Clenshaw's recurrence, $m d - d' + c$, rounded exactly as the plain recurrence rounds it,
+/// alongside each of its three rounding errors, found exactly by error-free transformations
+/// (barring underflow in the product).
+#[inline]
+#[cfg(any(feature = "compensated", feature = "rigorous-error"))]
+#[cfg_attr(
+    not(all(feature = "compensated", feature = "rigorous-error")),
+    expect(
+        clippy::single_call_fn,
+        reason = "shared by `step_exactly` and `step_error` when both are compiled"
+    )
+)]
+const fn step_with_errors(multiplier: f64, d: f64, dd: f64, c: f64) -> (f64, [f64; 3]) {
+    let (product, product_error) = two_prod(multiplier, d);
+    let (difference, difference_error) = two_sum(product, -dd);
+    let (sum, sum_error) = two_sum(difference, c);
+    (sum, [product_error, difference_error, sum_error])
+}
+
+/// The body of `eval_many` (and the batch APIs, on each group of points that share a piece):
+/// `series` at every entry of `ys` into the same position in `out` (up to the shorter of the two),
+/// `LANES` at a time in lockstep, by exactly the same arithmetic in each lane.
+#[inline]
+pub(crate) fn lockstep(
+    used: &[Finite<f64>],
+    #[cfg(feature = "error")] last_coefficient: Finite<f64>,
+    ys: &[Finite<f64>],
+    out: &mut [Approx],
+) {
+    for (chunk, results) in ys.chunks(LANES).zip(out.chunks_mut(LANES)) {
+        let mut xs = [0_f64; LANES];
+        for (lane, &y) in xs.iter_mut().zip(chunk) {
+            *lane = *y;
+        }
+        let two_xs = xs.map(|lane| 2_f64 * lane);
+        let mut ds = [0_f64; LANES];
+        let mut dds = [0_f64; LANES];
+        #[cfg(feature = "error")]
+        let mut es = [0_f64; LANES];
+        #[cfg(feature = "rigorous-error")]
+        let ratios = xs.map(growth);
+        // As in `series`:
+        #[cfg(feature = "compensated")]
+        let mut corrections = [0_f64; LANES];
+        #[cfg(feature = "compensated")]
+        let mut previous_corrections = [0_f64; LANES];
+        if let Some((&first, rest)) = used.split_first() {
+            for &c in rest.iter().rev() {
+                #[cfg(all(feature = "error", not(feature = "rigorous-error")))]
+                for (((e, &two_x), &d), &dd) in es.iter_mut().zip(&two_xs).zip(&ds).zip(&dds) {
+                    *e += ((two_x * d).abs() + dd.abs()) + c.abs();
+                }
+                #[cfg(feature = "rigorous-error")]
+                for ((((e, &ratio), &two_x), &d), &dd) in
+                    es.iter_mut().zip(&ratios).zip(&two_xs).zip(&ds).zip(&dds)
+                {
+                    *e = *e * ratio + step_error(two_x, d, dd, *c);
+                }
+                #[cfg(not(feature = "compensated"))]
+                for ((&two_x, d), dd) in two_xs.iter().zip(&mut ds).zip(&mut dds) {
+                    let tmp = *d;
+                    *d = ((two_x * *d) - *dd) + *c;
+                    *dd = tmp;
+                }
+                #[cfg(feature = "compensated")]
+                for (((&two_x, d), dd), (correction, previous_correction)) in two_xs
+                    .iter()
+                    .zip(&mut ds)
+                    .zip(&mut dds)
+                    .zip(corrections.iter_mut().zip(&mut previous_corrections))
+                {
+                    let (next, error) = step_exactly(two_x, *d, *dd, *c);
+                    (*dd, *d) = (*d, next);
+                    (*previous_correction, *correction) = (
+                        *correction,
+                        ((two_x * *correction) - *previous_correction) + error,
+                    );
+                }
+            }
+            let half = 0.5_f64 * *first;
+            #[cfg(all(feature = "error", not(feature = "rigorous-error")))]
+            for (((e, &x), &d), &dd) in es.iter_mut().zip(&xs).zip(&ds).zip(&dds) {
+                *e += ((x * d).abs() + dd.abs()) + half.abs();
+            }
+            #[cfg(feature = "rigorous-error")]
+            for ((((e, &ratio), &x), &d), &dd) in
+                es.iter_mut().zip(&ratios).zip(&xs).zip(&ds).zip(&dds)
+            {
+                *e = *e * ratio + step_error(x, d, dd, half);
+            }
+            #[cfg(not(feature = "compensated"))]
+            for ((&x, d), &dd) in xs.iter().zip(&mut ds).zip(&dds) {
+                *d = x * *d - dd + half;
+            }
+            #[cfg(all(feature = "compensated", not(feature = "rigorous-error")))]
+            for (((&x, d), &dd), (&correction, &previous_correction)) in xs
+                .iter()
+                .zip(&mut ds)
+                .zip(&dds)
+                .zip(corrections.iter().zip(&previous_corrections))
+            {
+                let (value, error) = step_exactly(x, *d, dd, half);
+                *d = value + (((x * correction) - previous_correction) + error);
+            }
+            // As in `series`:
+            #[cfg(all(feature = "compensated", feature = "rigorous-error"))]
+            for ((((&x, d), &dd), (&correction, &previous_correction)), e) in xs
+                .iter()
+                .zip(&mut ds)
+                .zip(&dds)
+                .zip(corrections.iter().zip(&previous_corrections))
+                .zip(&mut es)
+            {
+                let (value, error) = step_exactly(x, *d, dd, half);
+                *d = value + (((x * correction) - previous_correction) + error);
+                *e += (*d - value).abs();
+            }
+        }
+        for (lane, result) in results.iter_mut().enumerate() {
+            *result = Approx {
+                value: Finite::new(ds.get(lane).copied().unwrap_or(0_f64)),
+                #[cfg(feature = "error")]
+                error: error_bound(
+                    es.get(lane).copied().unwrap_or(0_f64),
+                    #[cfg(feature = "rigorous-error")]
+                    used.len(),
+                    last_coefficient,
+                ),
+            };
+        }
+    }
+}
+
+/// The body of `eval` and `eval_slice`, after truncation, independent of the length of the table
+/// so that every table shares one copy (which `opt-size` keeps out of line).
+///
+/// Clenshaw's recurrence, estimating its error from the three terms summed at each step
+/// (or with `rigorous-error`, bounding it from the three rounding errors of each step).
+/// With the `compensated` feature, each step's rounding error (from `step_exactly`) is carried through a recurrence of its own,
+/// then added back at the very end, as Graillat, Langlois, and Louvet compensate Horner's scheme.
+/// The leading sum is exactly the plain recurrence's, so only that last addition changes the result,
+/// which comes out about as accurate as the recurrence run in twice the precision, then rounded once.
+#[cfg_attr(not(feature = "opt-size"), inline)]
+#[cfg_attr(feature = "opt-size", inline(never))]
+const fn series(
+    used: &[Finite<f64>],
+    x: Finite<f64>,
+    #[cfg(feature = "error")] last_coefficient: Finite<f64>,
+) -> Approx {
+    let y = *x.get_ref();
+    let two_y = 2_f64 * y;
+    let mut d = 0_f64;
+    #[cfg(feature = "error")]
+    let mut e = 0_f64;
+    #[cfg(feature = "rigorous-error")]
+    let ratio = growth(y);
+    #[cfg(feature = "compensated")]
+    let mut correction = 0_f64;
+    #[cfg(feature = "compensated")]
+    let mut previous_correction = 0_f64;
+    if let [first, ref rest @ ..] = *used {
+        let mut dd = 0_f64;
+        let mut remaining = rest;
+        while let [ref init @ .., c] = *remaining {
+            let coefficient = *c.get_ref();
+            #[cfg(all(feature = "error", not(feature = "rigorous-error")))]
+            {
+                e += ((two_y * d).abs() + dd.abs()) + coefficient.abs();
+            }
+            #[cfg(feature = "rigorous-error")]
+            {
+                e = e * ratio + step_error(two_y, d, dd, coefficient);
+            }
+            #[cfg(not(feature = "compensated"))]
+            {
+                let tmp = d;
+                d = ((two_y * d) - dd) + coefficient;
+                dd = tmp;
+            }
+            #[cfg(feature = "compensated")]
+            {
+                let (next, error) = step_exactly(two_y, d, dd, coefficient);
+                (dd, d) = (d, next);
+                (previous_correction, correction) = (
+                    correction,
+                    ((two_y * correction) - previous_correction) + error,
+                );
+            }
+            remaining = init;
+        }
+        let half = 0.5_f64 * *first.get_ref();
+        #[cfg(all(feature = "error", not(feature = "rigorous-error")))]
+        {
+            e += ((y * d).abs() + dd.abs()) + half.abs();
+        }
+        #[cfg(feature = "rigorous-error")]
+        {
+            e = e * ratio + step_error(y, d, dd, half);
+        }
+        #[cfg(not(feature = "compensated"))]
+        {
+            d = y * d - dd + half;
+        }
+        #[cfg(feature = "compensated")]
+        {
+            let (value, error) = step_exactly(y, d, dd, half);
+            d = value + (((y * correction) - previous_correction) + error);
+            // The bound above is on the plain sum, so add how far compensating moved it:
+            #[cfg(feature = "rigorous-error")]
+            {
+                e += (d - value).abs();
+            }
+        }
+    }
 
     Approx {
-        value: d,
+        value: finite(d),
         #[cfg(feature = "error")]
-        error: NonNegative::new(Finite::new(constants::GSL_DBL_EPSILON)) * e
-            + NonNegative::new(last_coefficient.map(f64::abs)),
+        error: error_bound(
+            e,
+            #[cfg(feature = "rigorous-error")]
+            used.len(),
+            last_coefficient,
+        ),
     }
 }
 
+/// The error estimate of a sum, from what `series` (or `lockstep`) added up in `e`,
+/// plus the last coefficient of the table, GSL's stand-in for every term it leaves out.
+///
+/// GSL's estimate of the rounding is `GSL_DBL_EPSILON` times the magnitudes of every term summed.
+/// With `rigorous-error`, `e` is already a bound on the rounding (see `growth`),
+/// so it's only widened to cover the rounding in working it out
+/// (at most $4 (n + 2)$ operations through $n$ coefficients, each off by at most half an epsilon)
+/// and any underflow along the way.
+#[inline]
+#[cfg(feature = "error")]
+const fn error_bound(
+    e: f64,
+    #[cfg(feature = "rigorous-error")] terms: usize,
+    last_coefficient: Finite<f64>,
+) -> NonNegative<Finite<f64>> {
+    #[cfg(not(feature = "rigorous-error"))]
+    let rounding = constants::GSL_DBL_EPSILON * e;
+    #[cfg(feature = "rigorous-error")]
+    let rounding = {
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "far fewer than $2^{52}$ coefficients"
+        )]
+        let operations = 4_f64 * (terms as f64 + 2_f64);
+        e * (1_f64 + 2_f64 * operations * f64::EPSILON) + f64::MIN_POSITIVE
+    };
+    let error = rounding + last_coefficient.get_ref().abs();
+    debug_assert!(error.is_finite(), "Non-finite error estimate");
+    // SAFETY:
+    // `NonNegative<Finite<f64>>` is a `repr(transparent)` `f64`, a sum of magnitudes is never negative,
+    // and the `debug_assert` above checks that it's finite.
+    unsafe { mem::transmute::<f64, NonNegative<Finite<f64>>>(error) }
+}
+
+/// `Finite::new` in a `const fn`, which `sigma_types` only offers in release builds.
+#[inline]
+pub(crate) const fn finite(x: f64) -> Finite<f64> {
+    debug_assert!(x.is_finite(), "Non-finite value in a Chebyshev series");
+    // SAFETY:
+    // `Finite<f64>` is a `repr(transparent)` `f64`, and the `debug_assert` above checks its invariant.
+    unsafe { mem::transmute::<f64, Finite<f64>>(x) }
+}
+
+/// `Finite::all` in a `const fn`.
+#[inline]
+pub(crate) const fn finite_all<const N: usize>(coefficients: &[f64; N]) -> &[Finite<f64>; N] {
+    #[cfg(debug_assertions)]
+    {
+        let mut remaining: &[f64] = coefficients;
+        while let [coefficient, ref rest @ ..] = *remaining {
+            debug_assert!(
+                coefficient.is_finite(),
+                "Non-finite coefficient in a Chebyshev series",
+            );
+            remaining = rest;
+        }
+    }
+    let pointer: *const [f64; N] = coefficients;
+    // SAFETY:
+    // `Finite<f64>` is a `repr(transparent)` `f64`, and the `debug_assert` above checks its invariant.
+    unsafe { &*pointer.cast::<[Finite<f64>; N]>() }
+}
+
+/// `LessThan::new` in a `const fn`.
+#[inline]
+pub(crate) const fn less_than<const N: usize>(n: usize) -> LessThan<N> {
+    debug_assert!(n < N, "Order past the end of a Chebyshev series");
+    // SAFETY:
+    // `LessThan<N>` is a `repr(transparent)` `usize`, and the `debug_assert` above checks its invariant.
+    unsafe { mem::transmute::<usize, LessThan<N>>(n) }
+}
+
 /// Compile-time-compatible minimum of two large unsigned integers.
 #[inline]
-#[cfg_attr(not(test), expect(dead_code, reason = "TODO: REMOVE"))]
-#[cfg_attr(test, expect(clippy::single_call_fn, reason = "TODO: REMOVE"))]
 pub(crate) const fn min(a: usize, b: usize) -> usize {
     if a.checked_sub(b).is_some() { b } else { a }
 }
+
+/// A Chebyshev series, as `eval` takes it, rewritten as an ordinary polynomial (lowest power first).
+///
+/// Meant for compile time. Adds up each $T_{k}$ expanded through $T_{k + 1} = 2 x T_{k} - T_{k - 1}$.
+///
+/// Only well-conditioned where the coefficients shrink faster than the expansions of $T_{k}$ grow,
+/// as they do for every fit to E1 (the two forms agree to within a few units in the last place).
+#[inline]
+#[must_use]
+#[cfg(feature = "fast-poly")]
+#[expect(
+    clippy::indexing_slicing,
+    reason = "every index is below `N`, and evaluating this at compile time rejects any that aren't"
+)]
+pub const fn monomial<const N: usize>(coefficients: &[f64; N]) -> [f64; N] {
+    let mut polynomial = [0_f64; N];
+    // $T_{k - 1}$ and $T_{k}$, starting from $T_{-1} = 0$ (so that $T_{1} = x T_{0}$ with the factor below) and $T_{0} = 1$:
+    let mut previous = [0_f64; N];
+    let mut current = [0_f64; N];
+    if N > 0 {
+        current[0] = 1_f64;
+    }
+    let mut k = 0;
+    while k < N {
+        let weight = if k == 0 {
+            0.5_f64 * coefficients[0]
+        } else {
+            coefficients[k]
+        };
+        let factor = if k == 0 { 1_f64 } else { 2_f64 };
+        let mut next = [0_f64; N];
+        let mut i = 0;
+        while i < N {
+            polynomial[i] += weight * current[i];
+            let shifted = if i == 0 {
+                0_f64
+            } else {
+                current[i.wrapping_sub(1)]
+            };
+            next[i] = factor * shifted - previous[i];
+            i = i.wrapping_add(1);
+        }
+        previous = current;
+        current = next;
+        k = k.wrapping_add(1);
+    }
+    polynomial
+}
@@ -0,0 +1,161 @@
+//! E1 in a `const fn`, for baking values into a binary at compile time.
+//!
+//! Same pieces and Chebyshev fits as the runtime path,
+//! but `libm` isn't `const`, so the exponential and logarithm are reimplemented here
+//! (argument reduction by $\ln 2$ followed by a short series, as in `libm` itself),
+//! and there's no error estimate.
+
+use {
+    crate::{chebyshev, constants},
+    core::f64::consts::{LOG2_E, SQRT_2},
+};
+
+/// $\ln 2$ to 32 significant bits, so that multiplying it by any exponent in range is exact.
+const LN_2_HI: f64 = 6.931_471_803_691_238e-1;
+
+/// What's left of $\ln 2$ after `LN_2_HI`.
+const LN_2_LO: f64 = 1.908_214_929_270_587_7e-10;
+
+/// $e^{x}$ for `x` in range (up to about 709 in magnitude), within a couple of units in the last place.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "stands in for `libm::exp`")]
+const fn exp(x: f64) -> f64 {
+    /// Each $n$ in $\frac{r^{n}}{n!}$, through the first term under $10^{-20}$ once $|r| \leq \frac{\ln 2}{2}$.
+    const TERMS: [f64; 17] = [
+        1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64, 7_f64, 8_f64, 9_f64, 10_f64, 11_f64, 12_f64,
+        13_f64, 14_f64, 15_f64, 16_f64, 17_f64,
+    ];
+
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        reason = "`k` is a small whole number whenever `x` is in range"
+    )]
+    let k = (x * LOG2_E + if x < 0_f64 { -0.5_f64 } else { 0.5_f64 }) as i64;
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "`k` is a small whole number whenever `x` is in range"
+    )]
+    let multiple = k as f64;
+    let reduced = (x - multiple * LN_2_HI) - multiple * LN_2_LO;
+    // $1 + r (1 + \frac{r}{2} (1 + \frac{r}{3} (\cdots)))$:
+    let mut sum = 1_f64;
+    let mut remaining: &[f64] = &TERMS;
+    while let [ref init @ .., n] = *remaining {
+        sum = 1_f64 + sum * reduced / n;
+        remaining = init;
+    }
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_sign_loss,
+        reason = "the biased exponent of a normal power of two is positive"
+    )]
+    let power = f64::from_bits((k.wrapping_add(1023) as u64) << 52_u32);
+    sum * power
+}
+
+/// $\ln x$ for positive finite `x` (subnormals included), within a couple of units in the last place.
+#[inline]
+const fn ln(x: f64) -> f64 {
+    /// Each $2n + 1$ in $\tanh^{-1} s = \sum_{n} \frac{s^{2n + 1}}{2n + 1}$ past the first,
+    /// through the first term under $10^{-19}$ once $|s| \leq 3 - 2 \sqrt{2}$.
+    const TERMS: [f64; 12] = [
+        3_f64, 5_f64, 7_f64, 9_f64, 11_f64, 13_f64, 15_f64, 17_f64, 19_f64, 21_f64, 23_f64, 25_f64,
+    ];
+    /// Mask for the 52 explicit bits of the significand.
+    const SIGNIFICAND: u64 = (1_u64 << 52_u32) - 1_u64;
+
+    // Subnormals have no implicit leading bit, so scale them into the normals first:
+    let (normal, offset) = if x < f64::MIN_POSITIVE {
+        (x * 18_014_398_509_481_984_f64, -54_i64)
+    } else {
+        (x, 0_i64)
+    };
+    let bits = normal.to_bits();
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_wrap,
+        reason = "an 11-bit exponent fits in any signed integer"
+    )]
+    let biased = (bits >> 52_u32) as i64;
+    // Significand in $[1, 2)$, then in $[\frac{1}{\sqrt{2}}, \sqrt{2})$:
+    let unit = f64::from_bits((bits & SIGNIFICAND) | (1023_u64 << 52_u32));
+    let (significand, exponent) = if unit > SQRT_2 {
+        (
+            0.5_f64 * unit,
+            biased.wrapping_sub(1022).wrapping_add(offset),
+        )
+    } else {
+        (unit, biased.wrapping_sub(1023).wrapping_add(offset))
+    };
+    // $\ln m = 2 \tanh^{-1} s$ with $s = \frac{m - 1}{m + 1}$:
+    let s = (significand - 1_f64) / (significand + 1_f64);
+    let s_squared = s * s;
+    let mut sum = 0_f64;
+    let mut remaining: &[f64] = &TERMS;
+    while let [ref init @ .., n] = *remaining {
+        sum = sum * s_squared + 1_f64 / n;
+        remaining = init;
+    }
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "exponents of `f64`s are small whole numbers"
+    )]
+    let multiple = exponent as f64;
+    multiple * LN_2_HI + (multiple * LN_2_LO + 2_f64 * s * (1_f64 + sum * s_squared))
+}
+
+/// Evaluate a Chebyshev series by `chebyshev::eval` itself, through its last coefficient.
+#[inline]
+const fn clenshaw<const N: usize>(coefficients: &[f64; N], x: f64) -> f64 {
+    *chebyshev::eval(
+        chebyshev::finite_all(coefficients),
+        chebyshev::finite(x),
+        chebyshev::less_than(const { N - 1 }),
+    )
+    .value
+    .get_ref()
+}
+
+/// $\frac{e^{-x}}{x} (1 + c)$, the shape of every piece away from the origin.
+#[inline]
+const fn decaying(x: f64, c: f64) -> f64 {
+    (1_f64 / x) * exp(-x) * (1_f64 + c)
+}
+
+/// $E_{1}(x)$, with the same dispatch as `implementation::E1`.
+/// # Panics
+/// If `x` is zero, not finite, or at least `XMAX` in magnitude.
+#[inline]
+pub(crate) const fn E1(x: f64) -> f64 {
+    // In a const context, each of these is a compile error, which is the point:
+    assert!(!x.is_nan() && x != 0_f64, "E1 is undefined at zero and NaN");
+    assert!(x > constants::NXMAX, "Argument too large (negative) for E1");
+    assert!(x < constants::XMAX, "Argument too large (positive) for E1");
+    if x <= -10_f64 {
+        decaying(x, clenshaw(&constants::AE11, 20_f64 / x + 1_f64))
+    } else if x <= -4_f64 {
+        decaying(x, clenshaw(&constants::AE12, (40_f64 / x + 7_f64) / 3_f64))
+    } else if x <= -1_f64 {
+        -ln(-x) + clenshaw(&constants::E11, (2_f64 * x + 5_f64) / 3_f64)
+    } else if x < 0_f64 && (x + constants::EI_ZERO).abs() < constants::ROOT_RADIUS {
+        // Exact by Sterbenz's lemma, as in `piecewise::near_root`:
+        let distance = (-x - constants::EI_ZERO) - constants::EI_ZERO_LO;
+        let mut sum = 0_f64;
+        let mut remaining: &[f64] = &constants::EI_ROOT;
+        while let [ref init @ .., coefficient] = *remaining {
+            sum = sum * distance + coefficient;
+            remaining = init;
+        }
+        // $E_{1}(x) = -\text{Ei}(-x)$:
+        -sum * distance
+    } else if x <= 1_f64 {
+        (-ln(x.abs()) - 0.6875_f64 + x) + clenshaw(&constants::E12, x)
+    } else if x <= 4_f64 {
+        decaying(x, clenshaw(&constants::AE13, (8_f64 / x - 5_f64) / 3_f64))
+    } else {
+        decaying(x, clenshaw(&constants::AE14, 8_f64 / x - 1_f64))
+    }
+}
@@ -0,0 +1,321 @@
+//! Complex arguments, e.g. for oscillatory integrals and wave propagation.
+//!
+//! Everything here is on the principal branch, with its cut along the negative real axis.
+//! The sign of a zero imaginary part picks the side of the cut,
+//! so $E_{1}(-x \pm 0 i) = -\text{Ei}(x) \mp \pi i$ for positive $x$.
+//!
+//! No original C code: GSL has no complex exponential integrals.
+
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "complex floating-point arithmetic saturates instead of panicking"
+)]
+
+use {
+    crate::{Error, constants, consts},
+    core::{f64::consts::PI, fmt},
+    num_complex::Complex,
+    sigma_types::{Finite, Negative, NonNegative, Positive},
+};
+
+/// Largest modulus at which the power series is used everywhere,
+/// before cancellation between its terms costs more than the continued fraction would.
+const SERIES_RADIUS: f64 = 2_f64;
+
+/// Orders past this (plus a few times the modulus of `z`) skip the power series:
+/// `z + n` is then far enough from zero that the continued fraction converges quickly anyway.
+const MAX_SERIES_ORDER: f64 = 64_f64;
+
+/// Largest modulus at which the power series is used near the negative real axis,
+/// where the continued fraction converges slowly but the series has little cancellation.
+const SERIES_RADIUS_NEAR_CUT: f64 = 40_f64;
+
+/// An approximate complex value alongside an estimate of its own approximation error,
+/// which bounds the modulus of the difference from the exact value.
+///
+/// As with `crate::Approx`, the estimate is read through `Approx::error` whatever the features.
+#[cfg_attr(
+    feature = "error",
+    expect(
+        clippy::partial_pub_fields,
+        reason = "the estimate only exists with the `error` feature, so it's read through `error()` instead"
+    )
+)]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Approx {
+    /// Estimate of the approximation error for `value`.
+    #[cfg(feature = "error")]
+    pub(crate) error: NonNegative<Finite<f64>>,
+    /// Approximate value.
+    pub value: Complex<Finite<f64>>,
+}
+
+impl Approx {
+    /// Estimate of the approximation error for `value`,
+    /// or `None` if this build doesn't compute one (i.e. without the `error` feature).
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> Option<NonNegative<Finite<f64>>> {
+        #[cfg(feature = "error")]
+        {
+            Some(self.error)
+        }
+        #[cfg(not(feature = "error"))]
+        {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Approx {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            #[cfg(feature = "error")]
+            ref error,
+            ref value,
+        } = *self;
+        let unwrapped = Complex::new(*value.re, *value.im);
+        #[cfg(feature = "error")]
+        {
+            write!(f, "{unwrapped} +/- {error}")
+        }
+        #[cfg(not(feature = "error"))]
+        {
+            write!(f, "{unwrapped}")
+        }
+    }
+}
+
+/// Modified Lentz evaluation of the continued fraction
+/// $E_{n}(z) = e^{-z} \left( \frac{1}{z + n -} \frac{1 \cdot n}{z + n + 2 -} \frac{2 (n + 1)}{z + n + 4 -} \cdots \right)$,
+/// the complex counterpart of `implementation::en::continued_fraction`,
+/// which converges quickly whenever `z` is large and away from the negative real axis.
+/// On the cut itself, it converges to the average of either side, so the caller adds the imaginary part back.
+/// # Errors
+/// If the result is too large to represent.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the real implementation")]
+fn continued_fraction(n: f64, z: Complex<f64>) -> Result<Approx, Error> {
+    /// Give up on exact convergence after this many terms,
+    /// since rounding can leave successive ratios a few ULPs from 1 forever.
+    const MAX_TERMS: f64 = 10_000_f64;
+    /// Stand-in for zero to avoid dividing by it.
+    const TINY: f64 = 1e-300_f64;
+
+    let mut denominator = z + n;
+    let mut lentz_c = Complex::new(TINY.recip(), 0_f64);
+    let mut lentz_d = if denominator.norm() < TINY {
+        Complex::new(TINY.recip(), 0_f64)
+    } else {
+        denominator.inv()
+    };
+    let mut convergent = lentz_d;
+    let mut index = 1_f64;
+    #[cfg(feature = "error")]
+    let mut terms = 1_f64;
+    #[cfg(feature = "error")]
+    let mut last_delta: f64;
+    loop {
+        let numerator = -index * (n - 1_f64 + index);
+        denominator += 2_f64;
+        lentz_d = lentz_d * numerator + denominator;
+        if lentz_d.norm() < TINY {
+            lentz_d = Complex::new(TINY, 0_f64);
+        }
+        lentz_d = lentz_d.inv();
+        lentz_c = denominator + lentz_c.inv() * numerator;
+        if lentz_c.norm() < TINY {
+            lentz_c = Complex::new(TINY, 0_f64);
+        }
+        let delta = lentz_c * lentz_d;
+        convergent *= delta;
+        #[cfg(feature = "error")]
+        {
+            terms += 1_f64;
+            last_delta = (delta - 1_f64).norm();
+        }
+        if (delta - 1_f64).norm() <= f64::EPSILON || index >= MAX_TERMS {
+            break;
+        }
+        index += 1_f64;
+    }
+
+    let value = convergent * (-z).exp();
+    Ok(Approx {
+        value: Complex::new(
+            Finite::try_new(value.re).ok_or(Error::Overflow)?,
+            Finite::try_new(value.im).ok_or(Error::Overflow)?,
+        ),
+        #[cfg(feature = "error")]
+        // Rounding in every step and in the exponential, plus truncation after the last:
+        error: NonNegative::new(Finite::new(
+            ((terms + 2_f64 + z.norm()) * constants::GSL_DBL_EPSILON + last_delta) * value.norm(),
+        )),
+    })
+}
+
+/// $E_{n}(z) = \frac{(-z)^{n - 1}}{(n - 1)!} \left( \psi(n) - \ln z \right) - \sum_{k \neq n - 1} \frac{(-z)^{k}}{(k - n + 1) k!}$,
+/// with $\psi(n) = -\gamma + \sum_{m = 1}^{n - 1} \frac{1}{m}$,
+/// which converges everywhere but cancels badly for large `z` unless it's near the negative real axis.
+/// The logarithm puts the result on the principal branch, including a signed zero's side of the cut.
+/// # Errors
+/// If the result is too large to represent.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the real implementation")]
+fn series(n: u16, z: Complex<f64>) -> Result<Approx, Error> {
+    /// Past the order's own term, enough to converge anywhere the series is used,
+    /// since terms only start shrinking once `k` passes the modulus of `z`.
+    const MAX_TERMS: u16 = 2_000;
+
+    let power = n - 1;
+    let mut digamma = -consts::EULER_GAMMA;
+    for m in 1..n {
+        digamma += f64::from(m).recip();
+    }
+    let log = z.ln();
+
+    let mut term = Complex::new(1_f64, 0_f64);
+    let mut sum = Complex::new(0_f64, 0_f64);
+    #[cfg(feature = "error")]
+    let mut abs_sum = 0_f64;
+    #[cfg(feature = "error")]
+    let mut last_term = 0_f64;
+    for index in 0..power.saturating_add(MAX_TERMS) {
+        let k = f64::from(index);
+        if index > 0 {
+            term *= -z / k;
+        }
+        let contribution = if index == power {
+            term * (digamma - log)
+        } else {
+            -term / (k - f64::from(power))
+        };
+        sum += contribution;
+        #[cfg(feature = "error")]
+        {
+            abs_sum += contribution.norm();
+            last_term = contribution.norm();
+        }
+        if index > power && contribution.norm() <= f64::EPSILON * sum.norm() {
+            break;
+        }
+    }
+
+    Ok(Approx {
+        value: Complex::new(
+            Finite::try_new(sum.re).ok_or(Error::Overflow)?,
+            Finite::try_new(sum.im).ok_or(Error::Overflow)?,
+        ),
+        #[cfg(feature = "error")]
+        // Rounding accumulates in each term about as many times as the largest term's index,
+        // which is about the modulus of `z`; then add the truncated tail:
+        error: NonNegative::new(Finite::new(
+            (2_f64 + z.norm()) * constants::GSL_DBL_EPSILON * abs_sum
+                + 2_f64 * constants::GSL_DBL_EPSILON * sum.norm()
+                + last_term,
+        )),
+    })
+}
+
+/// The exponential integral $E_{1}(z) = \int_{z}^{\infty} \frac{e^{-t}}{t} \text{d}t$
+/// for complex `z`, on the principal branch.
+///
+/// On the real axis, agrees with the real `E1`;
+/// just above or below the negative real axis, it differs from `-Ei` by $\mp \pi i$.
+/// # Errors
+/// See `En`.
+#[inline]
+pub fn E1(z: Complex<Finite<f64>>) -> Result<Approx, Error> {
+    En(1, z)
+}
+
+/// The generalized exponential integral of integer order,
+/// $E_{n}(z) = \int_{1}^{\infty} \frac{e^{-zt}}{t^n} \text{d}t$,
+/// for complex `z`, analytically continued to the principal branch.
+///
+/// Uses the power series near the origin (and along the negative real axis) for modest orders,
+/// and a continued fraction elsewhere.
+/// For $n \geq 1$, the jump across the negative real axis is $2 \pi i \frac{(-z)^{n - 1}}{(n - 1)!}$.
+/// # Errors
+/// If `z` is zero and `n` is at most 1, where $E_{n}$ diverges,
+/// or if the real part of `z` is so large in magnitude that floating-point operations will fail down the line
+/// (just over 700, as for the real `En`).
+#[inline]
+pub fn En(n: usize, z: Complex<Finite<f64>>) -> Result<Approx, Error> {
+    let unwrapped = Complex::new(*z.re, *z.im);
+    if unwrapped.re < constants::NXMAX {
+        return Err(Error::ArgumentTooNegative(Negative::new(Finite::new(
+            unwrapped.re,
+        ))));
+    }
+    if unwrapped.re > constants::XMAX {
+        return Err(Error::ArgumentTooPositive(Positive::new(Finite::new(
+            unwrapped.re,
+        ))));
+    }
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "orders past 2^53 are indistinguishable in the result anyway"
+    )]
+    let order = n as f64;
+    if unwrapped.re == 0_f64 && unwrapped.im == 0_f64 {
+        return if n <= 1 {
+            Err(Error::Domain(Finite::new(0_f64)))
+        } else {
+            Ok(Approx {
+                value: Complex::new(Finite::new((order - 1_f64).recip()), Finite::new(0_f64)),
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    constants::GSL_DBL_EPSILON * (order - 1_f64).recip(),
+                )),
+            })
+        };
+    }
+    if n == 0 {
+        let value = (-unwrapped).exp() / unwrapped;
+        return Ok(Approx {
+            value: Complex::new(
+                Finite::try_new(value.re).ok_or(Error::Overflow)?,
+                Finite::try_new(value.im).ok_or(Error::Overflow)?,
+            ),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                (2_f64 + unwrapped.norm()) * constants::GSL_DBL_EPSILON * value.norm(),
+            )),
+        });
+    }
+
+    let modulus = unwrapped.norm();
+    // Hugging the cut, the series barely cancels at all (by at most a factor of $e$),
+    // while the continued fraction can stall when `n` is comparable to `-z`:
+    let hugging_cut = unwrapped.re < 0_f64 && modulus + unwrapped.re <= 1_f64;
+    if let Ok(small_order) = u16::try_from(n)
+        && order <= 4_f64.mul_add(modulus, MAX_SERIES_ORDER)
+        && (modulus <= SERIES_RADIUS
+            || hugging_cut
+            || (unwrapped.re < -2_f64 * unwrapped.im.abs() && modulus <= SERIES_RADIUS_NEAR_CUT))
+    {
+        return series(small_order, unwrapped);
+    }
+
+    let mut approx = continued_fraction(order, unwrapped)?;
+    if unwrapped.im == 0_f64 && unwrapped.re < 0_f64 {
+        // On the cut, pick the side by the sign of zero:
+        // half the jump, $\pi \frac{x^{n - 1}}{(n - 1)!}$, computed in logarithms to avoid spurious overflow.
+        let log_magnitude = (order - 1_f64) * libm::log(-unwrapped.re) - libm::lgamma(order);
+        let half_jump = PI * libm::exp(log_magnitude);
+        approx.value.im =
+            Finite::try_new(-half_jump.copysign(unwrapped.im)).ok_or(Error::Overflow)?;
+        #[cfg(feature = "error")]
+        {
+            approx.error = NonNegative::new(Finite::new(
+                **approx.error
+                    + (2_f64 + log_magnitude.abs()) * constants::GSL_DBL_EPSILON * half_jump,
+            ));
+        }
+    }
+    Ok(approx)
+}
@@ -16,10 +16,250 @@ pub(crate) mod size {
     pub(crate) const AE13: usize = 25;
     /// AE14
     pub(crate) const AE14: usize = 26;
+    /// CI
+    pub(crate) const CI: usize = 13;
     /// E11
     pub(crate) const E11: usize = 19;
     /// E12
     pub(crate) const E12: usize = 16;
+    /// `EI_ROOT`
+    pub(crate) const EI_ROOT: usize = 22;
+    /// EXPINT3
+    pub(crate) const EXPINT3: usize = 23;
+    /// EXPINT3A
+    pub(crate) const EXPINT3A: usize = 21;
+    /// F1
+    pub(crate) const F1: usize = 22;
+    /// F2
+    pub(crate) const F2: usize = 34;
+    /// G1
+    pub(crate) const G1: usize = 23;
+    /// G2
+    pub(crate) const G2: usize = 39;
+    /// SI
+    pub(crate) const SI: usize = 12;
+}
+
+/// How quickly each series falls off, for `Precision::RelTolerance`,
+/// alongside the smallest its result gets relative to the series' weight in it
+/// (found on a grid of two million points, then rounded down).
+pub(crate) mod decay {
+    use {
+        super::{piecewise, size},
+        crate::chebyshev::Decay,
+    };
+
+    /// AE11, in $\frac{e^{-x}}{x} (1 + c)$, where $|1 + c| \geq 1$.
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) const AE11: Decay<{ piecewise::AE11.coefficients.len() }> =
+        Decay::new(&piecewise::AE11.coefficients, 0.99_f64);
+
+    /// AE12, in $\frac{e^{-x}}{x} (1 + c)$, where $|1 + c| \geq 1.13$.
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) const AE12: Decay<{ piecewise::AE12.coefficients.len() }> =
+        Decay::new(&piecewise::AE12.coefficients, 1.1_f64);
+
+    /// AE13, in $\frac{e^{-x}}{x} (1 + c)$, where $|1 + c| \geq 0.596$.
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) const AE13: Decay<{ piecewise::AE13.coefficients.len() }> =
+        Decay::new(&piecewise::AE13.coefficients, 0.59_f64);
+
+    /// AE14, in $\frac{e^{-x}}{x} (1 + c)$, where $|1 + c| \geq 0.825$.
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) const AE14: Decay<{ piecewise::AE14.coefficients.len() }> =
+        Decay::new(&piecewise::AE14.coefficients, 0.82_f64);
+
+    /// CI, in $\ln x - \frac{1}{2} + c$, which is zero near $x = 0.6165$, so no tolerance cuts it short.
+    pub(crate) const CI: Decay<{ size::CI }> = Decay::new(&super::CI, 0_f64);
+
+    /// E11, in $-\ln |x| + c$, at least 1.895 in magnitude.
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) const E11: Decay<{ piecewise::E11.coefficients.len() }> =
+        Decay::new(&piecewise::E11.coefficients, 1.8_f64);
+
+    /// E12, in $-\ln |x| - 0.6875 + x + c$, at least $E_{1}(1) = 0.219$ in magnitude
+    /// once the neighbourhood of the zero of Ei (summed separately) is left out.
+    pub(crate) const E12: Decay<{ piecewise::E12.coefficients.len() }> =
+        Decay::new(&piecewise::E12.coefficients, 0.21_f64);
+
+    /// EXPINT3, in $x c$, where $|c| \geq 0.446$.
+    pub(crate) const EXPINT3: Decay<{ size::EXPINT3 }> = Decay::new(&super::EXPINT3, 0.44_f64);
+
+    /// EXPINT3A, in $\Gamma(4/3) - s c$, which is at least $31\,942 s$.
+    pub(crate) const EXPINT3A: Decay<{ size::EXPINT3A }> = Decay::new(&super::EXPINT3A, 31_000_f64);
+
+    /// F1, in $\frac{1 + c}{x}$, where $|1 + c| \geq 0.916$.
+    pub(crate) const F1: Decay<{ size::F1 }> = Decay::new(&super::F1, 0.91_f64);
+
+    /// F2, in $\frac{1 + c}{x}$, where $|1 + c| \geq 0.966$.
+    pub(crate) const F2: Decay<{ size::F2 }> = Decay::new(&super::F2, 0.96_f64);
+
+    /// G1, in $\frac{1 + c}{x^{2}}$, where $|1 + c| \geq 0.794$.
+    pub(crate) const G1: Decay<{ size::G1 }> = Decay::new(&super::G1, 0.79_f64);
+
+    /// G2, in $\frac{1 + c}{x^{2}}$, where $|1 + c| \geq 0.908$.
+    pub(crate) const G2: Decay<{ size::G2 }> = Decay::new(&super::G2, 0.9_f64);
+
+    /// SI, in $x \left( \frac{3}{4} + c \right)$, where $\left| \frac{3}{4} + c \right| \geq 0.439$.
+    pub(crate) const SI: Decay<{ size::SI }> = Decay::new(&super::SI, 0.43_f64);
+}
+
+/// The fits to E1 rewritten as ordinary polynomials, lowest power first, for `chebyshev::eval_estrin`.
+/// The `double-double` pieces keep to Clenshaw's recurrence on their own, longer tables.
+#[cfg(all(feature = "fast-poly", not(feature = "double-double")))]
+pub(crate) mod monomial {
+    use {super::piecewise, crate::chebyshev::monomial};
+
+    /// AE11, as a polynomial.
+    pub(crate) const AE11: [f64; piecewise::AE11.coefficients.len()] =
+        monomial(&piecewise::AE11.coefficients);
+    /// AE12, as a polynomial.
+    pub(crate) const AE12: [f64; piecewise::AE12.coefficients.len()] =
+        monomial(&piecewise::AE12.coefficients);
+    /// AE13, as a polynomial.
+    pub(crate) const AE13: [f64; piecewise::AE13.coefficients.len()] =
+        monomial(&piecewise::AE13.coefficients);
+    /// AE14, as a polynomial.
+    pub(crate) const AE14: [f64; piecewise::AE14.coefficients.len()] =
+        monomial(&piecewise::AE14.coefficients);
+    /// E11, as a polynomial.
+    pub(crate) const E11: [f64; piecewise::E11.coefficients.len()] =
+        monomial(&piecewise::E11.coefficients);
+    /// E12, as a polynomial.
+    pub(crate) const E12: [f64; piecewise::E12.coefficients.len()] =
+        monomial(&piecewise::E12.coefficients);
+}
+
+/// The series behind the double-precision pieces of E1 (`implementation::piecewise`),
+/// each on $[-1, 1]$ as in GSL, so that every piece maps its own argument onto it.
+/// Their coefficients are GSL's own (as `chebyshev::gsl` publishes them), or with the `tuned` feature, cut from the `quad` tables at build time
+/// to the fewest terms that meet the tolerance asked for, alongside a `ROOT_RADIUS` to suit (see `build.rs`).
+/// Every other backend keeps its own tables.
+#[cfg_attr(
+    feature = "double-double",
+    expect(
+        dead_code,
+        unused_imports,
+        reason = "the `double-double` pieces use the `quad` tables"
+    )
+)]
+pub(crate) mod piecewise {
+    /// The coefficients alone.
+    mod data {
+        #[cfg(not(feature = "tuned"))]
+        pub(crate) use super::super::{AE11, AE12, AE13, AE14, E11, E12, EI_ROOT, ROOT_RADIUS};
+
+        #[cfg(feature = "tuned")]
+        include!(concat!(env!("OUT_DIR"), "/tuned.rs"));
+    }
+
+    use crate::chebyshev::{Series, gsl, min};
+
+    pub(crate) use data::{EI_ROOT, ROOT_RADIUS};
+
+    /// `AE11_cs`.
+    pub(crate) const AE11: Series<{ data::AE11.len() }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: data::AE11,
+        order_sp: min(gsl::AE11.order_sp, data::AE11.len() - 1),
+    };
+
+    /// `AE12_cs`.
+    pub(crate) const AE12: Series<{ data::AE12.len() }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: data::AE12,
+        order_sp: min(gsl::AE12.order_sp, data::AE12.len() - 1),
+    };
+
+    /// `AE13_cs`.
+    pub(crate) const AE13: Series<{ data::AE13.len() }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: data::AE13,
+        order_sp: min(gsl::AE13.order_sp, data::AE13.len() - 1),
+    };
+
+    /// `AE14_cs`.
+    pub(crate) const AE14: Series<{ data::AE14.len() }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: data::AE14,
+        order_sp: min(gsl::AE14.order_sp, data::AE14.len() - 1),
+    };
+
+    /// `E11_cs`.
+    pub(crate) const E11: Series<{ data::E11.len() }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: data::E11,
+        order_sp: min(gsl::E11.order_sp, data::E11.len() - 1),
+    };
+
+    /// `E12_cs`.
+    pub(crate) const E12: Series<{ data::E12.len() }> = Series {
+        a: -1_f64,
+        b: 1_f64,
+        coefficients: data::E12,
+        order_sp: min(gsl::E12.order_sp, data::E12.len() - 1),
+    };
+}
+
+/// Longer tables for the `quad` backend, each `(hi, lo)` pair summing to a coefficient
+/// to about 32 significant digits, out to where the terms drop below $10^{-34}$.
+/// In their own file so that `build.rs` can cut the `tuned` tables from them too.
+#[cfg(feature = "quad")]
+pub(crate) mod quad {
+    include!("constants/quad.rs");
+}
+
+/// GSL's fits to E1 for `real` in single precision, each cut to its `single_order` and rounded to `f32` at compile time,
+/// with the first terms of the Taylor series about the zero of Ei beside them.
+pub(crate) mod single {
+    use crate::chebyshev::gsl;
+
+    /// Taylor terms enough for single precision within `ROOT_RADIUS` of the zero of Ei.
+    const EI_ROOT_TERMS: usize = 10;
+
+    /// `AE11`, in single precision.
+    pub(crate) const AE11: [f32; gsl::AE11.single_order() + 1] = gsl::AE11.to_f32();
+
+    /// `AE12`, in single precision.
+    pub(crate) const AE12: [f32; gsl::AE12.single_order() + 1] = gsl::AE12.to_f32();
+
+    /// `AE13`, in single precision.
+    pub(crate) const AE13: [f32; gsl::AE13.single_order() + 1] = gsl::AE13.to_f32();
+
+    /// `AE14`, in single precision.
+    pub(crate) const AE14: [f32; gsl::AE14.single_order() + 1] = gsl::AE14.to_f32();
+
+    /// `E11`, in single precision.
+    pub(crate) const E11: [f32; gsl::E11.single_order() + 1] = gsl::E11.to_f32();
+
+    /// `E12`, in single precision.
+    pub(crate) const E12: [f32; gsl::E12.single_order() + 1] = gsl::E12.to_f32();
+
+    /// `EI_ROOT`, in single precision.
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "evaluated at compile time, which rejects any index out of bounds"
+    )]
+    pub(crate) const EI_ROOT: [f32; EI_ROOT_TERMS] = {
+        let mut terms = [0_f32; EI_ROOT_TERMS];
+        let mut k = 0;
+        while k < EI_ROOT_TERMS {
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                reason = "rounding is the point"
+            )]
+            let rounded = super::EI_ROOT[k] as f32;
+            terms[k] = rounded;
+            k += 1;
+        }
+        terms
+    };
 }
 
 /// AE11
@@ -153,6 +393,23 @@ pub(crate) const AE14: [f64; size::AE14] = [
     -0.00000000000000005,
 ];
 
+/// Ci on [0, 4], in terms of $(x^2 - 8) / 8$.
+pub(crate) const CI: [f64; size::CI] = [
+    -0.34004281856055363156,
+    -1.0330216640117745681,
+    0.19388222659917082877,
+    -0.019182604360198658939,
+    0.0011078925258478496718,
+    -0.000041572345582472088038,
+    0.000001092785243002287153,
+    -0.000000021232859541834652196,
+    0.00000000031733482164348544865,
+    -0.0000000000037614154798768369938,
+    0.00000000000003622653488483964337,
+    -0.00000000000000028911528493651852433,
+    0.0000000000000000019432786067649442024,
+];
+
 /// E11
 pub(crate) const E11: [f64; size::E11] = [
     -16.11346165557149402600,
@@ -196,6 +453,267 @@ pub(crate) const E12: [f64; size::E12] = [
     0.00000000000000000315,
 ];
 
+/// Taylor coefficients of Ei about its zero, from the linear term up.
+pub(crate) const EI_ROOT: [f64; size::EI_ROOT] = [
+    3.8962157339071673102,
+    -3.2816078663985616709,
+    6.5223761454389256977,
+    -12.969697383536517036,
+    27.886297962942049979,
+    -62.378801528915418731,
+    143.53494880967509878,
+    -337.15582717874689168,
+    804.53183998213825066,
+    -1943.7966457234988407,
+    4743.7656504024308352,
+    -11673.463991167163644,
+    28926.955305435450874,
+    -72107.945868371589969,
+    180669.55858939196262,
+    -454696.21885446657465,
+    1148834.5468177443104,
+    -2912721.6638508374984,
+    7407692.9580005877597,
+    -18891727.000381531273,
+    48300034.930860247209,
+    -123768219.00249170921,
+];
+
+/// `expint_3` on [0, 2], divided by $x$, in terms of $x^3 / 4 - 1$.
+pub(crate) const EXPINT3: [f64; size::EXPINT3] = [
+    1.2691984142211260143,
+    -0.24884644638414098226,
+    0.080526220717231041246,
+    -0.025772733251968329337,
+    0.0075998788730737742928,
+    -0.002030695581940405104,
+    0.00049083458669932917292,
+    -0.00010768223914202076899,
+    0.000021551726264289836003,
+    -0.0000039567051373842860156,
+    0.0000006699240933895647248,
+    -0.00000010513218080703113467,
+    0.00000001536258019824631175,
+    -0.0000000020990960363575375422,
+    0.00000000026921095381010139763,
+    -0.000000000032519524221158829334,
+    0.0000000000037114815745798035254,
+    -0.00000000000040136518399588172815,
+    0.000000000000041233455200546348391,
+    -0.0000000000000040337494974572113138,
+    0.00000000000000037658485124759927126,
+    -0.000000000000000033618678496478560073,
+    0.0000000000000000028751689117710548588,
+];
+
+/// `expint_3` above 2, its distance from $\Gamma(4/3)$ divided by $e^{-x^3} / (3 x^2)$, in terms of $16 / x^3 - 1$.
+pub(crate) const EXPINT3A: [f64; size::EXPINT3A] = [
+    1.9270464955068273729,
+    -0.034929356520481380544,
+    0.0014503383718983009316,
+    -0.000089253367183279025616,
+    0.0000070542392191183837498,
+    -0.00000066717274547611216004,
+    0.000000072426758998244675696,
+    -0.0000000087825825605562042987,
+    0.0000000011672234427840392885,
+    -0.00000000016766312811838401323,
+    0.000000000025755015767624700289,
+    -0.0000000000041957888081705009639,
+    0.00000000000072010411552538912404,
+    -0.00000000000012949055431501650279,
+    0.000000000000024287025493067193434,
+    -0.0000000000000047331145591190704629,
+    0.00000000000000095530989469632374693,
+    -0.00000000000000019913641992334664469,
+    0.000000000000000042767170343654431842,
+    -0.0000000000000000094427679281829666872,
+    0.0000000000000000021394487128375251333,
+];
+
+/// Auxiliary function $f$ on [4, $\sqrt{50}$], in terms of $(1 / x^2 - 0.04125) / 0.02125$.
+pub(crate) const F1: [f64; size::F1] = [
+    -0.11910819690513636103,
+    -0.024782314499623624759,
+    0.0011910281453357821268,
+    -0.000092702771438856174831,
+    0.0000093373141568270996868,
+    -0.0000011058287820557143939,
+    0.00000014647720714601621693,
+    -0.00000002106944962876895326,
+    0.0000000032293492366848236383,
+    -0.0000000005206529617529375828,
+    0.000000000087487888457027875027,
+    -0.000000000015217618705612366829,
+    0.0000000000027257192405419573901,
+    -0.00000000000050070530759685562903,
+    0.000000000000094024090272606851178,
+    -0.000000000000018001444479180367834,
+    0.0000000000000035062621432741785826,
+    -0.00000000000000069352829267691497089,
+    0.0000000000000001390925136454216568,
+    -0.000000000000000028248688507417058508,
+    0.0000000000000000058031305693579080796,
+    -0.0000000000000000012046901573375819632,
+];
+
+/// Auxiliary function $f$ above $\sqrt{50}$, in terms of $100 / x^2 - 1$.
+pub(crate) const F2: [f64; size::F2] = [
+    -0.034840925389701323308,
+    -0.016684220567795968732,
+    0.00067529012412377385045,
+    -0.000053506662254470136288,
+    0.0000062693421779007526705,
+    -0.00000095266388019916680678,
+    0.00000017456292242509880426,
+    -0.000000036879540306530933071,
+    0.0000000087202677705139526408,
+    -0.0000000022601970391973874853,
+    0.0000000006324624976525061252,
+    -0.00000000018889118884717869241,
+    0.000000000059677467299978133726,
+    -0.000000000019804431173722390112,
+    0.0000000000068641395477210338371,
+    -0.0000000000024731019307019910607,
+    0.00000000000092263594549941404196,
+    -0.00000000000035523634999261784497,
+    0.00000000000014076049625351591462,
+    -0.000000000000057262284997476527944,
+    0.000000000000023865375454131718102,
+    -0.000000000000010171418907645971423,
+    0.0000000000000044259453107836442505,
+    -0.0000000000000019634493304918976206,
+    0.00000000000000088688748314810461114,
+    -0.00000000000000040743345027311547041,
+    0.00000000000000019016837215675339954,
+    -0.000000000000000090097072974780425389,
+    0.000000000000000043292112740956687637,
+    -0.000000000000000021081444653224796213,
+    0.000000000000000010396379070264523665,
+    -0.0000000000000000051889100794893202511,
+    0.0000000000000000026195532486989945519,
+    -0.0000000000000000013369039995130164784,
+];
+
+/// Auxiliary function $g$ on [4, $\sqrt{50}$], in terms of $(1 / x^2 - 0.04125) / 0.02125$.
+pub(crate) const G1: [f64; size::G1] = [
+    -0.30405787982534959545,
+    -0.056689098459712058773,
+    0.003904615817327564392,
+    -0.00037460759592022606186,
+    0.000043543155655984367955,
+    -0.0000057417294453025046562,
+    0.00000082825521045026297419,
+    -0.00000012782458925946427279,
+    0.000000020797835294868788444,
+    -0.0000000035313205921990798042,
+    0.00000000062108242363089510686,
+    -0.00000000011252154744462926493,
+    0.000000000020908891768442160527,
+    -0.0000000000039715831737681727689,
+    0.0000000000007690431314272089939,
+    -0.00000000000015146967427316135198,
+    0.000000000000030289214655235968412,
+    -0.00000000000000613997038347088254,
+    0.0000000000000012600605829510933553,
+    -0.00000000000000026150292509394836828,
+    0.000000000000000054827884489179682078,
+    -0.000000000000000011603818212952657147,
+    0.0000000000000000024771654107129795271,
+];
+
+/// Auxiliary function $g$ above $\sqrt{50}$, in terms of $100 / x^2 - 1$.
+pub(crate) const G2: [f64; size::G2] = [
+    -0.096732936753243250795,
+    -0.045207790795745958754,
+    0.0028190005352706258921,
+    -0.00028991677407589267876,
+    0.000040744466460093006559,
+    -0.0000071056382192212662148,
+    0.000001453472316293481813,
+    -0.00000033641165124790051242,
+    0.000000085977436792354194028,
+    -0.000000023843765640027413614,
+    0.0000000070831906493253322277,
+    -0.000000002231806835586543861,
+    0.00000000074010875999906898868,
+    -0.00000000025671714315732460167,
+    0.000000000092670730744712017748,
+    -0.000000000034669360148840222767,
+    0.000000000013395085392439664815,
+    -0.0000000000053291012592846096854,
+    0.000000000002177553621583957877,
+    -0.00000000000091188019488949942997,
+    0.00000000000039059927730094815387,
+    -0.00000000000017085294110496745995,
+    0.000000000000076202423560112608467,
+    -0.000000000000034609895553015989876,
+    0.000000000000015988511272142281571,
+    -0.0000000000000075047363387321863249,
+    0.0000000000000035757826452022792893,
+    -0.0000000000000017279962114422883408,
+    0.00000000000000084627660141801723556,
+    -0.00000000000000041973147958270057559,
+    0.00000000000000021068738981195550467,
+    -0.00000000000000010696821862533190356,
+    0.000000000000000054901261714458615442,
+    -0.000000000000000028470939482629959266,
+    0.000000000000000014911119823777882455,
+    -0.0000000000000000078835097900432684747,
+    0.0000000000000000042058628117550741634,
+    -0.0000000000000000022633621410889802823,
+    0.0000000000000000012281875394231412898,
+];
+
+/// Si on [0, 4], in terms of $(x^2 - 8) / 8$.
+pub(crate) const SI: [f64; size::SI] = [
+    -0.13156465981848419289,
+    -0.2776578526973601892,
+    0.035441405486665917975,
+    -0.0025631631447933977659,
+    0.00011623653904970092813,
+    -0.000003590432724160604267,
+    0.000000080234212370571016231,
+    -0.000000001356299769254025065,
+    0.000000000017944072159973677557,
+    -0.00000000000019083873430871454907,
+    0.0000000000000016669989586824330853,
+    -0.00000000000000001217309883685030425,
+];
+
+/// $E_{1}(1)$, equal to the Gompertz constant over $e$.
+pub(crate) const E1_1: f64 = 0.219_383_934_395_520_273_677_163_775_460_121_649_031_047_293_406_908;
+
+/// The unique real zero of Ei, rounded to the nearest `f64`.
+pub(crate) const EI_ZERO: f64 = 0.372_507_410_781_366_6;
+
+/// What's left of Ei's zero after `EI_ZERO`,
+/// so that distances from the true zero can be computed without cancellation.
+pub(crate) const EI_ZERO_LO: f64 = 1.314_018_341_438_602_8e-17;
+
+/// Half-width of the interval about minus the zero of Ei where a Taylor series takes over from `E12`,
+/// unless the `tuned` double-precision pieces pick their own (`piecewise::ROOT_RADIUS`).
+pub(crate) const ROOT_RADIUS: f64 = 0.0625_f64;
+
+/// The Euler-Mascheroni constant, often written $\gamma$.
+pub(crate) const EULER_GAMMA: f64 = 0.577_215_664_901_532_860_606_512_090_082_402_431;
+
+/// The Gompertz constant, $\delta = -e \, \text{Ei}(-1) = e \, E_{1}(1)$.
+pub(crate) const GOMPERTZ: f64 = 0.596_347_362_323_194_074_341_078_499_369_279_376_074_177_860_152;
+
+/// The logarithmic integral at 2, the offset between li and Li.
+pub(crate) const LI_2: f64 = 1.045_163_780_117_492_784_844_588_889_194_613_136_522_615_578_151;
+
+/// What's left of $\ln 10$ after `core::f64::consts::LN_10`,
+/// so that large multiples of it can be subtracted without losing the low bits.
+pub(crate) const LN_10_LO: f64 = -2.170_756_223_382_249_4e-16;
+
+/// `GSL_LOG_DBL_MAX`: the largest `x` for which $e^{x}$ is representable.
+pub(crate) const LOG_DBL_MAX: f64 = 709.782_712_893_384;
+
+/// `GSL_LOG_DBL_MIN`: the smallest `x` for which $e^{x}$ is a normal number.
+pub(crate) const LOG_DBL_MIN: f64 = -708.396_418_532_264_1;
+
 /*
 pub(crate) const AE11_F: &[Finite<f64>; size::AE11] = {
     let ptr: *const [f64; size::AE11] = &AE11;
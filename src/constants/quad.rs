@@ -0,0 +1,422 @@
+/// AE11, extended to double-double precision.
+pub(crate) const AE11: [(f64, f64); 118] = [
+    (0.12150323971606579, 4.911401710780807e-18),
+    (-0.06508877851355015, -3.0392971480956307e-18),
+    (0.004897651357459671, -2.905821107068711e-19),
+    (-0.0006492378430272168, -2.598755252120895e-20),
+    (9.384043458747103e-05, -5.508599207226844e-22),
+    (4.202363808820039e-07, -3.046886318677348e-24),
+    (-8.113374735904291e-06, -2.712518511231794e-22),
+    (2.8042476886631022e-06, 1.282434873231422e-22),
+    (5.648716444114937e-08, -1.6874095911415976e-24),
+    (-3.4480917445022337e-07, -1.088952992658078e-23),
+    (5.8209273578590736e-08, -2.9137235050660934e-25),
+    (3.871142634904246e-08, -1.3764504914442694e-24),
+    (-1.2453235014896396e-08, -4.982994446548928e-25),
+    (-5.118504888206408e-09, 3.4194183750320797e-25),
+    (2.148771527978497e-09, -1.9290609042396635e-25),
+    (8.684598980432204e-10, -1.3071419442831912e-26),
+    (-3.436501055463126e-10, -8.432816071268633e-27),
+    (-1.7979660319444188e-10, 1.1499096082535643e-27),
+    (4.744206065344532e-11, 1.5036711628120683e-28),
+    (4.042328248980016e-11, -2.5687454812721867e-27),
+    (-3.543928856885199e-12, -1.039211185692296e-28),
+    (-8.853444461628819e-12, -3.2151183075525705e-28),
+    (-9.601513950260589e-13, 9.636943285194011e-29),
+    (1.6929211184893772e-12, 8.728364816084933e-29),
+    (6.079908829009703e-13, -2.0283726308578717e-30),
+    (-2.2433865159470682e-13, 7.526321487093118e-30),
+    (-2.003279429513417e-13, 5.679196798442329e-30),
+    (-6.246376745126003e-15, 7.773942521062635e-32),
+    (4.557109719351219e-14, 7.922125185575557e-31),
+    (1.6383511371823757e-14, -1.7571757818028098e-31),
+    (-5.561223783509763e-15, -3.943815030183175e-31),
+    (-6.07481984361488e-15, 3.503369099952697e-31),
+    (-8.628537175307559e-16, -1.1165856973707525e-32),
+    (1.223658824992149e-15, 8.589758428218699e-32),
+    (7.167680228028337e-16, -3.4788391399094204e-32),
+    (-2.4394440083657013e-17, 1.4578639156091916e-33),
+    (-2.0185099311120185e-16, -1.0642020913690366e-32),
+    (-8.264394843683699e-17, 2.2232945241057365e-33),
+    (1.764665989556021e-17, -9.535471354031035e-34),
+    (3.110043437785543e-17, 1.2914966083927855e-33),
+    (9.940980260990406e-18, -3.944958782780402e-34),
+    (-3.924957101210021e-18, -1.6290449968333346e-34),
+    (-4.805977473708027e-18, -2.5778615889995223e-34),
+    (-1.3264213323708593e-18, -3.5691726460481605e-35),
+    (7.077455377551889e-19, -1.2138032925536267e-35),
+    (7.669403522743059e-19, 2.1212525096967714e-35),
+    (2.0588328133730787e-19, -1.0178548405763658e-35),
+    (-1.160083439705734e-19, -6.339474499028779e-36),
+    (-1.2733384175486425e-19, -9.737354801179732e-36),
+    (-3.734849444350421e-20, 1.4893207615204986e-36),
+    (1.7445569534315115e-20, -3.873744189408296e-37),
+    (2.1829835917913248e-20, 1.3641796337474398e-36),
+    (7.596971681473642e-21, 2.4032730606173796e-37),
+    (-2.258995586628448e-21, -3.883839097575378e-38),
+    (-3.7919876444030166e-21, -3.3081324876215467e-37),
+    (-1.6383434631757332e-21, -4.540542633707703e-38),
+    (1.8557549265064198e-22, -6.145340227296814e-40),
+    (6.479657660117741e-22, 2.057911425837352e-38),
+    (3.576550591701524e-22, 8.407668078584556e-40),
+    (2.1152578806463317e-23, 9.455462544959839e-40),
+    (-1.039400260524e-22, 2.5066440691245237e-40),
+    (-7.633788482880909e-23, -3.665686737144121e-39),
+    (-1.6759213610154417e-23, 4.5104832701072014e-40),
+    (1.4217592965602672e-23, -7.41413525165627e-40),
+    (1.543280535657055e-23, -1.3257466249378822e-39),
+    (5.882947423244805e-24, 2.8939005109967017e-40),
+    (-1.1489780418067434e-24, -8.843102251531414e-41),
+    (-2.8320491781409435e-24, -1.9681443104587777e-41),
+    (-1.6228323010805534e-24, 7.901768023281086e-41),
+    (-1.8437957507049147e-25, 1.1190319529025215e-41),
+    (4.323276825705252e-25, 1.72118108500611e-41),
+    (3.807133256763595e-25, 9.925977950423036e-42),
+    (1.2824160698518845e-25, 9.0128805308647e-42),
+    (-3.9545047554947316e-26, -1.5608833739873532e-42),
+    (-7.52058528336559e-26, 2.2341330474935777e-42),
+    (-4.255380702398532e-26, -1.4604330451036962e-42),
+    (-5.304900064522647e-27, 1.2221846826508974e-43),
+    (1.1291589825125415e-26, 3.836456040002341e-43),
+    (1.0613844560464387e-26, 3.958002638635892e-43),
+    (4.1168847899289e-27, 3.5003394111843288e-43),
+    (-6.670314039290717e-28, 6.017505970331927e-45),
+    (-2.034390372818083e-27, 2.194628623914336e-44),
+    (-1.3614072808721481e-27, -1.908573891465621e-44),
+    (-3.294510086820495e-28, -1.9836953054936549e-44),
+    (2.4228200083158533e-28, -1.1976562923147e-44),
+    (3.177615446765885e-28, -2.7489611445196967e-45),
+    (1.6637580268820218e-28, -2.265176889688412e-45),
+    (1.5942904871368405e-29, -4.624389901559899e-47),
+    (-4.972340787493269e-29, 2.4386062938458968e-45),
+    (-4.694116629175911e-29, -4.142260432107417e-46),
+    (-1.992420769368554e-29, 2.690200071895693e-46),
+    (1.3402492922581805e-30, -8.017393799144924e-47),
+    (8.746646045797712e-30, 6.190029417012304e-46),
+    (6.831220325135666e-30, -7.227671587635048e-47),
+    (2.394364359428348e-30, 5.923497054689736e-48),
+    (-6.113174084773695e-31, 1.7771253124208685e-47),
+    (-1.4501333592105657e-30, 8.64143760637249e-47),
+    (-1.0035928315700935e-30, -4.0914468521868905e-47),
+    (-2.9679277513945895e-31, 5.538165739033369e-48),
+    (1.3684146521223356e-31, 5.799455307772466e-48),
+    (2.3544234826266e-31, -1.9159317402218748e-47),
+    (1.5127373297479043e-31, -1.0933559941213388e-47),
+    (3.930010200046953e-32, -9.552788102751747e-49),
+    (-2.547110922209828e-32, 1.266211626932158e-48),
+    (-3.812769398045575e-32, -1.9352437155557186e-49),
+    (-2.363183260697184e-32, 5.3467084480405384e-49),
+    (-5.76955852668412e-33, -3.0646259528725583e-50),
+    (4.3325050712277666e-33, -2.814519834528251e-50),
+    (6.209667273344396e-33, 4.9570524538394076e-49),
+    (3.842592849838749e-33, -3.2998475484549776e-50),
+    (9.591225489030087e-34, -6.163573043915215e-50),
+    (-6.8997079467100275e-34, 2.7166037225207394e-50),
+    (-1.0188160708660014e-33, 3.500088404290413e-50),
+    (-6.4948223226108705e-34, 3.6722200913553297e-50),
+    (-1.7869592699682118e-34, -3.1844755944801592e-52),
+    (1.020640831989393e-34, 9.50993261371815e-52),
+    (1.6779564652752686e-34, 4.9044995650130757e-51),
+    (1.1340753571243996e-34, -1.7612055442698537e-51),
+];
+
+/// AE12, extended to double-double precision.
+pub(crate) const AE12: [(f64, f64); 50] = [
+    (0.5824174951347267, 3.5791067778125206e-18),
+    (-0.15834885090578274, -5.8391974098007475e-18),
+    (-0.006764275590323142, 3.843873985721659e-19),
+    (0.005125843950185726, -1.0389766483156875e-19),
+    (0.0004352324921693911, -2.0208855489576014e-20),
+    (-0.00014361336630548354, -1.279047347397861e-20),
+    (-4.180132055630179e-05, -4.820066555896772e-22),
+    (-2.7133957586409437e-06, 1.599808922378363e-22),
+    (1.1513819136470894e-06, -9.111031472728668e-23),
+    (4.206500220120268e-07, -1.4396991253588554e-23),
+    (6.658190139137445e-08, 2.0396519239796934e-24),
+    (6.621437772375856e-10, -1.0273300724122887e-26),
+    (-2.84410487054599e-09, 5.341208527490656e-26),
+    (-9.407241973680665e-10, -5.104686249640765e-26),
+    (-1.774766026862123e-10, -5.926016255568571e-27),
+    (-1.5830222990521852e-11, 1.4268185049532129e-27),
+    (2.9057328484482887e-12, -1.2274779523464018e-29),
+    (1.769356202383076e-12, -3.731701287284961e-29),
+    (4.927350241333027e-13, -9.98607217720811e-30),
+    (9.370976515557365e-14, 4.689086134042727e-30),
+    (1.0707654071417934e-14, -1.7862531313238741e-31),
+    (-5.37854729656784e-16, 2.7276067393966952e-33),
+    (-7.167610823182241e-16, -1.687596555663457e-33),
+    (-2.443859268049522e-16, 2.06829651625499e-32),
+    (-5.825108541479334e-17, -3.380644134536949e-33),
+    (-1.0467225589834776e-17, -5.75307367231514e-34),
+    (-1.2078344665617982e-18, 7.790285490050695e-35),
+    (4.0521846330000294e-20, -1.1385491190605438e-36),
+    (7.468720766413114e-20, -3.7342145032559208e-37),
+    (2.733606697777514e-20, -2.264460674756809e-36),
+    (7.131989937177969e-21, 9.9080606648167e-38),
+    (1.4883593279837884e-21, -2.051636492031588e-38),
+    (2.428822087833069e-22, -4.655607274290441e-39),
+    (2.404452372267084e-23, 1.5828589843125728e-40),
+    (-2.2820863145178494e-24, -3.9793980078788634e-41),
+    (-2.100342231816653e-24, -1.30198489479173e-40),
+    (-7.359550650672571e-25, 2.562667355198616e-41),
+    (-1.9473329156077444e-25, 6.169378491112692e-42),
+    (-4.28578113811172e-26, -5.2231040564615345e-43),
+    (-7.859382383766801e-27, 6.687885196163508e-43),
+    (-1.0957664513327471e-27, -2.9244516392825873e-45),
+    (-6.064783390066259e-29, 3.598012453922078e-45),
+    (2.925265550143185e-29, -2.0740048032951776e-45),
+    (1.5036053133995448e-29, -4.035735359540584e-46),
+    (4.753091715468055e-30, -1.7740792571178124e-47),
+    (1.219702614204569e-30, -3.109184995435188e-47),
+    (2.6956327620963987e-31, 1.923758217025909e-47),
+    (5.13710937647724e-32, 3.0142008824492037e-50),
+    (7.981756636537683e-33, 5.550388031113358e-49),
+    (7.742078479671432e-34, 7.7780772001070235e-50),
+];
+
+/// AE13, extended to double-double precision.
+pub(crate) const AE13: [(f64, f64); 55] = [
+    (-0.6057732466406035, 4.212905265465371e-17),
+    (-0.1125352434836609, -4.171007895658573e-18),
+    (0.01343226624790278, -4.062495760306758e-19),
+    (-0.0019268451873811457, -2.2209437839419528e-20),
+    (0.0003091183377206032, -2.6095680169153024e-20),
+    (-5.356413212961842e-05, 2.665658596527541e-21),
+    (9.827812880247493e-06, -7.656581120085586e-22),
+    (-1.8853689849165184e-06, 8.04468782249949e-23),
+    (3.7494319356894736e-07, -1.1485376839847553e-24),
+    (-7.682345587055264e-08, -5.760518009126048e-25),
+    (1.6143270567198776e-08, 1.4515219782955046e-24),
+    (-3.4668022114907356e-09, 1.1999251239525276e-25),
+    (7.587542091903628e-10, -5.0005710121654516e-27),
+    (-1.6886433329881412e-10, -2.6849358134921496e-27),
+    (3.8145706749552266e-11, -5.732768288591943e-28),
+    (-8.733026632444629e-12, -4.570180675957798e-28),
+    (2.023672864586796e-12, -8.353305165277799e-29),
+    (-4.741328303955583e-13, -3.427829244116624e-29),
+    (1.1221172048389864e-13, 2.5705854181648964e-30),
+    (-2.680422543484031e-14, -7.396136174541038e-32),
+    (6.457851441771653e-15, -3.1435637579451176e-31),
+    (-1.5682760501666479e-15, 5.3456535101400544e-33),
+    (3.8367865399315405e-16, 1.508520277362074e-34),
+    (-9.451717302757913e-17, -4.2442793912144544e-33),
+    (2.3434812288949573e-17, 5.038033346733136e-34),
+    (-5.845866158021471e-18, -9.242503297619909e-35),
+    (1.4666229867947778e-18, 1.3541622404134602e-35),
+    (-3.6993923476444474e-19, 9.612324626488612e-36),
+    (9.379015993672124e-20, 6.1327440842379805e-37),
+    (-2.3893673221937873e-20, -3.7427805469069165e-37),
+    (6.115062462949761e-21, -5.126879430816699e-38),
+    (-1.5718585327554025e-21, -1.5567672910908333e-38),
+    (4.0572387285585398e-22, 1.685920713861692e-39),
+    (-1.0514026554738035e-22, -3.267626159619561e-39),
+    (2.734966493063867e-23, 5.182889603573837e-40),
+    (-7.14016040802058e-24, -7.497052688635225e-41),
+    (1.870555243223507e-24, 1.3709772324325562e-40),
+    (-4.916746816687045e-25, 3.0046399895924504e-41),
+    (1.296498811968403e-25, -6.748284401735709e-42),
+    (-3.429251568836306e-26, -2.3950712790610697e-42),
+    (9.097224164388416e-27, -2.458591838852384e-43),
+    (-2.4202112314320956e-27, 1.724745640908508e-43),
+    (6.456361293465445e-28, -3.2855947786667398e-44),
+    (-1.7269132735337432e-28, 9.085591106706392e-45),
+    (4.630861165891731e-29, -1.7573026167243256e-46),
+    (-1.2448703636654714e-29, 1.868251709128874e-46),
+    (3.3544574083116756e-30, 2.397040202947189e-46),
+    (-9.05988684677291e-31, -3.186730370228604e-47),
+    (2.452414700582507e-31, 1.341795338233789e-47),
+    (-6.652817846399815e-32, -1.3926569110111056e-48),
+    (1.8085453136011661e-32, 9.19577733468331e-50),
+    (-4.926495191788161e-33, 1.161301707403587e-49),
+    (1.3446393640552134e-33, 8.351061815622611e-50),
+    (-3.6771223316139643e-34, 7.403589542218235e-51),
+    (1.0074451116511102e-34, -6.368691162518592e-51),
+];
+
+/// AE14, extended to double-double precision.
+pub(crate) const AE14: [(f64, f64); 71] = [
+    (-0.1892918000753017, 9.058663641373066e-18),
+    (-0.08648117855259871, -6.224340731911345e-18),
+    (0.007224101543746595, -1.1488989324766325e-20),
+    (-0.0008097559457557386, -2.866951908813067e-20),
+    (0.00010999134432661389, -3.952657436055965e-21),
+    (-1.7173329989377674e-05, 2.9241408639085006e-22),
+    (2.9856275144792833e-06, -1.589500172303362e-23),
+    (-5.65964914577193e-07, -2.766340386384915e-23),
+    (1.15268083971414e-07, 3.4607000982488545e-24),
+    (-2.4950304402693382e-08, 6.116915849372185e-27),
+    (5.692324201833754e-09, 2.6995119518498563e-25),
+    (-1.3599576648056003e-09, -1.8322499178971776e-26),
+    (3.3846628887608844e-10, 1.9135253126703502e-26),
+    (-8.737853904474682e-11, -3.2303113669720813e-27),
+    (2.33158866322266e-11, -1.200339296097355e-27),
+    (-6.411481049213786e-12, -2.8036013446641616e-28),
+    (1.8122469802048165e-12, -2.025330303889077e-29),
+    (-5.253831761558461e-13, 4.2143851167650765e-29),
+    (1.5592182725919257e-13, 1.6144404090578647e-30),
+    (-4.7291682970803986e-14, -1.05498903980019e-30),
+    (1.4637618643932435e-14, 4.385776150706335e-31),
+    (-4.617388988712924e-15, -3.086948052323564e-31),
+    (1.4827103482893693e-15, 7.282216553587695e-32),
+    (-4.841672496239229e-16, 1.2794092674423262e-32),
+    (1.6062155757002903e-16, 8.913594478470717e-33),
+    (-5.408917538957171e-17, 2.606561260820151e-33),
+    (1.847470159346898e-17, -1.374438441795512e-33),
+    (-6.395830792759095e-18, 3.0476845481774564e-34),
+    (2.2427807216997594e-18, 1.0560552773266835e-34),
+    (-7.961369173983947e-19, -2.1550463553711472e-35),
+    (2.8593081115401974e-19, 2.6055568908852435e-36),
+    (-1.0384502447011372e-19, -3.2233674704024995e-36),
+    (3.812040607097976e-20, 7.380387602781325e-37),
+    (-1.4137954177172005e-20, 3.3946998355362905e-37),
+    (5.2953678651827354e-21, 1.1969401946889864e-37),
+    (-2.0022642450268267e-21, 6.10637059087335e-38),
+    (7.640262751275251e-22, 4.207283717816656e-38),
+    (-2.9411190068688016e-22, 1.1805351045200898e-38),
+    (1.141823539078879e-22, -1.1058655771011745e-38),
+    (-4.4693084759549865e-23, -8.424929925399504e-40),
+    (1.7632624105721015e-23, 1.204002588159765e-39),
+    (-7.009968187930252e-24, -4.425569047986615e-40),
+    (2.8075735565566363e-24, -8.591137435608252e-41),
+    (-1.1325609449761843e-24, 3.3024981492505104e-41),
+    (4.6005746843730805e-25, -4.2932515781911877e-41),
+    (-1.8814485990233803e-25, -9.423902475952873e-42),
+    (7.744916111709121e-26, -3.433539326379365e-42),
+    (-3.208512760198942e-26, -1.6790476872360114e-42),
+    (1.337445542564047e-26, 2.714626182425492e-45),
+    (-5.6086718842770246e-27, -2.6598626265507392e-43),
+    (2.3658397208855108e-27, -8.173425216142945e-44),
+    (-1.0036561942544765e-27, -7.51052796503084e-44),
+    (4.281490832270545e-28, -2.394345605158887e-44),
+    (-1.8363452520116529e-28, 1.6497481861310816e-45),
+    (7.917798644373261e-29, 2.9572337214999393e-45),
+    (-3.431542611055604e-29, 1.2555912709242839e-45),
+    (1.494705185085603e-29, 1.3773943509022456e-45),
+    (-6.542616638631344e-30, 2.1096638336901444e-46),
+    (2.8775830198596365e-30, -6.038074156852723e-47),
+    (-1.2715614061045544e-30, -8.616026359495654e-47),
+    (5.644615800704418e-31, 1.9852146315558662e-47),
+    (-2.5169538231528344e-31, 9.606634745200121e-48),
+    (1.127243697825127e-31, 6.696416934453696e-48),
+    (-5.070158733040184e-32, -3.0883657139961924e-48),
+    (2.2900629500422351e-32, -5.282932257780535e-50),
+    (-1.0386226075147398e-32, 3.7155771960806915e-49),
+    (4.72951176821051e-33, -5.94658746681418e-50),
+    (-2.162160903671315e-33, 1.1016527474036724e-49),
+    (9.922910010446355e-34, 7.354654955680461e-51),
+    (-4.571268921338877e-34, 2.730304807787589e-50),
+    (2.1137303948150165e-34, -1.3938480138893642e-50),
+];
+
+/// E11, extended to double-double precision.
+pub(crate) const E11: [(f64, f64); 29] = [
+    (-16.113461655571495, 8.758303134468609e-16),
+    (7.79407277874268, 2.692030495050247e-16),
+    (-1.955405818863142, 6.937929906974667e-17),
+    (0.37337293866277943, 2.6118297794362132e-17),
+    (-0.05692503191092902, 1.2656938181388014e-18),
+    (0.0072110777696600915, 3.5307064500419615e-19),
+    (-0.0007810490144984159, 5.471165624714118e-21),
+    (7.388093356262168e-05, 6.523818933404373e-21),
+    (-6.202861875808204e-06, -1.686367214380779e-22),
+    (4.6816002303176734e-07, 1.1201949986591822e-23),
+    (-3.209288853329865e-08, 3.1096680371251602e-24),
+    (2.0151997487404535e-09, -1.403710965814579e-25),
+    (-1.1673686816697794e-10, 8.070712600214694e-27),
+    (6.276270667203995e-12, -3.05112010867411e-28),
+    (-3.148154167227544e-13, -1.593758291611023e-29),
+    (1.4799041744493474e-14, -2.212002453028226e-31),
+    (-6.545709158397967e-16, -3.300338309273398e-32),
+    (2.733687222313729e-17, 4.322592594684125e-34),
+    (-1.0813524349754407e-18, -2.8536569377611257e-35),
+    (4.06283280404343e-20, 2.6083192000979816e-36),
+    (-1.4535539358960456e-21, -6.993723802434364e-39),
+    (4.963274618164864e-23, -2.8471724296402602e-39),
+    (-1.6208612696636048e-24, 6.566121963352165e-41),
+    (5.0721448038607506e-26, 1.7788699344433794e-42),
+    (-1.5235811133374597e-27, 3.866865359966452e-44),
+    (4.400151125638308e-29, -1.5967316848511068e-45),
+    (-1.2236141948328337e-30, -7.864285903414395e-47),
+    (3.2809216862871484e-32, -2.178181551174324e-48),
+    (-8.493348251185176e-34, 7.279522352361126e-50),
+];
+
+/// E12, extended to double-double precision.
+pub(crate) const E12: [(f64, f64); 26] = [
+    (-0.037390214792202794, -6.917846030702687e-19),
+    (0.042723986062209576, 9.739504568989436e-19),
+    (-0.13031820798497004, -1.2980045666794339e-17),
+    (0.01441912402469889, 5.486823067276511e-20),
+    (-0.0013461707805106802, 3.489650430765016e-21),
+    (0.0001073102925306378, -2.7594180523499014e-21),
+    (-7.429999516119436e-06, -2.40320271419178e-22),
+    (4.537732569075371e-07, 2.142597435755574e-23),
+    (-2.4764172113906014e-08, 8.710747172786269e-25),
+    (1.2207658137459096e-09, -5.828930355343598e-26),
+    (-5.485141480640924e-11, 7.3772521763513205e-28),
+    (2.263621421300788e-12, -1.43637506040329e-28),
+    (-8.63589727169801e-14, -3.1548876576245053e-30),
+    (3.06291553669333e-15, 1.10131625513504e-31),
+    (-1.0148571885594415e-16, 3.2166764577276235e-34),
+    (3.1548217403406988e-18, -1.3247964921475373e-35),
+    (-9.23604240769241e-20, -3.473707471134845e-38),
+    (2.55504267970814e-21, 1.5429988873837888e-37),
+    (-6.699128056845668e-23, -1.894355016036026e-39),
+    (1.6692540543538733e-24, -9.970736147036705e-41),
+    (-3.962549251843798e-26, -2.1133642354705262e-42),
+    (8.981358965985456e-28, 1.9007033541774072e-44),
+    (-1.947633669932059e-29, -4.990057933633745e-46),
+    (4.048360190402265e-31, -4.3136563737144516e-47),
+    (-8.079815696479965e-33, 2.6456444634180964e-49),
+    (1.5508166412565496e-34, 8.346774661489643e-51),
+];
+
+/// `EI_ROOT`, extended to double-double precision.
+pub(crate) const EI_ROOT: [(f64, f64); 43] = [
+    (3.8962157339071672, 6.554692094590738e-17),
+    (-3.2816078663985615, -1.6928160232326668e-16),
+    (6.522376145438925, 3.987811557611198e-16),
+    (-12.969697383536516, -6.811566563199919e-16),
+    (27.88629796294205, 1.3225215501170397e-15),
+    (-62.37880152891542, -1.3237789503765673e-15),
+    (143.5349488096751, 1.27163990306069e-14),
+    (-337.1558271787469, 1.2200574747757885e-14),
+    (804.5318399821382, 5.6159238197387434e-14),
+    (-1943.7966457234988, 4.519053782094394e-16),
+    (4743.765650402431, 7.013030825539498e-14),
+    (-11673.463991167164, 1.9619911664197404e-13),
+    (28926.95530543545, -1.350920094856384e-13),
+    (-72107.9458683716, 2.9822271674882564e-12),
+    (180669.55858939196, -7.063684221138601e-13),
+    (-454696.21885446657, -5.042517199978581e-12),
+    (1148834.5468177444, -7.321387637444995e-11),
+    (-2912721.6638508374, -1.1124766908841804e-10),
+    (7407692.958000587, 4.6028182052181973e-10),
+    (-18891727.000381533, 1.7836090765883612e-09),
+    (48300034.930860244, 3.470985113644172e-09),
+    (-123768219.00249171, 3.3564498926467084e-09),
+    (317811105.66636217, 1.8821322705453085e-08),
+    (-817618569.3184928, -4.688195231716063e-08),
+    (2107109291.8643637, 7.094191099552643e-08),
+    (-5438996831.077285, 2.1662680589733115e-07),
+    (14060263909.95585, -2.2280734695775318e-07),
+    (-36396891001.49205, -3.1653654900416827e-06),
+    (94338595092.19165, -5.263286341889529e-06),
+    (-244811170506.643, -7.660380910202703e-06),
+    (635998181827.3706, 2.0296778504158765e-05),
+    (-1653989211524.3916, -0.00011473946084154118),
+    (4305601123377.465, -0.00017182628806007325),
+    (-11218476935676.422, 0.0003529155620413171),
+    (29255656955573.395, -0.0019107927264724507),
+    (-76355527419593.92, 0.0011088078196451964),
+    (199437279275942.5, 0.0025753893705017248),
+    (-521302192120109.25, 0.02231082775490934),
+    (1363558024737805.5, 0.0846577065366601),
+    (-3568973490569445.5, -0.19298889550729725),
+    (9347264660720378.0, 0.7432976552859157),
+    (-2.449538043038436e+16, -0.47624178303540887),
+    (6.422884411934019e+16, 0.710804023144754),
+];
+
+/// What's left of Ei's zero after `EI_ZERO` and `EI_ZERO_LO`.
+pub(crate) const EI_ZERO_LO_LO: f64 = 6.472_568_844_595_414_5e-34;
@@ -0,0 +1,242 @@
+//! Double-double arithmetic: unevaluated sums of two `f64`s,
+//! carrying about 32 significant digits with nothing but `f64` operations.
+//!
+//! The algorithms are the usual error-free transformations
+//! (Knuth's two-sum and a fused multiply-add for exact products),
+//! as in Hida, Li, and Bailey's QD library.
+
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "floating-point arithmetic saturates instead of panicking"
+)]
+
+use core::{
+    f64::consts,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A bound on the relative error of the evaluations built on this module,
+/// about a hundred times the rounding error of a single double-double operation.
+#[cfg(all(feature = "double-double", feature = "error"))]
+pub(crate) const RELATIVE_ERROR: f64 = 1e-30_f64;
+
+/// What's left of $\ln 2$ after `core::f64::consts::LN_2`.
+const LN_2_LO: f64 = 2.319_046_813_846_299_6e-17;
+
+/// What's left of $\ln 2$ after `LN_2_LO`,
+/// so that reducing by a thousand multiples of it still leaves 32 good digits.
+const LN_2_LO_LO: f64 = 5.707_708_438_416_212e-34;
+
+/// The unevaluated sum `hi + lo`, where `lo` is at most half a unit in the last place of `hi`.
+#[expect(clippy::exhaustive_structs, reason = "Simple structure")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    /// The leading `f64`, which on its own is the value rounded to the nearest `f64`.
+    pub hi: f64,
+    /// What's left after `hi`.
+    pub lo: f64,
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let (leading, error) = two_sum(self.hi, rhs.hi);
+        let (trailing, rounding) = two_sum(self.lo, rhs.lo);
+        let (partial, carry) = quick_two_sum(leading, error + trailing);
+        let (hi, lo) = quick_two_sum(partial, carry + rounding);
+        Self { hi, lo }
+    }
+}
+
+impl Add<f64> for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: f64) -> Self {
+        let (leading, error) = two_sum(self.hi, rhs);
+        let (hi, lo) = quick_two_sum(leading, error + self.lo);
+        Self { hi, lo }
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    /// Long division, one `f64` digit at a time.
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let first = self.hi / rhs.hi;
+        let remainder = self - rhs * first;
+        let second = remainder.hi / rhs.hi;
+        let last_remainder = remainder - rhs * second;
+        let third = last_remainder.hi / rhs.hi;
+        let (hi, lo) = quick_two_sum(first, second);
+        Self { hi, lo } + third
+    }
+}
+
+impl Div<f64> for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        self / Self::from(rhs)
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    #[inline]
+    fn from(hi: f64) -> Self {
+        Self { hi, lo: 0_f64 }
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (leading, error) = two_prod(self.hi, rhs.hi);
+        let (hi, lo) = quick_two_sum(
+            leading,
+            self.hi.mul_add(rhs.lo, self.lo.mul_add(rhs.hi, error)),
+        );
+        Self { hi, lo }
+    }
+}
+
+impl Mul<f64> for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        let (leading, error) = two_prod(self.hi, rhs);
+        let (hi, lo) = quick_two_sum(leading, self.lo.mul_add(rhs, error));
+        Self { hi, lo }
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Sub<f64> for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: f64) -> Self {
+        self + -rhs
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} + {})", self.hi, self.lo)
+    }
+}
+
+/// $e^{x}$ to double-double precision.
+///
+/// Reduces by multiples of $\ln 2$, then by $2^{10}$, sums the Taylor series of $e^{r} - 1$ for what's left,
+/// and squares back up in that form so the leading 1 never swamps the digits that matter.
+#[inline]
+#[must_use]
+pub(crate) fn exp(x: f64) -> DoubleDouble {
+    /// Halvings before summing the series (and squarings after).
+    const HALVINGS: i32 = 10;
+    /// Taylor terms, enough for $\frac{|r|^{n}}{n!} < 10^{-33}$ once $|r| \leq \frac{\ln 2}{2^{11}}$.
+    const TERMS: u8 = 10;
+
+    let k = libm::round(x / consts::LN_2);
+    let reduced = (DoubleDouble::from(x) - product(consts::LN_2, k) - product(LN_2_LO, k)
+        + LN_2_LO_LO * -k)
+        * libm::scalbn(1_f64, -HALVINGS);
+    let mut term = reduced;
+    let mut expm1 = reduced;
+    for n in 2..=TERMS {
+        term = term * reduced / f64::from(n);
+        expm1 = expm1 + term;
+    }
+    for _ in 0_i32..HALVINGS {
+        expm1 = expm1 * (expm1 + 2_f64);
+    }
+    let value = expm1 + 1_f64;
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        reason = "`k` is a small whole number whenever `x` is in range"
+    )]
+    let power = k as i32;
+    DoubleDouble {
+        hi: libm::scalbn(value.hi, power),
+        lo: libm::scalbn(value.lo, power),
+    }
+}
+
+/// $\ln x$ to double-double precision for positive `x`.
+///
+/// Splits off the binary exponent (so even subnormal `x` can't overflow the exponential below),
+/// then takes one Newton step from the `f64` logarithm of the mantissa, which doubles its digits.
+#[inline]
+#[must_use]
+pub(crate) fn ln(x: f64) -> DoubleDouble {
+    let (mantissa, exponent) = libm::frexp(x);
+    let guess = libm::log(mantissa);
+    let power = f64::from(exponent);
+    DoubleDouble::from(guess)
+        + (exp(-guess) * mantissa - 1_f64)
+        + product(consts::LN_2, power)
+        + product(LN_2_LO, power)
+        + LN_2_LO_LO * power
+}
+
+/// `a * b` exactly.
+#[inline]
+const fn product(a: f64, b: f64) -> DoubleDouble {
+    let (hi, lo) = two_prod(a, b);
+    DoubleDouble { hi, lo }
+}
+
+/// `a + b` exactly, as the rounded sum and its rounding error,
+/// assuming $|a| \geq |b|$.
+#[inline]
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    (sum, b - (sum - a))
+}
+
+/// `a * b` exactly, as the rounded product and its rounding error.
+#[inline]
+pub(crate) const fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+/// `a + b` exactly, as the rounded sum and its rounding error.
+#[inline]
+pub(crate) const fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    (sum, (a - (sum - b_virtual)) + (b - b_virtual))
+}
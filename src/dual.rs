@@ -0,0 +1,248 @@
+//! Dual numbers, for forward-mode automatic differentiation through pipelines containing E1 or Ei.
+//!
+//! A `Dual` carries a value and its derivative with respect to some input,
+//! and the arithmetic below applies the chain rule as it goes.
+//! E1 and Ei seed the derivative exactly from $E_{1}'(x) = -\frac{e^{-x}}{x}$ and $\text{Ei}'(x) = \frac{e^{x}}{x}$
+//! instead of differentiating their Chebyshev fits, which would be far less accurate.
+//! Error estimates are dropped: only the values travel.
+
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "floating-point arithmetic saturates instead of panicking"
+)]
+
+use {
+    crate::{Approx, Precision},
+    core::{
+        error, fmt,
+        ops::{Add, Div, Mul, Neg, Sub},
+    },
+    sigma_types::{Finite, NonZero},
+};
+
+/// A value alongside its derivative, $a + b \varepsilon$ with $\varepsilon^{2} = 0$.
+#[expect(clippy::exhaustive_structs, reason = "Simple structure")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Dual {
+    /// Derivative of `value` with respect to whatever was seeded with `Dual::variable`.
+    pub derivative: f64,
+    /// The value itself.
+    pub value: f64,
+}
+
+impl Dual {
+    /// A constant, whose derivative is zero.
+    #[inline]
+    #[must_use]
+    pub const fn constant(value: f64) -> Self {
+        Self {
+            derivative: 0_f64,
+            value,
+        }
+    }
+
+    /// The variable being differentiated with respect to, whose derivative is one.
+    #[inline]
+    #[must_use]
+    pub const fn variable(value: f64) -> Self {
+        Self {
+            derivative: 1_f64,
+            value,
+        }
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            derivative: self.derivative + rhs.derivative,
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl Add<f64> for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: f64) -> Self {
+        Self {
+            derivative: self.derivative,
+            value: self.value + rhs,
+        }
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        Self {
+            derivative: value.mul_add(-rhs.derivative, self.derivative) / rhs.value,
+            value,
+        }
+    }
+}
+
+impl Div<f64> for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self {
+            derivative: self.derivative / rhs,
+            value: self.value / rhs,
+        }
+    }
+}
+
+impl From<f64> for Dual {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            derivative: self
+                .value
+                .mul_add(rhs.derivative, self.derivative * rhs.value),
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self {
+            derivative: self.derivative * rhs,
+            value: self.value * rhs,
+        }
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            derivative: -self.derivative,
+            value: -self.value,
+        }
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Sub<f64> for Dual {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: f64) -> Self {
+        self + -rhs
+    }
+}
+
+impl fmt::Display for Dual {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.derivative)
+    }
+}
+
+/// Why a dual number couldn't be evaluated.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[expect(
+    clippy::error_impl_error,
+    reason = "the name every caller already uses, qualified as `exponential_integral::dual::Error`"
+)]
+pub enum Error {
+    /// Value was zero, infinite, or NaN.
+    Domain(f64),
+    /// Value was finite and nonzero, but the result or its derivative couldn't be evaluated.
+    Range(crate::Error),
+}
+
+impl fmt::Display for Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Domain(arg) => write!(f, "Argument outside the domain: {arg}"),
+            Self::Range(ref e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "`Display` already includes any wrapped `crate::Error`, so there's no separate cause, and the rest are deprecated, unstable, or sealed"
+)]
+impl error::Error for Error {}
+
+/// $f(x)$ and $f'(x)$ combined by the chain rule,
+/// skipping $f'(x)$ entirely for constants so they can't pick up a spurious overflow.
+#[inline]
+fn chain(
+    x: Dual,
+    f: impl FnOnce(NonZero<Finite<f64>>) -> Result<Approx, crate::Error>,
+    f_prime: impl FnOnce(NonZero<Finite<f64>>) -> Result<Approx, crate::Error>,
+) -> Result<Dual, Error> {
+    let point = Finite::try_new(x.value)
+        .and_then(NonZero::try_new)
+        .ok_or(Error::Domain(x.value))?;
+    let value = *f(point).map_err(Error::Range)?.value;
+    let derivative = if x.derivative == 0_f64 {
+        0_f64
+    } else {
+        x.derivative * *f_prime(point).map_err(Error::Range)?.value
+    };
+    Ok(Dual { derivative, value })
+}
+
+/// $E_{1}(x)$ of a dual number, with the derivative carried through $E_{1}'(x) = -\frac{e^{-x}}{x}$.
+/// # Errors
+/// If the value is zero or not finite,
+/// or if either E1 or its derivative can't be evaluated there (see `E1` and `E1_derivative`).
+#[inline]
+pub fn E1(x: Dual, max_precision: Precision) -> Result<Dual, Error> {
+    chain(
+        x,
+        |point| crate::E1(point, max_precision),
+        crate::E1_derivative,
+    )
+}
+
+/// $\text{Ei}(x)$ of a dual number, with the derivative carried through $\text{Ei}'(x) = \frac{e^{x}}{x}$.
+/// # Errors
+/// If the value is zero or not finite,
+/// or if either Ei or its derivative can't be evaluated there (see `Ei` and `Ei_derivative`).
+#[inline]
+pub fn Ei(x: Dual, max_precision: Precision) -> Result<Dual, Error> {
+    chain(
+        x,
+        |point| crate::Ei(point, max_precision),
+        crate::Ei_derivative,
+    )
+}
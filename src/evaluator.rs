@@ -0,0 +1,108 @@
+//! E1 and Ei with their configuration held as data, set up one setting at a time:
+//! `Evaluator::new().max_terms(20).with_error(true).e1(x)`, or `.rel_tolerance(1e-8)` in place of `.max_terms(20)`.
+//!
+//! Settings a build can't honour are ignored rather than rejected:
+//! without the `error` feature, no estimate is reported.
+
+use {
+    crate::{Approx, Error, Precision},
+    sigma_types::{Finite, NonNegative, NonZero},
+};
+
+/// A value of E1 or Ei, with its error estimate if one was asked for (and the `error` feature computed it).
+#[expect(clippy::exhaustive_structs, reason = "Simple structure")]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Evaluation {
+    /// Estimate of the approximation error for `value`, as `Approx::error`.
+    pub error: Option<NonNegative<Finite<f64>>>,
+    /// Approximate value.
+    pub value: Finite<f64>,
+}
+
+/// How to evaluate E1 and Ei: by default, every term of every series, reporting the error estimate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Evaluator {
+    /// How far to sum each Chebyshev series, as set by `max_terms` or `rel_tolerance`, whichever came last.
+    precision: Precision,
+    /// Whether to report the error estimate.
+    with_error: bool,
+}
+
+impl Default for Evaluator {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Evaluator {
+    /// $E_{1}(x)$, as `E1` gives it.
+    /// # Errors
+    /// See `E1`.
+    #[inline]
+    pub fn e1(self, x: NonZero<Finite<f64>>) -> Result<Evaluation, Error> {
+        crate::E1(x, self.precision).map(|approx| self.evaluation(approx))
+    }
+
+    /// $\text{Ei}(x)$, as `Ei` gives it.
+    /// # Errors
+    /// See `Ei`.
+    #[inline]
+    pub fn ei(self, x: NonZero<Finite<f64>>) -> Result<Evaluation, Error> {
+        crate::Ei(x, self.precision).map(|approx| self.evaluation(approx))
+    }
+
+    /// An `Approx` as this configuration reports it.
+    #[inline]
+    const fn evaluation(self, approx: Approx) -> Evaluation {
+        Evaluation {
+            error: if self.with_error {
+                approx.error()
+            } else {
+                None
+            },
+            value: approx.value,
+        }
+    }
+
+    /// Sum at most `max_terms` terms of each Chebyshev series (and at least one),
+    /// i.e. `Precision::Terms(max_terms - 1)`, since `Terms` counts the highest order rather than the terms.
+    /// Replaces any `rel_tolerance`.
+    #[inline]
+    #[must_use]
+    pub const fn max_terms(self, max_terms: usize) -> Self {
+        Self {
+            precision: Precision::Terms(max_terms.saturating_sub(1)),
+            ..self
+        }
+    }
+
+    /// Every term of every series, reporting the error estimate.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            precision: Precision::Full,
+            with_error: true,
+        }
+    }
+
+    /// Sum only as many terms of each Chebyshev series as `Precision::RelTolerance(rel_tolerance)` does.
+    /// Replaces any `max_terms`.
+    #[inline]
+    #[must_use]
+    pub const fn rel_tolerance(self, rel_tolerance: f64) -> Self {
+        Self {
+            precision: Precision::RelTolerance(rel_tolerance),
+            ..self
+        }
+    }
+
+    /// Whether to report the error estimate.
+    /// Only the `error` feature computes one; without it, `Evaluation::error` is always `None`.
+    #[inline]
+    #[must_use]
+    pub const fn with_error(self, with_error: bool) -> Self {
+        Self { with_error, ..self }
+    }
+}
@@ -0,0 +1,74 @@
+//! E1 and Ei on the extended real line, infinities included,
+//! e.g. for arguments from upstream computations that saturate to $\pm \infty$.
+//!
+//! The infinities give the limits $E_{1}(+\infty) = +0$, $E_{1}(-\infty) = -\infty$,
+//! $\text{Ei}(+\infty) = +\infty$, and $\text{Ei}(-\infty) = -0$ (each zero exact),
+//! and every finite argument gets exactly what `with_zero::E1` and `with_zero::Ei` give, errors included.
+//! For values past where those refuse an argument, see `lenient`.
+//!
+//! No original C code: GSL reports an overflow or underflow at either infinity.
+
+use {
+    crate::{
+        Approx, Error, Precision,
+        with_zero::{self, Value},
+    },
+    sigma_types::Finite,
+};
+
+#[cfg(feature = "error")]
+use sigma_types::NonNegative;
+
+/// An `f64` that isn't NaN: finite, or either infinity.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct NotNan(f64);
+
+impl NotNan {
+    /// The argument itself.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+
+    /// `x`, unless it's NaN.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: f64) -> Option<Self> {
+        if x.is_nan() { None } else { Some(Self(x)) }
+    }
+}
+
+/// $E_{1}(x)$, or its limit at zero or either infinity.
+/// # Errors
+/// See `crate::E1`.
+#[inline]
+pub fn E1(x: NotNan, max_precision: Precision) -> Result<Value, Error> {
+    match Finite::try_new(x.0) {
+        Some(finite) => with_zero::E1(finite, max_precision),
+        None if x.0 > 0_f64 => Ok(zero(0_f64)),
+        None => Ok(Value::NegativeInfinity),
+    }
+}
+
+/// $\text{Ei}(x)$, or its limit at zero or either infinity.
+/// # Errors
+/// See `crate::E1`.
+#[inline]
+pub fn Ei(x: NotNan, max_precision: Precision) -> Result<Value, Error> {
+    match Finite::try_new(x.0) {
+        Some(finite) => with_zero::Ei(finite, max_precision),
+        None if x.0 > 0_f64 => Ok(Value::PositiveInfinity),
+        None => Ok(zero(-0_f64)),
+    }
+}
+
+/// The limit at an infinity where the function vanishes: exactly `signed`, a zero of either sign.
+#[inline]
+fn zero(signed: f64) -> Value {
+    Value::Finite(Approx {
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(0_f64)),
+        value: Finite::new(signed),
+    })
+}
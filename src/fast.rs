@@ -0,0 +1,92 @@
+//! Ei to about seven significant digits, for graphics, games, and anything else in real time
+//! where the full-precision path is overkill.
+//!
+//! Same pieces as `E1`, each with its Chebyshev series (or, near the zero of Ei, its Taylor series)
+//! cut off at the first term that brings the relative error under $2 \times 10^{-8}$,
+//! measured against 30-digit arithmetic across each piece:
+//!
+//! | $x$                                 | Terms kept | Worst relative error    |
+//! |-------------------------------------|------------|-------------------------|
+//! | $(-\infty, -4)$                     | 10 of 26   | $7.4 \times 10^{-9}$    |
+//! | $[-4, -1)$                          | 11 of 25   | $5.4 \times 10^{-9}$    |
+//! | $[-1, 1)$, away from the zero of Ei | 9 of 16    | $5.3 \times 10^{-9}$    |
+//! | within $\frac{1}{16}$ of the zero   | 9 of 22    | $8.1 \times 10^{-9}$    |
+//! | $[1, 4)$                            | 10 of 19   | $1.6 \times 10^{-8}$    |
+//! | $[4, 10)$                           | 11 of 25   | $2.9 \times 10^{-9}$    |
+//! | $[10, \infty)$                      | 12 of 39   | $1.7 \times 10^{-8}$    |
+//!
+//! Rounding adds only a few units in the last place on top of that, so $10^{-7}$ holds with plenty to spare.
+//! No error estimates and no `Result`:
+//! zero gives $-\infty$, anything too large for an `f64` gives $+\infty$,
+//! anything too negative underflows toward zero, and NaN gives NaN.
+//!
+//! No original C code: GSL has no reduced-precision functions.
+
+use crate::{chebyshev, constants};
+
+/// AE11, through the first term that matters to seven digits.
+const AE11: &[f64] = constants::AE11.split_at(12).0;
+
+/// AE12, through the first term that matters to seven digits.
+const AE12: &[f64] = constants::AE12.split_at(11).0;
+
+/// AE13, through the first term that matters to seven digits.
+const AE13: &[f64] = constants::AE13.split_at(11).0;
+
+/// AE14, through the first term that matters to seven digits.
+const AE14: &[f64] = constants::AE14.split_at(10).0;
+
+/// E11, through the first term that matters to seven digits.
+const E11: &[f64] = constants::E11.split_at(10).0;
+
+/// E12, through the first term that matters to seven digits.
+const E12: &[f64] = constants::E12.split_at(9).0;
+
+/// `EI_ROOT`, through the first term that matters to seven digits.
+const EI_ROOT: &[f64] = constants::EI_ROOT.split_at(9).0;
+
+/// $\frac{e^{-z}}{z} (1 + c)$, the shape of every piece away from the origin (in terms of $z = -x$).
+#[inline]
+fn decaying(z: f64, c: f64) -> f64 {
+    (1_f64 / z) * libm::exp(-z) * (1_f64 + c)
+}
+
+/// $\text{Ei}(x) = \int_{-\infty}^{x} \frac{e^{t}}{t} \text{d}t$ to within about $2 \times 10^{-8}$ relative error.
+/// See the module documentation for the accuracy in each region.
+#[inline]
+#[must_use]
+pub fn Ei(x: f64) -> f64 {
+    if x == 0_f64 {
+        return f64::NEG_INFINITY;
+    }
+    if x >= constants::XMAX {
+        return f64::INFINITY;
+    }
+    // The same dispatch as `E1`, at $z = -x$, since $\text{Ei}(x) = -E_{1}(-x)$:
+    let z = -x;
+    let e1 = if z <= -10_f64 {
+        decaying(z, chebyshev::eval_scalar(AE11, 20_f64 / z + 1_f64))
+    } else if z <= -4_f64 {
+        decaying(
+            z,
+            chebyshev::eval_scalar(AE12, (40_f64 / z + 7_f64) / 3_f64),
+        )
+    } else if z <= -1_f64 {
+        -libm::log(-z) + chebyshev::eval_scalar(E11, (2_f64 * z + 5_f64) / 3_f64)
+    } else if z < 0_f64 && (z + constants::EI_ZERO).abs() < constants::ROOT_RADIUS {
+        // Exact by Sterbenz's lemma, as in `piecewise::near_root`:
+        let distance = (x - constants::EI_ZERO) - constants::EI_ZERO_LO;
+        let sum = EI_ROOT
+            .iter()
+            .rev()
+            .fold(0_f64, |sum, &coefficient| sum * distance + coefficient);
+        -(sum * distance)
+    } else if z <= 1_f64 {
+        (-libm::log(z.abs()) - 0.6875_f64 + z) + chebyshev::eval_scalar(E12, z)
+    } else if z <= 4_f64 {
+        decaying(z, chebyshev::eval_scalar(AE13, (8_f64 / z - 5_f64) / 3_f64))
+    } else {
+        decaying(z, chebyshev::eval_scalar(AE14, 8_f64 / z - 1_f64))
+    };
+    -e1
+}
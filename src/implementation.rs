@@ -1,11 +1,395 @@
 //! Behind the curtain: actual implementations. May change (but almost surely won't).
 
+/// Pieces of `En` for orders above 2.
+pub(crate) mod en {
+    #![cfg_attr(
+        not(test),
+        expect(clippy::single_call_fn, reason = "disjoint, so that's kinda the point")
+    )]
+
+    use {
+        crate::{Approx, Error, Precision, algorithms::Converged, implementation::E2},
+        sigma_types::{Finite, Positive},
+    };
+
+    #[cfg(feature = "error")]
+    use {crate::constants, sigma_types::NonNegative};
+
+    /// Highest order reached by forward recurrence before switching to the continued fraction,
+    /// which converges quickly once `n + x` is this large.
+    pub(crate) const MAX_FORWARD_RECURRENCE: usize = 32;
+
+    /// Modified Lentz evaluation of the continued fraction
+    /// $E_{n}(x) = e^{-x} \left( \frac{1}{x + n -} \frac{1 \cdot n}{x + n + 2 -} \frac{2 (n + 1)}{x + n + 4 -} \cdots \right)$,
+    /// which converges quickly whenever `x + n` isn't small.
+    /// Valid for any real order `n`, not just integers.
+    /// With `scale`, leaves off the $e^{-x}$.
+    /// # Errors
+    /// If the result is too large to represent (only possible for negative `n`).
+    #[inline]
+    pub(crate) fn continued_fraction(
+        n: f64,
+        x: Positive<Finite<f64>>,
+        scale: bool,
+    ) -> Result<Converged, Error> {
+        /// Give up on exact convergence after this many terms,
+        /// since rounding can leave successive ratios a few ULPs from 1 forever.
+        const MAX_TERMS: f64 = 10_000_f64;
+        /// Stand-in for zero to avoid dividing by it.
+        const TINY: f64 = 1e-300_f64;
+
+        let raw = **x;
+        // `lentz_d` and `convergent` are kept multiplied by `rescale`,
+        // since near `f64::MAX` they'd otherwise be subnormal and drift by a ULP per term
+        // without ever settling, instead of converging in a couple of terms:
+        let rescale = raw.max(1_f64);
+        let mut denominator = raw + n;
+        let mut lentz_c = TINY.recip();
+        let mut lentz_d = if denominator.abs() < TINY {
+            rescale / TINY
+        } else {
+            rescale / denominator
+        };
+        let mut convergent = lentz_d;
+        let mut index = 1_f64;
+        #[cfg(feature = "error")]
+        let mut terms = 1_f64;
+        #[cfg(feature = "error")]
+        let mut last_delta: f64;
+        loop {
+            let numerator = -index * (n - 1_f64 + index);
+            denominator += 2_f64;
+            let mut unscaled = numerator.mul_add(lentz_d / rescale, denominator);
+            if unscaled.abs() < TINY {
+                unscaled = TINY;
+            }
+            lentz_d = rescale / unscaled;
+            lentz_c = denominator + numerator / lentz_c;
+            if lentz_c.abs() < TINY {
+                lentz_c = TINY;
+            }
+            let delta = lentz_c / rescale * lentz_d;
+            convergent *= delta;
+            #[cfg(feature = "error")]
+            {
+                terms += 1_f64;
+                last_delta = (delta - 1_f64).abs();
+            }
+            if (delta - 1_f64).abs() <= f64::EPSILON || index >= MAX_TERMS {
+                break;
+            }
+            index += 1_f64;
+        }
+
+        convergent /= rescale;
+        let value = Finite::try_new(if scale {
+            convergent
+        } else {
+            convergent * libm::exp(-raw)
+        })
+        .ok_or(Error::Overflow)?;
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "a small positive integer, at most `MAX_TERMS`"
+        )]
+        let levels = index as usize;
+        Ok(Converged {
+            approx: Approx {
+                value,
+                #[cfg(feature = "error")]
+                // Rounding in every step, plus truncation after the last:
+                error: NonNegative::new(Finite::new(
+                    ((terms + 2_f64) * constants::GSL_DBL_EPSILON + last_delta) * value.abs(),
+                )),
+            },
+            terms: levels.saturating_add(1),
+        })
+    }
+
+    /// The ratio $\frac{E_{n+1}(x)}{E_{n}(x)} = 1 - \frac{1}{K}$, where
+    /// $K = x + n + 2 - \frac{2 (n + 1)}{x + n + 4 -} \frac{3 (n + 2)}{x + n + 6 -} \cdots$
+    /// is the tail of the continued fraction for $E_{n}$ (see `continued_fraction`)
+    /// after its first level, so neither $E_{n}$ nor $e^{-x}$ is ever formed.
+    /// Modified Lentz again, but $K$ is at least `x + n`, so nothing ever needs nudging away from zero.
+    #[inline]
+    pub(crate) fn ratio_continued_fraction(n: f64, x: Positive<Finite<f64>>) -> Converged {
+        /// Give up on exact convergence after this many terms,
+        /// since rounding can leave successive ratios a few ULPs from 1 forever.
+        const MAX_TERMS: f64 = 10_000_f64;
+
+        let raw = **x;
+        let mut denominator = raw + n + 2_f64;
+        let mut lentz_c = denominator;
+        let mut lentz_d = 0_f64;
+        let mut tail = denominator;
+        let mut index = 2_f64;
+        #[cfg(feature = "error")]
+        let mut terms = 1_f64;
+        #[cfg(feature = "error")]
+        let mut last_delta: f64;
+        loop {
+            let numerator = -index * (n - 1_f64 + index);
+            denominator += 2_f64;
+            lentz_d = numerator.mul_add(lentz_d, denominator).recip();
+            lentz_c = denominator + numerator / lentz_c;
+            let delta = lentz_c * lentz_d;
+            tail *= delta;
+            #[cfg(feature = "error")]
+            {
+                terms += 1_f64;
+                last_delta = (delta - 1_f64).abs();
+            }
+            if (delta - 1_f64).abs() <= f64::EPSILON || index >= MAX_TERMS {
+                break;
+            }
+            index += 1_f64;
+        }
+
+        let complement = tail.recip();
+        let value = 1_f64 - complement;
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "a small positive integer, at most `MAX_TERMS`"
+        )]
+        let levels = index as usize;
+        Converged {
+            approx: Approx {
+                value: Finite::new(value),
+                #[cfg(feature = "error")]
+                // Relative error in the tail carries over to `complement`, plus the final subtraction:
+                error: NonNegative::new(Finite::new(
+                    ((terms + 2_f64) * constants::GSL_DBL_EPSILON + last_delta) * complement
+                        + constants::GSL_DBL_EPSILON * value.abs(),
+                )),
+            },
+            terms: levels,
+        }
+    }
+
+    /// Forward recurrence $E_{k+1}(x) = \frac{e^{-x} - x E_{k}(x)}{k}$ from $E_{2}$,
+    /// stable since each step scales the previous error by $x / k \leq 1$.
+    /// With `scale`, every order is multiplied through by $e^{x}$.
+    /// # Errors
+    /// Never in practice (`x` is at most 1), but propagated from `E2` just in case.
+    #[inline]
+    pub(crate) fn forward_recurrence(
+        n: usize,
+        x: Finite<f64>,
+        scale: bool,
+        max_precision: Precision,
+    ) -> Result<Approx, Error> {
+        let e2 = E2(x, scale, max_precision)?;
+        let ex = if scale { 1_f64 } else { libm::exp(-*x) };
+        let mut value = *e2.value;
+        #[cfg(feature = "error")]
+        let mut error = **e2.error;
+        let mut k = 2_f64;
+        for _ in 2..n {
+            value = (ex - *x * value) / k;
+            #[cfg(feature = "error")]
+            {
+                error = (constants::GSL_DBL_EPSILON * ex + *x * error) / k
+                    + 2_f64 * constants::GSL_DBL_EPSILON * value.abs();
+            }
+            k += 1_f64;
+        }
+        Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(error)),
+        })
+    }
+}
+
+/// Pieces of the relative exponential functions.
+pub(crate) mod exprel {
+    use {crate::Approx, sigma_types::Finite};
+
+    #[cfg(feature = "error")]
+    use {crate::constants, sigma_types::NonNegative};
+
+    /// Evaluation of the continued fraction
+    /// $\text{exprel}_{n}(x) = \frac{1}{1 -} \frac{x}{n + 1 +} \frac{x}{n + 2 -} \frac{(n + 1) x}{n + 3 +} \frac{2 x}{n + 4 -} \cdots$
+    /// by forward recurrence on its numerators and denominators,
+    /// rescaled whenever they get too large.
+    /// # Original C code
+    /// ```c
+    /// static int exprel_n_CF(const double N, const double x, gsl_sf_result * result)
+    /// {
+    ///   const double RECUR_BIG = GSL_SQRT_DBL_MAX;
+    ///   const int maxiter = 5000;
+    ///   int n = 1;
+    ///   double Anm2 = 1.0;
+    ///   double Bnm2 = 0.0;
+    ///   double Anm1 = 0.0;
+    ///   double Bnm1 = 1.0;
+    ///   double a1 = 1.0;
+    ///   double b1 = 1.0;
+    ///   double a2 = -x;
+    ///   double b2 = N+1;
+    ///   double an, bn;
+    ///
+    ///   double fn;
+    ///
+    ///   double An = b1*Anm1 + a1*Anm2;   /* A1 */
+    ///   double Bn = b1*Bnm1 + a1*Bnm2;   /* B1 */
+    ///
+    ///   /* One explicit step, before we get to the main pattern. */
+    ///   n++;
+    ///   Anm2 = Anm1;
+    ///   Bnm2 = Bnm1;
+    ///   Anm1 = An;
+    ///   Bnm1 = Bn;
+    ///   An = b2*Anm1 + a2*Anm2;   /* A2 */
+    ///   Bn = b2*Bnm1 + a2*Bnm2;   /* B2 */
+    ///
+    ///   fn = An/Bn;
+    ///
+    ///   while(n < maxiter) {
+    ///     double old_fn;
+    ///     double del;
+    ///     n++;
+    ///     Anm2 = Anm1;
+    ///     Bnm2 = Bnm1;
+    ///     Anm1 = An;
+    ///     Bnm1 = Bn;
+    ///     an = ( GSL_IS_ODD(n) ? ((n-1)/2)*x : -(N+(n/2)-1)*x );
+    ///     bn = N + n - 1;
+    ///     An = bn*Anm1 + an*Anm2;
+    ///     Bn = bn*Bnm1 + an*Bnm2;
+    ///
+    ///     if(fabs(An) > RECUR_BIG || fabs(Bn) > RECUR_BIG) {
+    ///       An /= RECUR_BIG;
+    ///       Bn /= RECUR_BIG;
+    ///       Anm1 /= RECUR_BIG;
+    ///       Bnm1 /= RECUR_BIG;
+    ///       Anm2 /= RECUR_BIG;
+    ///       Bnm2 /= RECUR_BIG;
+    ///     }
+    ///
+    ///     old_fn = fn;
+    ///     fn = An/Bn;
+    ///     del = old_fn/fn;
+    ///
+    ///     if(fabs(del - 1.0) < 2.0*GSL_DBL_EPSILON) break;
+    ///   }
+    ///
+    ///   result->val = fn;
+    ///   result->err = 4.0*(n+1.0)*GSL_DBL_EPSILON*fabs(fn);
+    ///
+    ///   if(n == maxiter)
+    ///     GSL_ERROR ("error", GSL_EMAXITER);
+    ///   else
+    ///     return GSL_SUCCESS;
+    /// }
+    /// ```
+    #[inline]
+    #[cfg_attr(
+        not(test),
+        expect(clippy::single_call_fn, reason = "disjoint, so that's kinda the point")
+    )]
+    pub(crate) fn continued_fraction(n: f64, x: f64) -> Approx {
+        /// Give up on exact convergence after this many steps.
+        const MAX_ITERATIONS: f64 = 5_000_f64;
+        /// Rescale the recurrence above this, so that it never overflows.
+        const RECUR_BIG: f64 = 1.340_780_792_994_259_6e154_f64;
+
+        let mut a_prev = 0_f64;
+        let mut b_prev = 1_f64;
+        let mut a_curr = 1_f64;
+        let mut b_curr = 1_f64;
+
+        // One explicit step, before the main pattern:
+        let mut next_a = (n + 1_f64).mul_add(a_curr, -x * a_prev);
+        let mut next_b = (n + 1_f64).mul_add(b_curr, -x * b_prev);
+        a_prev = a_curr;
+        b_prev = b_curr;
+        a_curr = next_a;
+        b_curr = next_b;
+        let mut convergent = a_curr / b_curr;
+
+        let mut index = 2_f64;
+        loop {
+            index += 1_f64;
+            let half = libm::floor(index * 0.5_f64);
+            let numerator = if half * 2_f64 < index {
+                half * x
+            } else {
+                -(n + half - 1_f64) * x
+            };
+            let denominator = n + index - 1_f64;
+            next_a = denominator.mul_add(a_curr, numerator * a_prev);
+            next_b = denominator.mul_add(b_curr, numerator * b_prev);
+            a_prev = a_curr;
+            b_prev = b_curr;
+            a_curr = next_a;
+            b_curr = next_b;
+
+            if a_curr.abs() > RECUR_BIG || b_curr.abs() > RECUR_BIG {
+                a_curr /= RECUR_BIG;
+                b_curr /= RECUR_BIG;
+                a_prev /= RECUR_BIG;
+                b_prev /= RECUR_BIG;
+            }
+
+            let previous = convergent;
+            convergent = a_curr / b_curr;
+            if (previous / convergent - 1_f64).abs() < 2_f64 * f64::EPSILON
+                || index >= MAX_ITERATIONS
+            {
+                break;
+            }
+        }
+
+        Approx {
+            value: Finite::new(convergent),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                4_f64 * (index + 1_f64) * constants::GSL_DBL_EPSILON * convergent.abs(),
+            )),
+        }
+    }
+
+    /// Power series $\text{exprel}_{n}(x) = \sum_{k=0}^{\infty} \frac{n! \, x^k}{(n + k)!}$,
+    /// converging at least as quickly as that of $e^{x}$ and free of cancellation for `x` at most 1 in magnitude.
+    #[inline]
+    pub(crate) fn series(n: f64, x: f64) -> Approx {
+        let mut term = 1_f64;
+        let mut k = n;
+        let mut sum = 1_f64;
+        #[cfg(feature = "error")]
+        let mut abs_sum = 1_f64;
+        loop {
+            k += 1_f64;
+            term *= x / k;
+            sum += term;
+            #[cfg(feature = "error")]
+            {
+                abs_sum += term.abs();
+            }
+            if term.abs() <= f64::EPSILON * sum.abs() {
+                break;
+            }
+        }
+        Approx {
+            value: Finite::new(sum),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                constants::GSL_DBL_EPSILON * (abs_sum + sum.abs()),
+            )),
+        }
+    }
+}
+
 pub(crate) mod neg {
     //! E1 for inputs less than 0.
 
     use {
-        crate::{Approx, constants, implementation::piecewise, neg::HugeArgument},
-        core::{cmp::Ordering, hint::unreachable_unchecked},
+        crate::{Approx, Precision, constants, implementation::Piece, neg::HugeArgument},
         sigma_types::{Finite, Negative},
     };
 
@@ -16,84 +400,160 @@ pub(crate) mod neg {
     #[inline]
     pub(crate) fn E1(
         x: Negative<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
+        max_precision: Precision,
     ) -> Result<Approx, HugeArgument> {
-        match (**x).partial_cmp(&-10_f64) {
-            // = -10
-            Some(Ordering::Equal) => Ok(piecewise::le_neg_10(
-                x,
-                #[cfg(feature = "precision")]
-                max_precision,
-            )),
-            // (-\infty, -10)
-            Some(Ordering::Less) => match (**x).partial_cmp(&constants::NXMAX) {
-                // (-XMAX, -10]
-                Some(Ordering::Greater) => Ok(piecewise::le_neg_10(
-                    x,
-                    #[cfg(feature = "precision")]
-                    max_precision,
-                )),
-                // (-\infty, -XMAX]
-                Some(Ordering::Less | Ordering::Equal) => Err(HugeArgument(x)),
-                // SAFETY:
-                // absurd case: `x` is finite
-                None => unsafe { unreachable_unchecked() },
-            },
-            // (-10, 0)
-            Some(Ordering::Greater) => Ok(match (**x).partial_cmp(&-4_f64) {
-                // (-10, -4]
-                Some(Ordering::Less | Ordering::Equal) => piecewise::le_neg_4(
-                    x,
-                    #[cfg(feature = "precision")]
-                    max_precision,
-                ),
-                // (-4, 0)
-                Some(Ordering::Greater) => match (**x).partial_cmp(&-1_f64) {
-                    // (-4, -1]
-                    Some(Ordering::Less | Ordering::Equal) => piecewise::le_neg_1(
-                        x,
-                        #[cfg(feature = "precision")]
-                        max_precision,
-                    ),
-                    // (-1, 0)
-                    Some(Ordering::Greater) => piecewise::le_pos_1(
-                        x.also(),
-                        #[cfg(feature = "precision")]
-                        max_precision,
-                    ),
-                    // SAFETY:
-                    // absurd case: `x` is finite
-                    None => unsafe { unreachable_unchecked() },
-                },
-                // SAFETY:
-                // absurd case: `x` is finite
-                None => unsafe { unreachable_unchecked() },
-            }),
-            // SAFETY:
-            // absurd case: `x` is finite
-            None => unsafe { unreachable_unchecked() },
+        if **x <= constants::NXMAX {
+            return HugeArgument::fail(x);
         }
+        Ok(Piece::at(**x).E1(*x, max_precision))
     }
 }
 
 /// Specialized approximations to be used on disjoint intervals of the domain,
 /// instead of a one-size-fits-all approach.
 pub(crate) mod piecewise {
-    #![cfg_attr(
-        not(test),
-        expect(clippy::single_call_fn, reason = "disjoint, so that's kinda the point")
-    )]
-
+    #[cfg(not(feature = "double-double"))]
     use {
-        crate::{Approx, chebyshev, constants},
+        crate::{Approx, Precision, constants},
         sigma_types::{Finite, Negative, NonZero, One as _, Positive},
     };
 
-    #[cfg(feature = "error")]
+    #[cfg(all(feature = "error", not(feature = "double-double")))]
     use sigma_types::NonNegative;
 
-    #[cfg(feature = "precision")]
-    use sigma_types::usize::LessThan;
+    #[cfg(all(feature = "fast-poly", not(feature = "double-double")))]
+    use {crate::chebyshev, sigma_types::usize::LessThan};
+
+    #[cfg(feature = "double-double")]
+    pub(crate) use rounded::*;
+
+    /// The same pieces, but with every step (including the logarithms and exponentials)
+    /// carried out in double-double arithmetic on longer tables, then rounded once at the end,
+    /// so the result is within a hair of half a unit in the last place.
+    /// Any `max_precision` is ignored, since each table already stops where its terms stop mattering.
+    #[cfg(feature = "double-double")]
+    mod rounded {
+        use {
+            crate::{
+                Approx, Precision, constants,
+                double_double::{self, DoubleDouble},
+                quad,
+            },
+            sigma_types::{Finite, Negative, NonZero, Positive},
+        };
+
+        #[cfg(feature = "error")]
+        use sigma_types::NonNegative;
+
+        /// Half-width of the interval about minus the zero of Ei handled by `near_root`,
+        /// the `quad` backend's, since these pieces are its own.
+        pub(crate) const ROOT_RADIUS: f64 = constants::ROOT_RADIUS;
+
+        /// Between -4 and -1.
+        #[inline]
+        pub(crate) fn le_neg_1(
+            x: Negative<Finite<f64>>,
+            scale: bool,
+            _max_precision: Precision,
+        ) -> Approx {
+            round(quad::le_neg_1(**x, scale))
+        }
+
+        /// Between the minimum input (around -710) and -10.
+        #[inline]
+        pub(crate) fn le_neg_10(
+            x: Negative<Finite<f64>>,
+            scale: bool,
+            _max_precision: Precision,
+        ) -> Approx {
+            round(quad::le_neg_10(**x, scale))
+        }
+
+        /// Between -10 and -4.
+        #[inline]
+        pub(crate) fn le_neg_4(
+            x: Negative<Finite<f64>>,
+            scale: bool,
+            _max_precision: Precision,
+        ) -> Approx {
+            round(quad::le_neg_4(**x, scale))
+        }
+
+        /// Between -1 and +1.
+        #[inline]
+        pub(crate) fn le_pos_1(
+            x: NonZero<Finite<f64>>,
+            scale: bool,
+            _max_precision: Precision,
+        ) -> Approx {
+            round(quad::le_pos_1(**x, scale))
+        }
+
+        /// `le_pos_1`, given `ln_abs(x)`.
+        #[inline]
+        pub(crate) fn le_pos_1_with_ln(
+            x: NonZero<Finite<f64>>,
+            ln: DoubleDouble,
+            scale: bool,
+            _max_precision: Precision,
+        ) -> Approx {
+            round(quad::le_pos_1_with_ln(**x, ln, scale))
+        }
+
+        /// Between +1 and +4.
+        #[inline]
+        pub(crate) fn le_pos_4(
+            x: Positive<Finite<f64>>,
+            scale: bool,
+            _max_precision: Precision,
+        ) -> Approx {
+            round(quad::le_pos_4(**x, scale))
+        }
+
+        /// Between +4 and the maximum input (around 710).
+        #[inline]
+        pub(crate) fn le_pos_max(
+            x: Positive<Finite<f64>>,
+            scale: bool,
+            _max_precision: Precision,
+        ) -> Approx {
+            round(quad::le_pos_max(**x, scale))
+        }
+
+        /// $\ln |x|$, as `le_pos_1_with_ln` takes it.
+        #[inline]
+        #[expect(
+            clippy::single_call_fn,
+            reason = "`le_pos_1` in double-double takes its own logarithm"
+        )]
+        pub(crate) fn ln_abs(x: NonZero<Finite<f64>>) -> DoubleDouble {
+            double_double::ln(x.abs())
+        }
+
+        /// Within `ROOT_RADIUS` of minus the zero of Ei.
+        #[inline]
+        pub(crate) fn near_root(x: Negative<Finite<f64>>, scale: bool) -> Approx {
+            round(quad::near_root(**x, scale))
+        }
+
+        /// Round to the nearest `f64`, whose error is whatever was rounded off
+        /// plus the double-double evaluation's own.
+        #[inline]
+        fn round(value: DoubleDouble) -> Approx {
+            Approx {
+                value: Finite::new(value.hi),
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    value.lo.abs() + double_double::RELATIVE_ERROR * value.hi.abs(),
+                )),
+            }
+        }
+    }
+
+    /// Half-width of the interval about minus the zero of Ei handled by `near_root`,
+    /// which with the `tuned` feature `build.rs` picks alongside the tables.
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) const ROOT_RADIUS: f64 = constants::piecewise::ROOT_RADIUS;
 
     /// Between -4 and -1.
     /// # Original C code
@@ -107,31 +567,78 @@ pub(crate) mod piecewise {
     /// return GSL_SUCCESS;
     /// ```
     #[inline]
+    #[cfg(not(feature = "double-double"))]
     pub(crate) fn le_neg_1(
         x: Negative<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
+        scale: bool,
+        max_precision: Precision,
     ) -> Approx {
-        #![expect(
-            clippy::arithmetic_side_effects,
-            reason = "property-based testing ensures this never happens"
-        )]
+        #[cfg(not(feature = "fast-poly"))]
+        let cheb = constants::decay::E11.sum(max_precision, |order| {
+            constants::piecewise::E11.eval_mapped(le_neg_1_argument(x), order)
+        });
+        #[cfg(feature = "fast-poly")]
+        let cheb = constants::decay::E11.sum(max_precision, |order| {
+            chebyshev::eval_estrin(
+                Finite::all(&constants::piecewise::E11.coefficients),
+                &constants::monomial::E11,
+                le_neg_1_argument(x),
+                LessThan::new(order),
+            )
+        });
+
+        le_neg_1_given(x, scale, cheb)
+    }
+
+    /// Where `le_neg_1` sums `E11`, as GSL maps `x` onto $[-1, 1]$.
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_neg_1_argument(x: Negative<Finite<f64>>) -> Finite<f64> {
+        ((Finite::new(2_f64) * *x) + Finite::new(5_f64)) / Finite::new(3_f64)
+    }
 
+    /// `le_neg_1`, given the sum of `E11` at `le_neg_1_argument(x)`
+    /// (e.g. summed alongside other points by `chebyshev::lockstep`).
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_neg_1_given(x: Negative<Finite<f64>>, scale: bool, cheb: Approx) -> Approx {
         let abs = Finite::new(x.abs());
         let ln = Finite::new(abs.ln());
         let nln = -ln;
 
-        let cheb = chebyshev::eval(
-            Finite::all(&constants::E11),
-            ((Finite::new(2_f64) * *x) + Finite::new(5_f64)) / Finite::new(3_f64),
-            #[cfg(feature = "precision")]
-            LessThan::new(max_precision.min(const { constants::size::E11 - 1 })),
-        );
-
-        let value = nln + cheb.value;
+        let scale_factor = if scale {
+            (*x).map(libm::exp)
+        } else {
+            Finite::<f64>::ONE
+        };
+        let value = scale_factor * (nln + cheb.value);
         #[cfg(feature = "error")]
         let epsilon = NonNegative::new(Finite::new(constants::GSL_DBL_EPSILON));
         #[cfg(feature = "error")]
-        let init_err = cheb.error + epsilon * NonNegative::new(Finite::new(nln.abs()));
+        let init_err = NonNegative::new(scale_factor)
+            * (cheb.error + epsilon * NonNegative::new(Finite::new(nln.abs())));
         #[cfg(feature = "error")]
         let addl_err = NonNegative::new(Finite::new(2_f64))
             * epsilon
@@ -156,27 +663,74 @@ pub(crate) mod piecewise {
     /// return GSL_SUCCESS;
     /// ```
     #[inline]
+    #[cfg(not(feature = "double-double"))]
     pub(crate) fn le_neg_10(
         x: Negative<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
+        scale: bool,
+        max_precision: Precision,
     ) -> Approx {
-        #![expect(
-            clippy::arithmetic_side_effects,
-            reason = "property-based testing ensures this never happens"
-        )]
+        #[cfg(not(feature = "fast-poly"))]
+        let cheb = constants::decay::AE11.sum(max_precision, |order| {
+            constants::piecewise::AE11.eval_mapped(le_neg_10_argument(x), order)
+        });
+        #[cfg(feature = "fast-poly")]
+        let cheb = constants::decay::AE11.sum(max_precision, |order| {
+            chebyshev::eval_estrin(
+                Finite::all(&constants::piecewise::AE11.coefficients),
+                &constants::monomial::AE11,
+                le_neg_10_argument(x),
+                LessThan::new(order),
+            )
+        });
 
-        let s: Finite<f64> = (Finite::<f64>::ONE / *x) * (-*x).map(libm::exp);
+        le_neg_10_given(x, scale, cheb)
+    }
 
-        let cheb = chebyshev::eval(
-            Finite::all(&constants::AE11),
-            (Finite::new(20_f64) / *x) + Finite::<f64>::ONE,
-            #[cfg(feature = "precision")]
-            LessThan::new(max_precision.min(const { constants::size::AE11 - 1 })),
-        );
+    /// Where `le_neg_10` sums `AE11`, as GSL maps `x` onto $[-1, 1]$.
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_neg_10_argument(x: Negative<Finite<f64>>) -> Finite<f64> {
+        (Finite::new(20_f64) / *x) + Finite::<f64>::ONE
+    }
+
+    /// `le_neg_10`, given the sum of `AE11` at `le_neg_10_argument(x)`
+    /// (e.g. summed alongside other points by `chebyshev::lockstep`).
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_neg_10_given(x: Negative<Finite<f64>>, scale: bool, cheb: Approx) -> Approx {
+        let s: Finite<f64> = (Finite::<f64>::ONE / *x)
+            * if scale {
+                Finite::<f64>::ONE
+            } else {
+                (-*x).map(libm::exp)
+            };
 
         let value = s * (Finite::<f64>::ONE + cheb.value);
         #[cfg(feature = "error")]
-        let init_err = s * *cheb.error;
+        // `s` is negative here, which GSL overlooks, so take its magnitude:
+        let init_err = s.map(f64::abs) * *cheb.error;
         #[cfg(feature = "error")]
         let addl_err = {
             let abs_x: NonNegative<Finite<f64>> = x.map(|f| f.map(f64::abs));
@@ -204,27 +758,74 @@ pub(crate) mod piecewise {
     /// return GSL_SUCCESS;
     /// ```
     #[inline]
+    #[cfg(not(feature = "double-double"))]
     pub(crate) fn le_neg_4(
         x: Negative<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
+        scale: bool,
+        max_precision: Precision,
     ) -> Approx {
-        #![expect(
-            clippy::arithmetic_side_effects,
-            reason = "property-based testing ensures this never happens"
-        )]
+        #[cfg(not(feature = "fast-poly"))]
+        let cheb = constants::decay::AE12.sum(max_precision, |order| {
+            constants::piecewise::AE12.eval_mapped(le_neg_4_argument(x), order)
+        });
+        #[cfg(feature = "fast-poly")]
+        let cheb = constants::decay::AE12.sum(max_precision, |order| {
+            chebyshev::eval_estrin(
+                Finite::all(&constants::piecewise::AE12.coefficients),
+                &constants::monomial::AE12,
+                le_neg_4_argument(x),
+                LessThan::new(order),
+            )
+        });
+
+        le_neg_4_given(x, scale, cheb)
+    }
 
-        let s: Finite<f64> = (Finite::<f64>::ONE / *x) * (-*x).map(libm::exp);
+    /// Where `le_neg_4` sums `AE12`, as GSL maps `x` onto $[-1, 1]$.
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_neg_4_argument(x: Negative<Finite<f64>>) -> Finite<f64> {
+        ((Finite::new(40_f64) / *x) + Finite::new(7_f64)) / Finite::new(3_f64)
+    }
 
-        let cheb = chebyshev::eval(
-            Finite::all(&constants::AE12),
-            ((Finite::new(40_f64) / *x) + Finite::new(7_f64)) / Finite::new(3_f64),
-            #[cfg(feature = "precision")]
-            LessThan::new(max_precision.min(const { constants::size::AE12 - 1 })),
-        );
+    /// `le_neg_4`, given the sum of `AE12` at `le_neg_4_argument(x)`
+    /// (e.g. summed alongside other points by `chebyshev::lockstep`).
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_neg_4_given(x: Negative<Finite<f64>>, scale: bool, cheb: Approx) -> Approx {
+        let s: Finite<f64> = (Finite::<f64>::ONE / *x)
+            * if scale {
+                Finite::<f64>::ONE
+            } else {
+                (-*x).map(libm::exp)
+            };
 
         let value = s * (Finite::<f64>::ONE + cheb.value);
         #[cfg(feature = "error")]
-        let init_err = s * *cheb.error;
+        // `s` is negative here, which GSL overlooks, so take its magnitude:
+        let init_err = s.map(f64::abs) * *cheb.error;
         #[cfg(feature = "error")]
         let addl_err = {
             let abs_value: NonNegative<Finite<f64>> = NonNegative::new(value.map(f64::abs));
@@ -251,31 +852,75 @@ pub(crate) mod piecewise {
     /// return GSL_SUCCESS;
     /// ```
     #[inline]
+    #[cfg(not(feature = "double-double"))]
     pub(crate) fn le_pos_1(
         x: NonZero<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
+        scale: bool,
+        max_precision: Precision,
     ) -> Approx {
-        #![expect(
-            clippy::arithmetic_side_effects,
-            reason = "property-based testing ensures this never happens"
-        )]
+        le_pos_1_with_ln(x, ln_abs(x), scale, max_precision)
+    }
 
-        let abs = Finite::new(x.abs());
-        let ln = Finite::new(abs.ln());
-        let nln = -ln;
+    /// `le_pos_1`, given `ln_abs(x)` (e.g. shared between $x$ and $-x$).
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) fn le_pos_1_with_ln(
+        x: NonZero<Finite<f64>>,
+        ln: Finite<f64>,
+        scale: bool,
+        max_precision: Precision,
+    ) -> Approx {
+        #[cfg(not(feature = "fast-poly"))]
+        let cheb = constants::decay::E12.sum(max_precision, |order| {
+            constants::piecewise::E12.eval_mapped(*x, order)
+        });
+        #[cfg(feature = "fast-poly")]
+        let cheb = constants::decay::E12.sum(max_precision, |order| {
+            chebyshev::eval_estrin(
+                Finite::all(&constants::piecewise::E12.coefficients),
+                &constants::monomial::E12,
+                *x,
+                LessThan::new(order),
+            )
+        });
 
-        let cheb = chebyshev::eval(
-            Finite::all(&constants::E12),
-            *x,
-            #[cfg(feature = "precision")]
-            LessThan::new(max_precision.min(const { constants::size::E12 - 1 })),
-        );
+        le_pos_1_given(x, ln, scale, cheb)
+    }
+
+    /// `le_pos_1`, given `ln_abs(x)` and the sum of `E12` at `x`
+    /// (e.g. summed alongside other points by `chebyshev::lockstep`).
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_pos_1_given(
+        x: NonZero<Finite<f64>>,
+        ln: Finite<f64>,
+        scale: bool,
+        cheb: Approx,
+    ) -> Approx {
+        let nln = -ln;
 
-        let value = nln - Finite::new(0.6875_f64) + *x + cheb.value;
+        let scale_factor = if scale {
+            (*x).map(libm::exp)
+        } else {
+            Finite::<f64>::ONE
+        };
+        let value = scale_factor * (nln - Finite::new(0.6875_f64) + *x + cheb.value);
         #[cfg(feature = "error")]
         let epsilon = NonNegative::new(Finite::new(constants::GSL_DBL_EPSILON));
         #[cfg(feature = "error")]
-        let init_err = cheb.error + epsilon * NonNegative::new(Finite::new(nln.abs()));
+        let init_err = NonNegative::new(scale_factor)
+            * (cheb.error + epsilon * NonNegative::new(Finite::new(nln.abs())));
         #[cfg(feature = "error")]
         let addl_err = NonNegative::new(Finite::new(2_f64))
             * epsilon
@@ -300,23 +945,69 @@ pub(crate) mod piecewise {
     /// return GSL_SUCCESS;
     /// ```
     #[inline]
+    #[cfg(not(feature = "double-double"))]
     pub(crate) fn le_pos_4(
         x: Positive<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
+        scale: bool,
+        max_precision: Precision,
     ) -> Approx {
-        #![expect(
-            clippy::arithmetic_side_effects,
-            reason = "property-based testing ensures this never happens"
-        )]
+        #[cfg(not(feature = "fast-poly"))]
+        let cheb = constants::decay::AE13.sum(max_precision, |order| {
+            constants::piecewise::AE13.eval_mapped(le_pos_4_argument(x), order)
+        });
+        #[cfg(feature = "fast-poly")]
+        let cheb = constants::decay::AE13.sum(max_precision, |order| {
+            chebyshev::eval_estrin(
+                Finite::all(&constants::piecewise::AE13.coefficients),
+                &constants::monomial::AE13,
+                le_pos_4_argument(x),
+                LessThan::new(order),
+            )
+        });
 
-        let s = (Finite::<f64>::ONE / *x) * (-*x).map(f64::exp);
+        le_pos_4_given(x, scale, cheb)
+    }
 
-        let cheb = chebyshev::eval(
-            Finite::all(&constants::AE13),
-            (Finite::new(8_f64) / *x - Finite::new(5_f64)) / Finite::new(3_f64),
-            #[cfg(feature = "precision")]
-            LessThan::new(max_precision.min(const { constants::size::AE13 - 1 })),
-        );
+    /// Where `le_pos_4` sums `AE13`, as GSL maps `x` onto $[-1, 1]$.
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_pos_4_argument(x: Positive<Finite<f64>>) -> Finite<f64> {
+        (Finite::new(8_f64) / *x - Finite::new(5_f64)) / Finite::new(3_f64)
+    }
+
+    /// `le_pos_4`, given the sum of `AE13` at `le_pos_4_argument(x)`
+    /// (e.g. summed alongside other points by `chebyshev::lockstep`).
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_pos_4_given(x: Positive<Finite<f64>>, scale: bool, cheb: Approx) -> Approx {
+        let s = (Finite::<f64>::ONE / *x)
+            * if scale {
+                Finite::<f64>::ONE
+            } else {
+                (-*x).map(f64::exp)
+            };
 
         let value = s * (Finite::<f64>::ONE + cheb.value);
         #[cfg(feature = "error")]
@@ -351,23 +1042,69 @@ pub(crate) mod piecewise {
     ///   return GSL_SUCCESS;
     /// ```
     #[inline]
+    #[cfg(not(feature = "double-double"))]
     pub(crate) fn le_pos_max(
         x: Positive<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
+        scale: bool,
+        max_precision: Precision,
     ) -> Approx {
-        #![expect(
-            clippy::arithmetic_side_effects,
-            reason = "property-based testing ensures this never happens"
-        )]
+        #[cfg(not(feature = "fast-poly"))]
+        let cheb = constants::decay::AE14.sum(max_precision, |order| {
+            constants::piecewise::AE14.eval_mapped(le_pos_max_argument(x), order)
+        });
+        #[cfg(feature = "fast-poly")]
+        let cheb = constants::decay::AE14.sum(max_precision, |order| {
+            chebyshev::eval_estrin(
+                Finite::all(&constants::piecewise::AE14.coefficients),
+                &constants::monomial::AE14,
+                le_pos_max_argument(x),
+                LessThan::new(order),
+            )
+        });
 
-        let s = (Finite::<f64>::ONE / *x) * (-*x).map(f64::exp);
+        le_pos_max_given(x, scale, cheb)
+    }
 
-        let cheb = chebyshev::eval(
-            Finite::all(&constants::AE14),
-            (Finite::new(8_f64) / *x) - Finite::new(1_f64),
-            #[cfg(feature = "precision")]
-            LessThan::new(max_precision.min(const { constants::size::AE14 - 1 })),
-        );
+    /// Where `le_pos_max` sums `AE14`, as GSL maps `x` onto $[-1, 1]$.
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_pos_max_argument(x: Positive<Finite<f64>>) -> Finite<f64> {
+        (Finite::new(8_f64) / *x) - Finite::new(1_f64)
+    }
+
+    /// `le_pos_max`, given the sum of `AE14` at `le_pos_max_argument(x)`
+    /// (e.g. summed alongside other points by `chebyshev::lockstep`).
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+    #[cfg_attr(
+        feature = "fast-poly",
+        expect(
+            clippy::single_call_fn,
+            reason = "shared with `Piece::E1_many` otherwise"
+        )
+    )]
+    pub(crate) fn le_pos_max_given(x: Positive<Finite<f64>>, scale: bool, cheb: Approx) -> Approx {
+        let s = (Finite::<f64>::ONE / *x)
+            * if scale {
+                Finite::<f64>::ONE
+            } else {
+                (-*x).map(f64::exp)
+            };
 
         let value = s * (Finite::<f64>::ONE + cheb.value);
         #[cfg(feature = "error")]
@@ -376,10 +1113,11 @@ pub(crate) mod piecewise {
         let init_err = s * *(epsilon + cheb.error);
         #[cfg(feature = "error")]
         let addl_err = {
+            // Multiplying `epsilon` in before `x` keeps huge (scaled) arguments from overflowing.
             let also_x: NonNegative<Finite<f64>> = x.also();
             NonNegative::new(Finite::new(2_f64))
-                * (also_x + NonNegative::new(Finite::new(1_f64)))
                 * epsilon
+                * (also_x + NonNegative::new(Finite::new(1_f64)))
                 * NonNegative::new(Finite::new(value.abs()))
         };
 
@@ -389,14 +1127,53 @@ pub(crate) mod piecewise {
             error: NonNegative::new(init_err + *addl_err),
         }
     }
+
+    /// $\ln |x|$, as `le_pos_1_with_ln` takes it.
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) fn ln_abs(x: NonZero<Finite<f64>>) -> Finite<f64> {
+        Finite::new(Finite::new(x.abs()).ln())
+    }
+
+    /// Within `ROOT_RADIUS` of minus the zero of Ei, where E1 itself vanishes
+    /// and the Chebyshev pieces keep only absolute (not relative) accuracy.
+    /// No original C code: a Taylor series about the zero,
+    /// in the distance from it (computed without cancellation).
+    #[inline]
+    #[cfg(not(feature = "double-double"))]
+    pub(crate) fn near_root(x: Negative<Finite<f64>>, scale: bool) -> Approx {
+        // Exact by Sterbenz's lemma, since `-x` is within a factor of 2 of the zero:
+        let distance = (-**x - constants::EI_ZERO) - constants::EI_ZERO_LO;
+        let mut sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_sum = 0_f64;
+        for &coefficient in constants::piecewise::EI_ROOT.iter().rev() {
+            sum = sum.mul_add(distance, coefficient);
+            #[cfg(feature = "error")]
+            {
+                abs_sum = abs_sum.mul_add(distance.abs(), coefficient.abs());
+            }
+        }
+        let scale_factor = if scale { libm::exp(**x) } else { 1_f64 };
+        // $E_{1}(x) = -\text{Ei}(-x)$:
+        let value = -scale_factor * sum * distance;
+        Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                2_f64
+                    * constants::GSL_DBL_EPSILON
+                    * (scale_factor * abs_sum * distance.abs() + value.abs()),
+            )),
+        }
+    }
 }
 
 pub(crate) mod pos {
     //! E1 for inputs greater than 0.
 
     use {
-        crate::{Approx, constants, implementation::piecewise, pos::HugeArgument},
-        core::{cmp::Ordering, hint::unreachable_unchecked},
+        crate::{Approx, Precision, constants, implementation::Piece, pos::HugeArgument},
         sigma_types::{Finite, Positive},
     };
 
@@ -407,71 +1184,346 @@ pub(crate) mod pos {
     #[inline]
     pub(crate) fn E1(
         x: Positive<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
+        max_precision: Precision,
     ) -> Result<Approx, HugeArgument> {
-        match (**x).partial_cmp(&4_f64) {
-            // = 4
-            Some(Ordering::Equal) => Ok(piecewise::le_pos_4(
-                x,
-                #[cfg(feature = "precision")]
-                max_precision,
-            )),
-            // (0, +4)
-            Some(Ordering::Less) => Ok(match (**x).partial_cmp(&1_f64) {
-                // (0, +1]
-                Some(Ordering::Less | Ordering::Equal) => piecewise::le_pos_1(
-                    x.also(),
-                    #[cfg(feature = "precision")]
-                    max_precision,
-                ),
-                // (+1, +\infty]
-                Some(Ordering::Greater) => piecewise::le_pos_4(
-                    x,
-                    #[cfg(feature = "precision")]
-                    max_precision,
-                ),
-                // SAFETY:
-                // absurd case: `x` is finite
-                None => unsafe { unreachable_unchecked() },
-            }),
-            // (+4, +\infty)
-            Some(Ordering::Greater) => match (**x).partial_cmp(&constants::XMAX) {
-                Some(Ordering::Less) => Ok(piecewise::le_pos_max(
-                    x,
-                    #[cfg(feature = "precision")]
-                    max_precision,
-                )),
-                Some(Ordering::Equal | Ordering::Greater) => Err(HugeArgument(x)),
-                // SAFETY:
-                // absurd case: `x` is finite
-                None => unsafe { unreachable_unchecked() },
-            },
-            // SAFETY:
-            // absurd case: `x` is finite
-            None => unsafe { unreachable_unchecked() },
+        if **x >= constants::XMAX {
+            return HugeArgument::fail(x);
         }
+        Ok(Piece::at(**x).E1(*x, max_precision))
     }
 }
 
-use {
-    crate::{Approx, Error},
-    core::{cmp::Ordering, hint::unreachable_unchecked},
-    sigma_types::{Finite, NonZero},
-};
+/// Auxiliary functions shared by the sine and cosine integrals.
+pub(crate) mod sici {
+    use {
+        crate::{Approx, Precision, chebyshev, constants},
+        sigma_types::{Finite, Positive, usize::LessThan},
+    };
 
-/// # Errors
-/// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
-/// # Original C code
-/// Note that `scale` is pinned to `0`.
-/// ```c
-/// /* implementation for E1, allowing for scaling by exp(x) */
-/// static
-/// int expint_E1_impl(const double x, gsl_sf_result * result, const int scale)
-/// {
-///   const double xmaxt = -GSL_LOG_DBL_MIN;      /* XMAXT = -LOG (R1MACH(1)) */
-///   const double xmax  = xmaxt - log(xmaxt);    /* XMAX = XMAXT - LOG(XMAXT) */
-///
-///   /* CHECK_POINTER(result) */
+    #[cfg(feature = "error")]
+    use sigma_types::NonNegative;
+
+    /// The auxiliary functions
+    /// $f(x) = \text{Ci}(x) \sin x - (\text{Si}(x) - \pi / 2) \cos x$ and
+    /// $g(x) = -\text{Ci}(x) \cos x - (\text{Si}(x) - \pi / 2) \sin x$,
+    /// both for `x` of at least 4.
+    /// # Original C code
+    /// ```c
+    /// static void fg_asymp(const double x, gsl_sf_result * f, gsl_sf_result * g)
+    /// {
+    ///   const double xbig  = 1.0/GSL_SQRT_DBL_EPSILON;
+    ///   const double xmaxf = 1.0/GSL_DBL_MIN;
+    ///   const double xmaxg = 1.0/GSL_SQRT_DBL_MIN;
+    ///   const double xbnd  = 7.07106781187;
+    ///
+    ///   const double x2 = x*x;
+    ///
+    ///   if(x <= xbnd) {
+    ///     gsl_sf_result result_c1;
+    ///     gsl_sf_result result_c2;
+    ///     cheb_eval_e(&f1_cs, (1.0/x2-0.04125)/0.02125, &result_c1);
+    ///     cheb_eval_e(&g1_cs, (1.0/x2-0.04125)/0.02125, &result_c2);
+    ///     f->val = (1.0 + result_c1.val)/x;
+    ///     g->val = (1.0 + result_c2.val)/x2;
+    ///     f->err = result_c1.err/x  + 2.0 * GSL_DBL_EPSILON * fabs(f->val);
+    ///     g->err = result_c2.err/x2 + 2.0 * GSL_DBL_EPSILON * fabs(g->val);
+    ///   }
+    ///   else if(x <= xbig) {
+    ///     gsl_sf_result result_c1;
+    ///     gsl_sf_result result_c2;
+    ///     cheb_eval_e(&f2_cs, 100.0/x2-1.0, &result_c1);
+    ///     cheb_eval_e(&g2_cs, 100.0/x2-1.0, &result_c2);
+    ///     f->val = (1.0 + result_c1.val)/x;
+    ///     g->val = (1.0 + result_c2.val)/x2;
+    ///     f->err = result_c1.err/x  + 2.0 * GSL_DBL_EPSILON * fabs(f->val);
+    ///     g->err = result_c2.err/x2 + 2.0 * GSL_DBL_EPSILON * fabs(g->val);
+    ///   }
+    ///   else {
+    ///     f->val = (x < xmaxf ? 1.0/x  : 0.0);
+    ///     g->val = (x < xmaxg ? 1.0/x2 : 0.0);
+    ///     f->err = 2.0 * GSL_DBL_EPSILON * fabs(f->val);
+    ///     g->err = 2.0 * GSL_DBL_EPSILON * fabs(g->val);
+    ///   }
+    /// }
+    /// ```
+    #[inline]
+    pub(crate) fn fg_asymp(x: Positive<Finite<f64>>, max_precision: Precision) -> (Approx, Approx) {
+        /// Above this, both auxiliary functions are their leading terms to machine precision.
+        const XBIG: f64 = 67_108_864_f64;
+        /// Boundary between the two pairs of Chebyshev tables, roughly $\sqrt{50}$.
+        const XBND: f64 = 7.071_067_811_87;
+
+        let raw = **x;
+        // Squaring only overflows past `XBIG`, where `x2` goes unused.
+        let x2 = raw * raw;
+
+        if raw > XBIG {
+            // Each reciprocal underflows to zero exactly when GSL's cutoffs would zero it.
+            let f = 1_f64 / raw;
+            let g = 1_f64 / x2;
+            return (
+                Approx {
+                    value: Finite::new(f),
+                    #[cfg(feature = "error")]
+                    error: NonNegative::new(Finite::new(2_f64 * constants::GSL_DBL_EPSILON * f)),
+                },
+                Approx {
+                    value: Finite::new(g),
+                    #[cfg(feature = "error")]
+                    error: NonNegative::new(Finite::new(2_f64 * constants::GSL_DBL_EPSILON * g)),
+                },
+            );
+        }
+
+        let (c1, c2) = if raw <= XBND {
+            let arg = Finite::new((1_f64 / x2 - 0.041_25_f64) / 0.021_25_f64);
+            (
+                constants::decay::F1.sum(max_precision, |order| {
+                    chebyshev::eval(Finite::all(&constants::F1), arg, LessThan::new(order))
+                }),
+                constants::decay::G1.sum(max_precision, |order| {
+                    chebyshev::eval(Finite::all(&constants::G1), arg, LessThan::new(order))
+                }),
+            )
+        } else {
+            let arg = Finite::new(100_f64 / x2 - 1_f64);
+            (
+                constants::decay::F2.sum(max_precision, |order| {
+                    chebyshev::eval(Finite::all(&constants::F2), arg, LessThan::new(order))
+                }),
+                constants::decay::G2.sum(max_precision, |order| {
+                    chebyshev::eval(Finite::all(&constants::G2), arg, LessThan::new(order))
+                }),
+            )
+        };
+
+        let f = (1_f64 + *c1.value) / raw;
+        let g = (1_f64 + *c2.value) / x2;
+        (
+            Approx {
+                value: Finite::new(f),
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    **c1.error / raw + 2_f64 * constants::GSL_DBL_EPSILON * f.abs(),
+                )),
+            },
+            Approx {
+                value: Finite::new(g),
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    **c2.error / x2 + 2_f64 * constants::GSL_DBL_EPSILON * g.abs(),
+                )),
+            },
+        )
+    }
+}
+
+use {
+    crate::{
+        Approx, Approx10, BatchError, Error, Precision, chebyshev, constants, consts, e3,
+        incomplete_gamma,
+    },
+    core::{
+        cmp::Ordering,
+        f64::consts::{E, FRAC_PI_2, LN_10},
+        hint::unreachable_unchecked,
+    },
+    sigma_types::{Finite, Negative, NonNegative, NonZero, One as _, Positive, usize::LessThan},
+};
+
+#[cfg(feature = "error")]
+use sigma_types::Zero as _;
+
+/// Which of the `piecewise` approximations `E1` dispatches a given argument to.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Piece {
+    /// `piecewise::le_neg_1`.
+    Neg1,
+    /// `piecewise::le_neg_10`.
+    Neg10,
+    /// `piecewise::le_neg_4`.
+    Neg4,
+    /// `piecewise::le_pos_1`.
+    Pos1,
+    /// `piecewise::le_pos_4`.
+    Pos4,
+    /// `piecewise::le_pos_max`.
+    PosMax,
+    /// `piecewise::near_root`.
+    Root,
+}
+
+impl Piece {
+    /// Evaluate E1 at `z`, which must be `contained` in this piece.
+    /// With `opt-size`, this is the one copy of every piece that each entry point calls into.
+    #[cfg_attr(not(feature = "opt-size"), inline)]
+    #[cfg_attr(feature = "opt-size", inline(never))]
+    fn E1(self, z: Finite<f64>, max_precision: Precision) -> Approx {
+        match self {
+            Self::Neg10 => piecewise::le_neg_10(Negative::new(z), false, max_precision),
+            Self::Neg4 => piecewise::le_neg_4(Negative::new(z), false, max_precision),
+            Self::Neg1 => piecewise::le_neg_1(Negative::new(z), false, max_precision),
+            Self::Root => piecewise::near_root(Negative::new(z), false),
+            Self::Pos1 => piecewise::le_pos_1(NonZero::new(z), false, max_precision),
+            Self::Pos4 => piecewise::le_pos_4(Positive::new(z), false, max_precision),
+            Self::PosMax => piecewise::le_pos_max(Positive::new(z), false, max_precision),
+        }
+    }
+
+    /// `E1` at every entry of `zs`, which must all be `contained` in this piece,
+    /// into the same position in `out`, summing the piece's series at all of them in lockstep
+    /// (so with the same bits `E1` would give each on its own).
+    #[inline]
+    #[cfg(not(any(feature = "double-double", feature = "fast-poly")))]
+    fn E1_many(self, zs: &[Finite<f64>], out: &mut [Approx], max_precision: Precision) {
+        match self {
+            Self::Neg10 => in_lockstep(
+                &constants::piecewise::AE11,
+                zs,
+                out,
+                |z| piecewise::le_neg_10_argument(Negative::new(z)),
+                |z, cheb| piecewise::le_neg_10_given(Negative::new(z), false, cheb),
+                &constants::decay::AE11,
+                max_precision,
+            ),
+            Self::Neg4 => in_lockstep(
+                &constants::piecewise::AE12,
+                zs,
+                out,
+                |z| piecewise::le_neg_4_argument(Negative::new(z)),
+                |z, cheb| piecewise::le_neg_4_given(Negative::new(z), false, cheb),
+                &constants::decay::AE12,
+                max_precision,
+            ),
+            Self::Neg1 => in_lockstep(
+                &constants::piecewise::E11,
+                zs,
+                out,
+                |z| piecewise::le_neg_1_argument(Negative::new(z)),
+                |z, cheb| piecewise::le_neg_1_given(Negative::new(z), false, cheb),
+                &constants::decay::E11,
+                max_precision,
+            ),
+            Self::Root => {
+                for (&z, result) in zs.iter().zip(out) {
+                    *result = piecewise::near_root(Negative::new(z), false);
+                }
+            }
+            Self::Pos1 => in_lockstep(
+                &constants::piecewise::E12,
+                zs,
+                out,
+                |z| z,
+                |z, cheb| {
+                    let nonzero = NonZero::new(z);
+                    piecewise::le_pos_1_given(nonzero, piecewise::ln_abs(nonzero), false, cheb)
+                },
+                &constants::decay::E12,
+                max_precision,
+            ),
+            Self::Pos4 => in_lockstep(
+                &constants::piecewise::AE13,
+                zs,
+                out,
+                |z| piecewise::le_pos_4_argument(Positive::new(z)),
+                |z, cheb| piecewise::le_pos_4_given(Positive::new(z), false, cheb),
+                &constants::decay::AE13,
+                max_precision,
+            ),
+            Self::PosMax => in_lockstep(
+                &constants::piecewise::AE14,
+                zs,
+                out,
+                |z| piecewise::le_pos_max_argument(Positive::new(z)),
+                |z, cheb| piecewise::le_pos_max_given(Positive::new(z), false, cheb),
+                &constants::decay::AE14,
+                max_precision,
+            ),
+        }
+    }
+
+    /// The piece for `z`, which must be finite, nonzero, and strictly between `NXMAX` and `XMAX`.
+    ///
+    /// No cascade of comparisons to mispredict:
+    /// the number of boundaries below `z` indexes straight into a table,
+    /// and the neighbourhood of the root (all within $(-1, 0)$) overrides whatever that gives.
+    #[inline]
+    fn at(z: f64) -> Self {
+        /// Pieces in order, each starting just above a boundary in `-10`, `-4`, `-1`, `1`, and `4`.
+        const PIECES: [Piece; 6] = [
+            Piece::Neg10,
+            Piece::Neg4,
+            Piece::Neg1,
+            Piece::Pos1,
+            Piece::Pos4,
+            Piece::PosMax,
+        ];
+
+        let index = usize::from(z > -10_f64)
+            .wrapping_add(usize::from(z > -4_f64))
+            .wrapping_add(usize::from(z > -1_f64))
+            .wrapping_add(usize::from(z > 1_f64))
+            .wrapping_add(usize::from(z > 4_f64));
+        if (z + constants::EI_ZERO).abs() < piecewise::ROOT_RADIUS {
+            return Self::Root;
+        }
+        // SAFETY:
+        // at most five boundaries, so at most 5
+        *unsafe { PIECES.get_unchecked(index) }
+    }
+
+    /// Whether `E1` sends `z` here, by exactly the comparisons it makes
+    /// (so a grid agrees with pointwise evaluation to the bit).
+    #[inline]
+    fn contains(self, z: f64) -> bool {
+        match self {
+            Self::Neg10 => z > constants::NXMAX && z <= -10_f64,
+            Self::Neg4 => z > -10_f64 && z <= -4_f64,
+            Self::Neg1 => z > -4_f64 && z <= -1_f64,
+            Self::Root => z > -1_f64 && (z + constants::EI_ZERO).abs() < piecewise::ROOT_RADIUS,
+            Self::Pos1 => {
+                (z > -1_f64
+                    && z < 0_f64
+                    && (z + constants::EI_ZERO).abs() >= piecewise::ROOT_RADIUS)
+                    || (z > 0_f64 && z <= 1_f64)
+            }
+            Self::Pos4 => z > 1_f64 && z <= 4_f64,
+            Self::PosMax => z > 4_f64 && z < constants::XMAX,
+        }
+    }
+
+    /// Classify `z` from scratch.
+    /// # Errors
+    /// Whatever `E1` would return for `z`, or `Error::Domain` at zero.
+    #[inline]
+    fn of(z: Finite<f64>) -> Result<Self, Error> {
+        let raw = *z;
+        if raw <= constants::NXMAX {
+            return Err(Error::ArgumentTooNegative(Negative::new(z)));
+        }
+        if raw >= constants::XMAX {
+            return Err(Error::Underflow);
+        }
+        if raw == 0_f64 {
+            return Err(Error::Domain(Finite::new(-raw)));
+        }
+        Ok(Self::at(raw))
+    }
+}
+
+/// # Errors
+/// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
+/// # Original C code
+/// Note that `scale` is pinned to `0`.
+/// ```c
+/// /* implementation for E1, allowing for scaling by exp(x) */
+/// static
+/// int expint_E1_impl(const double x, gsl_sf_result * result, const int scale)
+/// {
+///   const double xmaxt = -GSL_LOG_DBL_MIN;      /* XMAXT = -LOG (R1MACH(1)) */
+///   const double xmax  = xmaxt - log(xmaxt);    /* XMAX = XMAXT - LOG(XMAXT) */
+///
+///   /* CHECK_POINTER(result) */
 ///
 ///   if(x < -xmax && !scale) {
 ///       OVERFLOW_ERROR(result);
@@ -547,32 +1599,2991 @@ use {
 /// # Errors
 /// See `Error`.
 #[inline]
-#[cfg_attr(
-    not(test),
-    expect(clippy::single_call_fn, reason = "to mirror the C implementation")
-)]
 #[expect(clippy::absolute_paths, reason = "always a collision except full path")]
-pub(crate) fn E1(
-    x: NonZero<Finite<f64>>,
-    #[cfg(feature = "precision")] max_precision: usize,
-) -> Result<Approx, Error> {
+pub(crate) fn E1(x: NonZero<Finite<f64>>, max_precision: Precision) -> Result<Approx, Error> {
     match (**x).partial_cmp(&0_f64) {
         // (-\infty, 0)
-        Some(Ordering::Less) => neg::E1(
-            x.also(),
-            #[cfg(feature = "precision")]
-            max_precision,
-        )
-        .map_err(|crate::neg::HugeArgument(arg)| Error::ArgumentTooNegative(arg)),
-        // (0, +\infty)
-        Some(Ordering::Greater) => pos::E1(
-            x.also(),
-            #[cfg(feature = "precision")]
-            max_precision,
-        )
-        .map_err(|crate::pos::HugeArgument(arg)| Error::ArgumentTooPositive(arg)),
+        Some(Ordering::Less) => neg::E1(x.also(), max_precision)
+            .map_err(|crate::neg::HugeArgument(arg)| Error::ArgumentTooNegative(arg)),
+        // (0, +\infty), where GSL's last `else` is an `UNDERFLOW_ERROR`:
+        Some(Ordering::Greater) => {
+            pos::E1(x.also(), max_precision).map_err(|crate::pos::HugeArgument(_)| Error::Underflow)
+        }
         // SAFETY:
         // absurd case: `x` is finite and nonzero
         Some(Ordering::Equal) | None => unsafe { unreachable_unchecked() },
     }
 }
+
+/// E1 scaled by $e^{x}$, dispatching straight to the pieces of `E1`:
+/// without the exponential, none of them can overflow or underflow.
+/// # Original C code
+/// Note that `scale` is pinned to `1` (see `E1` for `expint_E1_impl`).
+/// ```c
+/// int gsl_sf_expint_E1_scaled_e(const double x, gsl_sf_result * result)
+/// {
+///   return expint_E1_impl(x, result, 1);
+/// }
+/// ```
+#[inline]
+pub(crate) fn E1_scaled(x: NonZero<Finite<f64>>, max_precision: Precision) -> Approx {
+    let raw = **x;
+    if raw <= -10_f64 {
+        piecewise::le_neg_10(Negative::new(*x), true, max_precision)
+    } else if raw <= -4_f64 {
+        piecewise::le_neg_4(Negative::new(*x), true, max_precision)
+    } else if raw <= -1_f64 {
+        piecewise::le_neg_1(Negative::new(*x), true, max_precision)
+    } else if (raw + constants::EI_ZERO).abs() < piecewise::ROOT_RADIUS {
+        piecewise::near_root(Negative::new(*x), true)
+    } else if raw <= 1_f64 {
+        piecewise::le_pos_1(x, true, max_precision)
+    } else if raw <= 4_f64 {
+        piecewise::le_pos_4(Positive::new(*x), true, max_precision)
+    } else {
+        piecewise::le_pos_max(Positive::new(*x), true, max_precision)
+    }
+}
+
+/// E1 at `x`, already known to be finite, nonzero, and strictly between `NXMAX` and `XMAX`,
+/// straight to its piece without checking the range again.
+/// No original C code: see `E1` for the pieces.
+#[inline]
+pub(crate) fn E1_in_domain(x: Finite<f64>, max_precision: Precision) -> Approx {
+    Piece::at(*x).E1(x, max_precision)
+}
+
+/// E1 as a mantissa times a power of ten, so results past the range of `f64` still come back.
+///
+/// No original C code: GSL has no `_e10` variant of E1.
+/// Wherever `E1` succeeds, this is `E1` with `e10` zero.
+/// Past `XMAX` on either side, it's `E1_scaled` times $e^{-x} = 10^{e_{10}} e^{r}$,
+/// where $e_{10} = \lfloor \frac{-x}{\ln 10} \rfloor$ and the remainder $r = -x - e_{10} \ln 10$ lies in $[0, \ln 10)$.
+/// The remainder is computed with a fused multiply-add and the low bits of $\ln 10$,
+/// so it loses nothing to cancellation even when $e_{10}$ is huge.
+/// # Errors
+/// Only if even the exponent can't represent the result,
+/// with the error `E1` itself would have returned.
+#[inline]
+pub(crate) fn E1_e10(x: NonZero<Finite<f64>>, max_precision: Precision) -> Result<Approx10, Error> {
+    let unscaled = match E1(x, max_precision) {
+        Ok(approx) => {
+            return Ok(Approx10 {
+                e10: 0,
+                #[cfg(feature = "error")]
+                error: approx.error,
+                value: approx.value,
+            });
+        }
+        Err(err) => err,
+    };
+
+    let exponent = -**x;
+    let decades = libm::floor(exponent / LN_10);
+    if !(f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(&decades) {
+        return Err(unscaled);
+    }
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        reason = "an integer within the range of `i32` by the check above"
+    )]
+    let e10 = decades as i32;
+    let remainder = (-decades).mul_add(LN_10, exponent) - decades * constants::LN_10_LO;
+    let exponential = libm::exp(remainder);
+
+    let scaled = E1_scaled(x, max_precision);
+    let value = *scaled.value * exponential;
+    Ok(Approx10 {
+        e10,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            **scaled.error * exponential + 3_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+        value: Finite::new(value),
+    })
+}
+
+/// Check that every entry of `xs` can be evaluated (at $-x$ with `reflect`),
+/// numbering the entries from `offset`.
+/// # Errors
+/// At the first entry that can't be (see `BatchError`).
+#[inline]
+#[cfg_attr(
+    not(feature = "std"),
+    expect(clippy::single_call_fn, reason = "shared with `par`")
+)]
+pub(crate) fn check_slice(xs: &[f64], reflect: bool, offset: usize) -> Result<(), BatchError> {
+    for (index, &x) in (offset..).zip(xs) {
+        _ = classify(index, x, reflect)?;
+    }
+    Ok(())
+}
+
+/// Which `Piece` the entry `x` at `index` of a slice belongs to (at $-x$ with `reflect`),
+/// or why it belongs to none.
+#[inline]
+fn classify(index: usize, x: f64, reflect: bool) -> Result<Piece, BatchError> {
+    let z = Finite::try_new(if reflect { -x } else { x })
+        .ok_or(BatchError::Domain { index, value: x })?;
+    Piece::of(z).map_err(|error| match error {
+        Error::Domain(_) => BatchError::Domain { index, value: x },
+        Error::ArgumentTooNegative(_)
+        | Error::ArgumentTooPositive(_)
+        | Error::Overflow
+        | Error::Underflow => BatchError::Range { error, index },
+    })
+}
+
+/// `E1` at each entry of `zs` in the corresponding entry of `pieces` (up to the shortest of the three)
+/// into the same position in `out`: in lockstep (`Piece::E1_many`) if they all share one, or one at a time otherwise.
+#[inline]
+fn E1_group(zs: &[Finite<f64>], pieces: &[Piece], out: &mut [Approx], max_precision: Precision) {
+    #[cfg(not(any(feature = "double-double", feature = "fast-poly")))]
+    if let Some((&first, rest)) = pieces.split_first()
+        && rest.iter().all(|&piece| piece == first)
+    {
+        first.E1_many(zs.get(..pieces.len()).unwrap_or(zs), out, max_precision);
+        return;
+    }
+    for ((&z, &piece), result) in zs.iter().zip(pieces).zip(out) {
+        *result = piece.E1(z, max_precision);
+    }
+}
+
+/// The body of `Piece::E1_many` for a piece that sums `series` at `argument(z)`
+/// and finishes with `given(z, sum)`: `LANES` entries at a time,
+/// mapped into a buffer and summed together by `chebyshev::lockstep` (up to `order`).
+#[inline]
+#[cfg(not(any(feature = "double-double", feature = "fast-poly")))]
+fn in_lockstep<const N_COEFFICIENTS: usize>(
+    series: &chebyshev::Series<N_COEFFICIENTS>,
+    zs: &[Finite<f64>],
+    out: &mut [Approx],
+    argument: impl Fn(Finite<f64>) -> Finite<f64>,
+    given: impl Fn(Finite<f64>, Approx) -> Approx,
+    decay: &chebyshev::Decay<N_COEFFICIENTS>,
+    max_precision: Precision,
+) {
+    let order = decay.order(max_precision);
+    let all: &[Finite<f64>] = Finite::all(&series.coefficients);
+    let used = all.get(..=order).unwrap_or(all);
+    for (chunk, results) in zs
+        .chunks(chebyshev::LANES)
+        .zip(out.chunks_mut(chebyshev::LANES))
+    {
+        let mut ys = [Finite::new(0_f64); chebyshev::LANES];
+        for (y, &z) in ys.iter_mut().zip(chunk) {
+            *y = argument(z);
+        }
+        chebyshev::lockstep(
+            used,
+            #[cfg(feature = "error")]
+            Finite::new(series.coefficients.last().copied().unwrap_or(0_f64)),
+            ys.get(..chunk.len()).unwrap_or(&ys),
+            results,
+        );
+        for (result, &z) in results.iter_mut().zip(chunk) {
+            *result = given(z, decay.widen(*result, order));
+        }
+    }
+}
+
+/// No original C code: GSL evaluates one point at a time.
+/// Every entry is checked up front, so nothing is written unless everything can be.
+/// With `reflect`, this is Ei instead, as $-E_{1}(-x)$.
+/// # Errors
+/// See `E1_slice`.
+#[inline]
+pub(crate) fn E1_slice<T>(
+    xs: &[f64],
+    out: &mut [T],
+    reflect: bool,
+    convert: impl Fn(Approx) -> T,
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    if xs.len() != out.len() {
+        return Err(BatchError::LengthMismatch {
+            inputs: xs.len(),
+            outputs: out.len(),
+        });
+    }
+    check_slice(xs, reflect, 0)?;
+    fill_slice(xs, out, reflect, convert, 0, max_precision)
+}
+
+/// Evaluate every entry of `xs` into the same position in `out` (at $-x$, negated, with `reflect`),
+/// numbering the entries from `offset`.
+/// As in `Ei_linspace`, each entry first checks the previous entry's piece
+/// and only reclassifies on leaving it.
+///
+/// With `std` on x86, this picks the widest kernel the processor supports the first time it's called
+/// (`is_x86_feature_detected` caches the answer), so a binary built for the baseline
+/// still runs the loop (Clenshaw's recurrence included) compiled for AVX2 and FMA where they're available.
+/// Without `std`, whatever `-C target-feature` the crate was built with is all there is.
+/// No detection on `aarch64`, whose baseline already includes NEON.
+/// Either way, the arithmetic is the same (Rust never fuses a multiply and an add on its own),
+/// so every kernel gives the same bits.
+/// # Errors
+/// At the first entry that can't be evaluated, with the entries before it already filled,
+/// which can't happen once `check_slice` has passed.
+#[inline]
+#[cfg_attr(
+    not(feature = "std"),
+    expect(clippy::single_call_fn, reason = "shared with `par`")
+)]
+pub(crate) fn fill_slice<T>(
+    xs: &[f64],
+    out: &mut [T],
+    reflect: bool,
+    convert: impl Fn(Approx) -> T,
+    offset: usize,
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    #[cfg(all(
+        feature = "std",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(all(target_feature = "avx2", target_feature = "fma")),
+    ))]
+    if std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("fma") {
+        // SAFETY:
+        // Both features were just detected.
+        return unsafe { fill_slice_avx2_fma(xs, out, reflect, convert, offset, max_precision) };
+    }
+    fill_slice_portable(xs, out, reflect, convert, offset, max_precision)
+}
+
+/// `fill_slice_portable`, compiled for AVX2 and FMA.
+/// # Errors
+/// See `fill_slice`.
+#[inline]
+#[cfg(all(
+    feature = "std",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(all(target_feature = "avx2", target_feature = "fma")),
+))]
+#[target_feature(enable = "avx2,fma")]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "only `fill_slice` picks a kernel")
+)]
+pub(crate) fn fill_slice_avx2_fma<T>(
+    xs: &[f64],
+    out: &mut [T],
+    reflect: bool,
+    convert: impl Fn(Approx) -> T,
+    offset: usize,
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    fill_slice_portable(xs, out, reflect, convert, offset, max_precision)
+}
+
+/// The loop behind `fill_slice`, for whichever instruction set its caller was compiled for
+/// (hence always inlined: called on its own, it would only ever be compiled for the baseline).
+/// # Errors
+/// See `fill_slice`.
+#[inline(always)]
+#[cfg_attr(
+    not(all(
+        feature = "std",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(all(target_feature = "avx2", target_feature = "fma")),
+    )),
+    expect(
+        clippy::single_call_fn,
+        reason = "one call per kernel, and here there's only one"
+    )
+)]
+pub(crate) fn fill_slice_portable<T>(
+    xs: &[f64],
+    out: &mut [T],
+    reflect: bool,
+    convert: impl Fn(Approx) -> T,
+    offset: usize,
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    let mut piece = None;
+    for ((chunk, entries), start) in xs
+        .chunks(chebyshev::LANES)
+        .zip(out.chunks_mut(chebyshev::LANES))
+        .zip((offset..).step_by(chebyshev::LANES))
+    {
+        let mut zs = [Finite::new(0_f64); chebyshev::LANES];
+        let mut pieces = [Piece::Root; chebyshev::LANES];
+        let mut failure = None;
+        for (lane, ((index, &x), (z, slot))) in (start..)
+            .zip(chunk)
+            .zip(zs.iter_mut().zip(&mut pieces))
+            .enumerate()
+        {
+            *z = Finite::new(if reflect { -x } else { x });
+            let current = match piece {
+                Some(previous) if Piece::contains(previous, **z) => previous,
+                _ => match classify(index, x, reflect) {
+                    Ok(current) => current,
+                    Err(error) => {
+                        failure = Some((lane, error));
+                        break;
+                    }
+                },
+            };
+            piece = Some(current);
+            *slot = current;
+        }
+        let filled = failure.map_or(chunk.len(), |(lane, _)| lane);
+        let mut approxes = [Approx::default(); chebyshev::LANES];
+        if let Some(results) = approxes.get_mut(..filled) {
+            E1_group(
+                &zs,
+                pieces.get(..filled).unwrap_or(&pieces),
+                results,
+                max_precision,
+            );
+        }
+        for (entry, mut approx) in entries.iter_mut().zip(approxes).take(filled) {
+            if reflect {
+                approx.value = Finite::new(-*approx.value);
+            }
+            *entry = convert(approx);
+        }
+        if let Some((_, error)) = failure {
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+/// No original C code: GSL evaluates one point at a time.
+/// Point `k` of `n` is $\text{start} \cdot (1 - t) + \text{stop} \cdot t$ with $t = \frac{k}{n - 1}$,
+/// which hits both endpoints exactly and can't overflow.
+/// Since the grid is monotone, consecutive points almost always share a `Piece`,
+/// so each point first checks the previous point's piece and only reclassifies on leaving it,
+/// skipping `E1`'s sign split and search through every interval.
+/// # Errors
+/// See `Ei`, or `Error::Domain` if a point lands exactly on zero.
+/// The points before the failing one are already filled.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the other functions")]
+pub(crate) fn Ei_linspace(
+    start: Finite<f64>,
+    stop: Finite<f64>,
+    out: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), Error> {
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "grids past 2^53 points are indistinguishable from continuous anyway"
+    )]
+    let intervals = out.len().saturating_sub(1).max(1) as f64;
+    let mut piece = None;
+    let mut k = 0_f64;
+    for entries in out.chunks_mut(chebyshev::LANES) {
+        let mut zs = [Finite::new(0_f64); chebyshev::LANES];
+        let mut pieces = [Piece::Root; chebyshev::LANES];
+        let mut failure = None;
+        for (lane, (z, slot)) in zs
+            .iter_mut()
+            .zip(&mut pieces)
+            .take(entries.len())
+            .enumerate()
+        {
+            let t = k / intervals;
+            let x = start.mul_add(1_f64 - t, *stop * t);
+            *z = Finite::new(-x);
+            let current = match piece {
+                Some(previous) if Piece::contains(previous, **z) => previous,
+                _ => match Piece::of(*z) {
+                    Ok(current) => current,
+                    Err(error) => {
+                        failure = Some((lane, error));
+                        break;
+                    }
+                },
+            };
+            piece = Some(current);
+            *slot = current;
+            k += 1_f64;
+        }
+        let filled = failure.map_or(entries.len(), |(lane, _)| lane);
+        if let Some(results) = entries.get_mut(..filled) {
+            E1_group(
+                &zs,
+                pieces.get(..filled).unwrap_or(&pieces),
+                results,
+                max_precision,
+            );
+            for approx in results {
+                approx.value = Finite::new(-*approx.value);
+            }
+        }
+        if let Some((_, error)) = failure {
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+/// The derivative of Ei, $\text{Ei}'(x) = \frac{e^{x}}{x}$,
+/// which is also the derivative of E1 at $-x$.
+/// # Errors
+/// If the result is too large to represent:
+/// `x` just over 709, or so close to zero that $\frac{1}{x}$ overflows.
+#[inline]
+pub(crate) fn Ei_derivative(x: NonZero<Finite<f64>>) -> Result<Approx, Error> {
+    let value = libm::exp(**x) / **x;
+    Ok(Approx {
+        value: Finite::try_new(value).ok_or(Error::Overflow)?,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// Successive derivatives of Ei, starting from the first, into `derivatives`:
+/// `derivatives[m]` is $f_{m}(x) = \frac{\text{d}^{m}}{\text{d}x^{m}} \frac{e^{x}}{x}$,
+/// i.e. $\text{Ei}^{(m + 1)}(x)$.
+///
+/// Differentiating $x f_{0}(x) = e^{x}$ gives $f_{m} = \frac{e^{x} - m f_{m - 1}}{x}$.
+/// For negative `x`, both terms in the numerator are positive, so this recurrence is stable.
+/// For positive `x`, they cancel once `m` passes `x`, so each order is split instead into
+/// $f_{m} = \frac{(-1)^{m} m!}{x^{m + 1}} + \frac{e^{x}}{m + 1} \text{exprel}_{m + 1}(-x)$,
+/// with each piece accurate on its own and any cancellation between them inherent in $f_{m}$ itself.
+///
+/// With `reflect`, these are instead the derivatives of E1 at `x`,
+/// $E_{1}^{(m + 1)}(x) = (-1)^{m} \text{Ei}^{(m + 1)}(-x)$.
+/// # Errors
+/// If a derivative is too large to represent,
+/// in which case the entries before it are already filled and the rest are untouched.
+#[inline]
+pub(crate) fn Ei_derivatives(
+    x: NonZero<Finite<f64>>,
+    derivatives: &mut [Approx],
+    reflect: bool,
+) -> Result<(), Error> {
+    let raw = if reflect { -**x } else { **x };
+    let exponential = libm::exp(raw);
+    // Whether `m` is odd, i.e. whether reflecting flips the sign:
+    let mut odd = false;
+    if raw < 0_f64 {
+        let mut previous = 0_f64;
+        for (m, entry) in derivatives.iter_mut().enumerate() {
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_precision_loss,
+                reason = "orders past 2^53 are indistinguishable in the result anyway"
+            )]
+            let order = m as f64;
+            let value = order.mul_add(-previous, exponential) / raw;
+            *entry = Approx {
+                value: Finite::try_new(if reflect && odd { -value } else { value })
+                    .ok_or(Error::Overflow)?,
+                #[cfg(feature = "error")]
+                // Three roundings per step, with no amplification from the last:
+                error: NonNegative::new(Finite::new(
+                    3_f64.mul_add(order, 2_f64) * constants::GSL_DBL_EPSILON * value.abs(),
+                )),
+            };
+            previous = value;
+            odd = !odd;
+        }
+    } else {
+        // $\frac{(-1)^{m} m!}{x^{m + 1}}$, built up one order at a time:
+        let mut pole = raw.recip();
+        for (m, entry) in derivatives.iter_mut().enumerate() {
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_precision_loss,
+                reason = "orders past 2^53 are indistinguishable in the result anyway"
+            )]
+            let order = m as f64;
+            if m > 0 {
+                pole *= -order / raw;
+            }
+            if !pole.is_finite() {
+                return Err(Error::Overflow);
+            }
+            let relative = exprel_n(m.saturating_add(1), Finite::new(-raw))?;
+            let tail = exponential * *relative.value / (order + 1_f64);
+            let value = pole + tail;
+            *entry = Approx {
+                value: Finite::try_new(if reflect && odd { -value } else { value })
+                    .ok_or(Error::Overflow)?,
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    2_f64.mul_add(order, 1_f64) * constants::GSL_DBL_EPSILON * pole.abs()
+                        + exponential / (order + 1_f64)
+                            * 3_f64.mul_add(
+                                constants::GSL_DBL_EPSILON * relative.value.abs(),
+                                **relative.error,
+                            )
+                        + constants::GSL_DBL_EPSILON * value.abs(),
+                )),
+            };
+            odd = !odd;
+        }
+    }
+    Ok(())
+}
+
+/// Taylor coefficients of Ei about `x`, from the constant term up:
+/// `coefficients[k]` is $\frac{\text{Ei}^{(k)}(x)}{k!}$.
+///
+/// Past the constant term, these are $\frac{a_{k - 1}}{k}$,
+/// where $a_{m} = \frac{f_{m}}{m!}$ are the coefficients of $f_{0}(x) = \frac{e^{x}}{x}$
+/// (see `Ei_derivatives`), computed the same ways with the factorials divided out as they go,
+/// so nothing overflows that the coefficients themselves wouldn't:
+/// $a_{m} = \frac{1}{x} \left( \frac{e^{x}}{m!} - a_{m - 1} \right)$ for negative `x`, and
+/// $a_{m} = \frac{(-1)^{m}}{x^{m + 1}} + \frac{e^{x}}{(m + 1)!} \text{exprel}_{m + 1}(-x)$ for positive `x`.
+///
+/// With `reflect`, these are instead the coefficients of E1 about `x`,
+/// $\frac{E_{1}^{(k)}(x)}{k!} = (-1)^{k + 1} \frac{\text{Ei}^{(k)}(-x)}{k!}$.
+/// # Errors
+/// See `Ei` (or `E1`, with `reflect`) for the constant term;
+/// past that, if a coefficient is too large to represent.
+/// Either way, the entries before it are already filled and the rest are untouched.
+#[inline]
+pub(crate) fn Ei_taylor(
+    x: NonZero<Finite<f64>>,
+    coefficients: &mut [Approx],
+    reflect: bool,
+    max_precision: Precision,
+) -> Result<(), Error> {
+    let raw = if reflect { -**x } else { **x };
+    let Some((constant, rest)) = coefficients.split_first_mut() else {
+        return Ok(());
+    };
+    *constant = E1(NonZero::new(Finite::new(-raw)), max_precision)?;
+    if !reflect {
+        constant.value = Finite::new(-*constant.value);
+    }
+
+    let exponential = libm::exp(raw);
+    // Whether `k = m + 1` is even, i.e. whether reflecting flips the sign:
+    let mut even = false;
+    if raw < 0_f64 {
+        let mut previous = 0_f64;
+        // $\frac{e^{x}}{m!}$:
+        let mut term = exponential;
+        for (m, entry) in rest.iter_mut().enumerate() {
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_precision_loss,
+                reason = "orders past 2^53 are indistinguishable in the result anyway"
+            )]
+            let order = m as f64;
+            if m > 0 {
+                term /= order;
+            }
+            let value = (term - previous) / raw;
+            previous = value;
+            let coefficient = value / (order + 1_f64);
+            *entry = Approx {
+                value: Finite::try_new(if reflect && even { -coefficient } else { coefficient })
+                    .ok_or(Error::Overflow)?,
+                #[cfg(feature = "error")]
+                // Four roundings per step, with no amplification from the last:
+                error: NonNegative::new(Finite::new(
+                    4_f64.mul_add(order, 3_f64) * constants::GSL_DBL_EPSILON * coefficient.abs(),
+                )),
+            };
+            even = !even;
+        }
+    } else {
+        // $\frac{(-1)^{m}}{x^{m + 1}}$ and $\frac{e^{x}}{(m + 1)!}$:
+        let mut pole = raw.recip();
+        let mut term = exponential;
+        for (m, entry) in rest.iter_mut().enumerate() {
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_precision_loss,
+                reason = "orders past 2^53 are indistinguishable in the result anyway"
+            )]
+            let order = m as f64;
+            if m > 0 {
+                pole /= -raw;
+            }
+            if !pole.is_finite() {
+                return Err(Error::Overflow);
+            }
+            term /= order + 1_f64;
+            let relative = exprel_n(m.saturating_add(1), Finite::new(-raw))?;
+            let tail = term * *relative.value;
+            let coefficient = (pole + tail) / (order + 1_f64);
+            *entry = Approx {
+                value: Finite::try_new(if reflect && even {
+                    -coefficient
+                } else {
+                    coefficient
+                })
+                .ok_or(Error::Overflow)?,
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    ((order + 1_f64) * constants::GSL_DBL_EPSILON * pole.abs()
+                        + term
+                            * 3_f64.mul_add(
+                                constants::GSL_DBL_EPSILON * relative.value.abs(),
+                                **relative.error,
+                            ))
+                        / (order + 1_f64)
+                        + 2_f64 * constants::GSL_DBL_EPSILON * coefficient.abs(),
+                )),
+            };
+            even = !even;
+        }
+    }
+    Ok(())
+}
+
+/// $\text{Ei}(b) - \text{Ei}(a)$ without the cancellation of subtracting the two when they're close.
+///
+/// No original C code: GSL has no such difference.
+/// With both `a` and `b` at most 1 in magnitude, subtract the power series
+/// $\text{Ei}(x) = \gamma + \ln |x| + \sum_{k=1}^{\infty} \frac{x^k}{k \cdot k!}$ term by term,
+/// taking $\ln \left| \frac{b}{a} \right|$ in one piece
+/// and building each $\frac{b^k - a^k}{k!}$ from $b - a$ by recurrence, so nothing cancels.
+/// Farther out, if $b - a$ is small next to `a`, sum the Taylor series about `a` (see `Ei_taylor`);
+/// otherwise, the two values are far enough apart to subtract directly.
+/// # Errors
+/// See `Ei`.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the other functions")]
+pub(crate) fn Ei_diff(
+    a: NonZero<Finite<f64>>,
+    b: NonZero<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    /// Taylor coefficients to sum, enough for $4^{-k}$ and $\frac{2^k}{k!}$ to fall far below rounding error.
+    const TAYLOR_TERMS: usize = 32;
+    /// Power-series terms to sum for arguments at most 1 in magnitude.
+    const SERIES_TERMS: u8 = 24;
+
+    let (lo, hi) = (**a, **b);
+    let difference = hi - lo;
+
+    if lo.abs() <= 1_f64 && hi.abs() <= 1_f64 {
+        let ratio = hi.abs() / lo.abs();
+        let near = (0.5_f64..=2_f64).contains(&ratio);
+        let log_part = if near {
+            // Exact by Sterbenz's lemma:
+            let change = hi.abs() - lo.abs();
+            libm::log1p(change / lo.abs())
+        } else if ratio.is_normal() {
+            libm::log(ratio)
+        } else {
+            libm::log(hi.abs()) - libm::log(lo.abs())
+        };
+        // Relative to the result for `log1p`, but absolute for `log` of a rounded ratio;
+        // generous enough for the last case, where the ratio is so extreme
+        // that each logarithm is less than the difference between them:
+        #[cfg(feature = "error")]
+        let log_error = constants::GSL_DBL_EPSILON
+            * 3_f64.mul_add(log_part.abs(), if near { 0_f64 } else { 1_f64 });
+
+        // $\frac{a^{k - 1}}{(k - 1)!}$ and $\frac{b^k - a^k}{k!}$:
+        let mut power = 1_f64;
+        let mut gap = 0_f64;
+        let mut sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_sum = 0_f64;
+        for index in 1..=SERIES_TERMS {
+            let k = f64::from(index);
+            gap = power.mul_add(difference, hi * gap) / k;
+            power *= lo / k;
+            sum += gap / k;
+            #[cfg(feature = "error")]
+            {
+                abs_sum += gap.abs() / k;
+            }
+        }
+        let value = log_part + sum;
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                log_error
+                    + 4_f64 * f64::from(SERIES_TERMS) * constants::GSL_DBL_EPSILON * abs_sum
+                    + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+            )),
+        });
+    }
+
+    if difference.abs() <= 0.25_f64 * lo.abs() && difference.abs() <= 2_f64 {
+        let mut coefficients = [Approx::default(); TAYLOR_TERMS];
+        Ei_taylor(a, &mut coefficients, false, max_precision)?;
+        let mut sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut error = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_sum = 0_f64;
+        let mut power = 1_f64;
+        for coefficient in coefficients.iter().skip(1) {
+            power *= difference;
+            sum += *coefficient.value * power;
+            #[cfg(feature = "error")]
+            {
+                error += **coefficient.error * power.abs();
+                abs_sum += (*coefficient.value * power).abs();
+            }
+        }
+        return Ok(Approx {
+            value: Finite::new(sum),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                error + 2_f64 * constants::GSL_DBL_EPSILON * abs_sum,
+            )),
+        });
+    }
+
+    let upper = E1(NonZero::new(Finite::new(-hi)), max_precision)?;
+    let lower = E1(NonZero::new(Finite::new(-lo)), max_precision)?;
+    let value = *lower.value - *upper.value;
+    Ok(Approx {
+        value: Finite::new(value),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            **lower.error + **upper.error + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// Ei and its derivative, sharing one exponential:
+/// $\text{Ei}(x) = e^{x} \cdot e^{-x} \text{Ei}(x)$, where the scaled factor never needs one,
+/// and $\text{Ei}'(x) = e^{x} \cdot \frac{1}{x}$.
+/// # Errors
+/// See `Ei` for the bounds on `x`,
+/// plus overflow in the derivative when `x` is so close to zero that $\frac{1}{x}$ overflows.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the other functions")]
+pub(crate) fn Ei_with_derivative(
+    x: NonZero<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<(Approx, Approx), Error> {
+    let raw = **x;
+    // Exactly the bounds (and the errors) of `E1` at $-x$:
+    if raw >= constants::XMAX {
+        return Err(Error::ArgumentTooNegative(Negative::new(Finite::new(-raw))));
+    }
+    if raw <= constants::NXMAX {
+        return Err(Error::Underflow);
+    }
+
+    let exponential = libm::exp(raw);
+    let scaled = E1_scaled(NonZero::new(Finite::new(-raw)), max_precision);
+    let value = -*scaled.value * exponential;
+    let derivative = exponential / raw;
+    Ok((
+        Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                **scaled.error * exponential + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+            )),
+        },
+        Approx {
+            value: Finite::try_new(derivative).ok_or(Error::Overflow)?,
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                2_f64 * constants::GSL_DBL_EPSILON * derivative.abs(),
+            )),
+        },
+    ))
+}
+
+/// E1 at both $|x|$ and $-|x|$, sharing what the two have in common:
+/// one classification of $|x|$,
+/// one logarithm wherever both land in `le_pos_1` (which only ever needs $\ln |x|$),
+/// and, past 4, one exponential, since both pieces there are $\frac{e^{-z}}{z}$ times a series:
+/// each is evaluated scaled, then one divided and the other multiplied by $e^{|x|}$.
+/// Between 1 and 4 in magnitude, one side needs an exponential and the other a logarithm,
+/// so only the classification is shared.
+/// # Errors
+/// Exactly where `E1` at `x` fails, with the same error
+/// (`XMAX` and `NXMAX` are symmetric, so Ei fails there too).
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the other functions")]
+pub(crate) fn E1_Ei_pair(
+    x: NonZero<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<(Approx, Approx), Error> {
+    let raw = **x;
+    if raw >= constants::XMAX {
+        return Err(Error::Underflow);
+    }
+    if raw <= constants::NXMAX {
+        return Err(Error::ArgumentTooNegative(Negative::new(*x)));
+    }
+
+    let abs = raw.abs();
+    let (at_abs, at_neg) = if abs <= 1_f64 {
+        let positive = NonZero::new(Finite::new(abs));
+        let ln = piecewise::ln_abs(positive);
+        // The same comparison as `Piece::at`, so both land exactly where `E1` would send them:
+        let at_neg = if (-abs + constants::EI_ZERO).abs() < piecewise::ROOT_RADIUS {
+            piecewise::near_root(Negative::new(Finite::new(-abs)), false)
+        } else {
+            piecewise::le_pos_1_with_ln(NonZero::new(Finite::new(-abs)), ln, false, max_precision)
+        };
+        let at_abs = piecewise::le_pos_1_with_ln(positive, ln, false, max_precision);
+        (at_abs, at_neg)
+    } else if abs <= 4_f64 {
+        (
+            piecewise::le_pos_4(Positive::new(Finite::new(abs)), false, max_precision),
+            piecewise::le_neg_1(Negative::new(Finite::new(-abs)), false, max_precision),
+        )
+    } else {
+        let exponential = libm::exp(abs);
+        let scaled_abs =
+            piecewise::le_pos_max(Positive::new(Finite::new(abs)), true, max_precision);
+        let scaled_neg = if abs >= 10_f64 {
+            piecewise::le_neg_10(Negative::new(Finite::new(-abs)), true, max_precision)
+        } else {
+            piecewise::le_neg_4(Negative::new(Finite::new(-abs)), true, max_precision)
+        };
+        // As in `Ei_with_derivative`, the scaled error carries over, plus rounding:
+        let unscale = |mut scaled: Approx, value: f64| {
+            #[cfg(feature = "error")]
+            {
+                scaled.error = NonNegative::new(Finite::new(
+                    **scaled.error * (value / *scaled.value).abs()
+                        + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+                ));
+            }
+            scaled.value = Finite::new(value);
+            scaled
+        };
+        (
+            unscale(scaled_abs, *scaled_abs.value / exponential),
+            unscale(scaled_neg, *scaled_neg.value * exponential),
+        )
+    };
+
+    let (e1, mut ei) = if raw > 0_f64 {
+        (at_abs, at_neg)
+    } else {
+        (at_neg, at_abs)
+    };
+    ei.value = Finite::new(-*ei.value);
+    Ok((e1, ei))
+}
+
+/// # Errors
+/// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
+/// # Original C code
+/// ```c
+/// static
+/// int expint_E2_impl(const double x, gsl_sf_result * result, const int scale)
+/// {
+///   const double xmaxt = -GSL_LOG_DBL_MIN;
+///   const double xmax  = xmaxt - log(xmaxt);
+///
+///   /* CHECK_POINTER(result) */
+///
+///   if(x < -xmax && !scale) {
+///     OVERFLOW_ERROR(result);
+///   }
+///   else if (x == 0.0) {
+///     result->val = (scale ? 1.0 : 1.0);
+///     result->err = 0.0;
+///     return GSL_SUCCESS;
+///   } else if(x < 100.0) {
+///     const double ex = ( scale ? 1.0 : exp(-x) );
+///     gsl_sf_result result_E1;
+///     int stat_E1 = expint_E1_impl(x, &result_E1, scale);
+///     result->val  = ex - x*result_E1.val;
+///     result->err  = GSL_DBL_EPSILON*ex + fabs(x) * result_E1.err;
+///     result->err += 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return stat_E1;
+///   }
+///   else if(x < xmax || scale) {
+///     const double s = ( scale ? 1.0 : exp(-x) );
+///     const double c1  = -2.0;
+///     const double c2  =  6.0;
+///     const double c3  = -24.0;
+///     const double c4  =  120.0;
+///     const double c5  = -720.0;
+///     const double c6  =  5040.0;
+///     const double c7  = -40320.0;
+///     const double c8  =  362880.0;
+///     const double c9  = -3628800.0;
+///     const double c10 =  39916800.0;
+///     const double c11 = -479001600.0;
+///     const double c12 =  6227020800.0;
+///     const double c13 = -87178291200.0;
+///     const double y = 1.0/x;
+///     const double sum6 = c6+y*(c7+y*(c8+y*(c9+y*(c10+y*(c11+y*(c12+y*c13))))));
+///     const double sum  = y*(c1+y*(c2+y*(c3+y*(c4+y*(c5+y*sum6)))));
+///     result->val = s * (1.0 + sum)/x;
+///     result->err = 2.0 * (x + 1.0) * GSL_DBL_EPSILON * result->val;
+///     if(result->val == 0.0)
+///       UNDERFLOW_ERROR(result);
+///     else
+///       return GSL_SUCCESS;
+///   }
+///   else {
+///     UNDERFLOW_ERROR(result);
+///   }
+/// }
+/// ```
+#[inline]
+pub(crate) fn E2(x: Finite<f64>, scale: bool, max_precision: Precision) -> Result<Approx, Error> {
+    if *x < constants::NXMAX && !scale {
+        return Err(Error::ArgumentTooNegative(Negative::new(x)));
+    }
+
+    if *x == 0_f64 {
+        return Ok(Approx {
+            value: Finite::ONE,
+            #[cfg(feature = "error")]
+            error: NonNegative::ZERO,
+        });
+    }
+
+    if *x < 100_f64 {
+        let ex = if scale { 1_f64 } else { libm::exp(-*x) };
+        let e1 = if scale {
+            E1_scaled(NonZero::new(x), max_precision)
+        } else {
+            E1(NonZero::new(x), max_precision)?
+        };
+        let value = ex - *x * *e1.value;
+        #[cfg(feature = "error")]
+        let error = constants::GSL_DBL_EPSILON * ex
+            + x.abs() * **e1.error
+            + 2_f64 * constants::GSL_DBL_EPSILON * value.abs();
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(error)),
+        });
+    }
+
+    if *x < constants::XMAX || scale {
+        let s = if scale { 1_f64 } else { libm::exp(-*x) };
+        let y = x.recip();
+        let sum6 = 5_040_f64
+            + y * (-40_320_f64
+                + y * (362_880_f64
+                    + y * (-3_628_800_f64
+                        + y * (39_916_800_f64
+                            + y * (-479_001_600_f64
+                                + y * (6_227_020_800_f64 + y * -87_178_291_200_f64))))));
+        let sum =
+            y * (-2_f64 + y * (6_f64 + y * (-24_f64 + y * (120_f64 + y * (-720_f64 + y * sum6)))));
+        let value = s * (1_f64 + sum) / *x;
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                2_f64 * constants::GSL_DBL_EPSILON * (*x + 1_f64) * value,
+            )),
+        });
+    }
+
+    Err(Error::ArgumentTooPositive(Positive::new(x)))
+}
+
+/// No original C code: GSL only reaches the third order through `gsl_sf_expint_En_e`.
+/// Uses the recurrence $E_{n+1}(x) = \frac{e^{-x} - x E_{n}(x)}{n}$ up to 2,
+/// and, since each subtraction there loses digits as `x` grows,
+/// $E_{3}(x) = E_{1}(x) \frac{E_{2}(x)}{E_{1}(x)} \frac{E_{3}(x)}{E_{2}(x)}$ (see `en::ratio_continued_fraction`) above it,
+/// then the asymptotic series from 100.
+/// # Errors
+/// If `x` is negative or so large that floating-point operations will fail down the line (just over 710).
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "to mirror the C implementation")
+)]
+pub(crate) fn E3(x: Finite<f64>, max_precision: Precision) -> Result<Approx, e3::Error> {
+    if *x < 0_f64 {
+        return Err(e3::Error::NegativeArgument(Negative::new(x)));
+    }
+
+    if *x == 0_f64 {
+        return Ok(Approx {
+            value: Finite::new(0.5_f64),
+            #[cfg(feature = "error")]
+            error: NonNegative::ZERO,
+        });
+    }
+
+    if *x <= 2_f64 {
+        let ex = libm::exp(-*x);
+        let e1 = E1_scaled(NonZero::new(x), max_precision);
+        // Each multiplied through by $e^{x}$:
+        let e2 = 1_f64 - *x * *e1.value;
+        let e3 = 0.5_f64 * (1_f64 - *x * e2);
+        let value = ex * e3;
+        #[cfg(feature = "error")]
+        let error = {
+            let e2_error = constants::GSL_DBL_EPSILON
+                + *x * **e1.error
+                + 2_f64 * constants::GSL_DBL_EPSILON * e2.abs();
+            let e3_error = 0.5_f64 * (constants::GSL_DBL_EPSILON + *x * e2_error)
+                + 2_f64 * constants::GSL_DBL_EPSILON * e3.abs();
+            ex * e3_error + 2_f64 * constants::GSL_DBL_EPSILON * value.abs()
+        };
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(error)),
+        });
+    }
+
+    if *x < 100_f64 {
+        let positive = Positive::new(x);
+        let e1 = E1_scaled(NonZero::new(x), max_precision);
+        let e2_over_e1 = en::ratio_continued_fraction(1_f64, positive).approx;
+        let e3_over_e2 = en::ratio_continued_fraction(2_f64, positive).approx;
+        #[expect(
+            clippy::arithmetic_side_effects,
+            reason = "each factor is positive and at most 1, and so is their product"
+        )]
+        let product = e1 * e2_over_e1 * e3_over_e2 * libm::exp(-*x);
+        return Ok(product);
+    }
+
+    if *x < constants::XMAX {
+        // Coefficients are (-1)^k (k + 2)! / 2.
+        let s = libm::exp(-*x);
+        let y = x.recip();
+        let sum6 = 20_160_f64
+            + y * (-181_440_f64
+                + y * (1_814_400_f64
+                    + y * (-19_958_400_f64
+                        + y * (239_500_800_f64
+                            + y * (-3_113_510_400_f64
+                                + y * (43_589_145_600_f64 + y * -653_837_184_000_f64))))));
+        let sum = y
+            * (-3_f64 + y * (12_f64 + y * (-60_f64 + y * (360_f64 + y * (-2_520_f64 + y * sum6)))));
+        let value = s * (1_f64 + sum) / *x;
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                2_f64 * (*x + 1_f64) * constants::GSL_DBL_EPSILON * value,
+            )),
+        });
+    }
+
+    Err(e3::Error::ArgumentTooPositive(Positive::new(x)))
+}
+
+/// # Errors
+/// If `x` is outside the domain for order `n`
+/// or so large that floating-point operations will fail down the line (absolute value of just over 710).
+/// # Original C code
+/// Note that,
+/// instead of GSL's detour through the incomplete gamma function for `n > 2`,
+/// this uses forward recurrence (stable while `x` is at most each order it passes through)
+/// for small `x` and small `n`, and a continued fraction everywhere else.
+/// ```c
+/// static int
+/// expint_En_impl(const int n, const double x, gsl_sf_result * result, const int scale)
+/// {
+///   if (n < 0) {
+///     DOMAIN_ERROR(result);
+///   } else if (n == 0) {
+///     if (x == 0) {
+///       DOMAIN_ERROR(result);
+///     } else {
+///       result->val = (scale ? 1.0 : exp(-x)) / x;
+///       result->err = 2 * GSL_DBL_EPSILON * fabs(result->val);
+///       CHECK_UNDERFLOW(result);
+///       return GSL_SUCCESS;
+///     }
+///   } else if (n == 1) {
+///     return expint_E1_impl(x, result, scale);
+///   } else if (n == 2) {
+///     return expint_E2_impl(x, result, scale);
+///   } else {
+///     if(x < 0) {
+///       DOMAIN_ERROR(result);
+///     }
+///     if (x == 0) {
+///       result->val = (scale ? exp(x) : 1 ) * (1/(n-1.0));
+///       result->err = 2 * GSL_DBL_EPSILON * fabs(result->val);
+///       CHECK_UNDERFLOW(result);
+///       return GSL_SUCCESS;
+///     } else {
+///       gsl_sf_result result_g;
+///       double prefactor = pow(x, n-1);
+///       int status = gsl_sf_gamma_inc_e (1-n, x, &result_g);
+///       double scale_factor = ( scale ? exp(x) : 1.0 );
+///       result->val = scale_factor * prefactor * result_g.val;
+///       result->err = 2 * GSL_DBL_EPSILON * fabs(result->val);
+///       result->err += scale_factor * prefactor * result_g.err;
+///       if (status == GSL_SUCCESS) CHECK_UNDERFLOW(result);
+///       return status;
+///     }
+///   }
+/// }
+/// ```
+#[inline]
+pub(crate) fn En(
+    n: usize,
+    x: Finite<f64>,
+    scale: bool,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    match n {
+        0 => {
+            if *x == 0_f64 {
+                return Err(Error::Domain(x));
+            }
+            if scale {
+                let value = Finite::try_new(x.recip()).ok_or(Error::Overflow)?;
+                return Ok(Approx {
+                    value,
+                    #[cfg(feature = "error")]
+                    error: NonNegative::new(Finite::new(
+                        2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+                    )),
+                });
+            }
+            if *x < constants::NXMAX {
+                return Err(Error::ArgumentTooNegative(Negative::new(x)));
+            }
+            if *x >= constants::XMAX {
+                return Err(Error::ArgumentTooPositive(Positive::new(x)));
+            }
+            let value = Finite::try_new(libm::exp(-*x) / *x).ok_or(Error::Overflow)?;
+            Ok(Approx {
+                value,
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+                )),
+            })
+        }
+        1 => {
+            let nonzero = NonZero::try_new(x).ok_or(Error::Domain(x))?;
+            if scale {
+                Ok(E1_scaled(nonzero, max_precision))
+            } else {
+                E1(nonzero, max_precision)
+            }
+        }
+        2 => E2(x, scale, max_precision),
+        _ => {
+            if *x < 0_f64 {
+                return Err(Error::Domain(x));
+            }
+            if *x >= constants::XMAX && !scale {
+                return Err(Error::ArgumentTooPositive(Positive::new(x)));
+            }
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_precision_loss,
+                reason = "orders past 2^53 are indistinguishable in the result anyway"
+            )]
+            let order = n as f64;
+            if *x == 0_f64 {
+                return Ok(Approx {
+                    value: Finite::new((order - 1_f64).recip()),
+                    #[cfg(feature = "error")]
+                    error: NonNegative::new(Finite::new(
+                        constants::GSL_DBL_EPSILON * (order - 1_f64).recip(),
+                    )),
+                });
+            }
+            if *x <= 1_f64 && n <= en::MAX_FORWARD_RECURRENCE {
+                en::forward_recurrence(n, x, scale, max_precision)
+            } else {
+                en::continued_fraction(order, Positive::new(x), scale)
+                    .map(|converged| converged.approx)
+            }
+        }
+    }
+}
+
+/// No original C code: GSL evaluates one order at a time.
+/// Fills `orders[k]` with $E_{k + 1}(x)$ by recurrence from a single order computed directly,
+/// $E_{p}$ with $p = \lfloor x \rfloor$ (clamped to the orders asked for),
+/// so that every step runs in its stable direction: since each step from $E_{k}$ scales its error by $\frac{x}{k}$
+/// going up and $\frac{k}{x}$ going down, that's upward $E_{k+1}(x) = \frac{e^{-x} - x E_{k}(x)}{k}$ past `p`
+/// and downward $E_{k}(x) = \frac{e^{-x} - k E_{k+1}(x)}{x}$ below it.
+/// The pivot is always computed scaled, so $e^{-x}$ is computed once (and never with `scale`).
+/// Nonpositive `x` falls back to `En` order by order, since only E1 and E2 exist there anyway.
+/// # Errors
+/// See `En`; unless `x` is nonpositive (where the orders before the failing one are already filled),
+/// nothing is filled on error.
+#[inline]
+pub(crate) fn En_sequence(
+    x: Finite<f64>,
+    orders: &mut [Approx],
+    scale: bool,
+    max_precision: Precision,
+) -> Result<(), Error> {
+    let raw = *x;
+    if raw <= 0_f64 {
+        for (n, entry) in (1_usize..).zip(orders.iter_mut()) {
+            *entry = En(n, x, scale, max_precision)?;
+        }
+        return Ok(());
+    }
+    if raw >= constants::XMAX && !scale {
+        return Err(Error::ArgumentTooPositive(Positive::new(x)));
+    }
+
+    let Some(last) = orders.len().checked_sub(1) else {
+        return Ok(());
+    };
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "positive, and saturating if huge"
+    )]
+    let pivot = (raw as usize).saturating_sub(1).min(last);
+    let (below, above) = orders.split_at_mut(pivot);
+    let Some((start, rest)) = above.split_first_mut() else {
+        return Ok(());
+    };
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "orders past 2^53 are indistinguishable in the result anyway"
+    )]
+    let order = pivot as f64 + 1_f64;
+    let exponential = if scale { 1_f64 } else { libm::exp(-raw) };
+    let scaled = En(pivot.saturating_add(1), x, true, max_precision)?;
+    let value = *scaled.value * exponential;
+    *start = Approx {
+        value: Finite::new(value),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            **scaled.error * exponential + constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    };
+
+    let mut previous = *start;
+    let mut k = order;
+    for entry in rest {
+        let next = (exponential - raw * *previous.value) / k;
+        *entry = Approx {
+            value: Finite::new(next),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                (constants::GSL_DBL_EPSILON * exponential + raw * **previous.error) / k
+                    + 2_f64 * constants::GSL_DBL_EPSILON * next.abs(),
+            )),
+        };
+        previous = *entry;
+        k += 1_f64;
+    }
+
+    previous = *start;
+    k = order;
+    for entry in below.iter_mut().rev() {
+        k -= 1_f64;
+        let next = (exponential - k * *previous.value) / raw;
+        *entry = Approx {
+            value: Finite::new(next),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                (constants::GSL_DBL_EPSILON * exponential + k * **previous.error) / raw
+                    + 2_f64 * constants::GSL_DBL_EPSILON * next.abs(),
+            )),
+        };
+        previous = *entry;
+    }
+
+    Ok(())
+}
+
+/// No original C code: GSL has no ratio of successive orders.
+/// Where `x + n` is large enough for the continued fraction to converge quickly
+/// (the same split as `En`), evaluates $\frac{E_{n+1}(x)}{E_{n}(x)}$ directly from its tail,
+/// so it stays accurate long after both orders underflow.
+/// Everywhere else, both orders are representable once scaled by $e^{x}$, and the scaling cancels in the quotient.
+/// # Errors
+/// See `En_scaled` for either order, or if the quotient overflows
+/// (only near the root of $E_{1}$ for negative `x`).
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the other functions")]
+pub(crate) fn En_ratio(
+    n: usize,
+    x: Finite<f64>,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    if let Some(positive) = Positive::try_new(x)
+        && (*x > 1_f64 || n > en::MAX_FORWARD_RECURRENCE)
+    {
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "orders past 2^53 are indistinguishable in the result anyway"
+        )]
+        let order = n as f64;
+        return Ok(en::ratio_continued_fraction(order, positive).approx);
+    }
+    let lower = En(n, x, true, max_precision)?;
+    let upper = En(n.saturating_add(1), x, true, max_precision)?;
+    let value = Finite::try_new(*upper.value / *lower.value).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(
+            Finite::try_new(
+                value.abs().mul_add(**lower.error, **upper.error) / lower.value.abs()
+                    + constants::GSL_DBL_EPSILON * value.abs(),
+            )
+            .ok_or(Error::Overflow)?,
+        ),
+    })
+}
+
+/// $E_{\nu}(x) = \Gamma(1 - \nu) x^{\nu - 1} + \sum_{k=0}^{\infty} \frac{(-x)^k}{k! (\nu - 1 - k)}$
+/// for non-integer `nu` above 1 and `x` at most 1:
+/// the alternating series behind `incomplete_gamma::upper`, multiplied through by $x^{\nu - 1}$ term by term,
+/// since $\Gamma(1 - \nu, x)$ on its own overflows for small `x` long before $E_{\nu}(x) \approx \frac{1}{\nu - 1}$ does.
+/// Once the terms alternate, the truncated tail is bounded by the last term.
+#[inline]
+#[expect(
+    clippy::single_call_fn,
+    reason = "kept apart from `Enu`, as `incomplete_gamma` keeps each series"
+)]
+fn Enu_series(nu: f64, x: f64) -> Result<Approx, Error> {
+    /// Give up after this many terms, as `incomplete_gamma` does:
+    /// it takes that many only once a term has overflowed, so that the stopping test can never pass.
+    const MAX_TERMS: f64 = 10_000_f64;
+
+    let mut power = 1_f64;
+    let mut k = 0_f64;
+    let mut sum = (nu - 1_f64).recip();
+    #[cfg(feature = "error")]
+    let mut abs_sum = sum.abs();
+    #[cfg(feature = "error")]
+    let mut last: f64;
+    loop {
+        k += 1_f64;
+        power *= -x / k;
+        let term = power / (nu - 1_f64 - k);
+        sum += term;
+        #[cfg(feature = "error")]
+        {
+            abs_sum += term.abs();
+            last = term.abs();
+        }
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+        if k >= MAX_TERMS {
+            return Err(Error::Overflow);
+        }
+    }
+
+    // Underflows harmlessly to zero wherever it's negligible next to the sum:
+    let singular = libm::tgamma(1_f64 - nu) * libm::pow(x, nu - 1_f64);
+    let value = Finite::try_new(singular + sum).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            last + constants::GSL_DBL_EPSILON
+                * (2_f64 * singular.abs() + 2_f64 * abs_sum + 2_f64 * value.abs()),
+        )),
+    })
+}
+
+/// No original C code: GSL stops at integer orders.
+/// Uses the continued fraction (valid for any real order) where it converges quickly
+/// and $E_{\nu}(x) = x^{\nu - 1} \Gamma(1 - \nu, x)$ everywhere else,
+/// summed directly (`Enu_series`) above order 1, where $\Gamma(1 - \nu, x)$ alone can overflow.
+/// # Errors
+/// If `x` is outside the domain for order `nu`,
+/// so large that floating-point operations will fail down the line (just over 710),
+/// or if the result is too large to represent.
+#[inline]
+pub(crate) fn Enu(
+    nu: Finite<f64>,
+    x: Finite<f64>,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    if *x < 0_f64 {
+        return Err(Error::Domain(x));
+    }
+
+    if *nu >= 0_f64 && libm::floor(*nu) >= *nu {
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "nonnegative integer by the check above, and saturating if huge"
+        )]
+        let n = *nu as usize;
+        return En(n, x, false, max_precision);
+    }
+
+    if *x == 0_f64 {
+        return if *nu > 1_f64 {
+            let value = (*nu - 1_f64).recip();
+            Ok(Approx {
+                value: Finite::new(value),
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(constants::GSL_DBL_EPSILON * value)),
+            })
+        } else {
+            Err(Error::Domain(x))
+        };
+    }
+
+    if *x >= constants::XMAX {
+        return Err(Error::ArgumentTooPositive(Positive::new(x)));
+    }
+
+    // Same crossover as `incomplete_gamma::upper`, but without
+    // multiplying and then dividing by a potentially huge power of `x`:
+    if *x > 1_f64 && *x >= 2_f64 - *nu {
+        return en::continued_fraction(*nu, Positive::new(x), false)
+            .map(|converged| converged.approx);
+    }
+
+    if *nu > 1_f64 {
+        return Enu_series(*nu, *x);
+    }
+
+    let gamma = incomplete_gamma::upper(Finite::new(1_f64 - *nu), Positive::new(x), max_precision)?;
+    let prefactor = libm::pow(*x, *nu - 1_f64);
+    let value = Finite::try_new(prefactor * *gamma.value).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            prefactor * **gamma.error + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// One node of `order_derivative_quadrature`:
+/// the integrand at $s = e^{\frac{\pi}{2} \sinh w}$, times $\frac{\text{d}s}{\text{d}w}$.
+#[inline]
+fn order_derivative_node(w: f64, slope: f64, x: f64, shift: f64) -> Approx {
+    let s = libm::exp(FRAC_PI_2 * libm::sinh(w));
+    let growth = x * libm::exp(s);
+    let exponent = slope.mul_add(s, -growth) - shift;
+    // Catches both `-inf` and `NaN` (from `inf - inf`), which are both zero in the limit:
+    if exponent.partial_cmp(&f64::MIN) != Some(Ordering::Greater) {
+        return Approx::default();
+    }
+    let term = s * s * FRAC_PI_2 * libm::cosh(w) * libm::exp(exponent);
+    Approx {
+        value: Finite::new(term),
+        // Rounding in the exponent is relative error in the term:
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            term * constants::GSL_DBL_EPSILON * ((slope * s).abs() + growth + shift.abs() + 4_f64),
+        )),
+    }
+}
+
+/// $\int_{0}^{\infty} s e^{(1 - \nu) s - x e^{s}} \text{d}s = \int_{1}^{\infty} e^{-xt} t^{-\nu} \ln t \text{d}t$
+/// for `x` at least 1 by double-exponential quadrature:
+/// substituting $s = e^{\frac{\pi}{2} \sinh w}$ makes the integrand fall off double-exponentially in both directions,
+/// so the trapezoidal rule in $w$ converges exponentially as its step halves.
+/// The integrand is divided by its peak, $e^{\text{shift}}$, which is returned alongside,
+/// since either can be out of range where their product isn't.
+#[inline]
+fn order_derivative_quadrature(nu: f64, x: f64) -> (Approx, f64) {
+    /// Lowest node (before the change of variables),
+    /// below which every term is negligible.
+    const LOWEST: f64 = -7_f64;
+    /// Intervals between the initial nodes, half a unit apart,
+    /// past which every term is negligible or zero for `x` at least 1.
+    const INTERVALS: usize = 21;
+    /// Stop halving the step after this many levels.
+    const LEVELS: usize = 10;
+
+    let slope = 1_f64 - nu;
+    let peak = if slope > x {
+        libm::log(slope / x)
+    } else {
+        0_f64
+    };
+    let shift = slope.mul_add(peak, -x * libm::exp(peak));
+
+    let mut step = 0.5_f64;
+    let mut sum = 0_f64;
+    #[cfg(feature = "error")]
+    let mut rounding = 0_f64;
+    let mut w = LOWEST;
+    for _ in 0..=INTERVALS {
+        let node = order_derivative_node(w, slope, x, shift);
+        sum += *node.value;
+        #[cfg(feature = "error")]
+        {
+            rounding += **node.error;
+        }
+        w += step;
+    }
+    let mut estimate = step * sum;
+    #[cfg(feature = "error")]
+    let mut change = estimate;
+
+    let mut intervals = INTERVALS;
+    for _ in 0..LEVELS {
+        // Only the midpoints are new:
+        w = 0.5_f64.mul_add(step, LOWEST);
+        for _ in 0..intervals {
+            let node = order_derivative_node(w, slope, x, shift);
+            sum += *node.value;
+            #[cfg(feature = "error")]
+            {
+                rounding += **node.error;
+            }
+            w += step;
+        }
+        step *= 0.5_f64;
+        intervals <<= 1_u32;
+        let refined = step * sum;
+        let difference = (refined - estimate).abs();
+        estimate = refined;
+        #[cfg(feature = "error")]
+        {
+            change = difference;
+        }
+        // Strictly, so that an estimate of zero (before the nodes find the peak) keeps going:
+        if difference < 4_f64 * f64::EPSILON * estimate {
+            break;
+        }
+    }
+
+    (
+        Approx {
+            value: Finite::new(estimate),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(change + step * rounding)),
+        },
+        shift,
+    )
+}
+
+/// $T^{-k} \int_{1}^{T} t^{b - 1} \ln t \text{d}t = \frac{T^{b - k} (b \ln T - 1) + T^{-k}}{b^{2}}$,
+/// given $\ln T$ and $k \ln T$, where the factor of $T^{-k}$ keeps large `b` from overflowing.
+/// Near $b \ln T = 0$, where that cancels, uses
+/// $(\ln T)^{2} \sum_{j=0}^{\infty} \frac{(j + 1) (b \ln T)^{j}}{(j + 2)!}$ instead.
+/// # Errors
+/// If the result is too large to represent.
+#[inline]
+fn log_power_integral(b: f64, log: f64, decay: f64) -> Result<Approx, Error> {
+    let y = b * log;
+    if y.abs() <= 1_f64 {
+        let mut term = 0.5_f64;
+        let mut sum = term;
+        let mut j = 0_f64;
+        loop {
+            j += 1_f64;
+            term *= y * (j + 1_f64) / (j * (j + 2_f64));
+            sum += term;
+            if term.abs() <= f64::EPSILON * sum {
+                break;
+            }
+        }
+        let value = libm::exp(-decay) * log * log * sum;
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                (j + decay + 4_f64) * constants::GSL_DBL_EPSILON * value,
+            )),
+        });
+    }
+
+    let power = libm::exp(y - decay);
+    let floor = libm::exp(-decay);
+    let value =
+        Finite::try_new(power.mul_add(y - 1_f64, floor) / (b * b)).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        // `exp` turns the rounding of its argument into relative error:
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            constants::GSL_DBL_EPSILON
+                * power
+                * ((y - 1_f64).abs() / (b * b))
+                * (y.abs() + decay + 2_f64)
+                + constants::GSL_DBL_EPSILON * (floor * (decay + 1_f64) / (b * b) + 3_f64 * *value),
+        )),
+    })
+}
+
+/// No original C code: GSL has no derivatives with respect to order.
+/// Differentiates $E_{\nu}(x) = \int_{1}^{\infty} \frac{e^{-xt}}{t^{\nu}} \text{d}t$ under the integral:
+/// $\frac{\partial E_{\nu}}{\partial \nu}(x) = -\int_{1}^{\infty} e^{-xt} t^{-\nu} \ln t \text{d}t$.
+/// For `x` at least 1, that integral goes to `order_derivative_quadrature`.
+/// Below 1, the integral up to $\frac{1}{x}$ is the series
+/// $\sum_{k=0}^{\infty} \frac{(-x)^{k}}{k!} \int_{1}^{1 / x} t^{k - \nu} \ln t \text{d}t$,
+/// whose truncation after `k` terms is at most $\frac{e}{(k + 1)!}$ times its first term,
+/// since $x t \leq 1$ throughout;
+/// the rest, substituting $t = \frac{u}{x}$, is
+/// $x^{\nu - 1} \left( \frac{\partial E_{\nu}}{\partial \nu}(1) - \ln x E_{\nu}(1) \right)$
+/// in terms of the quadrature at 1.
+/// Every piece is negative, so nothing cancels, integer orders included.
+/// # Errors
+/// If `x` is negative (or zero for `nu` at most 1),
+/// so large that floating-point operations will fail down the line (just over 710),
+/// or if the result is too large to represent.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the other functions")]
+pub(crate) fn Enu_order_derivative(
+    nu: Finite<f64>,
+    x: Finite<f64>,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    let raw = *x;
+    if raw < 0_f64 {
+        return Err(Error::Domain(x));
+    }
+
+    if raw == 0_f64 {
+        return if *nu > 1_f64 {
+            let value = -((*nu - 1_f64).powi(2)).recip();
+            Ok(Approx {
+                value: Finite::new(value),
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+                )),
+            })
+        } else {
+            Err(Error::Domain(x))
+        };
+    }
+
+    if raw >= constants::XMAX {
+        return Err(Error::ArgumentTooPositive(Positive::new(x)));
+    }
+
+    if raw >= 1_f64 {
+        let (integral, shift) = order_derivative_quadrature(*nu, raw);
+        let scale = Finite::try_new(libm::exp(shift)).ok_or(Error::Overflow)?;
+        let value = Finite::try_new(-*scale * *integral.value).ok_or(Error::Overflow)?;
+        return Ok(Approx {
+            value,
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                *scale * **integral.error
+                    + constants::GSL_DBL_EPSILON * (shift.abs() + 2_f64) * value.abs(),
+            )),
+        });
+    }
+
+    let (integral, shift) = order_derivative_quadrature(*nu, 1_f64);
+    let at_one = Enu(nu, Finite::new(1_f64), max_precision)?;
+
+    let log = -libm::log(raw);
+    let mut b = 1_f64 - *nu;
+    let first = log_power_integral(b, log, 0_f64)?;
+    let mut sum = *first.value;
+    #[cfg(feature = "error")]
+    let mut rounding = **first.error;
+    let mut reciprocal_factorial = 1_f64;
+    let mut sign = 1_f64;
+    let mut k = 0_f64;
+    loop {
+        k += 1_f64;
+        reciprocal_factorial /= k;
+        if *first.value * reciprocal_factorial <= f64::EPSILON / E * sum {
+            break;
+        }
+        sign = -sign;
+        b += 1_f64;
+        // $\frac{(-x)^{k}}{k!}$, with $x^{k}$ folded into the integral:
+        let integral_k = log_power_integral(b, log, k * log)?;
+        let term = sign * reciprocal_factorial * *integral_k.value;
+        sum += term;
+        #[cfg(feature = "error")]
+        {
+            rounding += reciprocal_factorial * **integral_k.error
+                + 2_f64 * constants::GSL_DBL_EPSILON * sum.abs();
+        }
+    }
+
+    // $x^{\nu - 1} e^{\text{shift}} = e^{(1 - \nu) \ln \frac{1}{x} + \text{shift}}$ in one exponential:
+    let exponent = (1_f64 - *nu).mul_add(log, shift);
+    let scale = Finite::try_new(libm::exp(exponent)).ok_or(Error::Overflow)?;
+    let inner = *integral.value + libm::exp(-shift) * log * *at_one.value;
+    let value = Finite::try_new(-sum - *scale * inner).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            rounding
+                + *first.value * reciprocal_factorial * E
+                + *scale * **integral.error
+                + libm::exp(exponent - shift) * log * **at_one.error
+                + constants::GSL_DBL_EPSILON * *scale * inner * (exponent.abs() + 2_f64)
+                + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// No original C code: GSL has no entire exponential integral.
+/// Uses the power series for `x` at most 1 in magnitude
+/// (including zero, where `E1` is singular), and $\gamma + \ln |x| + E_{1}(x)$ everywhere else.
+/// Past `XMAX`, $E_{1}(x)$ is far below rounding error, so it's dropped.
+/// # Errors
+/// If `x` is so negative that the result overflows (just under -710).
+#[inline]
+#[expect(clippy::absolute_paths, reason = "always a collision except full path")]
+pub(crate) fn Ein(
+    x: Finite<f64>,
+    max_precision: Precision,
+) -> Result<Approx, crate::neg::HugeArgument> {
+    let raw = *x;
+    if raw.abs() <= 1_f64 {
+        return Ok(ein_series(raw));
+    }
+
+    let log_part = consts::EULER_GAMMA + libm::log(raw.abs());
+    // `None` only past `XMAX`:
+    let e1 = if raw < 0_f64 {
+        Some(neg::E1(Negative::new(x), max_precision)?)
+    } else {
+        pos::E1(Positive::new(x), max_precision).ok()
+    };
+    let value = log_part + e1.map_or(0_f64, |approx| *approx.value);
+    Ok(Approx {
+        value: Finite::new(value),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            e1.map_or(0_f64, |approx| **approx.error)
+                + 2_f64 * constants::GSL_DBL_EPSILON * (log_part.abs() + value.abs()),
+        )),
+    })
+}
+
+/// No original C code: GSL has no regular part of Ei.
+/// For `x` at most 1 in magnitude, this is `le_pos_1` at $-x$ without its logarithm,
+/// $\text{Ei}(x) - \ln |x| - \gamma = 0.6875 - \gamma + x - \text{E12}(-x)$,
+/// except within `REGULAR_SERIES` of zero, where the Chebyshev series cancels against $0.6875 - \gamma$
+/// and the power series $\sum_{k=1}^{\infty} \frac{x^k}{k \cdot k!}$ takes over.
+/// Everywhere else, this is $-\text{Ein}(-x)$.
+/// # Errors
+/// If `x` is so large that the result overflows (just over 710).
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "to mirror the other functions")
+)]
+#[expect(clippy::absolute_paths, reason = "always a collision except full path")]
+pub(crate) fn Ei_regular(x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    /// Below this, the Chebyshev series would lose more than a couple of bits to cancellation.
+    const REGULAR_SERIES: f64 = 0.25_f64;
+
+    let raw = *x;
+    if raw.abs() < REGULAR_SERIES {
+        let mut series = ein_series(-raw);
+        series.value = Finite::new(-*series.value);
+        return Ok(series);
+    }
+
+    if raw.abs() <= 1_f64 {
+        let cheb = constants::decay::E12.sum(max_precision, |order| {
+            constants::piecewise::E12.eval_mapped(Finite::new(-raw), order)
+        });
+        let constant = 0.6875_f64 - consts::EULER_GAMMA;
+        let value = constant + raw - *cheb.value;
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                **cheb.error + constants::GSL_DBL_EPSILON * (constant + 2_f64 * value.abs()),
+            )),
+        });
+    }
+
+    let mut ein = Ein(Finite::new(-raw), max_precision)
+        .map_err(|crate::neg::HugeArgument(arg)| Error::ArgumentTooNegative(arg))?;
+    ein.value = Finite::new(-*ein.value);
+    Ok(ein)
+}
+
+/// The antiderivative of E1 vanishing at zero,
+/// $\int_{0}^{x} E_{1}(t) \text{d}t = 1 - E_{2}(x) = 1 - e^{-x} + x E_{1}(x)$,
+/// which is also $\int_{0}^{-x} \text{Ei}(t) \text{d}t$.
+///
+/// No original C code: GSL has no integrals of E1.
+/// For positive `x`, both $1 - e^{-x}$ and $x E_{1}(x)$ are positive, so their sum is accurate as long as each is;
+/// composing $1 - E_{2}(x)$ instead would cancel almost everything for small `x`.
+/// For negative `x`, write $a = -x$ and use the power series
+/// $a (\ln a - 1 + \gamma) + \sum_{k=2}^{\infty} \frac{a^k}{(k - 1) k!}$,
+/// whose terms past the first are all positive,
+/// then past `INTEGRAL_ASYMPTOTIC` the asymptotic expansion $1 + e^{a} \sum_{k=1}^{\infty} \frac{k!}{a^k}$.
+/// # Errors
+/// If `x` is so negative that the result overflows (just under -709).
+#[inline]
+pub(crate) fn E1_integral(x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    /// Past this, the asymptotic expansion's smallest term is far below rounding error.
+    const INTEGRAL_ASYMPTOTIC: f64 = 50_f64;
+
+    let raw = *x;
+    if raw == 0_f64 {
+        return Ok(Approx {
+            value: Finite::new(0_f64),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(0_f64)),
+        });
+    }
+
+    if raw > 0_f64 {
+        let exponential = libm::exp(-raw);
+        let scaled = E1_scaled(NonZero::new(x), max_precision);
+        let product = raw * exponential * *scaled.value;
+        let value = -libm::expm1(-raw) + product;
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                raw * exponential * **scaled.error
+                    + 2_f64 * constants::GSL_DBL_EPSILON * (product + value),
+            )),
+        });
+    }
+
+    let a = -raw;
+    if a <= INTEGRAL_ASYMPTOTIC {
+        let leading = a * (libm::log(a) - 1_f64 + consts::EULER_GAMMA);
+        let mut power = a;
+        let mut k = 1_f64;
+        let mut sum = 0_f64;
+        loop {
+            k += 1_f64;
+            power *= a / k;
+            let term = power / (k - 1_f64);
+            sum += term;
+            if term <= f64::EPSILON * sum {
+                break;
+            }
+        }
+        let value = leading + sum;
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            // Each term carries rounding from every factor before it,
+            // and the leading term from its logarithm:
+            error: NonNegative::new(Finite::new(
+                constants::GSL_DBL_EPSILON
+                    * 2_f64.mul_add(k * sum, 4_f64.mul_add(leading.abs(), value.abs())),
+            )),
+        });
+    }
+
+    let mut term = 1_f64;
+    let mut k = 0_f64;
+    let mut sum = 0_f64;
+    loop {
+        k += 1_f64;
+        let next = term * k / a;
+        if next <= f64::EPSILON * sum || next >= term {
+            break;
+        }
+        term = next;
+        sum += term;
+    }
+    let value = libm::exp(a).mul_add(sum, 1_f64);
+    Ok(Approx {
+        value: Finite::try_new(value).ok_or(Error::Overflow)?,
+        #[cfg(feature = "error")]
+        // Rounding in every term and in the exponential, plus truncation:
+        error: NonNegative::new(Finite::new(
+            2_f64.mul_add(k, 4_f64) * constants::GSL_DBL_EPSILON * value,
+        )),
+    })
+}
+
+/// Power series $\text{Ein}(x) = \sum_{k=1}^{\infty} \frac{(-1)^{k+1} x^k}{k \cdot k!}$,
+/// converging quickly for `x` at most 1 in magnitude.
+#[inline]
+pub(crate) fn ein_series(x: f64) -> Approx {
+    let mut power = 1_f64;
+    let mut k = 0_f64;
+    let mut sum = 0_f64;
+    #[cfg(feature = "error")]
+    let mut abs_sum = 0_f64;
+    loop {
+        k += 1_f64;
+        power *= -x / k;
+        let term = -power / k;
+        sum += term;
+        #[cfg(feature = "error")]
+        {
+            abs_sum += term.abs();
+        }
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    Approx {
+        value: Finite::new(sum),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            constants::GSL_DBL_EPSILON * (abs_sum + sum.abs()),
+        )),
+    }
+}
+
+/// No original C code: GSL has no logarithmic integral.
+/// Near $x = 1$, where $t = \ln x$ is small, uses $\gamma + \ln |t| - \text{Ein}(-t)$
+/// straight from the power series; everywhere else, $x e^{-t} \text{Ei}(t)$,
+/// which can't overflow or underflow since $e^{t} = x$ is never formed.
+/// # Errors
+/// If `x` isn't positive, or is exactly 1 (a logarithmic singularity).
+#[inline]
+#[expect(clippy::absolute_paths, reason = "always a collision except full path")]
+pub(crate) fn li(x: Finite<f64>, max_precision: Precision) -> Result<Approx, crate::li::Error> {
+    let raw = *x;
+    if raw <= 0_f64 {
+        return Err(crate::li::Error::Domain(x));
+    }
+    if raw - 1_f64 == 0_f64 {
+        return Err(crate::li::Error::Singularity);
+    }
+
+    let t = libm::log(raw);
+    if t.abs() <= 1_f64 {
+        let log_part = consts::EULER_GAMMA + libm::log(t.abs());
+        let series = ein_series(-t);
+        let value = log_part - *series.value;
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                **series.error
+                    + constants::GSL_DBL_EPSILON * (raw + log_part.abs() + 2_f64 * value.abs()),
+            )),
+        });
+    }
+
+    // `t` is nonzero by the check above.
+    let scaled = crate::Ei_scaled(NonZero::new(Finite::new(t)), max_precision);
+    let value = raw * *scaled.value;
+    Ok(Approx {
+        value: Finite::new(value),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            raw * **scaled.error
+                + constants::GSL_DBL_EPSILON * raw
+                + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// No original C code: GSL has no offset logarithmic integral.
+/// Subtracts `LI_2`, correctly rounded, so its only error is the half-ULP of that rounding.
+/// # Errors
+/// If `x` isn't positive, or is exactly 1 (a logarithmic singularity).
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "to mirror the other functions")
+)]
+#[expect(clippy::absolute_paths, reason = "always a collision except full path")]
+pub(crate) fn Li(x: Finite<f64>, max_precision: Precision) -> Result<Approx, crate::li::Error> {
+    let offset = li(x, max_precision)?;
+    let value = *offset.value - constants::LI_2;
+    Ok(Approx {
+        value: Finite::new(value),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            **offset.error
+                + 0.5_f64 * constants::GSL_DBL_EPSILON * constants::LI_2
+                + constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// No original C code: GSL has no root-finding.
+/// Newton's method on Ei, with $\text{Ei}'(x) = e^{x} / x$,
+/// falling back to bisection whenever a step would leave the bracket known to contain the root
+/// or would shrink more slowly than bisection (as it does for tiny guesses, where Ei is logarithmic).
+/// Since Ei has only the one zero, any positive `guess` converges to it;
+/// guesses past 1 are pulled back to 1 first, where Newton's method is already well-behaved.
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "to mirror the other functions")
+)]
+pub(crate) fn find_root_near(guess: Positive<Finite<f64>>, max_precision: Precision) -> Approx {
+    /// Newton's method converges quadratically, so this is only ever hit by bisection.
+    const MAX_ITERATIONS: usize = 100;
+
+    let mut lo = 0_f64;
+    let mut hi = 1_f64;
+    let mut x = (**guess).min(hi);
+    let mut previous_step = hi - lo;
+    #[cfg(feature = "error")]
+    let mut last_step = 0_f64;
+    #[cfg(feature = "error")]
+    let mut scaled_error = 0_f64;
+    for _ in 0..MAX_ITERATIONS {
+        let Some(nonzero) = NonZero::try_new(Finite::new(x)) else {
+            break;
+        };
+        let scaled = crate::Ei_scaled(nonzero, max_precision);
+        #[cfg(feature = "error")]
+        {
+            scaled_error = **scaled.error;
+        }
+        let residual = *scaled.value;
+        if residual < 0_f64 {
+            lo = x;
+        } else {
+            hi = x;
+        }
+        let mut next = x - residual * x;
+        if next <= lo || next >= hi || 2_f64 * (next - x).abs() > previous_step.abs() {
+            next = 0.5_f64 * (lo + hi);
+        }
+        let step = next - x;
+        previous_step = step;
+        #[cfg(feature = "error")]
+        {
+            last_step = step;
+        }
+        x = next;
+        if step.abs() <= f64::EPSILON * x {
+            break;
+        }
+    }
+
+    Approx {
+        value: Finite::new(x),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            last_step.abs() + x * (scaled_error + 2_f64 * constants::GSL_DBL_EPSILON),
+        )),
+    }
+}
+
+/// No original C code: GSL has no inverse exponential integral.
+/// Solves $\text{Ei}(u) = y$ for positive `u` by Newton's method,
+/// with $\text{Ei}'(u) = e^{u} / u$, falling back to bisection whenever a step
+/// would leave the bracket known to contain the root.
+/// Everything is scaled by $e^{-u}$ so that neither side overflows.
+/// # Errors
+/// If `y` is so negative that the root is too close to zero to represent (just under -707).
+#[inline]
+pub(crate) fn Ei_inv(y: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    /// Newton's method converges quadratically, so this is only ever hit by bisection.
+    const MAX_ITERATIONS: usize = 200;
+    /// Past this, $\text{Ei}(u)$ exceeds the largest finite `y`.
+    const U_MAX: f64 = 717_f64;
+
+    let raw = *y;
+
+    // Near $u = 0$, $\text{Ei}(u) = \gamma + \ln u + u + \dots$,
+    // so very negative `y` have $u \approx e^{y - \gamma}$ to within rounding:
+    let small = libm::exp(raw - consts::EULER_GAMMA);
+    if small <= f64::EPSILON {
+        // Subnormal roots have lost too many bits to be worth returning:
+        if small < f64::MIN_POSITIVE {
+            return Err(Error::ArgumentTooNegative(Negative::new(y)));
+        }
+        let value = Finite::new(small);
+        return Ok(Approx {
+            value,
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(2_f64 * constants::GSL_DBL_EPSILON * small)),
+        });
+    }
+
+    let mut lo = 0_f64;
+    let mut hi = U_MAX;
+    let mut u = if raw >= 2_f64 {
+        libm::log(raw * libm::log(raw)).min(hi)
+    } else {
+        small
+    };
+    #[cfg(feature = "error")]
+    let mut last_step = 0_f64;
+    #[cfg(feature = "error")]
+    let mut scaled_error = 0_f64;
+    for _ in 0..MAX_ITERATIONS {
+        let Some(nonzero) = NonZero::try_new(Finite::new(u)) else {
+            break;
+        };
+        let scaled = crate::Ei_scaled(nonzero, max_precision);
+        #[cfg(feature = "error")]
+        {
+            scaled_error = **scaled.error;
+        }
+        let residual = *scaled.value - raw * libm::exp(-u);
+        if residual < 0_f64 {
+            lo = u;
+        } else {
+            hi = u;
+        }
+        let mut next = u - residual * u;
+        if next <= lo || next >= hi {
+            next = 0.5_f64 * (lo + hi);
+        }
+        let step = next - u;
+        #[cfg(feature = "error")]
+        {
+            last_step = step;
+        }
+        u = next;
+        if step.abs() <= f64::EPSILON * u {
+            break;
+        }
+    }
+
+    // The solver's own tolerance, plus errors in `Ei` (and in `y` itself)
+    // mapped back through $\text{d}u / \text{d}y = u e^{-u}$:
+    Ok(Approx {
+        value: Finite::new(u),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            last_step.abs()
+                + u * (scaled_error
+                    + constants::GSL_DBL_EPSILON * raw.abs() * libm::exp(-u)
+                    + 2_f64 * constants::GSL_DBL_EPSILON),
+        )),
+    })
+}
+
+/// No original C code: GSL has no inverse exponential integral.
+/// Solves $\ln E_{1}(x) = \ln y$ by Newton's method,
+/// with $\frac{\text{d}}{\text{d}x} \ln E_{1}(x) = -\frac{1}{x e^{x} E_{1}(x)}$,
+/// falling back to bisection whenever a step would leave the bracket known to contain the root.
+/// Working with logarithms of the scaled function keeps every step representable,
+/// even where $E_{1}(x)$ itself underflows.
+/// # Errors
+/// If `y` is so large that the root is too close to zero to represent (just over 707).
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "to mirror the other functions")
+)]
+pub(crate) fn E1_inv(y: Positive<Finite<f64>>, max_precision: Precision) -> Result<Approx, Error> {
+    /// Newton's method converges quadratically, so this is only ever hit by bisection.
+    const MAX_ITERATIONS: usize = 200;
+    /// Past this, $E_{1}(x)$ is below the smallest positive `y`.
+    const X_MAX: f64 = 746_f64;
+
+    let raw = **y;
+
+    // Near $x = 0$, $E_{1}(x) = -\gamma - \ln x + x - \dots$,
+    // so large `y` have $x \approx e^{-\gamma - y}$ to within rounding,
+    // as long as $-\gamma - y$ isn't rounded first (which the exponential would magnify by $y$):
+    let small = libm::exp(-raw) * libm::exp(-consts::EULER_GAMMA);
+    if small <= f64::EPSILON {
+        // Subnormal roots have lost too many bits to be worth returning:
+        if small < f64::MIN_POSITIVE {
+            return Err(Error::ArgumentTooPositive(y));
+        }
+        return Ok(Approx {
+            value: Finite::new(small),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(2_f64 * constants::GSL_DBL_EPSILON * small)),
+        });
+    }
+
+    let ln_y = libm::log(raw);
+    let mut lo = 0_f64;
+    let mut hi = X_MAX;
+    // Far from zero, $E_{1}(x) \approx e^{-x} / x$, so $x \approx -\ln y - \ln(-\ln y)$:
+    let mut x = if raw >= 0.5_f64 {
+        small
+    } else {
+        (-ln_y - libm::log(-ln_y)).max(small)
+    };
+    #[cfg(feature = "error")]
+    let mut last_step = 0_f64;
+    #[cfg(feature = "error")]
+    let mut scaled = 1_f64;
+    #[cfg(feature = "error")]
+    let mut scaled_error = 0_f64;
+    for _ in 0..MAX_ITERATIONS {
+        let Some(nonzero) = NonZero::try_new(Finite::new(x)) else {
+            break;
+        };
+        let approx = E1_scaled(nonzero, max_precision);
+        #[cfg(feature = "error")]
+        {
+            scaled = *approx.value;
+            scaled_error = **approx.error;
+        }
+        // $\ln E_{1}(x) - \ln y$, decreasing in `x`:
+        let residual = libm::log(*approx.value) - x - ln_y;
+        if residual > 0_f64 {
+            lo = x;
+        } else {
+            hi = x;
+        }
+        let mut next = x + residual * x * *approx.value;
+        if next <= lo || next >= hi {
+            next = 0.5_f64 * (lo + hi);
+        }
+        let step = next - x;
+        #[cfg(feature = "error")]
+        {
+            last_step = step;
+        }
+        x = next;
+        if step.abs() <= f64::EPSILON * x {
+            break;
+        }
+    }
+
+    // The solver's own tolerance, plus errors in `E1` (and in `y` itself)
+    // mapped back through $\text{d}x / \text{d} \ln E_{1} = -x e^{x} E_{1}(x)$:
+    Ok(Approx {
+        value: Finite::new(x),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            last_step.abs()
+                + x * (scaled_error
+                    + constants::GSL_DBL_EPSILON * scaled
+                    + 2_f64 * constants::GSL_DBL_EPSILON),
+        )),
+    })
+}
+
+/// No original C code: GSL has no inverse logarithmic integral.
+/// Since $\text{li}(x) = \text{Ei}(\ln x)$, this is $e^{\text{Ei}^{-1}(y)}$,
+/// so $x$ itself is only formed at the very end.
+/// # Errors
+/// If the result is too large to represent.
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "to mirror the other functions")
+)]
+pub(crate) fn li_inv(y: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    // So negative that $\ln x$ is within rounding of zero (or underflows to it):
+    let small = libm::exp(*y - consts::EULER_GAMMA);
+    if small <= f64::EPSILON {
+        let value = libm::exp(small);
+        return Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(2_f64 * constants::GSL_DBL_EPSILON * value)),
+        });
+    }
+
+    let u = Ei_inv(y, max_precision)?;
+    let value = Finite::try_new(libm::exp(*u.value)).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            *value * (**u.error + 2_f64 * constants::GSL_DBL_EPSILON),
+        )),
+    })
+}
+
+/// # Original C code
+/// ```c
+/// int gsl_sf_Si_e(const double x, gsl_sf_result * result)
+/// {
+///   double ax = fabs(x);
+///
+///   if(ax < GSL_SQRT_DBL_EPSILON) {
+///     result->val = x;
+///     result->err = 0.0;
+///     return GSL_SUCCESS;
+///   }
+///   else if(ax <= 4.0) {
+///     gsl_sf_result result_c;
+///     cheb_eval_e(&si_cs, (x*x-8.0)*0.125, &result_c);
+///     result->val  =  x * (0.75 + result_c.val);
+///     result->err  = ax * result_c.err;
+///     result->err += 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else {
+///     gsl_sf_result f;
+///     gsl_sf_result g;
+///     fg_asymp(ax, &f, &g);
+///     result->val  = 0.5 * M_PI - f.val*cos(ax) - g.val*sin(ax);
+///     result->err  = f.err + g.err;
+///     result->err += 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     if(x < 0.0) result->val = -result->val;
+///     return GSL_SUCCESS;
+///   }
+/// }
+/// ```
+#[inline]
+pub(crate) fn Si(x: Finite<f64>, max_precision: Precision) -> Approx {
+    /// Below this, $\text{Si}(x) = x$ to machine precision.
+    const SQRT_DBL_EPSILON: f64 = 1.490_116_119_384_765_6e-8_f64;
+
+    let raw = *x;
+    let abs = raw.abs();
+
+    if abs < SQRT_DBL_EPSILON {
+        return Approx {
+            value: x,
+            #[cfg(feature = "error")]
+            error: NonNegative::ZERO,
+        };
+    }
+
+    if abs <= 4_f64 {
+        let cheb = constants::decay::SI.sum(max_precision, |order| {
+            chebyshev::eval(
+                Finite::all(&constants::SI),
+                Finite::new((raw * raw - 8_f64) * 0.125_f64),
+                LessThan::new(order),
+            )
+        });
+        let value = raw * (0.75_f64 + *cheb.value);
+        return Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                abs * **cheb.error + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+            )),
+        };
+    }
+
+    let (f, g) = sici::fg_asymp(Positive::new(Finite::new(abs)), max_precision);
+    let magnitude = FRAC_PI_2 - *f.value * libm::cos(abs) - *g.value * libm::sin(abs);
+    Approx {
+        value: Finite::new(if raw < 0_f64 { -magnitude } else { magnitude }),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            **f.error + **g.error + 2_f64 * constants::GSL_DBL_EPSILON * magnitude.abs(),
+        )),
+    }
+}
+
+/// Since `libm`'s sine and cosine are faithfully rounded,
+/// their own errors are folded in as one epsilon each.
+/// # Original C code
+/// ```c
+/// int gsl_sf_Ci_e(const double x, gsl_sf_result * result)
+/// {
+///   if(x <= 0.0) {
+///     DOMAIN_ERROR(result);
+///   }
+///   else if(x <= 4.0) {
+///     const double lx = log(x);
+///     const double y  = (x*x-8.0)*0.125;
+///     gsl_sf_result result_c;
+///     cheb_eval_e(&ci_cs, y, &result_c);
+///     result->val  = lx - 0.5 + result_c.val;
+///     result->err  = 2.0 * GSL_DBL_EPSILON * (fabs(lx) + 0.5) + result_c.err;
+///     result->err += 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else {
+///     gsl_sf_result sin_result;
+///     gsl_sf_result cos_result;
+///     int stat_sin = gsl_sf_sin_e(x, &sin_result);
+///     int stat_cos = gsl_sf_cos_e(x, &cos_result);
+///     gsl_sf_result f;
+///     gsl_sf_result g;
+///     fg_asymp(x, &f, &g);
+///     result->val  = f.val*sin_result.val - g.val*cos_result.val;
+///     result->err  = fabs(f.err*sin_result.val);
+///     result->err += fabs(g.err*cos_result.val);
+///     result->err += fabs(f.val*sin_result.err);
+///     result->err += fabs(g.val*cos_result.err);
+///     result->err += 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_ERROR_SELECT_2(stat_sin, stat_cos);
+///   }
+/// }
+/// ```
+#[inline]
+pub(crate) fn Ci(x: Positive<Finite<f64>>, max_precision: Precision) -> Approx {
+    let raw = **x;
+
+    if raw <= 4_f64 {
+        let ln = libm::log(raw);
+        let cheb = constants::decay::CI.sum(max_precision, |order| {
+            chebyshev::eval(
+                Finite::all(&constants::CI),
+                Finite::new((raw * raw - 8_f64) * 0.125_f64),
+                LessThan::new(order),
+            )
+        });
+        let value = ln - 0.5_f64 + *cheb.value;
+        return Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                2_f64 * constants::GSL_DBL_EPSILON * (ln.abs() + 0.5_f64 + value.abs())
+                    + **cheb.error,
+            )),
+        };
+    }
+
+    let (f, g) = sici::fg_asymp(x, max_precision);
+    let sin = libm::sin(raw);
+    let cos = libm::cos(raw);
+    let value = *f.value * sin - *g.value * cos;
+    Approx {
+        value: Finite::new(value),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            (**f.error * sin).abs()
+                + (**g.error * cos).abs()
+                + constants::GSL_DBL_EPSILON * (f.value.abs() + g.value.abs())
+                + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    }
+}
+
+/// No original C code: GSL has no entire cosine integral.
+/// Uses the power series $\sum_{k=1}^{\infty} \frac{(-1)^{k+1} x^{2k}}{2k \cdot (2k)!}$
+/// for `x` at most 4 in magnitude, where subtracting from `Ci` would cancel catastrophically,
+/// and $\gamma + \ln |x| - \text{Ci}(|x|)$ everywhere else.
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "to mirror the other functions")
+)]
+pub(crate) fn Cin(x: Finite<f64>, max_precision: Precision) -> Approx {
+    let abs = x.abs();
+
+    if abs <= 4_f64 {
+        let square = abs * abs;
+        let mut power = -1_f64;
+        let mut k = 0_f64;
+        let mut sum = 0_f64;
+        #[cfg(feature = "error")]
+        let mut abs_sum = 0_f64;
+        loop {
+            k += 2_f64;
+            power *= -square / ((k - 1_f64) * k);
+            let term = power / k;
+            sum += term;
+            #[cfg(feature = "error")]
+            {
+                abs_sum += term.abs();
+            }
+            if term.abs() <= f64::EPSILON * sum.abs() {
+                break;
+            }
+        }
+        return Approx {
+            value: Finite::new(sum),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                constants::GSL_DBL_EPSILON * (abs_sum + sum.abs()),
+            )),
+        };
+    }
+
+    let log_part = consts::EULER_GAMMA + libm::log(abs);
+    let ci = Ci(Positive::new(Finite::new(abs)), max_precision);
+    let value = log_part - *ci.value;
+    Approx {
+        value: Finite::new(value),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            **ci.error + 2_f64 * constants::GSL_DBL_EPSILON * (log_part.abs() + value.abs()),
+        )),
+    }
+}
+
+/// No original C code: GSL has no complex exponential integrals.
+/// For `y` at most 4 in magnitude, this is just `Ci` and `Si`.
+/// Everywhere else, both parts come from one evaluation of the auxiliary functions $f$ and $g$
+/// (and one sine and cosine), which also gives $\text{Si}(|y|) - \frac{\pi}{2} = -f \cos |y| - g \sin |y|$
+/// directly instead of by cancellation against $\frac{\pi}{2}$.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror the other functions")]
+pub(crate) fn E1_imag(y: NonZero<Finite<f64>>, max_precision: Precision) -> (Approx, Approx) {
+    let raw = **y;
+    let abs = raw.abs();
+    let direction = if raw < 0_f64 { -1_f64 } else { 1_f64 };
+
+    if abs <= 4_f64 {
+        let ci = Ci(Positive::new(Finite::new(abs)), max_precision);
+        let si = Si(*y, max_precision);
+        let imaginary = *si.value - direction * FRAC_PI_2;
+        return (
+            Approx {
+                value: Finite::new(-*ci.value),
+                #[cfg(feature = "error")]
+                error: ci.error,
+            },
+            Approx {
+                value: Finite::new(imaginary),
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    **si.error + constants::GSL_DBL_EPSILON * imaginary.abs(),
+                )),
+            },
+        );
+    }
+
+    let (f, g) = sici::fg_asymp(Positive::new(Finite::new(abs)), max_precision);
+    let sin = libm::sin(abs);
+    let cos = libm::cos(abs);
+    let ci = *f.value * sin - *g.value * cos;
+    let imaginary = -direction * (*f.value * cos + *g.value * sin);
+    #[cfg(feature = "error")]
+    let shared =
+        **f.error + **g.error + constants::GSL_DBL_EPSILON * (f.value.abs() + g.value.abs());
+    (
+        Approx {
+            value: Finite::new(-ci),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                shared + 2_f64 * constants::GSL_DBL_EPSILON * ci.abs(),
+            )),
+        },
+        Approx {
+            value: Finite::new(imaginary),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                shared + 2_f64 * constants::GSL_DBL_EPSILON * imaginary.abs(),
+            )),
+        },
+    )
+}
+
+/// # Original C code
+/// ```c
+/// int gsl_sf_expint_3_e(const double x, gsl_sf_result *result)
+/// {
+///   const double val_infinity = 0.892979511569249211;
+///
+///   /* CHECK_POINTER(result) */
+///
+///   if(x < 0.0) {
+///     DOMAIN_ERROR(result);
+///   }
+///   else if(x < 1.6*GSL_ROOT3_DBL_EPSILON) {
+///     result->val = x;
+///     result->err = 0.0;
+///     return GSL_SUCCESS;
+///   }
+///   else if(x <= 2.0) {
+///     const double t = x*x*x/4.0 - 1.0;
+///     gsl_sf_result result_c;
+///     cheb_eval_e(&expint3_cs, t, &result_c);
+///     result->val = x * result_c.val;
+///     result->err = x * result_c.err;
+///     return GSL_SUCCESS;
+///   }
+///   else if(x < pow(-GSL_LOG_DBL_EPSILON, 1.0/3.0)) {
+///     const double t = 16.0/(x*x*x) - 1.0;
+///     const double s = exp(-x*x*x)/(3.0*x*x);
+///     gsl_sf_result result_c;
+///     cheb_eval_e(&expint3a_cs, t, &result_c);
+///     result->val = val_infinity - result_c.val * s;
+///     result->err = val_infinity * GSL_DBL_EPSILON + s * result_c.err;
+///     return GSL_SUCCESS;
+///   }
+///   else {
+///     result->val = val_infinity;
+///     result->err = val_infinity * GSL_DBL_EPSILON;
+///     return GSL_SUCCESS;
+///   }
+/// }
+/// ```
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "to mirror the other functions")
+)]
+pub(crate) fn expint_3(x: NonNegative<Finite<f64>>, max_precision: Precision) -> Approx {
+    /// Below this, the integrand is 1 to machine precision.
+    const SMALL: f64 = 9.688_727_123_829_349e-6_f64;
+    /// Above this, the integral has saturated to machine precision.
+    const SATURATION: f64 = 3.303_261_342_348_473_f64;
+    /// The integral over the whole positive axis, $\Gamma(4/3)$.
+    const VAL_INFINITY: f64 = 0.892_979_511_569_249_2_f64;
+
+    let raw = **x;
+
+    if raw < SMALL {
+        return Approx {
+            value: *x,
+            #[cfg(feature = "error")]
+            error: NonNegative::ZERO,
+        };
+    }
+
+    let cube = raw * raw * raw;
+
+    if raw <= 2_f64 {
+        let cheb = constants::decay::EXPINT3.sum(max_precision, |order| {
+            chebyshev::eval(
+                Finite::all(&constants::EXPINT3),
+                Finite::new(cube / 4_f64 - 1_f64),
+                LessThan::new(order),
+            )
+        });
+        return Approx {
+            value: Finite::new(raw * *cheb.value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(raw * **cheb.error)),
+        };
+    }
+
+    if raw < SATURATION {
+        let s = libm::exp(-cube) / (3_f64 * raw * raw);
+        let cheb = constants::decay::EXPINT3A.sum(max_precision, |order| {
+            chebyshev::eval(
+                Finite::all(&constants::EXPINT3A),
+                Finite::new(16_f64 / cube - 1_f64),
+                LessThan::new(order),
+            )
+        });
+        return Approx {
+            value: Finite::new(VAL_INFINITY - *cheb.value * s),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                VAL_INFINITY * constants::GSL_DBL_EPSILON + s * **cheb.error,
+            )),
+        };
+    }
+
+    Approx {
+        value: Finite::new(VAL_INFINITY),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(VAL_INFINITY * constants::GSL_DBL_EPSILON)),
+    }
+}
+
+/// Where GSL subtracts 1 from `exp(x)` by hand, uses `expm1` instead,
+/// and replaces GSL's fixed Taylor polynomial near zero with the full series,
+/// which stays accurate out to `x` of magnitude 1.
+/// # Original C code
+/// ```c
+/// int gsl_sf_exprel_e(const double x, gsl_sf_result * result)
+/// {
+///   const double cut = 0.002;
+///
+///   if(x < GSL_LOG_DBL_MIN) {
+///     result->val = -1.0/x;
+///     result->err = GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else if(x < -cut) {
+///     result->val = (exp(x) - 1.0)/x;
+///     result->err = 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else if(x < cut) {
+///     result->val = (1.0 + 0.5*x*(1.0 + x/3.0*(1.0 + 0.25*x*(1.0 + 0.2*x))));
+///     result->err = 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else if(x < GSL_LOG_DBL_MAX) {
+///     result->val = (exp(x) - 1.0)/x;
+///     result->err = 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else {
+///     OVERFLOW_ERROR(result);
+///   }
+/// }
+/// ```
+/// # Errors
+/// If `x` is so large that the result overflows (just under 710).
+#[inline]
+pub(crate) fn exprel(x: Finite<f64>) -> Result<Approx, Error> {
+    let raw = *x;
+    if raw.abs() <= 1_f64 {
+        return Ok(exprel::series(1_f64, raw));
+    }
+    let raw_value = if raw < constants::LOG_DBL_MIN {
+        -raw.recip()
+    } else if raw < constants::LOG_DBL_MAX {
+        libm::expm1(raw) / raw
+    } else {
+        return Err(Error::Overflow);
+    };
+    let value = Finite::try_new(raw_value).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// Like `exprel`, uses `expm1` and the full series near zero.
+/// # Original C code
+/// ```c
+/// int gsl_sf_exprel_2_e(double x, gsl_sf_result * result)
+/// {
+///   const double cut = 0.002;
+///
+///   if(x < GSL_LOG_DBL_MIN) {
+///     result->val = -2.0/x*(1.0 + 1.0/x);
+///     result->err = 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else if(x < -cut) {
+///     result->val = 2.0*(exp(x) - 1.0 - x)/(x*x);
+///     result->err = 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else if(x < cut) {
+///     result->val = (1.0 + 1.0/3.0*x*(1.0 + 0.25*x*(1.0 + 0.2*x*(1.0 + 1.0/6.0*x))));
+///     result->err = GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else if(x < GSL_LOG_DBL_MAX) {
+///     result->val = 2.0*(exp(x) - 1.0 - x)/(x*x);
+///     result->err = 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///     return GSL_SUCCESS;
+///   }
+///   else {
+///     OVERFLOW_ERROR(result);
+///   }
+/// }
+/// ```
+/// # Errors
+/// If `x` is so large that the result overflows (just under 710).
+#[inline]
+pub(crate) fn exprel_2(x: Finite<f64>) -> Result<Approx, Error> {
+    let raw = *x;
+    if raw.abs() <= 1_f64 {
+        return Ok(exprel::series(2_f64, raw));
+    }
+    let raw_value = if raw < constants::LOG_DBL_MIN {
+        -2_f64 / raw * (1_f64 + raw.recip())
+    } else if raw < constants::LOG_DBL_MAX {
+        2_f64 * (libm::expm1(raw) - raw) / (raw * raw)
+    } else {
+        return Err(Error::Overflow);
+    };
+    let value = Finite::try_new(raw_value).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// Uses the full series near zero in place of GSL's two-term polynomial.
+/// # Original C code
+/// ```c
+/// int gsl_sf_exprel_n_e(const int N, const double x, gsl_sf_result * result)
+/// {
+///   if(N < 0) {
+///     DOMAIN_ERROR(result);
+///   }
+///   else if(x == 0.0) {
+///     result->val = 1.0;
+///     result->err = 0.0;
+///     return GSL_SUCCESS;
+///   }
+///   else if(fabs(x) < GSL_ROOT3_DBL_EPSILON * N) {
+///     result->val = 1.0 + x/(N+1) * (1.0 + x/(N+2));
+///     result->err = 2.0 * GSL_DBL_EPSILON;
+///     return GSL_SUCCESS;
+///   }
+///   else if(N == 0) {
+///     return gsl_sf_exp_e(x, result);
+///   }
+///   else if(N == 1) {
+///     return gsl_sf_exprel_e(x, result);
+///   }
+///   else if(N == 2) {
+///     return gsl_sf_exprel_2_e(x, result);
+///   }
+///   else {
+///     if(x > N && (-x + N*(1.0 + log(x/N)) < GSL_LOG_DBL_EPSILON)) {
+///       /* x is much larger than n.
+///        * Ignore polynomial part, so
+///        * exprel_N(x) ~= e^x N!/x^N
+///        */
+///       gsl_sf_result lnf_N;
+///       double lg_N;
+///       gsl_sf_lnfact_e((unsigned int) N, &lnf_N);
+///       lg_N  = lnf_N.val - N*log(x);
+///       return gsl_sf_exp_err_e(x + lg_N, 2.0*GSL_DBL_EPSILON*(fabs(x) + fabs(lg_N)), result);
+///     }
+///     else if(x > N) {
+///       /* Write the identity
+///        *   exprel_n(x) = e^x n! / x^n (1 - Gamma[n,x]/Gamma[n])
+///        * then use the asymptotic expansion
+///        * Gamma[n,x] ~ x^(n-1) e^(-x) (1 + (n-1)/x + (n-1)(n-2)/x^2 + ...)
+///        */
+///       double ln_x = log(x);
+///       gsl_sf_result lnf_N;
+///       double lg_N;
+///       double lnpre_val;
+///       double lnpre_err;
+///       gsl_sf_lnfact_e((unsigned int) N, &lnf_N);    /* log(N!)       */
+///       lg_N  = lnf_N.val - log(N);                   /* log(Gamma(N)) */
+///       lnpre_val  = x + lnf_N.val - N*ln_x;
+///       lnpre_err  = GSL_DBL_EPSILON * (fabs(x) + fabs(lnf_N.val) + fabs(N*ln_x));
+///       if(lnpre_val < GSL_LOG_DBL_MAX - 5.0) {
+///         int stat_eG;
+///         gsl_sf_result bigG_ratio;
+///         gsl_sf_result pre;
+///         int stat_ex = gsl_sf_exp_err_e(lnpre_val, lnpre_err, &pre);
+///         double ln_bigG_ratio_pre = -x + (N-1)*ln_x - lg_N;
+///         double bigGsum = 1.0;
+///         double term = 1.0;
+///         int k;
+///         for(k=1; k<N; k++) {
+///           term *= (N-k)/x;
+///           bigGsum += term;
+///         }
+///         stat_eG = gsl_sf_exp_mult_e(ln_bigG_ratio_pre, bigGsum, &bigG_ratio);
+///         if(stat_eG == GSL_SUCCESS) {
+///           result->val  = pre.val * (1.0 - bigG_ratio.val);
+///           result->err  = pre.val * (2.0*GSL_DBL_EPSILON + bigG_ratio.err);
+///           result->err += pre.err * fabs(1.0 - bigG_ratio.val);
+///           result->err += 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///           return stat_ex;
+///         }
+///         else {
+///           result->val = 0.0;
+///           result->err = 0.0;
+///           return stat_eG;
+///         }
+///       }
+///       else {
+///         OVERFLOW_ERROR(result);
+///       }
+///     }
+///     else if(x > -10.0*N) {
+///       return exprel_n_CF(N, x, result);
+///     }
+///     else {
+///       /* x -> -Inf asymptotic:
+///        * exprel_n(x) ~ e^x n!/x^n - n/x (1 + (n-1)/x + (n-1)(n-2)/x + ...)
+///        *             ~ - n/x (1 + (n-1)/x + (n-1)(n-2)/x + ...)
+///        */
+///       double sum  = 1.0;
+///       double term = 1.0;
+///       int k;
+///       for(k=1; k<N; k++) {
+///         term *= (N-k)/x;
+///         sum  += term;
+///       }
+///       result->val = -N/x * sum;
+///       result->err = 2.0 * GSL_DBL_EPSILON * fabs(result->val);
+///       return GSL_SUCCESS;
+///     }
+///   }
+/// }
+/// ```
+/// # Errors
+/// If the result is too large to represent.
+#[inline]
+pub(crate) fn exprel_n(n: usize, x: Finite<f64>) -> Result<Approx, Error> {
+    /// `GSL_LOG_DBL_EPSILON`: below this, $e^{x}$ is negligible next to 1.
+    const LOG_DBL_EPSILON: f64 = -36.043_653_389_117_15_f64;
+
+    let raw = *x;
+    match n {
+        0 => {
+            if raw >= constants::LOG_DBL_MAX {
+                return Err(Error::Overflow);
+            }
+            let value = Finite::new(libm::exp(raw));
+            return Ok(Approx {
+                value,
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+                )),
+            });
+        }
+        1 => return exprel(x),
+        2 => return exprel_2(x),
+        _ => {}
+    }
+
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "orders past 2^53 are indistinguishable in the result anyway"
+    )]
+    let order = n as f64;
+
+    if raw.abs() <= 1_f64 {
+        return Ok(exprel::series(order, raw));
+    }
+
+    if raw > order {
+        let ln_x = libm::log(raw);
+        // $\ln n!$:
+        let ln_factorial = libm::lgamma(order + 1_f64);
+        if order.mul_add(1_f64 + libm::log(raw / order), -raw) < LOG_DBL_EPSILON {
+            // So much larger than `n` that the polynomial part is negligible:
+            // $\text{exprel}_{n}(x) \approx e^{x} n! / x^{n}$
+            let log_value = order.mul_add(-ln_x, raw + ln_factorial);
+            let value = Finite::try_new(libm::exp(log_value)).ok_or(Error::Overflow)?;
+            return Ok(Approx {
+                value,
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(
+                    2_f64
+                        * constants::GSL_DBL_EPSILON
+                        * (raw.abs() + (log_value - raw).abs() + 1_f64)
+                        * value.abs(),
+                )),
+            });
+        }
+
+        // $\text{exprel}_{n}(x) = e^{x} n! / x^{n} (1 - \Gamma(n, x) / \Gamma(n))$,
+        // with the asymptotic expansion
+        // $\Gamma(n, x) \approx x^{n - 1} e^{-x} (1 + (n - 1) / x + (n - 1)(n - 2) / x^2 + \cdots)$
+        let ln_prefactor = order.mul_add(-ln_x, raw + ln_factorial);
+        if ln_prefactor >= constants::LOG_DBL_MAX - 5_f64 {
+            return Err(Error::Overflow);
+        }
+        let prefactor = libm::exp(ln_prefactor);
+        // $\ln \Gamma(n)$:
+        let ln_gamma = ln_factorial - libm::log(order);
+        let mut sum = 1_f64;
+        let mut term = 1_f64;
+        let mut k = 1_f64;
+        // The terms only shrink (`x` exceeds `n`), so stop once they're negligible:
+        while k < order && term.abs() > f64::EPSILON * sum.abs() {
+            term *= (order - k) / raw;
+            sum += term;
+            k += 1_f64;
+        }
+        let ratio = sum * libm::exp((order - 1_f64).mul_add(ln_x, -raw) - ln_gamma);
+        let value = prefactor * (1_f64 - ratio);
+        return Ok(Approx {
+            value: Finite::try_new(value).ok_or(Error::Overflow)?,
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                prefactor * (2_f64 * constants::GSL_DBL_EPSILON + ratio * order * f64::EPSILON)
+                    + constants::GSL_DBL_EPSILON
+                        * (raw.abs() + ln_factorial.abs() + (order * ln_x).abs())
+                        * prefactor
+                        * (1_f64 - ratio).abs()
+                    + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+            )),
+        });
+    }
+
+    if raw > -10_f64 * order {
+        return Ok(exprel::continued_fraction(order, raw));
+    }
+
+    // Very negative, so $e^{x}$ is negligible:
+    // $\text{exprel}_{n}(x) \approx -\frac{n}{x} (1 + (n - 1) / x + (n - 1)(n - 2) / x^2 + \cdots)$
+    let mut sum = 1_f64;
+    let mut term = 1_f64;
+    let mut k = 1_f64;
+    // Each term is under a tenth of the last, so stop once they're negligible:
+    while k < order && term.abs() > f64::EPSILON * sum.abs() {
+        term *= (order - k) / raw;
+        sum += term;
+        k += 1_f64;
+    }
+    let value = -order / raw * sum;
+    Ok(Approx {
+        value: Finite::new(value),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
@@ -0,0 +1,64 @@
+//! E1 and Ei on arguments checked once up front, e.g. for hot loops over inputs validated in bulk.
+//!
+//! An `InDomain` is finite, nonzero, and strictly between `NXMAX` and `XMAX` (just over $\pm 701$),
+//! which is exactly where `E1` and `Ei` both succeed,
+//! so `E1` and `Ei` here return a plain `Approx`:
+//! no range check on every call, and no error branch to predict.
+//! Values are the same to the bit as `crate::E1` and `crate::Ei` give.
+//!
+//! No original C code: GSL checks the range on every call.
+
+use {
+    crate::{Approx, Error, Precision, constants, implementation},
+    sigma_types::{Finite, Negative, NonZero},
+};
+
+/// A finite, nonzero `f64` strictly between `NXMAX` and `XMAX`, where `E1` and `Ei` both succeed.
+///
+/// The range is symmetric, so negating one gives another.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct InDomain(NonZero<Finite<f64>>);
+
+impl InDomain {
+    /// The argument itself.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> NonZero<Finite<f64>> {
+        self.0
+    }
+
+    /// `x`, if both `E1` and `Ei` succeed there.
+    /// # Errors
+    /// Exactly when `E1` would fail at `x`, with the same error.
+    #[inline]
+    pub fn new(x: NonZero<Finite<f64>>) -> Result<Self, Error> {
+        if **x <= constants::NXMAX {
+            return Err(Error::ArgumentTooNegative(Negative::new(*x)));
+        }
+        if **x >= constants::XMAX {
+            return Err(Error::Underflow);
+        }
+        Ok(Self(x))
+    }
+}
+
+/// $E_{1}(x)$, as `crate::E1` gives it, with nothing left to go wrong.
+#[inline]
+#[must_use]
+pub fn E1(x: InDomain, max_precision: Precision) -> Approx {
+    implementation::E1_in_domain(*x.0, max_precision)
+}
+
+/// $\text{Ei}(x) = -E_{1}(-x)$, as `crate::Ei` gives it, with nothing left to go wrong.
+#[inline]
+#[must_use]
+pub fn Ei(x: InDomain, max_precision: Precision) -> Approx {
+    #![expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+
+    let mut approx = implementation::E1_in_domain(-*x.0, max_precision);
+    approx.value = -approx.value;
+    approx
+}
@@ -0,0 +1,181 @@
+//! The upper incomplete gamma function,
+//! $\Gamma(a, x) = \int_{x}^{\infty} t^{a - 1} e^{-t} \text{d}t$,
+//! for real `a` (including zero and negative values) and `x > 0`.
+//!
+//! Behind `Enu`, but useful on its own (e.g. for the gamma and chi-squared distributions),
+//! so it's exposed here as-is. Like GSL's `gamma_inc`, it switches between
+//! power series for small `x` and a continued fraction for large `x`,
+//! and its error estimates cover both truncation of the series and rounding along the way.
+
+#![expect(
+    clippy::single_call_fn,
+    reason = "each series is kept separate for readability"
+)]
+
+use {
+    crate::{Approx, Error, Precision, implementation},
+    sigma_types::{Finite, Positive},
+};
+
+#[cfg(feature = "error")]
+use {crate::constants, sigma_types::NonNegative};
+
+/// Give up on either series after this many terms, as `Error::Overflow`:
+/// it takes that many only once a term has overflowed, so that the stopping test can never pass.
+const MAX_TERMS: f64 = 10_000_f64;
+
+/// Series $\Gamma(a, x) = \Gamma(a) - x^a \sum_{k=0}^{\infty} \frac{(-x)^k}{k! (a + k)}$,
+/// valid unless `a` is a nonpositive integer and converging quickly for `x` at most 1.
+/// Once the terms alternate, the truncated tail is bounded by the last term.
+#[inline]
+fn alternating_series(a: f64, x: f64) -> Result<Approx, Error> {
+    let mut power = 1_f64;
+    let mut k = 0_f64;
+    let mut sum = a.recip();
+    #[cfg(feature = "error")]
+    let mut abs_sum = sum.abs();
+    #[cfg(feature = "error")]
+    let mut last: f64;
+    loop {
+        k += 1_f64;
+        power *= -x / k;
+        let term = power / (a + k);
+        sum += term;
+        #[cfg(feature = "error")]
+        {
+            abs_sum += term.abs();
+            last = term.abs();
+        }
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+        if k >= MAX_TERMS {
+            return Err(Error::Overflow);
+        }
+    }
+
+    let gamma = libm::tgamma(a);
+    let x_to_the_a = libm::pow(x, a);
+    let value = Finite::try_new(gamma - x_to_the_a * sum).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            x_to_the_a * last
+                + constants::GSL_DBL_EPSILON
+                    * (2_f64 * gamma.abs() + 2_f64 * x_to_the_a * abs_sum + 2_f64 * value.abs()),
+        )),
+    })
+}
+
+/// Modified Lentz evaluation of Legendre's continued fraction
+/// $\Gamma(a, x) = e^{-x} x^a \left( \frac{1}{x + 1 - a -} \frac{1 (1 - a)}{x + 3 - a -} \frac{2 (2 - a)}{x + 5 - a -} \cdots \right)$,
+/// which is $x^a E_{1 - a}(x)$ and converges quickly whenever `x` isn't small.
+#[inline]
+fn continued_fraction(a: f64, x: Positive<Finite<f64>>) -> Result<Approx, Error> {
+    let scaled = implementation::en::continued_fraction(1_f64 - a, x, false)?.approx;
+    let x_to_the_a = libm::pow(**x, a);
+    let value = Finite::try_new(x_to_the_a * *scaled.value).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            x_to_the_a * **scaled.error + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// Series $\gamma(a, x) = e^{-x} x^a \sum_{n=0}^{\infty} \frac{x^n}{a (a + 1) \cdots (a + n)}$
+/// for the lower incomplete gamma function, subtracted from $\Gamma(a)$.
+/// Only for `a > 0`, and converging quickly for `x < a + 1`.
+/// The terms shrink at least geometrically by then, so the truncated tail
+/// is bounded by a geometric series starting from the last term.
+#[inline]
+fn lower_series(a: f64, x: f64) -> Result<Approx, Error> {
+    let mut denominator = a;
+    let mut term = a.recip();
+    let mut sum = term;
+    let mut n = 0_f64;
+    loop {
+        n += 1_f64;
+        denominator += 1_f64;
+        term *= x / denominator;
+        sum += term;
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+        if n >= MAX_TERMS {
+            return Err(Error::Overflow);
+        }
+    }
+
+    let gamma = libm::tgamma(a);
+    let prefactor = libm::exp(a.mul_add(libm::log(x), -x));
+    let lower = sum * prefactor;
+    let value = Finite::try_new(gamma - lower).ok_or(Error::Overflow)?;
+    #[cfg(feature = "error")]
+    let ratio = x / (denominator + 1_f64);
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            prefactor * term * ratio / (1_f64 - ratio)
+                + constants::GSL_DBL_EPSILON
+                    * (2_f64 * gamma.abs() + 2_f64 * lower + 2_f64 * value.abs()),
+        )),
+    })
+}
+
+/// The upper incomplete gamma function $\Gamma(a, x)$ for `x > 0`.
+///
+/// For `x` above both 1 and `a + 1`, uses Legendre's continued fraction;
+/// below that, a power series (through the lower incomplete gamma function when `a > 0`),
+/// except at nonpositive integer `a`, where $\Gamma(a)$ has poles and `En` takes over.
+/// # Errors
+/// If the result (or a term of either series on the way) is too large to represent,
+/// or (for nonpositive integer `a`) if `x` is too large for `En`.
+#[inline]
+pub fn upper(
+    a: Finite<f64>,
+    x: Positive<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    let raw_a = *a;
+    let raw_x = **x;
+
+    if raw_x > 1_f64 && raw_x >= raw_a + 1_f64 {
+        return continued_fraction(raw_a, x);
+    }
+
+    if raw_a > 0_f64 {
+        return if raw_x > 1_f64 {
+            lower_series(raw_a, raw_x)
+        } else {
+            alternating_series(raw_a, raw_x)
+        };
+    }
+
+    if libm::floor(raw_a) < raw_a {
+        return alternating_series(raw_a, raw_x);
+    }
+
+    // Nonpositive integer `a = -m`, where `Gamma(a)` has poles:
+    // $\Gamma(-m, x) = x^{-m} E_{m + 1}(x)$
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "nonnegative integer by the checks above, and saturating if huge"
+    )]
+    let order = (1_f64 - raw_a) as usize;
+    let en = implementation::En(order, *x, false, max_precision)?;
+    let x_to_the_a = libm::pow(raw_x, raw_a);
+    let value = Finite::try_new(x_to_the_a * *en.value).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            x_to_the_a * **en.error + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
@@ -0,0 +1,183 @@
+//! Ei interpolated from a precomputed table, for millions of approximate evaluations at a time
+//! (e.g. once per cell per timestep) where about ten significant digits are plenty.
+//!
+//! Each interval is a cubic (Hermite) matching Ei and its exact derivative $\frac{e^{x}}{x}$ at both ends,
+//! so the error falls with the fourth power of the spacing $h$, as $\frac{h^{4}}{384} |\text{Ei}^{(4)}|$ at worst.
+//! That worst case is at the middle of an interval,
+//! so building a table also evaluates Ei at every midpoint and keeps the largest discrepancy as its error bound.
+//! A query then costs a division and a handful of multiplications, with no exponentials or logarithms.
+//!
+//! No original C code: GSL evaluates Ei from scratch every time.
+
+use {
+    crate::{Approx, Ei_derivative, Error, Precision},
+    sigma_types::{Finite, NonZero},
+};
+
+#[cfg(feature = "error")]
+use {crate::constants, sigma_types::NonNegative};
+
+/// Ei at `N` evenly spaced points, ready to interpolate between.
+///
+/// Holds two `[f64; N]`s, so large tables belong on the heap (e.g. in a `Box`), not the stack.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableEvaluator<const N: usize> {
+    /// `N - 1`, the number of intervals.
+    intervals: f64,
+    /// Largest interpolation error found at any midpoint,
+    /// relative to the larger magnitude at either end of its interval.
+    relative_error: f64,
+    /// The derivative of Ei at each point.
+    slopes: [f64; N],
+    /// First point.
+    start: f64,
+    /// Last point.
+    stop: f64,
+    /// Ei at each point.
+    values: [f64; N],
+}
+
+impl<const N: usize> TableEvaluator<N> {
+    /// An interpolated value of Ei anywhere from the first point to the last,
+    /// with an error estimate of `relative_error` times the larger magnitude at either end of its interval.
+    /// # Errors
+    /// `Error::Domain` if `x` is outside the table.
+    #[inline]
+    pub fn Ei(&self, x: Finite<f64>) -> Result<Approx, Error> {
+        let raw = *x;
+        if !(self.start..=self.stop).contains(&raw) {
+            return Err(Error::Domain(x));
+        }
+        let position = (raw - self.start) / (self.stop - self.start) * self.intervals;
+        let left = libm::floor(position).min(self.intervals - 1_f64);
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "a whole number from 0 to `N - 2`"
+        )]
+        let k = left as usize;
+        let (Some(&[p0, p1]), Some(&[m0, m1])) = (
+            self.values.get(k..k.wrapping_add(2)),
+            self.slopes.get(k..k.wrapping_add(2)),
+        ) else {
+            return Err(Error::Domain(x));
+        };
+        let value = hermite(
+            (node(self.start, self.stop, self.intervals, left), p0, m0),
+            (
+                node(self.start, self.stop, self.intervals, left + 1_f64),
+                p1,
+                m1,
+            ),
+            raw,
+        );
+        Ok(Approx {
+            value: Finite::new(value),
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                self.relative_error * p0.abs().max(p1.abs())
+                    + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+            )),
+        })
+    }
+
+    /// Tabulate Ei at `N` evenly spaced points from `start` to `stop` inclusive,
+    /// then check the interpolation at the middle of every interval:
+    /// $2N - 1$ evaluations of Ei in all.
+    /// # Errors
+    /// `Error::Domain` if `start` isn't below `stop` or the two are on opposite sides of zero,
+    /// or whatever `Ei` or `Ei_derivative` returns anywhere on the grid.
+    #[inline]
+    pub fn new(
+        start: NonZero<Finite<f64>>,
+        stop: NonZero<Finite<f64>>,
+        max_precision: Precision,
+    ) -> Result<Self, Error> {
+        const {
+            assert!(
+                N >= 2,
+                "A table needs at least two points to interpolate between"
+            );
+        }
+
+        let (lo, hi) = (**start, **stop);
+        if lo >= hi {
+            return Err(Error::Domain(*start));
+        }
+        if (lo < 0_f64) != (hi < 0_f64) {
+            return Err(Error::Domain(Finite::new(0_f64)));
+        }
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "tables past 2^53 points are indistinguishable from continuous anyway"
+        )]
+        let intervals = N.wrapping_sub(1) as f64;
+        let mut table = Self {
+            intervals,
+            relative_error: 0_f64,
+            slopes: [0_f64; N],
+            start: lo,
+            stop: hi,
+            values: [0_f64; N],
+        };
+
+        let ei = |x: f64| {
+            crate::Ei(NonZero::new(Finite::new(x)), max_precision).map(|approx| *approx.value)
+        };
+        let mut k = 0_f64;
+        for (value, slope) in table.values.iter_mut().zip(&mut table.slopes) {
+            let x = NonZero::new(Finite::new(node(lo, hi, intervals, k)));
+            *value = ei(**x)?;
+            *slope = *Ei_derivative(x)?.value;
+            k += 1_f64;
+        }
+
+        let mut interval = 0_f64;
+        for ((&p0, &p1), (&m0, &m1)) in table
+            .values
+            .iter()
+            .zip(table.values.iter().skip(1))
+            .zip(table.slopes.iter().zip(table.slopes.iter().skip(1)))
+        {
+            let (left, right) = (
+                node(lo, hi, intervals, interval),
+                node(lo, hi, intervals, interval + 1_f64),
+            );
+            let middle = 0.5_f64 * (left + right);
+            let discrepancy = hermite((left, p0, m0), (right, p1, m1), middle) - ei(middle)?;
+            table.relative_error = table
+                .relative_error
+                .max(discrepancy.abs() / p0.abs().max(p1.abs()));
+            interval += 1_f64;
+        }
+        Ok(table)
+    }
+
+    /// Largest interpolation error found at the middle of any interval when the table was built,
+    /// relative to the larger magnitude at either end of that interval.
+    #[inline]
+    #[must_use]
+    pub const fn relative_error(&self) -> f64 {
+        self.relative_error
+    }
+}
+
+/// The cubic through `(x, value, slope)` at both ends of an interval, at `x`.
+#[inline]
+fn hermite((left, p0, m0): (f64, f64, f64), (right, p1, m1): (f64, f64, f64), x: f64) -> f64 {
+    let h = right - left;
+    let t = (x - left) / h;
+    let s = 1_f64 - t;
+    s * s * ((1_f64 + 2_f64 * t) * p0 + t * h * m0)
+        + t * t * ((3_f64 - 2_f64 * t) * p1 - s * h * m1)
+}
+
+/// Point `k` (zero-indexed) of `intervals + 1` from `start` to `stop`,
+/// as $\text{start} \cdot (1 - t) + \text{stop} \cdot t$ with $t = \frac{k}{\text{intervals}}$, as in `Ei_linspace`.
+#[inline]
+fn node(start: f64, stop: f64, intervals: f64, k: f64) -> f64 {
+    let t = k / intervals;
+    start.mul_add(1_f64 - t, stop * t)
+}
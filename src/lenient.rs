@@ -0,0 +1,75 @@
+//! E1 and Ei on every `f64`, with `libm`'s conventions in place of sigma types and `Result`s,
+//! e.g. as drop-in replacements wherever plain `f64`s go in and come out.
+//!
+//! NaN gives NaN, and zero (of either sign) gives the infinity each function diverges to there.
+//! Past where `E1` and `Ei` refuse an argument, the value comes from the scaled function instead,
+//! e.g. $\text{Ei}(x) = e^{x} \cdot e^{-x} \text{Ei}(x)$,
+//! so a result only overflows to infinity or underflows to zero where the exact value does,
+//! and the infinities give the limits: $\text{Ei}(+\infty) = +\infty$, $\text{Ei}(-\infty) = -0$,
+//! $E_{1}(+\infty) = +0$, and $E_{1}(-\infty) = -\infty$.
+//!
+//! Every series is summed in full, and any error estimate is dropped.
+//!
+//! No original C code: GSL reports domain and range errors instead.
+
+use {
+    crate::{Approx, Error, Precision},
+    sigma_types::{Finite, NonZero},
+};
+
+/// $E_{1}(x)$ for any `x` at all.
+/// See the module documentation for what happens at zero, at the infinities, and to NaN.
+#[inline]
+#[must_use]
+pub fn e1(x: f64) -> f64 {
+    let Some(nonzero) = Finite::try_new(x).and_then(NonZero::try_new) else {
+        return if x.is_nan() {
+            x
+        } else if x == 0_f64 {
+            f64::INFINITY
+        } else if x > 0_f64 {
+            0_f64
+        } else {
+            f64::NEG_INFINITY
+        };
+    };
+    value(
+        crate::E1(nonzero, Precision::Full),
+        || crate::E1_scaled(nonzero, Precision::Full),
+        -x,
+    )
+}
+
+/// $\text{Ei}(x)$ for any `x` at all.
+/// See the module documentation for what happens at zero, at the infinities, and to NaN.
+#[inline]
+#[must_use]
+pub fn ei(x: f64) -> f64 {
+    let Some(nonzero) = Finite::try_new(x).and_then(NonZero::try_new) else {
+        return if x.is_nan() {
+            x
+        } else if x == 0_f64 {
+            f64::NEG_INFINITY
+        } else if x > 0_f64 {
+            f64::INFINITY
+        } else {
+            -0_f64
+        };
+    };
+    value(
+        crate::Ei(nonzero, Precision::Full),
+        || crate::Ei_scaled(nonzero, Precision::Full),
+        x,
+    )
+}
+
+/// The value of `result`, or if the argument was out of range,
+/// `scaled()` times $e^{\text{exponent}}$, in two halves so that neither overflows before the product would.
+#[inline]
+fn value(result: Result<Approx, Error>, scaled: impl FnOnce() -> Approx, exponent: f64) -> f64 {
+    if let Ok(approx) = result {
+        return *approx.value;
+    }
+    let half = libm::exp(0.5_f64 * exponent);
+    *scaled().value * half * half
+}
@@ -5,18 +5,164 @@
 //! Inspired by [GSL's implementation](https://github.com/ampl/gsl/blob/ff49e28bdffb893a1c0f6e3eff151296e0e71f82/specfunc/expint.c#L8).
 
 #![no_std]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![expect(non_snake_case, reason = "Proper mathematical names")]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod algorithms;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod bickley;
+#[cfg(feature = "alloc")]
+pub mod cache;
 pub mod chebyshev;
+mod compile_time;
+#[cfg(feature = "num-complex")]
+pub mod complex;
 mod constants;
+
+pub mod consts {
+    //! Constants that show up alongside the exponential integral in downstream formulas,
+    //! each correctly rounded to the nearest `f64`.
+
+    use crate::constants;
+
+    /// $E_{1}(1) = -\text{Ei}(-1) \approx 0.219\,383\,934\,395\,520\,273\,677\,163\,775\,460$.
+    pub const E1_1: f64 = constants::E1_1;
+
+    /// The zero of Ei, $x_{0} = \ln \mu \approx 0.372\,507\,410\,781\,366\,634\,461\,991\,866\,580$,
+    /// where $\mu$ is the Ramanujan-Soldner constant. The same value as `root::EI_ZERO`.
+    pub const EI_ZERO: f64 = constants::EI_ZERO;
+
+    /// The Euler-Mascheroni constant, $\gamma \approx 0.577\,215\,664\,901\,532\,860\,606\,512\,090\,082$.
+    ///
+    /// Every series and regular part in this crate uses this exact value,
+    /// so identities like $\text{Ein}(x) = \gamma + \ln |x| - \text{Ei}(-x)$ can be checked to the bit.
+    pub const EULER_GAMMA: f64 = constants::EULER_GAMMA;
+
+    /// The Gompertz constant,
+    /// $\delta = -e \, \text{Ei}(-1) = \int_{0}^{\infty} \frac{e^{-t}}{1 + t} \text{d}t \approx 0.596\,347\,362\,323\,194\,074\,341\,078\,499\,369$.
+    pub const GOMPERTZ: f64 = constants::GOMPERTZ;
+
+    /// The logarithmic integral at 2, $\text{li}(2) \approx 1.045\,163\,780\,117\,492\,784\,844\,588\,889\,194$,
+    /// the offset between `li` and `Li`.
+    pub const LI_2: f64 = constants::LI_2;
+}
+
+#[cfg(feature = "quad")]
+pub mod double_double;
+pub mod dual;
+
+pub mod e3 {
+    //! The third exponential integral, e.g. for plane-parallel radiative transfer.
+
+    use {
+        crate::constants,
+        core::{error, fmt},
+        sigma_types::{Finite, Negative, Positive},
+    };
+
+    /// Why E3 couldn't be evaluated.
+    #[non_exhaustive]
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    #[expect(
+        clippy::error_impl_error,
+        reason = "the name every caller already uses, qualified as `exponential_integral::e3::Error`"
+    )]
+    pub enum Error {
+        /// Argument was greater than the safe maximum.
+        ArgumentTooPositive(Positive<Finite<f64>>),
+        /// Argument was less than 0, where (as in GSL) orders above 2 are left undefined.
+        NegativeArgument(Negative<Finite<f64>>),
+    }
+
+    impl fmt::Display for Error {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match *self {
+                Self::ArgumentTooPositive(arg) => write!(
+                    f,
+                    "Argument too large (positive): maximum is {}, but {arg} was supplied",
+                    constants::XMAX,
+                ),
+                Self::NegativeArgument(arg) => {
+                    write!(f, "Argument must be nonnegative, but {arg} was supplied",)
+                }
+            }
+        }
+    }
+
+    #[expect(
+        clippy::missing_trait_methods,
+        reason = "no underlying cause, and the rest are deprecated, unstable, or sealed"
+    )]
+    impl error::Error for Error {}
+}
+
+pub mod evaluator;
+pub mod extended;
+pub mod fast;
 mod implementation;
+pub mod in_domain;
+pub mod incomplete_gamma;
+pub mod interpolation;
+#[cfg(feature = "interval")]
+pub mod interval;
+pub mod lenient;
+pub mod li {
+    //! The logarithmic integral, e.g. for approximating the prime-counting function.
+
+    use {
+        core::{error, fmt},
+        sigma_types::Finite,
+    };
+
+    /// Why li couldn't be evaluated.
+    #[non_exhaustive]
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    #[expect(
+        clippy::error_impl_error,
+        reason = "the name every caller already uses, qualified as `exponential_integral::li::Error`"
+    )]
+    pub enum Error {
+        /// Argument was not greater than 0, where the logarithm is undefined.
+        Domain(Finite<f64>),
+        /// Argument was exactly 1, where li has a logarithmic singularity.
+        Singularity,
+    }
+
+    impl fmt::Display for Error {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match *self {
+                Self::Domain(arg) => {
+                    write!(f, "Argument must be positive, but {arg} was supplied")
+                }
+                Self::Singularity => write!(f, "Argument was 1, where li diverges"),
+            }
+        }
+    }
+
+    #[expect(
+        clippy::missing_trait_methods,
+        reason = "no underlying cause, and the rest are deprecated, unstable, or sealed"
+    )]
+    impl error::Error for Error {}
+}
+
+pub mod molecular;
+pub mod moments;
+#[cfg(feature = "mp")]
+pub mod mp;
 
 pub mod neg {
     //! Inputs less than 0.
 
     use {
-        crate::{Approx, constants, implementation::neg, pos},
-        core::fmt,
+        crate::{Approx, Precision, constants, implementation::neg, pos},
+        core::{error, fmt},
         sigma_types::{Finite, Negative},
     };
 
@@ -25,8 +171,19 @@ pub mod neg {
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
     pub struct HugeArgument(pub Negative<Finite<f64>>);
 
+    impl HugeArgument {
+        /// `Err(Self(arg))`, outlined and marked cold so that range checks cost the hot path a single branch.
+        #[cold]
+        #[inline(never)]
+        #[expect(clippy::single_call_fn, reason = "mirrors `pos::HugeArgument::fail`")]
+        pub(crate) const fn fail<T>(arg: Negative<Finite<f64>>) -> Result<T, Self> {
+            Err(Self(arg))
+        }
+    }
+
     impl fmt::Display for HugeArgument {
-        #[inline]
+        #[cold]
+        #[inline(never)]
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             let Self(ref arg) = *self;
             write!(
@@ -37,44 +194,138 @@ pub mod neg {
         }
     }
 
+    #[expect(
+        clippy::missing_trait_methods,
+        reason = "no underlying cause, and the rest are deprecated, unstable, or sealed"
+    )]
+    impl error::Error for HugeArgument {}
+
     /// E1 on inputs less than 0.
     /// # Errors
     /// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
     #[inline]
-    pub fn E1(
-        x: Negative<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
-    ) -> Result<Approx, HugeArgument> {
-        neg::E1(
-            x,
-            #[cfg(feature = "precision")]
-            max_precision,
-        )
+    pub fn E1(x: Negative<Finite<f64>>, max_precision: Precision) -> Result<Approx, HugeArgument> {
+        neg::E1(x, max_precision)
     }
 
     /// Ei on inputs less than 0.
     /// # Errors
     /// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
-    #[inline(always)]
-    pub fn Ei(
-        x: Negative<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
-    ) -> Result<Approx, HugeArgument> {
+    #[cfg_attr(not(feature = "opt-size"), inline(always))]
+    #[cfg_attr(feature = "opt-size", inline)]
+    pub fn Ei(x: Negative<Finite<f64>>, max_precision: Precision) -> Result<Approx, HugeArgument> {
         #![expect(
             clippy::arithmetic_side_effects,
             reason = "property-based testing ensures this never happens"
         )]
 
-        pos::E1(
-            -x,
-            #[cfg(feature = "precision")]
-            max_precision,
-        )
-        .map(|mut approx| {
-            approx.value = -approx.value;
-            approx
+        pos::E1(-x, max_precision)
+            .map(|mut approx| {
+                approx.value = -approx.value;
+                approx
+            })
+            .map_err(|pos::HugeArgument(arg)| HugeArgument(-arg))
+    }
+}
+
+#[cfg(feature = "std")]
+pub mod par;
+#[cfg(feature = "quad")]
+pub mod quad;
+
+pub mod real;
+
+pub mod root {
+    //! The unique real zero of Ei.
+    //!
+    //! E1 and Ei evaluate it with small *relative* error nearby,
+    //! switching to a Taylor series about the zero itself.
+
+    use {
+        crate::{Approx, Precision, constants, implementation},
+        sigma_types::{Finite, Positive},
+    };
+
+    /// The zero of Ei, $x_{0} = \ln \mu \approx 0.372\,507\,410\,781\,366\,634\,461\,991\,866\,580$,
+    /// rounded to the nearest `f64`.
+    ///
+    /// Here $\mu$ is the Ramanujan-Soldner constant, the zero of the logarithmic integral.
+    pub const EI_ZERO: f64 = constants::EI_ZERO;
+
+    /// Refine `guess` to the zero of Ei by safeguarded Newton iteration,
+    /// e.g. to see how far `max_precision` limits can shift it.
+    ///
+    /// Ei has no other real zero, so every positive `guess` converges to `EI_ZERO`.
+    #[inline]
+    #[must_use]
+    pub fn find_root_near(guess: Positive<Finite<f64>>, max_precision: Precision) -> Approx {
+        implementation::find_root_near(guess, max_precision)
+    }
+}
+
+#[cfg(feature = "simd")]
+pub mod simd;
+pub mod single;
+
+pub mod theis {
+    //! Transient flow to a well in a confined aquifer (the Theis solution),
+    //! where hydrogeologists know E1 as the well function $W(u)$.
+    //!
+    //! Inputs can be in any consistent units (e.g. metres and days):
+    //! the well function's argument is dimensionless, and drawdown comes out in the length unit.
+
+    use {
+        crate::{Approx, Error, Precision},
+        core::f64::consts::PI,
+        sigma_types::{Finite, NonZero, Positive},
+    };
+
+    #[cfg(feature = "error")]
+    use {crate::constants, sigma_types::NonNegative};
+
+    /// The Theis well function, $W(u) = E_{1}(u)$.
+    /// # Errors
+    /// If `u` is so large that floating-point operations will fail down the line (just over 700).
+    #[inline]
+    pub fn W(u: Positive<Finite<f64>>, max_precision: Precision) -> Result<Approx, Error> {
+        crate::E1(NonZero::new(*u), max_precision)
+    }
+
+    /// Drawdown $s = \frac{Q}{4 \pi T} W(u)$ with $u = \frac{r^{2} S}{4 T t}$.
+    ///
+    /// Measured at distance `radius` ($r$) from a well that has pumped at a steady rate `discharge` ($Q$) for `time` ($t$),
+    /// in an aquifer with the given `transmissivity` ($T$) and `storativity` ($S$).
+    ///
+    /// Negative `discharge` (injection) gives negative drawdown, i.e. a rising water table.
+    /// # Errors
+    /// If $u$ is too large (far from the well, or too soon for any measurable drawdown)
+    /// or is zero after rounding (where drawdown is unbounded),
+    /// or if the drawdown itself is too large to represent.
+    #[inline]
+    pub fn drawdown(
+        discharge: Finite<f64>,
+        transmissivity: Positive<Finite<f64>>,
+        storativity: Positive<Finite<f64>>,
+        radius: Positive<Finite<f64>>,
+        time: Positive<Finite<f64>>,
+        max_precision: Precision,
+    ) -> Result<Approx, Error> {
+        let raw_u = **radius * **radius * **storativity / (4_f64 * **transmissivity * **time);
+        let finite_u = Finite::try_new(raw_u).ok_or(Error::Overflow)?;
+        let u = Positive::try_new(finite_u).ok_or(Error::Domain(finite_u))?;
+        let well = W(u, max_precision)?;
+        let scale = *discharge / (4_f64 * PI * **transmissivity);
+        let value = Finite::try_new(scale * *well.value).ok_or(Error::Overflow)?;
+        Ok(Approx {
+            value,
+            #[cfg(feature = "error")]
+            // The well function's own error, plus rounding in $u$ (a few ULPs, relative),
+            // which moves $W$ by $\left| W'(u) \right| = e^{-u} / u$ per unit, i.e. $e^{-u}$ per relative unit:
+            error: NonNegative::new(Finite::new(
+                scale.abs() * (**well.error + 4_f64 * constants::GSL_DBL_EPSILON * libm::exp(-raw_u))
+                    + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+            )),
         })
-        .map_err(|pos::HugeArgument(arg)| HugeArgument(-arg))
     }
 }
 
@@ -82,8 +333,8 @@ pub mod pos {
     //! Inputs greater than 0.
 
     use {
-        crate::{Approx, constants, implementation::pos, neg},
-        core::fmt,
+        crate::{Approx, Precision, constants, implementation::pos, neg},
+        core::{error, fmt},
         sigma_types::{Finite, Positive},
     };
 
@@ -92,8 +343,18 @@ pub mod pos {
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
     pub struct HugeArgument(pub Positive<Finite<f64>>);
 
+    impl HugeArgument {
+        /// `Err(Self(arg))`, outlined and marked cold so that range checks cost the hot path a single branch.
+        #[cold]
+        #[inline(never)]
+        pub(crate) const fn fail<T>(arg: Positive<Finite<f64>>) -> Result<T, Self> {
+            Err(Self(arg))
+        }
+    }
+
     impl fmt::Display for HugeArgument {
-        #[inline]
+        #[cold]
+        #[inline(never)]
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             let Self(ref arg) = *self;
             write!(
@@ -104,58 +365,55 @@ pub mod pos {
         }
     }
 
+    #[expect(
+        clippy::missing_trait_methods,
+        reason = "no underlying cause, and the rest are deprecated, unstable, or sealed"
+    )]
+    impl error::Error for HugeArgument {}
+
     /// E1 on inputs less than 0.
     /// # Errors
     /// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
     #[inline]
-    pub fn E1(
-        x: Positive<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
-    ) -> Result<Approx, HugeArgument> {
-        pos::E1(
-            x,
-            #[cfg(feature = "precision")]
-            max_precision,
-        )
+    pub fn E1(x: Positive<Finite<f64>>, max_precision: Precision) -> Result<Approx, HugeArgument> {
+        pos::E1(x, max_precision)
     }
 
     /// Ei on inputs less than 0.
     /// # Errors
     /// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
-    #[inline(always)]
-    pub fn Ei(
-        x: Positive<Finite<f64>>,
-        #[cfg(feature = "precision")] max_precision: usize,
-    ) -> Result<Approx, HugeArgument> {
+    #[cfg_attr(not(feature = "opt-size"), inline(always))]
+    #[cfg_attr(feature = "opt-size", inline)]
+    pub fn Ei(x: Positive<Finite<f64>>, max_precision: Precision) -> Result<Approx, HugeArgument> {
         #![expect(
             clippy::arithmetic_side_effects,
             reason = "property-based testing ensures this never happens"
         )]
 
-        neg::E1(
-            -x,
-            #[cfg(feature = "precision")]
-            max_precision,
-        )
-        .map(|mut approx| {
-            approx.value = -approx.value;
-            approx
-        })
-        .map_err(|neg::HugeArgument(arg)| HugeArgument(-arg))
+        neg::E1(-x, max_precision)
+            .map(|mut approx| {
+                approx.value = -approx.value;
+                approx
+            })
+            .map_err(|neg::HugeArgument(arg)| HugeArgument(-arg))
     }
 }
 
 #[cfg(test)]
 mod test;
 
+pub mod with_zero;
+
 use {
-    core::fmt,
-    sigma_types::{Finite, Negative, NonZero, Positive},
+    core::{
+        error,
+        f64::consts::PI,
+        fmt,
+        ops::{Add, Mul, Neg, Sub},
+    },
+    sigma_types::{Finite, Negative, NonNegative, NonZero, Positive},
 };
 
-#[cfg(feature = "error")]
-use sigma_types::NonNegative;
-
 /// An approximate value alongside an estimate of its own approximation error.
 /// # Original C code
 /// ```c
@@ -165,16 +423,99 @@ use sigma_types::NonNegative;
 /// };
 /// typedef struct gsl_sf_result_struct gsl_sf_result;
 /// ```
-#[expect(clippy::exhaustive_structs, reason = "Simple structure")]
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+///
+/// The estimate is only computed with the `error` feature, so it's kept private and read through `Approx::error`,
+/// which exists either way. The struct is `#[non_exhaustive]`, so no struct literal or pattern outside this crate
+/// depends on which fields a build has, and none stops compiling when another crate turns the feature on or off.
+///
+/// Approximations combine with `+`, `-`, and `*` (by another `Approx` or by an `f64`),
+/// carrying the estimate through to first order, plus one rounding of the result as GSL adds after each step:
+/// e.g. `Ei(b)? - Ei(a)?` is off by at most the sum of both estimates and a unit in the last place.
+/// As with `Finite`'s own operators, the result must stay finite (checked in debug builds).
+#[cfg_attr(
+    feature = "error",
+    expect(
+        clippy::partial_pub_fields,
+        reason = "the estimate only exists with the `error` feature, so it's read through `error()` instead"
+    )
+)]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct Approx {
     /// Estimate of the approximation error for `value`.
     #[cfg(feature = "error")]
-    pub error: NonNegative<Finite<f64>>,
+    pub(crate) error: NonNegative<Finite<f64>>,
     /// Approximate value.
     pub value: Finite<f64>,
 }
 
+impl Approx {
+    /// Whether the values of `self` and `other` are within `k_sigma` times both error estimates of each other,
+    /// i.e. whether $\text{value} \pm k \, \text{error}$ for each intersect.
+    /// Without the `error` feature, there's no estimate to widen either by, so only equal values agree.
+    #[inline]
+    #[must_use]
+    pub fn agrees_within(&self, other: &Self, k_sigma: f64) -> bool {
+        let width = self
+            .error()
+            .zip(other.error())
+            .map_or(0_f64, |(mine, theirs)| **mine + **theirs);
+        (*self.value - *other.value).abs() <= k_sigma * width
+    }
+
+    /// Estimate of the approximation error for `value`,
+    /// or `None` if this build doesn't compute one (i.e. without the `error` feature).
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> Option<NonNegative<Finite<f64>>> {
+        #[cfg(feature = "error")]
+        {
+            Some(self.error)
+        }
+        #[cfg(not(feature = "error"))]
+        {
+            None
+        }
+    }
+
+    /// The error estimate in units in the last place of `value`, i.e. divided by the gap
+    /// from `|value|` up to the next `f64` (the smallest subnormal at zero; at `f64::MAX`, the gap below it).
+    /// `None` without the `error` feature.
+    #[inline]
+    #[must_use]
+    pub fn error_in_ulps(&self) -> Option<NonNegative<f64>> {
+        let magnitude = self.value.abs();
+        let above = magnitude.next_up();
+        let ulp = if above.is_finite() {
+            above - magnitude
+        } else {
+            magnitude - magnitude.next_down()
+        };
+        self.error().map(|error| NonNegative::new(**error / ulp))
+    }
+
+    /// Whether $\text{value} \pm \text{error}$ for `self` and `other` intersect: `agrees_within(other, 1.0)`.
+    #[inline]
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.agrees_within(other, 1_f64)
+    }
+
+    /// The error estimate relative to the magnitude of `value`, or `None` without the `error` feature.
+    /// Around a value of exactly zero, this is zero if the estimate is too, and $+\infty$ otherwise.
+    #[inline]
+    #[must_use]
+    pub fn relative_error(&self) -> Option<NonNegative<f64>> {
+        self.error().map(|error| {
+            NonNegative::new(if **error == 0_f64 {
+                0_f64
+            } else {
+                **error / self.value.abs()
+            })
+        })
+    }
+}
+
 impl fmt::Display for Approx {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -194,26 +535,328 @@ impl fmt::Display for Approx {
     }
 }
 
+impl Add for Approx {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        #![expect(
+            clippy::arithmetic_side_effects,
+            reason = "staying finite is the caller's to ensure, as with `Finite`'s own operators"
+        )]
+
+        let value = self.value + rhs.value;
+        Self {
+            #[cfg(feature = "error")]
+            error: self.error + rhs.error + rounding(value),
+            value,
+        }
+    }
+}
+
+impl Mul for Approx {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        #![expect(
+            clippy::arithmetic_side_effects,
+            reason = "staying finite is the caller's to ensure, as with `Finite`'s own operators"
+        )]
+
+        let value = self.value * rhs.value;
+        Self {
+            // $|a| \delta b + |b| \delta a$, dropping the second-order $\delta a \, \delta b$:
+            #[cfg(feature = "error")]
+            error: magnitude(self.value) * rhs.error
+                + magnitude(rhs.value) * self.error
+                + rounding(value),
+            value,
+        }
+    }
+}
+
+impl Mul<f64> for Approx {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        #![expect(
+            clippy::arithmetic_side_effects,
+            reason = "staying finite is the caller's to ensure, as with `Finite`'s own operators"
+        )]
+
+        let factor = Finite::new(rhs);
+        let value = self.value * factor;
+        Self {
+            #[cfg(feature = "error")]
+            error: magnitude(factor) * self.error + rounding(value),
+            value,
+        }
+    }
+}
+
+impl Neg for Approx {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        #![expect(clippy::arithmetic_side_effects, reason = "negation is exact")]
+
+        Self {
+            #[cfg(feature = "error")]
+            error: self.error,
+            value: -self.value,
+        }
+    }
+}
+
+impl Sub for Approx {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        #![expect(
+            clippy::arithmetic_side_effects,
+            reason = "staying finite is the caller's to ensure, as with `Finite`'s own operators"
+        )]
+
+        let value = self.value - rhs.value;
+        Self {
+            #[cfg(feature = "error")]
+            error: self.error + rhs.error + rounding(value),
+            value,
+        }
+    }
+}
+
+/// An approximate value and its error estimate, both times a power of ten,
+/// for results outside the range of `f64`.
+/// # Original C code
+/// ```c
+/// struct gsl_sf_result_e10_struct {
+///   double val;
+///   double err;
+///   int    e10;
+/// };
+/// typedef struct gsl_sf_result_e10_struct gsl_sf_result_e10;
+/// ```
+///
+/// As with `Approx`, the estimate is read through `Approx10::error` whatever the features.
+#[cfg_attr(
+    feature = "error",
+    expect(
+        clippy::partial_pub_fields,
+        reason = "the estimate only exists with the `error` feature, so it's read through `error()` instead"
+    )
+)]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Approx10 {
+    /// Power of ten multiplying both `value` and `error`.
+    pub e10: i32,
+    /// Estimate of the approximation error for `value`.
+    #[cfg(feature = "error")]
+    pub(crate) error: NonNegative<Finite<f64>>,
+    /// Approximate value.
+    pub value: Finite<f64>,
+}
+
+impl Approx10 {
+    /// Estimate of the approximation error for `value` (times the same power of ten),
+    /// or `None` if this build doesn't compute one (i.e. without the `error` feature).
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> Option<NonNegative<Finite<f64>>> {
+        #[cfg(feature = "error")]
+        {
+            Some(self.error)
+        }
+        #[cfg(not(feature = "error"))]
+        {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Approx10 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            e10,
+            #[cfg(feature = "error")]
+            ref error,
+            ref value,
+        } = *self;
+        #[cfg(feature = "error")]
+        {
+            write!(f, "({value} +/- {error}) * 10^{e10}")
+        }
+        #[cfg(not(feature = "error"))]
+        {
+            write!(f, "{value} * 10^{e10}")
+        }
+    }
+}
+
+/// Why a slice couldn't be evaluated, and at which entry.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum BatchError {
+    /// An entry was zero, infinite, or NaN.
+    Domain {
+        /// Position of the entry in the input slice.
+        index: usize,
+        /// The entry itself.
+        value: f64,
+    },
+    /// The input and output slices had different lengths.
+    LengthMismatch {
+        /// Length of the input slice.
+        inputs: usize,
+        /// Length of the output slice.
+        outputs: usize,
+    },
+    /// An entry was finite and nonzero, but couldn't be evaluated.
+    Range {
+        /// What went wrong, as evaluating the entry on its own would report it.
+        error: Error,
+        /// Position of the entry in the input slice.
+        index: usize,
+    },
+}
+
+impl fmt::Display for BatchError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Domain { index, value } => {
+                write!(f, "Argument outside the domain at index {index}: {value}")
+            }
+            Self::LengthMismatch { inputs, outputs } => write!(
+                f,
+                "{inputs} inputs but room for {outputs} outputs: lengths must match"
+            ),
+            Self::Range { ref error, index } => write!(f, "At index {index}: {error}"),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "`Display` already includes any wrapped `Error`, so there's no separate cause, and the rest are deprecated, unstable, or sealed"
+)]
+impl error::Error for BatchError {}
+
+/// Which value to give Ei on its positive half-line, where the defining integral passes through a pole.
+///
+/// The real Ei is the Cauchy principal value there, but Ei continued off the real axis,
+/// as $-E_{1}(-z)$ on the principal branch of the complex `E1`,
+/// has a cut along the positive real axis, and its limits from either side differ by $2 \pi i$.
+#[expect(clippy::exhaustive_enums, reason = "a cut only has two sides")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Branch {
+    /// The limit from below the real axis, $\text{Ei}(x) + \pi i$.
+    LowerHalfPlane,
+    /// The Cauchy principal value, $\text{Ei}(x)$ itself (the average of both sides).
+    PrincipalValue,
+    /// The limit from above the real axis, $\text{Ei}(x) - \pi i$.
+    UpperHalfPlane,
+}
+
 /// An approximate value alongside an estimate of its own approximation error.
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[expect(
+    clippy::error_impl_error,
+    reason = "the name every caller already uses, qualified as `exponential_integral::Error`"
+)]
 pub enum Error {
     /// Argument was less than the safe minimum.
     ArgumentTooNegative(Negative<Finite<f64>>),
     /// Argument was less than the safe maximum.
     ArgumentTooPositive(Positive<Finite<f64>>),
+    /// Argument was outside the function's domain,
+    /// e.g. zero where the function diverges
+    /// or negative where (as in GSL) it's left undefined.
+    Domain(Finite<f64>),
+    /// The result is too large to represent.
+    Overflow,
+    /// The result is too small to represent:
+    /// $E_{1}(x)$ for `x` past `XMAX`, as GSL reports it.
+    Underflow,
 }
 
 impl fmt::Display for Error {
-    #[inline]
+    #[cold]
+    #[inline(never)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::ArgumentTooNegative(arg) => fmt::Display::fmt(&neg::HugeArgument(arg), f),
             Self::ArgumentTooPositive(arg) => fmt::Display::fmt(&pos::HugeArgument(arg), f),
+            Self::Domain(arg) => write!(f, "Argument outside the domain: {arg}"),
+            Self::Overflow => write!(f, "Result too large to represent"),
+            Self::Underflow => write!(f, "Result too small to represent"),
         }
     }
 }
 
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "no underlying cause, and the rest are deprecated, unstable, or sealed"
+)]
+impl error::Error for Error {}
+
+/// How far to sum each Chebyshev series, as every `max_precision` argument takes it.
+///
+/// `Terms` is what a bare `usize` meant there before, and `From<usize>` still gives it.
+/// Every build takes and honours it, so no feature adds or removes an argument anywhere;
+/// the `precision` feature that used to is kept only so that manifests naming it still build.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub enum Precision {
+    /// Every term of every series.
+    #[default]
+    Full,
+    /// Only as many terms as keep the relative error each series contributes under this tolerance (e.g. `1e-8`),
+    /// chosen region by region from how quickly that region's coefficients fall off,
+    /// so that regions whose series converge faster sum fewer terms.
+    /// Near a zero of the function (e.g. Ci's), no relative tolerance can be met short of every term.
+    RelTolerance(f64),
+    /// Each series up to the term of this order, i.e. at most one more term than this
+    /// (or every term, if the series is shorter).
+    Terms(usize),
+}
+
+impl From<usize> for Precision {
+    #[inline]
+    fn from(order: usize) -> Self {
+        Self::Terms(order)
+    }
+}
+
+/// $|x|$, by which an error estimate scales.
+#[inline]
+#[cfg(feature = "error")]
+fn magnitude(x: Finite<f64>) -> NonNegative<Finite<f64>> {
+    NonNegative::new(x.map(f64::abs))
+}
+
+/// One rounding of `value`, as GSL adds to the error estimate after each step of arithmetic.
+#[inline]
+#[cfg(feature = "error")]
+fn rounding(value: Finite<f64>) -> NonNegative<Finite<f64>> {
+    #![expect(
+        clippy::arithmetic_side_effects,
+        reason = "a finite value times machine epsilon can't overflow"
+    )]
+
+    NonNegative::new(Finite::new(constants::GSL_DBL_EPSILON)) * magnitude(value)
+}
+
+/// Also known as the upper incomplete gamma function at order zero, $\Gamma(0, x)$:
+/// see `gamma_inc_0` for that convention.
+/// For an argument that may be zero, see `with_zero::E1`.
 /// # Original C code
 /// ```c
 /// int gsl_sf_expint_E1_e(const double x, gsl_sf_result * result)
@@ -223,22 +866,241 @@ impl fmt::Display for Error {
 /// ```
 ///
 /// # Errors
-/// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
+/// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710):
+/// `Error::ArgumentTooNegative` below zero, and, as in GSL, `Error::Underflow` above it.
 #[inline]
-pub fn E1(
-    x: NonZero<Finite<f64>>,
-    #[cfg(feature = "precision")] max_precision: usize,
-) -> Result<Approx, Error> {
-    implementation::E1(
-        x,
-        #[cfg(feature = "precision")]
-        max_precision,
-    )
+pub fn E1(x: NonZero<Finite<f64>>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::E1(x, max_precision)
 }
 
-/// # Original C code
-/// ```c
-/// int gsl_sf_expint_Ei_e(const double x, gsl_sf_result * result)
+/// E1 without checking `x` against `NXMAX` and `XMAX`, straight to the piece that covers it,
+/// for callers who have already made sure of the range (e.g. once for a whole array).
+///
+/// Exactly `E1` wherever `E1` succeeds. To have the check made once and remembered instead,
+/// see `in_domain::InDomain`, which needs no `unsafe`.
+/// # Safety
+/// `x` must be strictly between `NXMAX` and `XMAX` (just over $\pm 701$), i.e. wherever `E1` wouldn't return an error.
+/// Past either end, the exponential overflows or underflows,
+/// and the value handed back as `Finite` may be infinite or NaN,
+/// which every later use is entitled to assume can't happen.
+#[inline]
+#[must_use]
+pub unsafe fn E1_unchecked(x: NonZero<Finite<f64>>, max_precision: Precision) -> Approx {
+    debug_assert!(
+        **x > constants::NXMAX && **x < constants::XMAX,
+        "`E1_unchecked` called outside its range",
+    );
+    implementation::E1_in_domain(*x, max_precision)
+}
+
+/// E1 in a `const fn`, e.g. `const TAU0: f64 = E1_const(2.0);`.
+///
+/// Same pieces and Chebyshev fits as `E1`, but with `const` reimplementations of the exponential and logarithm,
+/// so it can differ from `E1` by a few units in the last place. No error estimate.
+/// # Panics
+/// If `x` is zero, not finite, or so large that `E1` would return an error.
+/// In a const context, that's a compile error instead.
+#[inline]
+#[must_use]
+pub const fn E1_const(x: f64) -> f64 {
+    compile_time::E1(x)
+}
+
+/// E1 at every entry of `xs`, into the same position in `out`,
+/// e.g. for scientific code that holds its arguments in arrays.
+///
+/// Agrees with calling `E1` at each entry to the bit,
+/// but checks every entry before evaluating any,
+/// then lets consecutive entries that fall into the same approximation share the work of finding it
+/// (so sorted or gridded inputs go fastest).
+/// # Errors
+/// If the slices' lengths differ, or if `E1` would fail at any entry (see `BatchError`),
+/// in which case `out` is left untouched.
+#[inline]
+pub fn E1_slice(xs: &[f64], out: &mut [f64], max_precision: Precision) -> Result<(), BatchError> {
+    implementation::E1_slice(xs, out, false, |approx| *approx.value, max_precision)
+}
+
+/// `E1_slice`, keeping each entry's error estimate.
+/// # Errors
+/// See `E1_slice`.
+#[inline]
+pub fn E1_slice_approx(
+    xs: &[f64],
+    out: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    implementation::E1_slice(xs, out, false, |approx| approx, max_precision)
+}
+
+/// E1 scaled by $e^{x}$, i.e. $e^{x} E_{1}(x)$.
+///
+/// Unlike E1 itself, this stays representable for every nonzero finite input,
+/// so there's no `XMAX` cutoff and nothing to go wrong.
+/// # Original C code
+/// ```c
+/// int gsl_sf_expint_E1_scaled_e(const double x, gsl_sf_result * result)
+/// {
+///   return expint_E1_impl(x, result, 1);
+/// }
+/// ```
+#[inline]
+#[must_use]
+pub fn E1_scaled(x: NonZero<Finite<f64>>, max_precision: Precision) -> Approx {
+    implementation::E1_scaled(x, max_precision)
+}
+
+/// # Original C code
+/// ```c
+/// int gsl_sf_expint_E2_e(const double x, gsl_sf_result * result)
+/// {
+///   return expint_E2_impl(x, result, 0);
+/// }
+/// ```
+///
+/// # Errors
+/// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
+#[inline]
+pub fn E2(x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::E2(x, false, max_precision)
+}
+
+/// The third exponential integral, $E_{3}(x) = \int_{1}^{\infty} \frac{e^{-xt}}{t^3} \text{d}t$.
+/// # Errors
+/// If `x` is negative or so large that floating-point operations will fail down the line (just over 710).
+#[inline]
+pub fn E3(x: Finite<f64>, max_precision: Precision) -> Result<Approx, e3::Error> {
+    implementation::E3(x, max_precision)
+}
+
+/// The generalized exponential integral of integer order,
+/// $E_{n}(x) = \int_{1}^{\infty} \frac{e^{-xt}}{t^n} \text{d}t$.
+/// # Original C code
+/// ```c
+/// int gsl_sf_expint_En_e(const int n, const double x, gsl_sf_result * result)
+/// {
+///   return expint_En_impl(n, x, result, 0);
+/// }
+/// ```
+///
+/// # Errors
+/// If `x` is outside the domain for order `n` (see `Error::Domain`)
+/// or so large that floating-point operations will fail down the line (absolute value of just over 710).
+#[inline]
+pub fn En(n: usize, x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::En(n, x, false, max_precision)
+}
+
+/// `En` scaled by $e^{x}$, i.e. $e^{x} E_{n}(x)$,
+/// which never underflows for large `x` and so has no `XMAX` ceiling.
+/// # Original C code
+/// ```c
+/// int gsl_sf_expint_En_scaled_e(const int n, const double x, gsl_sf_result * result)
+/// {
+///   return expint_En_impl(n, x, result, 1);
+/// }
+/// ```
+///
+/// # Errors
+/// If `x` is outside the domain for order `n` (see `Error::Domain`)
+/// or, for order 0, so close to zero that $1 / x$ overflows.
+#[inline]
+pub fn En_scaled(n: usize, x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::En(n, x, true, max_precision)
+}
+
+/// Every order from $E_{1}(x)$ up at once, e.g. for transport codes that need whole sequences at each point.
+///
+/// `orders[k]` is $E_{k + 1}(x)$, so `orders.len()` is the highest order.
+/// Much cheaper than calling `En` for each order:
+/// only one order is computed directly, and the rest follow by recurrence in whichever direction is stable.
+/// # Errors
+/// See `En`. For negative `x`, the orders before the failing one are already filled;
+/// otherwise, nothing is filled on error.
+#[inline]
+pub fn En_sequence(
+    x: Finite<f64>,
+    orders: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), Error> {
+    implementation::En_sequence(x, orders, false, max_precision)
+}
+
+/// `En_sequence` scaled by $e^{x}$, i.e. $e^{x} E_{k + 1}(x)$ in `orders[k]`,
+/// which never underflows for large `x` and so has no `XMAX` ceiling.
+/// # Errors
+/// See `En_scaled`. For negative `x`, the orders before the failing one are already filled;
+/// otherwise, nothing is filled on error.
+#[inline]
+pub fn En_scaled_sequence(
+    x: Finite<f64>,
+    orders: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), Error> {
+    implementation::En_sequence(x, orders, true, max_precision)
+}
+
+/// The ratio of successive orders, $\frac{E_{n+1}(x)}{E_{n}(x)}$,
+/// e.g. for transport and astrophysics codes at large `x`,
+/// where dividing `En` by `En` would be dividing two underflowed values.
+///
+/// For positive `x`, this lies strictly between 0 and 1 and tends to $1 - \frac{1}{x}$ as `x` grows.
+/// Uses a continued fraction for the ratio itself wherever it converges quickly,
+/// so there's no `XMAX` ceiling.
+/// # Errors
+/// If `x` is outside the domain for order `n` or `n + 1` (see `Error::Domain`),
+/// or if the ratio is too large to represent (only near the root of $E_{1}$ for negative `x`).
+#[inline]
+pub fn En_ratio(n: usize, x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::En_ratio(n, x, max_precision)
+}
+
+/// The generalized exponential integral of real (not necessarily integer) order.
+///
+/// $E_{\nu}(x) = \int_{1}^{\infty} \frac{e^{-xt}}{t^{\nu}} \text{d}t = x^{\nu - 1} \Gamma(1 - \nu, x)$.
+/// Integer orders defer to `En`.
+/// # Errors
+/// If `x` is negative (or zero for `nu` at most 1),
+/// so large that floating-point operations will fail down the line (just over 710),
+/// or if the result is too large to represent.
+#[inline]
+pub fn Enu(nu: Finite<f64>, x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::Enu(nu, x, max_precision)
+}
+
+/// The derivative of `Enu` with respect to its order, e.g. for the sensitivity of a fitted order.
+///
+/// $\frac{\partial E_{\nu}}{\partial \nu}(x) = -\int_{1}^{\infty} e^{-xt} t^{-\nu} \ln t \text{d}t$
+/// is always negative, since $E_{\nu}(x)$ decreases in $\nu$ for `x > 0`.
+/// Uses a series for `x < 1` and quadrature of the integral otherwise.
+/// # Errors
+/// If `x` is negative (or zero for `nu` at most 1),
+/// so large that floating-point operations will fail down the line (just over 710),
+/// or if the result is too large to represent.
+#[inline]
+pub fn Enu_order_derivative(
+    nu: Finite<f64>,
+    x: Finite<f64>,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    implementation::Enu_order_derivative(nu, x, max_precision)
+}
+
+/// The entire exponential integral,
+/// $\text{Ein}(x) = \int_{0}^{x} \frac{1 - e^{-t}}{t} \text{d}t = \gamma + \ln |x| - \text{Ei}(-x)$.
+///
+/// Unlike E1 and Ei, this has no singularity at zero, so any finite `x` goes.
+/// # Errors
+/// If `x` is so negative that the result overflows (just under -710).
+#[inline]
+pub fn Ein(x: Finite<f64>, max_precision: Precision) -> Result<Approx, neg::HugeArgument> {
+    implementation::Ein(x, max_precision)
+}
+
+/// For an argument that may be zero, see `with_zero::Ei`.
+/// # Original C code
+/// ```c
+/// int gsl_sf_expint_Ei_e(const double x, gsl_sf_result * result)
 /// {
 ///   /* CHECK_POINTER(result) */
 ///
@@ -252,23 +1114,628 @@ pub fn E1(
 ///
 /// # Errors
 /// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 710).
-#[inline(always)]
-pub fn Ei(
-    x: NonZero<Finite<f64>>,
-    #[cfg(feature = "precision")] max_precision: usize,
-) -> Result<Approx, Error> {
+#[cfg_attr(not(feature = "opt-size"), inline(always))]
+#[cfg_attr(feature = "opt-size", inline)]
+pub fn Ei(x: NonZero<Finite<f64>>, max_precision: Precision) -> Result<Approx, Error> {
     #![expect(
         clippy::arithmetic_side_effects,
         reason = "property-based testing ensures this never happens"
     )]
 
-    E1(
-        -x,
-        #[cfg(feature = "precision")]
-        max_precision,
-    )
-    .map(|mut approx| {
+    E1(-x, max_precision).map(|mut approx| {
+        approx.value = -approx.value;
+        approx
+    })
+}
+
+/// Ei without checking `x` against `NXMAX` and `XMAX`: see `E1_unchecked`.
+///
+/// Exactly `Ei` wherever `Ei` succeeds.
+/// # Safety
+/// As for `E1_unchecked`: `x` must be strictly between `NXMAX` and `XMAX`, where `Ei` wouldn't return an error.
+#[inline]
+#[must_use]
+pub unsafe fn Ei_unchecked(x: NonZero<Finite<f64>>, max_precision: Precision) -> Approx {
+    #![expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+
+    debug_assert!(
+        **x > constants::NXMAX && **x < constants::XMAX,
+        "`Ei_unchecked` called outside its range",
+    );
+    let mut approx = implementation::E1_in_domain(-*x, max_precision);
+    approx.value = -approx.value;
+    approx
+}
+
+/// Ei in a `const fn`: see `E1_const`.
+/// # Panics
+/// See `E1_const`.
+#[inline]
+#[must_use]
+pub const fn Ei_const(x: f64) -> f64 {
+    -compile_time::E1(-x)
+}
+
+/// Ei on an evenly spaced grid from `start` to `stop` inclusive, one point per entry of `out`,
+/// e.g. for plotting or generating tables.
+///
+/// Agrees with calling `Ei` at each point to the bit, but consecutive points share the work of
+/// deciding which approximation covers them, instead of each starting from scratch.
+/// With a single entry, that entry is `Ei(start)`.
+/// # Errors
+/// See `Ei`, or `Error::Domain` if a grid point lands exactly on zero.
+/// The points before the failing one are already filled.
+#[inline]
+pub fn Ei_linspace(
+    start: Finite<f64>,
+    stop: Finite<f64>,
+    out: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), Error> {
+    implementation::Ei_linspace(start, stop, out, max_precision)
+}
+
+/// Ei at every entry of `xs`, into the same position in `out`:
+/// see `E1_slice`.
+/// # Errors
+/// If the slices' lengths differ, or if `Ei` would fail at any entry (see `BatchError`),
+/// in which case `out` is left untouched.
+#[inline]
+pub fn Ei_slice(xs: &[f64], out: &mut [f64], max_precision: Precision) -> Result<(), BatchError> {
+    implementation::E1_slice(xs, out, true, |approx| *approx.value, max_precision)
+}
+
+/// `Ei_slice`, keeping each entry's error estimate.
+/// # Errors
+/// See `Ei_slice`.
+#[inline]
+pub fn Ei_slice_approx(
+    xs: &[f64],
+    out: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    implementation::E1_slice(xs, out, true, |approx| approx, max_precision)
+}
+
+/// Ei scaled by $e^{-x}$, i.e. $e^{-x} \text{Ei}(x)$.
+///
+/// Unlike Ei itself, this stays representable for every nonzero finite input.
+/// # Original C code
+/// ```c
+/// int gsl_sf_expint_Ei_scaled_e(const double x, gsl_sf_result * result)
+/// {
+///   /* CHECK_POINTER(result) */
+///
+///   {
+///     int status = gsl_sf_expint_E1_scaled_e(-x, result);
+///     result->val = -result->val;
+///     return status;
+///   }
+/// }
+/// ```
+#[cfg_attr(not(feature = "opt-size"), inline(always))]
+#[cfg_attr(feature = "opt-size", inline)]
+#[must_use]
+pub fn Ei_scaled(x: NonZero<Finite<f64>>, max_precision: Precision) -> Approx {
+    #![expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+
+    let mut approx = E1_scaled(-x, max_precision);
+    approx.value = -approx.value;
+    approx
+}
+
+/// E1 as a mantissa times a power of ten, e.g. past `XMAX` on either side,
+/// where `E1` itself would fail to represent the result.
+///
+/// Wherever `E1` succeeds, this gives the same result with `e10` zero.
+/// # Errors
+/// Only if `x` is so large that even the power of ten can't represent the result
+/// (roughly $10^{\pm 2^{31}}$), with the same error as `E1`.
+#[inline]
+pub fn E1_e10(x: NonZero<Finite<f64>>, max_precision: Precision) -> Result<Approx10, Error> {
+    implementation::E1_e10(x, max_precision)
+}
+
+/// Ei as a mantissa times a power of ten, e.g. past `XMAX` on either side,
+/// where `Ei` itself would fail to represent the result.
+///
+/// Wherever `Ei` succeeds, this gives the same result with `e10` zero.
+/// # Errors
+/// Only if `x` is so large that even the power of ten can't represent the result
+/// (roughly $10^{\pm 2^{31}}$), with the same error as `Ei`.
+#[inline]
+pub fn Ei_e10(x: NonZero<Finite<f64>>, max_precision: Precision) -> Result<Approx10, Error> {
+    #![expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+
+    implementation::E1_e10(-x, max_precision).map(|mut approx| {
         approx.value = -approx.value;
         approx
     })
 }
+
+/// The derivative of E1, $E_{1}'(x) = -\frac{e^{-x}}{x}$.
+/// # Errors
+/// If the result is too large to represent:
+/// `x` just under -709, or so close to zero that $\frac{1}{x}$ overflows.
+#[inline]
+pub fn E1_derivative(x: NonZero<Finite<f64>>) -> Result<Approx, Error> {
+    #![expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+
+    implementation::Ei_derivative(-x)
+}
+
+/// The integral of E1 from 0, $\int_{0}^{x} E_{1}(t) \text{d}t = 1 - E_{2}(x) = 1 - e^{-x} + x E_{1}(x)$,
+/// e.g. for radiative flux or heat flow.
+///
+/// Evaluated without the cancellation that composing `E2` or `E1` by hand suffers for small `x`.
+/// E1 diverges only logarithmically at zero, so this is finite everywhere;
+/// for negative `x`, it integrates the real part, $-\text{Ei}(-t)$.
+/// # Errors
+/// If `x` is so negative that the result overflows (just under -709).
+#[inline]
+pub fn E1_integral(x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::E1_integral(x, max_precision)
+}
+
+/// The first `derivatives.len()` derivatives of E1, from the first up.
+///
+/// `derivatives[m]` is $E_{1}^{(m + 1)}(x) = (-1)^{m} \text{Ei}^{(m + 1)}(-x)$:
+/// see `Ei_derivatives`.
+/// # Errors
+/// If a derivative is too large to represent,
+/// in which case the entries before it are already filled and the rest are untouched.
+#[inline]
+pub fn E1_derivatives(x: NonZero<Finite<f64>>, derivatives: &mut [Approx]) -> Result<(), Error> {
+    implementation::Ei_derivatives(x, derivatives, true)
+}
+
+/// The derivative of Ei, $\text{Ei}'(x) = \frac{e^{x}}{x}$.
+/// # Errors
+/// If the result is too large to represent:
+/// `x` just over 709, or so close to zero that $\frac{1}{x}$ overflows.
+#[inline]
+pub fn Ei_derivative(x: NonZero<Finite<f64>>) -> Result<Approx, Error> {
+    implementation::Ei_derivative(x)
+}
+
+/// The first `derivatives.len()` derivatives of Ei, from the first up.
+///
+/// `derivatives[m]` is $\text{Ei}^{(m + 1)}(x) = \frac{\text{d}^{m}}{\text{d}x^{m}} \frac{e^{x}}{x}$,
+/// which is $e^{x}$ times a polynomial in $\frac{1}{x}$.
+/// Expanding that polynomial term by term cancels catastrophically for positive `x`,
+/// so it's never evaluated directly; each entry carries its own error estimate.
+/// # Errors
+/// If a derivative is too large to represent,
+/// in which case the entries before it are already filled and the rest are untouched.
+#[inline]
+pub fn Ei_derivatives(x: NonZero<Finite<f64>>, derivatives: &mut [Approx]) -> Result<(), Error> {
+    implementation::Ei_derivatives(x, derivatives, false)
+}
+
+/// The difference $\text{Ei}(b) - \text{Ei}(a)$, i.e. $\int_{a}^{b} \frac{e^{t}}{t} \text{d}t$ for `a` and `b` of the same sign.
+///
+/// Subtracting two calls to `Ei` destroys all accuracy when `a` and `b` are close,
+/// since the difference is then far smaller than the rounding error in either value;
+/// this switches to local series instead, keeping the error relative to the difference itself.
+/// # Errors
+/// See `Ei`.
+#[inline]
+pub fn Ei_diff(
+    a: NonZero<Finite<f64>>,
+    b: NonZero<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    implementation::Ei_diff(a, b, max_precision)
+}
+
+/// Taylor coefficients of E1 about `x`, from the constant term up.
+///
+/// `coefficients[k]` is $\frac{E_{1}^{(k)}(x)}{k!}$,
+/// so `coefficients.len()` is one more than the highest order:
+/// $E_{1}(x + h) \approx \sum_{k} \text{coefficients}[k] \, h^{k}$ for `h` within `x` of 0.
+/// # Errors
+/// See `E1` for the constant term;
+/// past that, if a coefficient is too large to represent.
+/// Either way, the entries before it are already filled and the rest are untouched.
+#[inline]
+pub fn E1_taylor(
+    x: NonZero<Finite<f64>>,
+    coefficients: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), Error> {
+    implementation::Ei_taylor(x, coefficients, true, max_precision)
+}
+
+/// The integral of Ei from 0, $\int_{0}^{x} \text{Ei}(t) \text{d}t = x \text{Ei}(x) - (e^{x} - 1)$.
+///
+/// Evaluated without the cancellation that composing `Ei` by hand suffers,
+/// both for small `x` and for large positive `x`, where both terms are nearly $e^{x}$.
+/// Ei diverges only logarithmically at zero, so this is finite everywhere.
+/// # Errors
+/// If `x` is so large that the result overflows (just over 709).
+#[inline]
+pub fn Ei_integral(x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    #![expect(
+        clippy::arithmetic_side_effects,
+        reason = "property-based testing ensures this never happens"
+    )]
+
+    implementation::E1_integral(-x, max_precision)
+}
+
+/// The natural logarithm of $|\text{Ei}(x)|$, and the sign of $\text{Ei}(x)$ as $\pm 1$,
+/// e.g. for likelihoods where Ei itself would overflow or underflow.
+///
+/// Since $\text{Ei}(x) = e^{x} \cdot e^{-x} \text{Ei}(x)$,
+/// this is `x` plus the logarithm of `Ei_scaled`, so every nonzero finite `x` goes.
+/// # Errors
+/// Never in practice, but nominally if $\text{Ei}(x)$ rounds to exactly zero.
+#[inline]
+pub fn Ei_ln(
+    x: NonZero<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<(Approx, Finite<f64>), Error> {
+    let scaled = Ei_scaled(x, max_precision);
+    let sign = Finite::new(libm::copysign(1_f64, *scaled.value));
+    let value = Finite::try_new(**x + libm::log(scaled.value.abs())).ok_or(Error::Overflow)?;
+    Ok((
+        Approx {
+            value,
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                // Multiplying `epsilon` in first keeps huge arguments from overflowing:
+                **scaled.error / scaled.value.abs()
+                    + constants::GSL_DBL_EPSILON * x.abs()
+                    + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+            )),
+        },
+        sign,
+    ))
+}
+
+/// The regular part of Ei, $\text{Ei}(x) - \ln |x| - \gamma = \sum_{k=1}^{\infty} \frac{x^k}{k \cdot k!}$,
+/// e.g. for subtracting the logarithmic singularity out of an integral equation.
+///
+/// Computed without ever forming the logarithm, so it's accurate to the last few bits even for tiny `x`
+/// (where subtracting $\ln |x|$ back out of Ei would lose everything), and defined at zero.
+/// The regular part of E1, $E_{1}(x) + \ln |x| + \gamma$, is `Ein`.
+/// # Errors
+/// If `x` is so large that the result overflows (just over 710).
+#[inline]
+pub fn Ei_regular(x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::Ei_regular(x, max_precision)
+}
+
+/// Taylor coefficients of Ei about `x`, from the constant term up,
+/// e.g. to embed Ei in a Taylor-mode integrator.
+///
+/// `coefficients[k]` is $\frac{\text{Ei}^{(k)}(x)}{k!}$,
+/// so `coefficients.len()` is one more than the highest order:
+/// $\text{Ei}(x + h) \approx \sum_{k} \text{coefficients}[k] \, h^{k}$ for `h` within `x` of 0.
+/// # Errors
+/// See `Ei` for the constant term;
+/// past that, if a coefficient is too large to represent.
+/// Either way, the entries before it are already filled and the rest are untouched.
+#[inline]
+pub fn Ei_taylor(
+    x: NonZero<Finite<f64>>,
+    coefficients: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), Error> {
+    implementation::Ei_taylor(x, coefficients, false, max_precision)
+}
+
+/// Ei on a chosen side of its branch cut, as `(real part, imaginary part)`,
+/// so that complex-analysis users get an unambiguous value.
+///
+/// The real part is always `Ei(x)`.
+/// The imaginary part is zero for negative `x` and for the principal value,
+/// and otherwise the nearest `f64` to $\mp \pi$ (see `Branch` for which is which).
+/// # Errors
+/// See `Ei`.
+#[inline]
+pub fn Ei_with_branch(
+    x: NonZero<Finite<f64>>,
+    branch: Branch,
+    max_precision: Precision,
+) -> Result<(Approx, Finite<f64>), Error> {
+    let real = Ei(x, max_precision)?;
+    let imaginary = if **x < 0_f64 {
+        0_f64
+    } else {
+        match branch {
+            Branch::LowerHalfPlane => PI,
+            Branch::PrincipalValue => 0_f64,
+            Branch::UpperHalfPlane => -PI,
+        }
+    };
+    Ok((real, Finite::new(imaginary)))
+}
+
+/// Ei and its derivative together, as `(value, derivative)`,
+/// computing the exponential they share only once (e.g. for each step of Newton's method).
+/// # Errors
+/// See `Ei`; also if `x` is so close to zero that the derivative overflows.
+#[inline]
+pub fn Ei_with_derivative(
+    x: NonZero<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<(Approx, Approx), Error> {
+    implementation::Ei_with_derivative(x, max_precision)
+}
+
+/// E1 and Ei at the same `x`, as `(E1(x), Ei(x))`,
+/// e.g. for $\text{Shi}(x) = \frac{E_{1}(x) + \text{Ei}(x)}{2}$ and $\text{Chi}(x) = \frac{\text{Ei}(x) - E_{1}(x)}{2}$.
+///
+/// Since $\text{Ei}(x) = -E_{1}(-x)$, this is E1 at both $x$ and $-x$,
+/// sharing the choice of approximation and whatever exponential or logarithm the two have in common.
+/// Up to 4 in magnitude, both agree with `E1` and `Ei` to the bit;
+/// past 4, each rounds once more than `E1` would, which the error estimates account for.
+/// # Errors
+/// See `E1`: the bounds are symmetric, so both fail together.
+#[inline]
+pub fn E1_Ei_pair(
+    x: NonZero<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<(Approx, Approx), Error> {
+    implementation::E1_Ei_pair(x, max_precision)
+}
+
+/// The offset logarithmic integral, $\text{Li}(x) = \text{li}(x) - \text{li}(2) = \int_{2}^{x} \frac{\text{d}t}{\ln t}$,
+/// the usual approximation to the prime-counting function.
+///
+/// The constant $\text{li}(2)$ is built in at full precision,
+/// and its rounding error is folded into the error estimate.
+/// # Errors
+/// If `x` isn't positive, or is exactly 1 (see `li::Error`).
+#[inline]
+pub fn Li(x: Finite<f64>, max_precision: Precision) -> Result<Approx, li::Error> {
+    implementation::Li(x, max_precision)
+}
+
+/// The logarithmic integral, $\text{li}(x) = \int_{0}^{x} \frac{\text{d}t}{\ln t} = \text{Ei}(\ln x)$.
+///
+/// Composing `Ei` with a logarithm by hand loses accuracy near $x = 1$;
+/// this handles that region with its own expansion.
+/// # Errors
+/// If `x` isn't positive, or is exactly 1 (see `li::Error`).
+#[inline]
+pub fn li(x: Finite<f64>, max_precision: Precision) -> Result<Approx, li::Error> {
+    implementation::li(x, max_precision)
+}
+
+/// The inverse of E1 on the positive axis: the positive `x` with $E_{1}(x) = y$.
+///
+/// E1 decreases from $+\infty$ at zero to 0 on the positive axis, so every positive `y` has exactly one such `x`.
+/// Large `y` put `x` at about $e^{-\gamma - y}$, which eventually underflows.
+/// # Errors
+/// If `y` is so large that `x` would be subnormal (just over 707).
+#[inline]
+pub fn E1_inv(y: Positive<Finite<f64>>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::E1_inv(y, max_precision)
+}
+
+/// The inverse exponential integral on its positive branch:
+/// the positive `x` with $\text{Ei}(x) = y$.
+///
+/// Ei increases from $-\infty$ at zero to $+\infty$ on the positive axis, so every `y` has exactly one such `x`.
+/// (Negative `y` also have a second, negative solution, which this ignores.)
+/// Very negative `y` put `x` at about $e^{y - \gamma}$, which eventually underflows.
+/// # Errors
+/// If `y` is so negative that `x` would be subnormal (just under -707).
+#[inline]
+pub fn Ei_inv(y: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::Ei_inv(y, max_precision)
+}
+
+/// The inverse logarithmic integral: the `x` greater than 1 with $\text{li}(x) = y$,
+/// e.g. for estimating the `y`th prime.
+/// Very negative `y` put `x` so close to 1 that it may round to exactly 1.
+/// # Errors
+/// If `y` is so large that `x` can't be represented (around $10^{305}$).
+#[inline]
+pub fn li_inv(y: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    implementation::li_inv(y, max_precision)
+}
+
+/// The sine integral, $\text{Si}(x) = \int_{0}^{x} \frac{\sin t}{t} \text{d}t$.
+/// # Original C code
+/// ```c
+/// int gsl_sf_Si_e(const double x, gsl_sf_result * result);
+/// ```
+/// (See `implementation::Si` for the body.)
+#[inline]
+#[must_use]
+pub fn Si(x: Finite<f64>, max_precision: Precision) -> Approx {
+    implementation::Si(x, max_precision)
+}
+
+/// The cosine integral, $\text{Ci}(x) = -\int_{x}^{\infty} \frac{\cos t}{t} \text{d}t$,
+/// defined only for positive `x` (it has a logarithmic singularity at zero).
+/// # Original C code
+/// ```c
+/// int gsl_sf_Ci_e(const double x, gsl_sf_result * result);
+/// ```
+/// (See `implementation::Ci` for the body.)
+#[inline]
+#[must_use]
+pub fn Ci(x: Positive<Finite<f64>>, max_precision: Precision) -> Approx {
+    implementation::Ci(x, max_precision)
+}
+
+/// The entire cosine integral,
+/// $\text{Cin}(x) = \int_{0}^{x} \frac{1 - \cos t}{t} \text{d}t = \gamma + \ln |x| - \text{Ci}(|x|)$.
+///
+/// Unlike Ci, this has no singularity at zero, so any finite `x` goes.
+#[inline]
+#[must_use]
+pub fn Cin(x: Finite<f64>, max_precision: Precision) -> Approx {
+    implementation::Cin(x, max_precision)
+}
+
+/// E1 on the imaginary axis, as its real and imaginary parts,
+/// e.g. for signal processing without pulling in `complex`.
+///
+/// $E_{1}(iy) = -\text{Ci}(|y|) + i \left( \text{Si}(y) - \text{sgn}(y) \frac{\pi}{2} \right)$.
+/// Cheaper than calling `Ci` and `Si` separately,
+/// and more accurate for large `y`, where the imaginary part is small
+/// but $\text{Si}(y)$ and $\frac{\pi}{2}$ are not.
+#[inline]
+#[must_use]
+pub fn E1_imag(y: NonZero<Finite<f64>>, max_precision: Precision) -> (Approx, Approx) {
+    implementation::E1_imag(y, max_precision)
+}
+
+/// The third-order exponential-type integral, $\int_{0}^{x} e^{-t^3} \text{d}t$,
+/// which saturates at $\Gamma(4/3)$ for large `x`.
+/// # Original C code
+/// ```c
+/// int gsl_sf_expint_3_e(const double x, gsl_sf_result * result);
+/// ```
+/// (See `implementation::expint_3` for the body.)
+#[inline]
+#[must_use]
+pub fn expint_3(x: NonNegative<Finite<f64>>, max_precision: Precision) -> Approx {
+    implementation::expint_3(x, max_precision)
+}
+
+/// GSL's natural form of `E1`, for line-by-line ports of C code that calls `gsl_sf_expint_E1`:
+/// the value alone, on any `double`.
+///
+/// Where `E1` would fail, this gives whatever GSL leaves in `result.val` on the same failure
+/// (after calling its error handler, which has no counterpart here):
+/// NaN at zero (a domain error), $+\infty$ below `-XMAX` (an overflow),
+/// and zero above `XMAX` (an underflow).
+/// The infinities fall into the same branches, and NaN fails every comparison on the way down
+/// and lands in the last one, so it gives zero as well.
+/// For libm-style results instead, see `lenient::e1`.
+/// # Original C code
+/// ```c
+/// double gsl_sf_expint_E1(const double x)
+/// {
+///   EVAL_RESULT(gsl_sf_expint_E1_e(x, &result));
+/// }
+/// ```
+#[inline]
+#[must_use]
+pub fn expint_E1(x: f64) -> f64 {
+    let Some(nonzero) = Finite::try_new(x).and_then(NonZero::try_new) else {
+        return if x == 0_f64 {
+            f64::NAN
+        } else if x < 0_f64 {
+            f64::INFINITY
+        } else {
+            0_f64
+        };
+    };
+    match E1(nonzero, Precision::Full) {
+        Ok(approx) => *approx.value,
+        Err(Error::ArgumentTooNegative(_) | Error::Overflow) => f64::INFINITY,
+        Err(Error::ArgumentTooPositive(_) | Error::Underflow) => 0_f64,
+        Err(Error::Domain(_)) => f64::NAN,
+    }
+}
+
+/// GSL's natural form of `Ei`, for line-by-line ports of C code that calls `gsl_sf_expint_Ei`.
+///
+/// As in GSL, this is exactly `-expint_E1(-x)`, failures included,
+/// so the overflow past `XMAX` comes out as $-\infty$ (GSL negates the $+\infty$ it reported for `E1`),
+/// and NaN comes out as negative zero.
+/// For libm-style results instead, see `lenient::ei`.
+/// # Original C code
+/// ```c
+/// double gsl_sf_expint_Ei(const double x)
+/// {
+///   EVAL_RESULT(gsl_sf_expint_Ei_e(x, &result));
+/// }
+/// ```
+#[inline]
+#[must_use]
+pub fn expint_Ei(x: f64) -> f64 {
+    -expint_E1(-x)
+}
+
+/// The upper incomplete gamma function at order zero, $\Gamma(0, x) = E_{1}(x)$,
+/// for code written against the incomplete-gamma convention.
+///
+/// As in GSL, only positive `x` are in the domain:
+/// $\Gamma(0, 0)$ diverges, and negative `x` would need a branch cut.
+/// # Original C code
+/// From `gsl_sf_gamma_inc_e`, specialized to `a = 0`:
+/// ```c
+/// if(x < 0.0) {
+///   DOMAIN_ERROR(result);
+/// }
+/// else if(x == 0.0) {
+///   return gsl_sf_gamma_e(a, result);
+/// }
+/// else if(a == 0.0)
+/// {
+///   return gsl_sf_expint_E1_e(x, result);
+/// }
+/// ```
+/// # Errors
+/// If `x` isn't positive, or is so large that E1 fails (see `E1`).
+#[inline]
+pub fn gamma_inc_0(x: Finite<f64>, max_precision: Precision) -> Result<Approx, Error> {
+    let Some(positive) = Positive::try_new(x) else {
+        return Err(Error::Domain(x));
+    };
+    implementation::E1(NonZero::new(*positive), max_precision)
+}
+
+/// The relative exponential function, $\text{exprel}(x) = \frac{e^{x} - 1}{x}$,
+/// continuous through 1 at `x = 0`.
+/// # Original C code
+/// ```c
+/// int gsl_sf_exprel_e(const double x, gsl_sf_result * result);
+/// ```
+/// (See `implementation::exprel` for the body.)
+/// # Errors
+/// If `x` is so large that the result overflows (just under 710).
+#[inline]
+pub fn exprel(x: Finite<f64>) -> Result<Approx, Error> {
+    implementation::exprel(x)
+}
+
+/// The second relative exponential function, $\text{exprel}_{2}(x) = \frac{2 (e^{x} - 1 - x)}{x^2}$,
+/// continuous through 1 at `x = 0`.
+/// # Original C code
+/// ```c
+/// int gsl_sf_exprel_2_e(double x, gsl_sf_result * result);
+/// ```
+/// (See `implementation::exprel_2` for the body.)
+/// # Errors
+/// If `x` is so large that the result overflows (just under 710).
+#[inline]
+pub fn exprel_2(x: Finite<f64>) -> Result<Approx, Error> {
+    implementation::exprel_2(x)
+}
+
+/// The `n`th relative exponential function,
+/// $\text{exprel}_{n}(x) = \frac{n!}{x^{n}} \left( e^{x} - \sum_{k=0}^{n-1} \frac{x^k}{k!} \right)$.
+///
+/// That is, $e^{x}$ with its first `n` Taylor terms removed and rescaled to 1 at `x = 0`.
+/// # Original C code
+/// ```c
+/// int gsl_sf_exprel_n_e(const int N, const double x, gsl_sf_result * result);
+/// ```
+/// (See `implementation::exprel_n` for the body.)
+/// # Errors
+/// If the result is too large to represent.
+#[inline]
+pub fn exprel_n(n: usize, x: Finite<f64>) -> Result<Approx, Error> {
+    implementation::exprel_n(n, x)
+}
@@ -0,0 +1,174 @@
+//! The auxiliary integrals of Slater-type orbitals, e.g. for two-center overlaps in quantum chemistry.
+//!
+//! $A_{n}(x) = \int_{1}^{\infty} t^{n} e^{-xt} \text{d}t = E_{-n}(x)$ and
+//! $B_{n}(x) = \int_{-1}^{1} t^{n} e^{-xt} \text{d}t$, always for a whole range of orders at once,
+//! since that's how they're consumed.
+//!
+//! Both follow from integrating by parts:
+//! $A_{n}(x) = \frac{e^{-x} + n A_{n - 1}(x)}{x}$ and
+//! $B_{n}(x) = \frac{(-1)^{n} e^{x} - e^{-x} + n B_{n - 1}(x)}{x}$.
+//! Each step scales the error it inherits by $\frac{n}{x}$,
+//! so the first is stable upward everywhere (where nothing cancels),
+//! while the second is stable upward only while $n < |x|$ and has to run downward past that.
+
+use {
+    crate::{Approx, Error, constants},
+    sigma_types::{Finite, Positive},
+};
+
+#[cfg(feature = "error")]
+use sigma_types::NonNegative;
+
+/// Power series $B_{n}(x) = \sum_{k} \frac{2 (-x)^{k}}{k! (n + k + 1)}$ over `k` with the same parity as `n`,
+/// whose terms all share a sign, so nothing cancels.
+/// Converges quickly once $n \geq |x|$, where the terms shrink at least geometrically
+/// past $k = |x|$ and the truncated tail is bounded by a geometric series from the last term.
+#[inline]
+#[cfg_attr(
+    not(test),
+    expect(clippy::single_call_fn, reason = "kept separate for readability")
+)]
+pub(crate) fn series(n: f64, x: f64) -> Approx {
+    let odd = libm::floor(0.5_f64 * n) * 2_f64 < n;
+    let mut k = if odd { 1_f64 } else { 0_f64 };
+    // $\frac{(-x)^{k}}{k!}$:
+    let mut power = if odd { -x } else { 1_f64 };
+    let mut term = 2_f64 * power / (n + k + 1_f64);
+    let mut sum = term;
+    loop {
+        power *= x * x / ((k + 1_f64) * (k + 2_f64));
+        k += 2_f64;
+        term = 2_f64 * power / (n + k + 1_f64);
+        sum += term;
+        if k > x.abs() && term.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+    }
+
+    #[cfg(feature = "error")]
+    let ratio = x * x / ((k + 1_f64) * (k + 2_f64));
+    Approx {
+        value: Finite::new(sum),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            term.abs() * ratio / (1_f64 - ratio)
+                + (k + 2_f64) * constants::GSL_DBL_EPSILON * sum.abs(),
+        )),
+    }
+}
+
+/// $A_{n}(x) = \int_{1}^{\infty} t^{n} e^{-xt} \text{d}t$ for `x > 0`
+/// and every `n` from 0 up to (not including) `orders.len()`,
+/// by upward recurrence from $A_{0}(x) = \frac{e^{-x}}{x}$.
+/// # Errors
+/// If `x` is so large that floating-point operations will fail down the line (just over 700),
+/// or if any order is too large to represent,
+/// in which case those of lower order are still filled in, but the rest of `orders` is unspecified.
+#[inline]
+pub fn A(x: Positive<Finite<f64>>, orders: &mut [Approx]) -> Result<(), Error> {
+    let raw = **x;
+    if raw >= constants::XMAX {
+        return Err(Error::ArgumentTooPositive(x));
+    }
+
+    let exponential = libm::exp(-raw);
+    let mut previous = Approx::default();
+    let mut n = 0_f64;
+    for entry in orders {
+        let value = Finite::try_new(n.mul_add(*previous.value, exponential) / raw)
+            .ok_or(Error::Overflow)?;
+        *entry = Approx {
+            value,
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                constants::GSL_DBL_EPSILON.mul_add(exponential, n * **previous.error) / raw
+                    + 2_f64 * constants::GSL_DBL_EPSILON * *value,
+            )),
+        };
+        previous = *entry;
+        n += 1_f64;
+    }
+    Ok(())
+}
+
+/// $B_{n}(x) = \int_{-1}^{1} t^{n} e^{-xt} \text{d}t$
+/// for every `n` from 0 up to (not including) `orders.len()`.
+///
+/// Runs the recurrence upward from $B_{0}(x) = \frac{2 \sinh x}{x}$ while $n < |x|$,
+/// and downward from one power series at the top after that.
+/// # Errors
+/// If any order is too large to represent (only possible past $|x| = 709$),
+/// in which case the rest of `orders` is unspecified.
+#[inline]
+pub fn B(x: Finite<f64>, orders: &mut [Approx]) -> Result<(), Error> {
+    let raw = *x;
+    let growing = Finite::try_new(libm::exp(raw)).ok_or(Error::Overflow)?;
+    let shrinking = Finite::try_new(libm::exp(-raw)).ok_or(Error::Overflow)?;
+    #[cfg(feature = "error")]
+    let boundary_error = constants::GSL_DBL_EPSILON * (raw.abs() + 1_f64) * (*growing + *shrinking);
+
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "nonnegative, and saturating if huge"
+    )]
+    let upward = (raw.abs() as usize).min(orders.len());
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "orders past 2^53 are indistinguishable in the result anyway"
+    )]
+    let top = orders.len() as f64;
+    let (below, above) = orders.split_at_mut(upward);
+
+    // Upward, where each step loses less than it keeps (so `x` is nonzero),
+    // starting from $B_{-1} = 0$ so that the first step gives $B_{0}(x) = \frac{e^{x} - e^{-x}}{x}$:
+    let mut n = 0_f64;
+    let mut sign = 1_f64;
+    let mut previous = Approx::default();
+    for entry in &mut *below {
+        let value = Finite::try_new((sign * *growing - *shrinking + n * *previous.value) / raw)
+            .ok_or(Error::Overflow)?;
+        *entry = Approx {
+            value,
+            #[cfg(feature = "error")]
+            error: NonNegative::new(Finite::new(
+                (boundary_error + n * **previous.error) / raw.abs()
+                    + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+            )),
+        };
+        previous = *entry;
+        n += 1_f64;
+        sign = -sign;
+    }
+
+    // Downward, from the series at the top:
+    // $B_{n - 1}(x) = \frac{x B_{n}(x) - (-1)^{n} e^{x} + e^{-x}}{n}$.
+    n = top;
+    let mut higher: Option<Approx> = None;
+    for entry in above.iter_mut().rev() {
+        n -= 1_f64;
+        let current = higher.map_or_else(
+            || series(n, raw),
+            |next| {
+                // $(-1)^{n + 1}$ for the order above this one:
+                let odd = libm::floor(0.5_f64 * n) * 2_f64 < n;
+                let next_sign = if odd { 1_f64 } else { -1_f64 };
+                let value = (raw * *next.value - next_sign * *growing + *shrinking) / (n + 1_f64);
+                Approx {
+                    value: Finite::new(value),
+                    #[cfg(feature = "error")]
+                    error: NonNegative::new(Finite::new(
+                        (raw.abs() * **next.error + boundary_error) / (n + 1_f64)
+                            + 2_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+                    )),
+                }
+            },
+        );
+        higher = Some(current);
+        *entry = current;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,307 @@
+//! Moments of E1, e.g. for moment methods with transport kernels.
+//!
+//! Covers both $M_{k}(x) = \int_{0}^{x} t^k E_{1}(t) \text{d}t$ for `x > 0`
+//! and the complete moments $\int_{0}^{\infty} t^k E_{1}(t) \text{d}t = \frac{k!}{k + 1}$.
+//!
+//! Integrating by parts gives the closed form
+//! $M_{k}(x) = \frac{x^{k + 1} E_{1}(x) + \gamma(k + 1, x)}{k + 1}$
+//! in terms of the lower incomplete gamma function $\gamma$.
+//! Both terms are positive, so all that's left is $\gamma(k + 1, x)$ without cancellation:
+//! a power series when $x < k + 1$, and otherwise the finite sum
+//! $\gamma(k + 1, x) = k! \left( 1 - e^{-x} \sum_{j = 0}^{k} \frac{x^j}{j!} \right)$,
+//! whose sum is then at most about a half.
+
+use {
+    crate::{Approx, Error, Precision, implementation},
+    sigma_types::{Finite, NonZero, Positive},
+};
+
+#[cfg(feature = "error")]
+use {crate::constants, sigma_types::NonNegative};
+
+/// $x^{a} e^{-x}$ through a single exponential, so neither factor overflows or underflows alone.
+/// # Errors
+/// If the result is too large to represent.
+#[inline]
+fn power_exp(a: f64, x: f64) -> Result<Approx, Error> {
+    let log = libm::log(x);
+    let exponent = a.mul_add(log, -x);
+    let value = Finite::try_new(libm::exp(exponent)).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        // Rounding in the exponent is relative error in the result:
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            constants::GSL_DBL_EPSILON
+                * (a * log).abs().mul_add(2_f64, exponent.abs() + 1_f64)
+                * *value,
+        )),
+    })
+}
+
+/// Series $\frac{e^{x}}{x^{k + 1}} \gamma(k + 1, x) = \sum_{n=0}^{\infty} \frac{x^n}{(k + 1) (k + 2) \cdots (k + 1 + n)}$,
+/// converging quickly for `x < k + 1`, where the terms shrink at least geometrically
+/// and the truncated tail is bounded by a geometric series starting from the last term.
+#[inline]
+fn scaled_lower_series(k: f64, x: f64) -> Approx {
+    let mut denominator = k + 1_f64;
+    let mut term = denominator.recip();
+    let mut sum = term;
+    loop {
+        denominator += 1_f64;
+        term *= x / denominator;
+        sum += term;
+        if term <= f64::EPSILON * sum {
+            break;
+        }
+    }
+
+    #[cfg(feature = "error")]
+    let ratio = x / (denominator + 1_f64);
+    Approx {
+        value: Finite::new(sum),
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            term * ratio / (1_f64 - ratio)
+                + 2_f64 * (denominator - k) * constants::GSL_DBL_EPSILON * sum,
+        )),
+    }
+}
+
+/// $M_{k}(x) = \frac{x^{k + 1} e^{-x}}{k + 1} \left( e^{x} E_{1}(x) + \frac{e^{x}}{x^{k + 1}} \gamma(k + 1, x) \right)$,
+/// given $x^{k + 1} e^{-x}$ and the scaled lower incomplete gamma function
+/// from the series or its recurrence.
+#[inline]
+fn from_scaled(
+    k: f64,
+    e1_scaled: Approx,
+    prefactor: Approx,
+    scaled: Approx,
+) -> Result<Approx, Error> {
+    let sum = *e1_scaled.value + *scaled.value;
+    let value = Finite::try_new(*prefactor.value * sum / (k + 1_f64)).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            *prefactor.value * (**e1_scaled.error + **scaled.error) / (k + 1_f64)
+                + **prefactor.error * sum / (k + 1_f64)
+                + 3_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// $M_{k}(x) = \frac{x^{k + 1} E_{1}(x) + \gamma(k + 1, x)}{k + 1}$,
+/// given the lower incomplete gamma function (with its absolute error).
+#[inline]
+fn from_lower(
+    k: f64,
+    x: f64,
+    e1_scaled: Approx,
+    lower: f64,
+    #[cfg(feature = "error")] lower_error: f64,
+) -> Result<Approx, Error> {
+    let prefactor = power_exp(k + 1_f64, x)?;
+    let boundary = *prefactor.value * *e1_scaled.value;
+    let value = Finite::try_new((boundary + lower) / (k + 1_f64)).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            (**prefactor.error * *e1_scaled.value
+                + *prefactor.value * **e1_scaled.error
+                + lower_error)
+                / (k + 1_f64)
+                + 3_f64 * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// The complete moment $\int_{0}^{\infty} t^k E_{1}(t) \text{d}t = \frac{k!}{k + 1}$.
+/// # Errors
+/// If the result is too large to represent, i.e. past `k = 171`.
+#[inline]
+pub fn complete(k: usize) -> Result<Approx, Error> {
+    let mut factorial = 1_f64;
+    let mut j = 0_f64;
+    for _ in 0..k {
+        j += 1_f64;
+        factorial *= j;
+        if factorial.is_infinite() {
+            return Err(Error::Overflow);
+        }
+    }
+
+    let value = Finite::try_new(factorial / (j + 1_f64)).ok_or(Error::Overflow)?;
+    Ok(Approx {
+        value,
+        #[cfg(feature = "error")]
+        error: NonNegative::new(Finite::new(
+            (j + 1_f64) * constants::GSL_DBL_EPSILON * value.abs(),
+        )),
+    })
+}
+
+/// The moment $M_{k}(x) = \int_{0}^{x} t^k E_{1}(t) \text{d}t$ for `x > 0`, straight from its closed form.
+///
+/// See `partials` to fill every order up to some `k` at once.
+/// # Errors
+/// If the result is too large to represent.
+#[inline]
+pub fn partial(
+    k: usize,
+    x: Positive<Finite<f64>>,
+    max_precision: Precision,
+) -> Result<Approx, Error> {
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "orders past 2^53 are indistinguishable in the result anyway"
+    )]
+    let order = k as f64;
+    let raw = **x;
+    let e1_scaled = implementation::E1_scaled(NonZero::new(*x), max_precision);
+
+    if raw < order + 1_f64 {
+        // Checked first: if this overflows, `x` could be close enough to `k` to need many terms.
+        let prefactor = power_exp(order + 1_f64, raw)?;
+        return from_scaled(order, e1_scaled, prefactor, scaled_lower_series(order, raw));
+    }
+
+    // $e^{-x} \sum_{j = 0}^{k} \frac{x^j}{j!}$, largest term first since `j < x`:
+    let factorial = libm::tgamma(order + 1_f64);
+    if !factorial.is_finite() {
+        return Err(Error::Overflow);
+    }
+    let power = power_exp(order, raw)?;
+    #[cfg(feature = "error")]
+    let relative = if *power.value > 0_f64 {
+        **power.error / *power.value
+    } else {
+        0_f64
+    };
+    let mut term = *power.value / factorial;
+    let mut sum = term;
+    let mut j = order;
+    while j > 0_f64 && term > f64::EPSILON * sum {
+        term *= j / raw;
+        sum += term;
+        j -= 1_f64;
+    }
+
+    #[cfg(feature = "error")]
+    let lower_error = factorial
+        * sum.mul_add(
+            (order + 3_f64).mul_add(constants::GSL_DBL_EPSILON, relative),
+            2_f64 * constants::GSL_DBL_EPSILON,
+        );
+    from_lower(
+        order,
+        raw,
+        e1_scaled,
+        factorial * (1_f64 - sum),
+        #[cfg(feature = "error")]
+        lower_error,
+    )
+}
+
+/// The moments $M_{k}(x) = \int_{0}^{x} t^k E_{1}(t) \text{d}t$ for `x > 0`
+/// and every `k` from 0 up to (not including) `moments.len()`.
+///
+/// Rather than each closed form, runs the recurrence
+/// $\gamma(k + 1, x) = k \gamma(k, x) - x^k e^{-x}$
+/// in whichever direction is stable: upward while $k < x$,
+/// and downward (from one series at the top) after that,
+/// propagating the error of each step into the next.
+/// # Errors
+/// If any moment is too large to represent,
+/// in which case those of lower order are still filled in, but the rest of `moments` is unspecified.
+#[inline]
+pub fn partials(
+    x: Positive<Finite<f64>>,
+    moments: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), Error> {
+    let raw = **x;
+    let e1_scaled = implementation::E1_scaled(NonZero::new(*x), max_precision);
+
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "positive, and saturating if huge"
+    )]
+    let upward = (raw as usize).min(moments.len());
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "orders past 2^53 are indistinguishable in the result anyway"
+    )]
+    let top = moments.len() as f64;
+    let (below, above) = moments.split_at_mut(upward);
+
+    // Upward, where each step loses less than it keeps:
+    let mut order = 0_f64;
+    // $\gamma(1, x) = 1 - e^{-x}$:
+    let mut lower = -libm::expm1(-raw);
+    #[cfg(feature = "error")]
+    let mut lower_error = constants::GSL_DBL_EPSILON * lower;
+    for moment in &mut *below {
+        if order > 0_f64 {
+            let power = power_exp(order, raw)?;
+            let kept = order * lower;
+            lower = kept - *power.value;
+            #[cfg(feature = "error")]
+            {
+                lower_error = order.mul_add(
+                    lower_error,
+                    **power.error + 2_f64 * constants::GSL_DBL_EPSILON * kept,
+                );
+            }
+        }
+        *moment = from_lower(
+            order,
+            raw,
+            e1_scaled,
+            lower,
+            #[cfg(feature = "error")]
+            lower_error,
+        )?;
+        order += 1_f64;
+    }
+
+    // Downward, scaled by $\frac{e^{x}}{x^{k + 1}}$ to stay representable:
+    // $S_{k - 1} = \frac{1 + x S_{k}}{k}$.
+    let turning = order;
+    order = top;
+    let mut scaled: Option<Approx> = None;
+    for moment in above.iter_mut().rev() {
+        order -= 1_f64;
+        let current = scaled.map_or_else(
+            || scaled_lower_series(order, raw),
+            |higher| {
+                let value = raw.mul_add(*higher.value, 1_f64) / (order + 1_f64);
+                Approx {
+                    value: Finite::new(value),
+                    #[cfg(feature = "error")]
+                    error: NonNegative::new(Finite::new(
+                        raw * **higher.error / (order + 1_f64)
+                            + 2_f64 * constants::GSL_DBL_EPSILON * value,
+                    )),
+                }
+            },
+        );
+        scaled = Some(current);
+        *moment = current;
+    }
+
+    // Then back up, unscaling in order so anything too large comes last:
+    order = turning;
+    for moment in above {
+        let prefactor = power_exp(order + 1_f64, raw)?;
+        *moment = from_scaled(order, e1_scaled, prefactor, *moment)?;
+        order += 1_f64;
+    }
+
+    Ok(())
+}
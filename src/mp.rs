@@ -0,0 +1,166 @@
+//! E1 and Ei to any number of bits, e.g. as an oracle for testing the double-precision kernels.
+//!
+//! No Chebyshev fits here: every value comes from the convergent series
+//! $\text{Ei}(x) = \gamma + \ln |x| + \sum_{k = 1}^{\infty} \frac{x^{k}}{k \cdot k!}$,
+//! with $\gamma$ itself from a Bessel-function formula (Brent's algorithm B1).
+//! The series is truncated only once its terms shrink geometrically by at least half,
+//! so what's left is at most twice the next term.
+//! Where its terms cancel (below zero, and near the zero of Ei),
+//! the working precision grows by however many bits the cancellation costs,
+//! measured from the sum itself, plus a fixed margin for rounding along the way.
+//!
+//! No original C code: GSL has no arbitrary-precision functions.
+
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "arbitrary-precision arithmetic doesn't overflow"
+)]
+#![expect(
+    clippy::single_call_fn,
+    reason = "each step is kept separate for readability"
+)]
+
+use {
+    crate::{Error, constants},
+    core::{f64::consts::LOG2_E, num::NonZeroUsize},
+    dashu_float::{
+        FBig,
+        ops::{Abs as _, EstimatedLog2 as _},
+        round::mode::HalfEven,
+    },
+    sigma_types::{Finite, Negative, NonZero, Positive},
+};
+
+/// Bits carried beyond the requested precision,
+/// enough to absorb the rounding error of a few thousand operations with plenty to spare.
+const GUARD_BITS: usize = 64;
+
+/// A binary floating-point number of any precision, rounded to nearest (ties to even).
+pub type Float = FBig<HalfEven, 2>;
+
+/// An `f64` as a `Float` of `bits` precision (exactly, since `bits` is never below 53).
+#[inline]
+fn convert(x: f64, bits: usize) -> Result<Float, Error> {
+    Float::try_from(x)
+        .map_err(|_| Error::Domain(Finite::new(x)))
+        .map(|exact| exact.with_precision(bits).value())
+}
+
+/// $E_{1}(x)$ to `precision_bits` significant bits,
+/// within just over half a unit in the last place.
+/// # Errors
+/// If $|x|$ reaches `XMAX`, exactly as for the double-precision `E1`,
+/// so the two can be compared anywhere the latter is defined.
+#[inline]
+pub fn E1(x: NonZero<Finite<f64>>, precision_bits: NonZeroUsize) -> Result<Float, Error> {
+    let raw = **x;
+    if raw <= constants::NXMAX {
+        return Err(Error::ArgumentTooNegative(Negative::new(*x)));
+    }
+    if raw >= constants::XMAX {
+        return Err(Error::ArgumentTooPositive(Positive::new(*x)));
+    }
+    let target = precision_bits.get() + GUARD_BITS;
+    // $E_{1}(x) = -\text{Ei}(-x)$, whose series terms cancel down from about $e^{x}$ to about $e^{-x}$ for positive `x`:
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "a nonnegative whole number of bits, at most a few thousand"
+    )]
+    let cancellation = libm::ceil(2_f64 * raw.max(0_f64) * LOG2_E) as usize;
+    let mut bits = target + cancellation;
+    loop {
+        let (sum, magnitude) = Ei_series(-raw, bits)?;
+        let lost = if sum.repr().is_zero() {
+            bits
+        } else {
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                reason = "a nonnegative whole number of bits, at most a few thousand"
+            )]
+            let estimate = libm::ceilf(magnitude.log2_est() - sum.log2_est()).max(0_f32) as usize;
+            estimate + 1
+        };
+        if bits >= target + lost {
+            return Ok(-sum.with_precision(precision_bits.get()).value());
+        }
+        bits = target + lost;
+    }
+}
+
+/// $\text{Ei}(x) = -E_{1}(-x)$ to `precision_bits` significant bits,
+/// within just over half a unit in the last place.
+/// # Errors
+/// See `E1`.
+#[inline]
+pub fn Ei(x: NonZero<Finite<f64>>, precision_bits: NonZeroUsize) -> Result<Float, Error> {
+    E1(NonZero::new(Finite::new(-**x)), precision_bits).map(|value| -value)
+}
+
+/// The Euler-Mascheroni constant to about `bits` bits, by Brent's algorithm B1:
+/// $\gamma \approx \frac{U}{V} $, where $V = \sum_{k} \left( \frac{n^{k}}{k!} \right)^{2}$
+/// and $U$ weights each term of $V$ by $H_{k} - \ln n$,
+/// with an error of about $\pi e^{-4n}$.
+#[inline]
+fn euler_gamma(bits: usize) -> Float {
+    // Just over the $n = \frac{\ln 2}{4} b$ at which $e^{-4n} = 2^{-b}$:
+    let order = bits.div_ceil(5) + 2;
+    let order_squared = Float::from(order).with_precision(bits).value().sqr();
+    let mut weighted_term = -Float::from(order).with_precision(bits).value().ln();
+    let mut term = Float::ONE.with_precision(bits).value();
+    let mut weighted = weighted_term.clone();
+    let mut total = term.clone();
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "a few thousand bits at most, far below where `f32` loses integers"
+    )]
+    let negligible = bits as f32 + 8_f32;
+    for k in 1_usize.. {
+        term = term * &order_squared / k / k;
+        weighted_term = (weighted_term * &order_squared / k + &term) / k;
+        weighted += &weighted_term;
+        total += &term;
+        // Past $2n$, each term is under a quarter of the last, so what's left is under a third of this one:
+        if k > 2 * order
+            && total.log2_est() - (weighted_term.clone().abs() + &term).log2_est() > negligible
+        {
+            break;
+        }
+    }
+    weighted / total
+}
+
+/// $\text{Ei}(x)$ from its convergent series at `bits` of working precision,
+/// alongside the sum of the magnitudes of everything added up to get it,
+/// which bounds how much rounding error could have built up.
+#[inline]
+fn Ei_series(x: f64, bits: usize) -> Result<(Float, Float), Error> {
+    let y = convert(x, bits)?;
+    let gamma = euler_gamma(bits);
+    let logarithm = y.clone().abs().ln();
+    let mut magnitude = gamma.clone() + logarithm.clone().abs();
+    let mut sum = gamma + logarithm;
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_precision_loss,
+        reason = "a few thousand bits at most, far below where `f32` loses integers"
+    )]
+    let negligible = bits as f32 + 8_f32;
+    // $\frac{x^{k}}{k!}$:
+    let mut power = Float::ONE.with_precision(bits).value();
+    for k in 1_u32.. {
+        power = power * &y / k;
+        let term = power.clone() / k;
+        magnitude += term.clone().abs();
+        sum += &term;
+        // Past $k = 2|x|$, each term is under half the last, so what's left is under this one:
+        if f64::from(k) >= 2_f64 * x.abs() && magnitude.log2_est() - term.log2_est() > negligible {
+            break;
+        }
+    }
+    Ok((sum, magnitude))
+}
@@ -0,0 +1,118 @@
+//! E1 and Ei over slices split across every core, e.g. for transport solvers with millions of grid points.
+//!
+//! Same results as `E1_slice` and friends at the crate root, to the bit and in the same places:
+//! each entry is only ever written to its own position, whichever thread gets to it.
+//! As there, every entry is checked before any is written (here in parallel too),
+//! and the failure reported is the first by position, not by time.
+//!
+//! The output is cut into chunks of `CHUNK_BYTES`, each on one thread,
+//! with every boundary between chunks on a `LINE_BYTES` boundary in memory
+//! (after a short unaligned head, evaluated before the rest),
+//! so no two threads ever write to the same cache line.
+//!
+//! No original C code: GSL evaluates one point at a time.
+
+use {
+    crate::{Approx, BatchError, Precision, implementation},
+    rayon::{
+        iter::{IndexedParallelIterator as _, ParallelIterator as _},
+        slice::{ParallelSlice as _, ParallelSliceMut as _},
+    },
+};
+
+/// Bytes of output per chunk:
+/// enough evaluations that scheduling them is noise, and a multiple of `LINE_BYTES`.
+const CHUNK_BYTES: usize = 1 << 15;
+
+/// Alignment of the boundaries between chunks,
+/// which covers every common cache line, including the pairs of 64-byte lines some processors fetch together.
+const LINE_BYTES: usize = 128;
+
+/// `implementation::E1_slice`, in parallel.
+/// # Errors
+/// See `E1_slice` at the crate root.
+#[inline]
+fn slice<T: Send>(
+    xs: &[f64],
+    out: &mut [T],
+    reflect: bool,
+    convert: impl Fn(Approx) -> T + Sync,
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    if xs.len() != out.len() {
+        return Err(BatchError::LengthMismatch {
+            inputs: xs.len(),
+            outputs: out.len(),
+        });
+    }
+    let chunk = CHUNK_BYTES.div_ceil(size_of::<T>().max(1));
+
+    if let Some(error) = xs
+        .par_chunks(chunk)
+        .enumerate()
+        .find_map_first(|(k, inputs)| {
+            implementation::check_slice(inputs, reflect, k.wrapping_mul(chunk)).err()
+        })
+    {
+        return Err(error);
+    }
+
+    let head = out.as_ptr().align_offset(LINE_BYTES).min(out.len());
+    let (head_xs, body_xs) = xs.split_at(head);
+    let (head_out, body_out) = out.split_at_mut(head);
+    implementation::fill_slice(head_xs, head_out, reflect, &convert, 0, max_precision)?;
+    body_xs
+        .par_chunks(chunk)
+        .zip(body_out.par_chunks_mut(chunk))
+        .enumerate()
+        .try_for_each(|(k, (inputs, outputs))| {
+            implementation::fill_slice(
+                inputs,
+                outputs,
+                reflect,
+                &convert,
+                head.wrapping_add(k.wrapping_mul(chunk)),
+                max_precision,
+            )
+        })
+}
+
+/// `E1_slice` at the crate root, split across every core.
+/// # Errors
+/// See `E1_slice` at the crate root.
+#[inline]
+pub fn E1_slice(xs: &[f64], out: &mut [f64], max_precision: Precision) -> Result<(), BatchError> {
+    slice(xs, out, false, |approx| *approx.value, max_precision)
+}
+
+/// `E1_slice_approx` at the crate root, split across every core.
+/// # Errors
+/// See `E1_slice` at the crate root.
+#[inline]
+pub fn E1_slice_approx(
+    xs: &[f64],
+    out: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    slice(xs, out, false, |approx| approx, max_precision)
+}
+
+/// `Ei_slice` at the crate root, split across every core.
+/// # Errors
+/// See `Ei_slice` at the crate root.
+#[inline]
+pub fn Ei_slice(xs: &[f64], out: &mut [f64], max_precision: Precision) -> Result<(), BatchError> {
+    slice(xs, out, true, |approx| *approx.value, max_precision)
+}
+
+/// `Ei_slice_approx` at the crate root, split across every core.
+/// # Errors
+/// See `Ei_slice` at the crate root.
+#[inline]
+pub fn Ei_slice_approx(
+    xs: &[f64],
+    out: &mut [Approx],
+    max_precision: Precision,
+) -> Result<(), BatchError> {
+    slice(xs, out, true, |approx| approx, max_precision)
+}
@@ -0,0 +1,185 @@
+//! E1 and Ei to about 32 significant digits, e.g. for validating double-precision kernels.
+//!
+//! Same pieces as the double-precision functions,
+//! but with every Chebyshev fit (and the Taylor series about Ei's zero) regenerated in double-double,
+//! out to where the terms drop below $10^{-34}$,
+//! and every step, including the exponentials and logarithms, carried out in double-double arithmetic.
+//! Inputs are ordinary `f64`s, since that's what the kernels being checked take.
+//!
+//! Results smaller than about $10^{-290}$ lose digits as their low word falls into the subnormals.
+
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "floating-point arithmetic saturates instead of panicking"
+)]
+#![cfg_attr(
+    not(feature = "double-double"),
+    expect(
+        clippy::single_call_fn,
+        reason = "each piece is kept separate to share with the `double-double` feature"
+    )
+)]
+
+use {
+    crate::{
+        Error, chebyshev, constants,
+        double_double::{self, DoubleDouble},
+    },
+    sigma_types::{Finite, Negative, NonZero, Positive},
+};
+
+/// $\frac{e^{-x}}{x} (1 + c)$, the shape of every piece away from the origin,
+/// or $\frac{1 + c}{x}$ if `scale`d by $e^{x}$.
+#[inline]
+fn decaying(x: f64, c: DoubleDouble, scale: bool) -> DoubleDouble {
+    let numerator = if scale {
+        c + 1_f64
+    } else {
+        double_double::exp(-x) * (c + 1_f64)
+    };
+    numerator / x
+}
+
+/// $E_{1}(x)$ to double-double precision.
+/// # Errors
+/// If `x` is so large that floating-point operations will fail down the line (absolute value of just over 700),
+/// exactly as for the double-precision `E1`.
+#[inline]
+pub fn E1(x: NonZero<Finite<f64>>) -> Result<DoubleDouble, Error> {
+    let raw = **x;
+    Ok(if raw <= constants::NXMAX {
+        return Err(Error::ArgumentTooNegative(Negative::new(*x)));
+    } else if raw <= -10_f64 {
+        le_neg_10(raw, false)
+    } else if raw <= -4_f64 {
+        le_neg_4(raw, false)
+    } else if raw <= -1_f64 {
+        le_neg_1(raw, false)
+    } else if raw < 0_f64 && (raw + constants::EI_ZERO).abs() < constants::ROOT_RADIUS {
+        near_root(raw, false)
+    } else if raw <= 1_f64 {
+        le_pos_1(raw, false)
+    } else if raw <= 4_f64 {
+        le_pos_4(raw, false)
+    } else if raw < constants::XMAX {
+        le_pos_max(raw, false)
+    } else {
+        return Err(Error::ArgumentTooPositive(Positive::new(*x)));
+    })
+}
+
+/// Between -4 and -1: see `implementation::piecewise::le_neg_1`.
+#[inline]
+pub(crate) fn le_neg_1(x: f64, scale: bool) -> DoubleDouble {
+    let value = chebyshev::eval_scalar(
+        &constants::quad::E11,
+        (DoubleDouble::from(x) * 2_f64 + 5_f64) / 3_f64,
+    ) - double_double::ln(-x);
+    if scale {
+        value * double_double::exp(x)
+    } else {
+        value
+    }
+}
+
+/// At most -10: see `implementation::piecewise::le_neg_10`.
+#[inline]
+pub(crate) fn le_neg_10(x: f64, scale: bool) -> DoubleDouble {
+    decaying(
+        x,
+        chebyshev::eval_scalar(
+            &constants::quad::AE11,
+            DoubleDouble::from(20_f64) / x + 1_f64,
+        ),
+        scale,
+    )
+}
+
+/// Between -10 and -4: see `implementation::piecewise::le_neg_4`.
+#[inline]
+pub(crate) fn le_neg_4(x: f64, scale: bool) -> DoubleDouble {
+    decaying(
+        x,
+        chebyshev::eval_scalar(
+            &constants::quad::AE12,
+            (DoubleDouble::from(40_f64) / x + 7_f64) / 3_f64,
+        ),
+        scale,
+    )
+}
+
+/// Between -1 and +1: see `implementation::piecewise::le_pos_1`.
+#[inline]
+pub(crate) fn le_pos_1(x: f64, scale: bool) -> DoubleDouble {
+    le_pos_1_with_ln(x, double_double::ln(x.abs()), scale)
+}
+
+/// `le_pos_1`, given $\ln |x|$ (e.g. shared between $x$ and $-x$).
+#[inline]
+pub(crate) fn le_pos_1_with_ln(x: f64, ln: DoubleDouble, scale: bool) -> DoubleDouble {
+    let value =
+        chebyshev::eval_scalar(&constants::quad::E12, DoubleDouble::from(x)) - ln - 0.6875_f64 + x;
+    if scale {
+        value * double_double::exp(x)
+    } else {
+        value
+    }
+}
+
+/// Between +1 and +4: see `implementation::piecewise::le_pos_4`.
+#[inline]
+pub(crate) fn le_pos_4(x: f64, scale: bool) -> DoubleDouble {
+    decaying(
+        x,
+        chebyshev::eval_scalar(
+            &constants::quad::AE13,
+            (DoubleDouble::from(8_f64) / x - 5_f64) / 3_f64,
+        ),
+        scale,
+    )
+}
+
+/// Above +4: see `implementation::piecewise::le_pos_max`.
+#[inline]
+pub(crate) fn le_pos_max(x: f64, scale: bool) -> DoubleDouble {
+    decaying(
+        x,
+        chebyshev::eval_scalar(
+            &constants::quad::AE14,
+            DoubleDouble::from(8_f64) / x - 1_f64,
+        ),
+        scale,
+    )
+}
+
+/// Within `ROOT_RADIUS` of minus the zero of Ei: see `implementation::piecewise::near_root`.
+#[inline]
+pub(crate) fn near_root(x: f64, scale: bool) -> DoubleDouble {
+    // Exact by Sterbenz's lemma, as in the double-precision `near_root`:
+    let distance = DoubleDouble::from(-x - constants::EI_ZERO)
+        - DoubleDouble {
+            hi: constants::EI_ZERO_LO,
+            lo: constants::quad::EI_ZERO_LO_LO,
+        };
+    let sum = constants::quad::EI_ROOT
+        .iter()
+        .rev()
+        .fold(DoubleDouble::default(), |sum, &(hi, lo)| {
+            sum * distance + DoubleDouble { hi, lo }
+        });
+    // $E_{1}(x) = -\text{Ei}(-x)$:
+    let value = -(sum * distance);
+    if scale {
+        value * double_double::exp(x)
+    } else {
+        value
+    }
+}
+
+/// $\text{Ei}(x) = -E_{1}(-x)$ to double-double precision.
+/// # Errors
+/// See `E1`.
+#[inline]
+pub fn Ei(x: NonZero<Finite<f64>>) -> Result<DoubleDouble, Error> {
+    E1(NonZero::new(Finite::new(-**x))).map(|value| -value)
+}
@@ -0,0 +1,252 @@
+//! E1 and Ei generic over the floating-point type, so that one code path serves every precision.
+//!
+//! Same pieces and Chebyshev fits as the double-precision functions,
+//! with each table cut off where the type stops being able to tell:
+//! for single-precision types, at its last coefficient above `f32::EPSILON` (never past GSL's `order_sp`)
+//! and rounded to `f32` at compile time, and in full otherwise.
+//! Unlike the `f64` functions at the crate root, there's no error estimate,
+//! so the arithmetic is exactly what the type itself does.
+
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "floating-point arithmetic saturates instead of panicking"
+)]
+
+use {
+    crate::{
+        Error,
+        chebyshev::{self, Scalar},
+        constants,
+    },
+    core::{
+        fmt,
+        ops::{Add, Div, Mul, Neg, Sub},
+    },
+    sigma_types::{CanBeInfinite, Finite, Negative, NonZero, Positive, Zero},
+};
+
+/// A floating-point type E1 and Ei can be evaluated in.
+///
+/// Implemented for `f32` and `f64`;
+/// anything else with the usual arithmetic, an exponential, and a logarithm can implement it too.
+pub trait Real:
+    Add<Output = Self>
+    + CanBeInfinite
+    + Copy
+    + fmt::Debug
+    + Div<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + PartialOrd
+    + Scalar<Coefficient = f64>
+    + Sub<Output = Self>
+    + Zero
+{
+    /// Whether the single-precision tables (see `Series::single_order`) are enough for this type.
+    const SINGLE_PRECISION: bool = false;
+
+    /// Past this, $E_{1}(x)$ underflows and $\text{Ei}(-x)$ overflows:
+    /// $-\ln$ of the smallest normal value, minus its own logarithm (see `XMAX` for `f64`).
+    const XMAX: f64;
+
+    /// Absolute value.
+    #[must_use]
+    fn abs(self) -> Self;
+
+    /// $e^{x}$.
+    #[must_use]
+    fn exp(self) -> Self;
+
+    /// The nearest value to an `f64`.
+    #[must_use]
+    fn from_f64(x: f64) -> Self;
+
+    /// Natural logarithm of a positive value.
+    #[must_use]
+    fn ln(self) -> Self;
+
+    /// The nearest `f64`.
+    #[must_use]
+    fn to_f64(self) -> f64;
+}
+
+impl Real for f32 {
+    const SINGLE_PRECISION: bool = true;
+    const XMAX: f64 = 82.866_8_f64;
+
+    #[inline]
+    fn abs(self) -> Self {
+        libm::fabsf(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        libm::expf(self)
+    }
+
+    #[inline]
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        reason = "rounding is the point"
+    )]
+    fn from_f64(x: f64) -> Self {
+        x as Self
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        libm::logf(self)
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl Real for f64 {
+    const XMAX: Self = constants::XMAX;
+
+    #[inline]
+    fn abs(self) -> Self {
+        libm::fabs(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        libm::exp(self)
+    }
+
+    #[inline]
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        libm::log(self)
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+/// A Chebyshev series from its single-precision copy if that's all `R` can use,
+/// or through every coefficient otherwise.
+#[inline]
+fn series<R: Real>(coefficients: &[f64], single: &[f32], x: R) -> R {
+    if R::SINGLE_PRECISION {
+        chebyshev::recurrence(single, x, |c| R::from_f64(f64::from(c)))
+    } else {
+        chebyshev::eval_scalar(coefficients, x)
+    }
+}
+
+/// $\frac{e^{-x}}{x} (1 + c)$, the shape of every piece away from the origin.
+#[inline]
+fn decaying<R: Real>(x: R, c: R) -> R {
+    (R::from_f64(1_f64) / x) * (-x).exp() * (R::from_f64(1_f64) + c)
+}
+
+/// $E_{1}(x) = \int_{1}^{\infty} \frac{e^{-xt}}{t} \text{d}t$ in any `Real` type.
+/// # Errors
+/// If $|x|$ reaches `R::XMAX`, past which floating-point operations will fail down the line.
+#[inline]
+pub fn E1<R: Real>(x: NonZero<Finite<R>>) -> Result<Finite<R>, Error> {
+    let raw = **x;
+    let wide = raw.to_f64();
+    let value = if wide <= -R::XMAX {
+        return Err(Error::ArgumentTooNegative(Negative::new(Finite::new(wide))));
+    } else if wide <= -10_f64 {
+        decaying(
+            raw,
+            series(
+                &constants::AE11,
+                &constants::single::AE11,
+                R::from_f64(20_f64) / raw + R::from_f64(1_f64),
+            ),
+        )
+    } else if wide <= -4_f64 {
+        decaying(
+            raw,
+            series(
+                &constants::AE12,
+                &constants::single::AE12,
+                (R::from_f64(40_f64) / raw + R::from_f64(7_f64)) / R::from_f64(3_f64),
+            ),
+        )
+    } else if wide <= -1_f64 {
+        series(
+            &constants::E11,
+            &constants::single::E11,
+            (R::from_f64(2_f64) * raw + R::from_f64(5_f64)) / R::from_f64(3_f64),
+        ) - (-raw).ln()
+    } else if wide < 0_f64 && (wide + constants::EI_ZERO).abs() < constants::ROOT_RADIUS {
+        near_root(raw)
+    } else if wide <= 1_f64 {
+        series(&constants::E12, &constants::single::E12, raw)
+            - raw.abs().ln()
+            - R::from_f64(0.6875_f64)
+            + raw
+    } else if wide <= 4_f64 {
+        decaying(
+            raw,
+            series(
+                &constants::AE13,
+                &constants::single::AE13,
+                (R::from_f64(8_f64) / raw - R::from_f64(5_f64)) / R::from_f64(3_f64),
+            ),
+        )
+    } else if wide < R::XMAX {
+        decaying(
+            raw,
+            series(
+                &constants::AE14,
+                &constants::single::AE14,
+                R::from_f64(8_f64) / raw - R::from_f64(1_f64),
+            ),
+        )
+    } else {
+        return Err(Error::ArgumentTooPositive(Positive::new(Finite::new(wide))));
+    };
+    Ok(Finite::new(value))
+}
+
+/// $\text{Ei}(x) = -E_{1}(-x)$ in any `Real` type.
+/// # Errors
+/// See `E1`.
+#[inline]
+pub fn Ei<R: Real>(x: NonZero<Finite<R>>) -> Result<Finite<R>, Error> {
+    E1(NonZero::new(Finite::new(-**x))).map(|value| Finite::new(-*value))
+}
+
+/// Within `ROOT_RADIUS` of minus the zero of Ei, as in `piecewise::near_root`,
+/// through the term that drops below single precision at the edge if that's all `R` can use.
+#[inline]
+#[expect(clippy::single_call_fn, reason = "to mirror `piecewise::near_root`")]
+fn near_root<R: Real>(x: R) -> R {
+    let zero = R::from_f64(constants::EI_ZERO);
+    let zero_lo = R::from_f64((constants::EI_ZERO - zero.to_f64()) + constants::EI_ZERO_LO);
+    // Exact by Sterbenz's lemma, since `-x` is within a factor of 2 of the zero:
+    let distance = (-x - zero) - zero_lo;
+    let sum = if R::SINGLE_PRECISION {
+        constants::single::EI_ROOT
+            .iter()
+            .rev()
+            .fold(<R as Zero>::ZERO, |sum, &coefficient| {
+                sum * distance + R::from_f64(f64::from(coefficient))
+            })
+    } else {
+        constants::EI_ROOT
+            .iter()
+            .rev()
+            .fold(<R as Zero>::ZERO, |sum, &coefficient| {
+                sum * distance + R::from_f64(coefficient)
+            })
+    };
+    // $E_{1}(x) = -\text{Ei}(-x)$:
+    -(sum * distance)
+}
@@ -0,0 +1,187 @@
+//! E1 and Ei at a whole SIMD vector of points at once, e.g. for filling large grids.
+//!
+//! Same pieces and Chebyshev fits as the double-precision functions,
+//! with every lane stepping through the same recurrence in lockstep (see `chebyshev::eval_simd`).
+//! The piecewise dispatch becomes a mask per piece:
+//! each piece that any lane falls into is evaluated across the whole vector,
+//! then kept only in the lanes that belong to it.
+//! Exponentials and logarithms have no portable vector form, so those go through `libm` one lane at a time.
+//! As in `real`, there's no error estimate, and every fit is used in full.
+//!
+//! Needs a nightly compiler, for `core::simd`.
+//!
+//! No original C code: GSL evaluates one point at a time.
+
+#![expect(
+    clippy::arithmetic_side_effects,
+    reason = "floating-point arithmetic saturates instead of panicking"
+)]
+
+use {
+    crate::{chebyshev, constants},
+    core::{
+        error, fmt,
+        simd::{Mask, Select as _, Simd, cmp::SimdPartialOrd as _, num::SimdFloat as _},
+    },
+    sigma_types::{Finite, Negative},
+};
+
+/// Why a vector couldn't be evaluated.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[expect(
+    clippy::error_impl_error,
+    reason = "the name every caller already uses, qualified as `exponential_integral::simd::Error`"
+)]
+pub enum Error {
+    /// A lane was zero, infinite, or NaN.
+    Domain(f64),
+    /// Every lane was finite and nonzero, but one was too large in magnitude.
+    Range(crate::Error),
+}
+
+impl fmt::Display for Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Domain(arg) => write!(f, "Argument outside the domain: {arg}"),
+            Self::Range(ref e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "`Display` already includes any wrapped `crate::Error`, so there's no separate cause, and the rest are deprecated, unstable, or sealed"
+)]
+impl error::Error for Error {}
+
+/// $\frac{e^{-x}}{x} (1 + c)$ in every lane, the shape of every piece away from the origin.
+#[inline]
+fn decaying<const N: usize>(x: Simd<f64, N>, c: Simd<f64, N>) -> Simd<f64, N> {
+    (Simd::splat(1_f64) / x) * lanewise(-x, libm::exp) * (Simd::splat(1_f64) + c)
+}
+
+/// Overwrite the lanes of `value` set in `mask` with `piece`,
+/// which is only evaluated if any of them are.
+#[inline]
+fn fill<const N: usize>(
+    value: &mut Simd<f64, N>,
+    mask: Mask<i64, N>,
+    piece: impl FnOnce() -> Simd<f64, N>,
+) {
+    if mask.any() {
+        *value = mask.select(piece(), *value);
+    }
+}
+
+/// `f` applied to each lane in turn, for what has no vector form.
+#[inline]
+fn lanewise<const N: usize>(x: Simd<f64, N>, f: fn(f64) -> f64) -> Simd<f64, N> {
+    Simd::from_array(x.to_array().map(f))
+}
+
+/// $E_{1}(x) = \int_{1}^{\infty} \frac{e^{-xt}}{t} \text{d}t$ in every lane of `x`.
+/// # Errors
+/// If any lane is zero or not finite, or at least `XMAX` in magnitude (see `E1`),
+/// reporting the first such lane.
+#[inline]
+pub fn E1<const N: usize>(x: Simd<f64, N>) -> Result<Simd<f64, N>, Error> {
+    for &lane in x.as_array() {
+        if !lane.is_finite() || lane == 0_f64 {
+            return Err(Error::Domain(lane));
+        }
+        if lane <= constants::NXMAX {
+            return Err(Error::Range(crate::Error::ArgumentTooNegative(
+                Negative::new(Finite::new(lane)),
+            )));
+        }
+        if lane >= constants::XMAX {
+            return Err(Error::Range(crate::Error::Underflow));
+        }
+    }
+
+    let le_neg_10 = x.simd_le(Simd::splat(-10_f64));
+    let le_neg_4 = x.simd_le(Simd::splat(-4_f64));
+    let le_neg_1 = x.simd_le(Simd::splat(-1_f64));
+    let le_pos_1 = x.simd_le(Simd::splat(1_f64));
+    let le_pos_4 = x.simd_le(Simd::splat(4_f64));
+    let near_root = !le_neg_1
+        & x.simd_lt(Simd::splat(0_f64))
+        & (x + Simd::splat(constants::EI_ZERO))
+            .abs()
+            .simd_lt(Simd::splat(constants::ROOT_RADIUS));
+
+    let mut value = Simd::splat(0_f64);
+    fill(&mut value, le_neg_10, || {
+        decaying(
+            x,
+            chebyshev::eval_simd(
+                &constants::AE11,
+                Simd::splat(20_f64) / x + Simd::splat(1_f64),
+            ),
+        )
+    });
+    fill(&mut value, le_neg_4 & !le_neg_10, || {
+        decaying(
+            x,
+            chebyshev::eval_simd(
+                &constants::AE12,
+                (Simd::splat(40_f64) / x + Simd::splat(7_f64)) / Simd::splat(3_f64),
+            ),
+        )
+    });
+    fill(&mut value, le_neg_1 & !le_neg_4, || {
+        -lanewise(x.abs(), libm::log)
+            + chebyshev::eval_simd(
+                &constants::E11,
+                (Simd::splat(2_f64) * x + Simd::splat(5_f64)) / Simd::splat(3_f64),
+            )
+    });
+    fill(&mut value, near_root, || {
+        // Exact by Sterbenz's lemma, as in `piecewise::near_root`:
+        let distance = (-x - Simd::splat(constants::EI_ZERO)) - Simd::splat(constants::EI_ZERO_LO);
+        let sum = constants::EI_ROOT
+            .iter()
+            .rev()
+            .fold(Simd::splat(0_f64), |sum, &coefficient| {
+                sum * distance + Simd::splat(coefficient)
+            });
+        // $E_{1}(x) = -\text{Ei}(-x)$:
+        -(sum * distance)
+    });
+    fill(&mut value, le_pos_1 & !le_neg_1 & !near_root, || {
+        (-lanewise(x.abs(), libm::log) - Simd::splat(0.6875_f64) + x)
+            + chebyshev::eval_simd(&constants::E12, x)
+    });
+    fill(&mut value, le_pos_4 & !le_pos_1, || {
+        decaying(
+            x,
+            chebyshev::eval_simd(
+                &constants::AE13,
+                (Simd::splat(8_f64) / x - Simd::splat(5_f64)) / Simd::splat(3_f64),
+            ),
+        )
+    });
+    fill(&mut value, !le_pos_4, || {
+        decaying(
+            x,
+            chebyshev::eval_simd(
+                &constants::AE14,
+                Simd::splat(8_f64) / x - Simd::splat(1_f64),
+            ),
+        )
+    });
+    Ok(value)
+}
+
+/// $\text{Ei}(x) = -E_{1}(-x)$ in every lane of `x`.
+/// # Errors
+/// See `E1`.
+#[inline]
+pub fn Ei<const N: usize>(x: Simd<f64, N>) -> Result<Simd<f64, N>, Error> {
+    E1(-x).map(|value| -value).map_err(|e| match e {
+        Error::Domain(lane) => Error::Domain(-lane),
+        Error::Range(_) => e,
+    })
+}
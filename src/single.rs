@@ -0,0 +1,31 @@
+//! E1 and Ei in single precision, for code that runs everything in `f32`
+//! (e.g. alongside a GPU or on an embedded target without a double-precision FPU).
+//!
+//! Shorthand for `real::E1::<f32>` and `real::Ei::<f32>`:
+//! the same pieces and Chebyshev fits as the double-precision functions,
+//! but with each table cut off after its last coefficient above `f32::EPSILON` (never past GSL's `order_sp`)
+//! and rounded to `f32` at compile time, so each evaluation sums 8 to 10 terms where `f64` sums 16 to 39,
+//! all of it in `f32` arithmetic.
+//! Accurate to within about six units in the last place,
+//! the worst of it where a fit's leading terms cancel.
+
+use {
+    crate::{Error, real},
+    sigma_types::{Finite, NonZero},
+};
+
+/// $E_{1}(x) = \int_{1}^{\infty} \frac{e^{-xt}}{t} \text{d}t$ in single precision.
+/// # Errors
+/// If `x` is so large that single-precision operations will fail down the line (absolute value of just over 82).
+#[inline]
+pub fn E1(x: NonZero<Finite<f32>>) -> Result<Finite<f32>, Error> {
+    real::E1(x)
+}
+
+/// $\text{Ei}(x) = -E_{1}(-x)$ in single precision.
+/// # Errors
+/// See `E1`.
+#[inline]
+pub fn Ei(x: NonZero<Finite<f32>>) -> Result<Finite<f32>, Error> {
+    real::Ei(x)
+}
@@ -1,232 +1,6395 @@
-#![cfg_attr(
-    not(feature = "precision"),
-    expect(
-        unused_variables,
-        reason = "`quickcheck` macro doesn't like conditional compilation, so some must go unused"
-    )
-)]
+// Only the benchmarks use `criterion`:
+use criterion as _;
 
-mod doesnt_crash {
-    mod chebyshev {
+mod agrees {
+    //! Independent algorithms should agree with the Chebyshev tables.
+
+    mod approx_compare {
+        use {
+            crate::{Approx, E1, Precision},
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        #[cfg(feature = "error")]
+        use sigma_types::NonNegative;
+
+        /// `value`, give or take `error` (if this build keeps an estimate at all).
+        #[cfg_attr(
+            not(feature = "error"),
+            expect(unused_variables, reason = "no estimate to keep")
+        )]
+        pub(super) fn approx(value: f64, error: f64) -> Approx {
+            Approx {
+                #[cfg(feature = "error")]
+                error: NonNegative::new(Finite::new(error)),
+                value: Finite::new(value),
+            }
+        }
+
+        // Intervals of equal width that just touch, then miss by half a width either side.
+        #[test]
+        fn intervals() {
+            let (lower, touching, apart) = (
+                approx(1_f64, 0.5_f64),
+                approx(2_f64, 0.5_f64),
+                approx(2.5_f64, 0.5_f64),
+            );
+            let widened = cfg!(feature = "error");
+            assert_eq!(lower.overlaps(&touching), widened);
+            assert_eq!(touching.overlaps(&lower), widened);
+            assert!(!lower.overlaps(&apart));
+            assert_eq!(lower.agrees_within(&apart, 1.5_f64), widened);
+            assert!(!lower.agrees_within(&apart, 1.4_f64));
+        }
+
+        // Every value agrees with itself, however narrowly.
+        #[quickcheck]
+        fn reflexive(x: NonZero<Finite<f64>>) -> bool {
+            let Ok(approx) = E1(x, Precision::Full) else {
+                return true;
+            };
+            approx.overlaps(&approx) && approx.agrees_within(&approx, 0_f64)
+        }
+    }
+
+    mod approx_error {
+        use {
+            super::approx_compare::approx,
+            crate::{Approx, E1, Precision},
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // The estimate over the magnitude of the value.
+        #[quickcheck]
+        fn relative(x: NonZero<Finite<f64>>) -> bool {
+            let Ok(approx) = E1(x, Precision::Full) else {
+                return true;
+            };
+            let expected = approx.error().map(|error| **error / approx.value.abs());
+            approx.relative_error().map(|relative| *relative) == expected
+        }
+
+        // Just below a power of two, the last place is half as wide as at the power itself,
+        // though the relative error barely moves; at zero, it's the smallest subnormal.
+        #[test]
+        fn ulps() {
+            let ulps = |value: f64, error: f64| approx(value, error).error_in_ulps().map(|n| *n);
+            let expected = |n: f64| cfg!(feature = "error").then_some(n);
+            assert_eq!(ulps(2_f64.next_down(), f64::EPSILON), expected(1_f64));
+            assert_eq!(ulps(2_f64, f64::EPSILON), expected(0.5_f64));
+            assert_eq!(ulps(-2_f64, 4_f64 * f64::EPSILON), expected(2_f64));
+            assert_eq!(ulps(0_f64, f64::from_bits(3)), expected(3_f64));
+            assert_eq!(
+                ulps(f64::MAX, f64::MAX - f64::MAX.next_down()),
+                expected(1_f64)
+            );
+        }
+
+        // No estimate around an exact zero is zero, but any other is infinitely large relative to it.
+        #[test]
+        fn zero() {
+            let exact = cfg!(feature = "error").then_some(0_f64);
+            assert_eq!(
+                Approx::default().relative_error().map(|relative| *relative),
+                exact
+            );
+            let one = NonZero::new(Finite::new(1_f64));
+            let cancelled = cfg!(feature = "error").then_some(f64::INFINITY);
+            assert_eq!(
+                E1(one, Precision::Full)
+                    .map(|approx| (approx - approx).relative_error().map(|relative| *relative)),
+                Ok(cancelled)
+            );
+        }
+    }
+
+    mod approx_ops {
+        use {
+            crate::{Approx, E1, Ei, Precision},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        /// The error estimate as a plain `f64`, if there is one.
+        fn estimate(approx: Approx) -> Option<f64> {
+            approx.error().map(|error| **error)
+        }
+
+        // Values combine exactly as `f64`s would, and estimates only ever grow,
+        // by the other factor's magnitude in a product (and unchanged by negation).
+        #[quickcheck]
+        #[expect(
+            clippy::arithmetic_side_effects,
+            reason = "overflow is discarded before anything is combined"
+        )]
+        fn propagation(
+            x: NonZero<Finite<f64>>,
+            y: NonZero<Finite<f64>>,
+            factor: Finite<f64>,
+        ) -> TestResult {
+            let (Ok(lhs), Ok(rhs)) = (E1(x, Precision::Full), Ei(y, Precision::Full)) else {
+                return TestResult::discard();
+            };
+            let (left, right, scale) = (*lhs.value, *rhs.value, *factor);
+            if !(left * right).is_finite() || !(left * scale).is_finite() {
+                return TestResult::discard();
+            }
+            let (left_error, right_error) = (estimate(lhs), estimate(rhs));
+            let sum = left_error.zip(right_error).map(|(el, er)| el + er);
+            let product = left_error
+                .zip(right_error)
+                .map(|(el, er)| right.abs() * el + left.abs() * er);
+            let values = [
+                ((lhs + rhs).value, left + right),
+                ((lhs - rhs).value, left - right),
+                ((lhs * rhs).value, left * right),
+                ((lhs * scale).value, left * scale),
+                ((-lhs).value, -left),
+            ];
+            TestResult::from_bool(
+                values
+                    .iter()
+                    .all(|&(approx, exact)| approx.to_bits() == exact.to_bits())
+                    && estimate(lhs + rhs) >= sum
+                    && estimate(lhs - rhs) >= sum
+                    && estimate(lhs * rhs) >= product
+                    && estimate(lhs * scale) >= left_error.map(|el| scale.abs() * el)
+                    && estimate(-lhs) == left_error,
+            )
+        }
+    }
+
+    mod bickley {
         extern crate alloc;
 
         use {
-            crate::chebyshev, alloc::format, quickcheck::TestResult, quickcheck_macros::quickcheck,
+            crate::bickley::{Ki, asymptotic, trapezoidal},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::Finite,
         };
 
-        // Chebyshev approximation can balloon out of control,
-        // so it doesn't need to succeed for all inputs,
-        // but only on those we give it.
+        // Wherever the asymptotic expansion converges, quadrature should agree.
+        #[quickcheck]
+        fn asymptotic_vs_trapezoidal(n: u8, x: Finite<f64>) -> TestResult {
+            if !(30_f64..=700_f64).contains(&*x) {
+                return TestResult::discard();
+            }
+            let Some(expansion) = asymptotic(f64::from(n), *x) else {
+                return TestResult::discard();
+            };
+            let quadrature = trapezoidal(f64::from(n), *x);
+            let relative = ((*expansion.value - *quadrature.value) / *quadrature.value).abs();
+            if relative < 1e-13_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ki{n}({x}): the asymptotic expansion gave {} but quadrature gave {} (relative difference {relative})",
+                    expansion.value, quadrature.value,
+                ))
+            }
+        }
 
-        // Test our `const` implementation of `min`
-        // again the standard library.
+        // Each order is the integral of the one before, so a central difference should give it back (negated).
         #[quickcheck]
-        fn min(a: usize, b: usize) -> TestResult {
-            let c = chebyshev::min(a, b);
-            let m = a.min(b);
-            if c == m {
+        fn central_difference(n: u8, x: Finite<f64>) -> TestResult {
+            let Some(previous) = n.checked_sub(1) else {
+                return TestResult::discard();
+            };
+            if !(0.01_f64..=50_f64).contains(&*x) {
+                return TestResult::discard();
+            }
+            let step = 1e-6_f64 * *x;
+            let (Ok(above), Ok(below), Ok(lower)) = (
+                Ki(usize::from(n), Finite::new(*x + step)),
+                Ki(usize::from(n), Finite::new(*x - step)),
+                Ki(usize::from(previous), x),
+            ) else {
+                return TestResult::error("Ki failed");
+            };
+            let difference = (*above.value - *below.value) / (2_f64 * step);
+            let relative = ((difference + *lower.value) / *lower.value).abs();
+            if relative < 1e-6_f64 {
                 TestResult::passed()
             } else {
                 TestResult::error(format!(
-                    "`chebyshev::min({a}, {b}) = {c}`, but `{a}.min({b}) = {m}`"
+                    "Ki{previous}({x}) = {} but the central difference of Ki{n} gave {} (relative difference {relative})",
+                    lower.value, -difference,
                 ))
             }
         }
     }
 
-    mod implementation {
+    #[cfg(feature = "alloc")]
+    mod cache {
+        extern crate alloc;
 
-        mod neg {
-            use {
-                crate::implementation::neg::*,
-                quickcheck_macros::quickcheck,
-                sigma_types::{Finite, Negative},
-            };
+        use {
+            crate::{Ei, Precision, cache::CachedEi},
+            alloc::{format, vec::Vec},
+            core::num::NonZeroUsize,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
 
-            #[quickcheck]
-            fn e1(x: Negative<Finite<f64>>, order: usize) {
-                _ = E1(
-                    x,
-                    #[cfg(feature = "precision")]
-                    order,
-                );
+        // Hits, misses, and collisions alike should give exactly what `Ei` does,
+        // here with so few slots that most arguments evict each other.
+        #[quickcheck]
+        fn uncached(xs: Vec<NonZero<Finite<f64>>>, capacity: NonZeroUsize) -> TestResult {
+            let mut cache = CachedEi::new(
+                NonZeroUsize::new(capacity.get() & 7).unwrap_or(NonZeroUsize::MIN),
+                Precision::Full,
+            );
+            let len = xs.len();
+            for (k, x) in xs.into_iter().cycle().take(len.wrapping_mul(3)).enumerate() {
+                if k == len {
+                    cache.clear();
+                }
+                let expected = Ei(x, Precision::Full);
+                let actual = cache.Ei(x);
+                // Compare as text, which distinguishes every bit that matters and survives NaN:
+                if format!("{actual:?}") != format!("{expected:?}") {
+                    return TestResult::error(format!(
+                        "At {x}, expected {expected:?} but the cache gave {actual:?}"
+                    ));
+                }
             }
+            TestResult::passed()
         }
+    }
 
-        mod piecewise {
-            use {
-                crate::{constants, implementation::piecewise::*},
-                quickcheck::TestResult,
-                quickcheck_macros::quickcheck,
-                sigma_types::{Finite, Negative, NonZero, Positive},
+    #[cfg(feature = "num-complex")]
+    mod complex {
+        #![expect(
+            clippy::arithmetic_side_effects,
+            reason = "complex floating-point arithmetic saturates instead of panicking"
+        )]
+
+        extern crate alloc;
+
+        use {
+            crate::{Branch, E1, E1_imag, Ei, Ei_with_branch, En, Precision, complex},
+            alloc::format,
+            core::f64::consts::PI,
+            num_complex::Complex,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero, Positive},
+        };
+
+        // Reflecting the argument across the real axis reflects the result.
+        #[quickcheck]
+        fn conjugate(re: Finite<f64>, im: Finite<f64>) -> TestResult {
+            let (Ok(above), Ok(below)) = (
+                complex::E1(Complex::new(re, im)),
+                complex::E1(Complex::new(re, Finite::new(-*im))),
+            ) else {
+                return TestResult::discard();
             };
+            if above.value.re == below.value.re && above.value.im == Finite::new(-*below.value.im) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({re} ± {im}i) gave {above} and {below}, which aren't conjugates",
+                ))
+            }
+        }
 
-            #[quickcheck]
-            fn neg_10(x: Negative<Finite<f64>>, order: usize) -> TestResult {
-                if **x < constants::NXMAX {
-                    return TestResult::discard();
-                }
-                if **x > -10_f64 {
-                    return TestResult::discard();
-                }
-                _ = le_neg_10(
-                    x,
-                    #[cfg(feature = "precision")]
-                    order,
-                );
+        // On the imaginary axis, the real-valued decomposition should match the complex evaluation.
+        #[quickcheck]
+        fn imaginary_axis(y: NonZero<Finite<f64>>) -> TestResult {
+            // Past this, the complex evaluation squares the modulus and overflows:
+            if y.abs() > 1e150_f64 {
+                return TestResult::discard();
+            }
+            let Ok(full) = complex::E1(Complex::new(Finite::new(0_f64), *y)) else {
+                return TestResult::discard();
+            };
+            let (real, imaginary) = E1_imag(y, Precision::Full);
+            let difference = (Complex::new(*real.value, *imaginary.value)
+                - Complex::new(*full.value.re, *full.value.im))
+            .norm();
+            let modulus = Complex::new(*full.value.re, *full.value.im).norm();
+            if difference <= 1e-10_f64 * modulus.max(1e-300_f64) {
                 TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({y}i): the complex evaluation gave {full} but E1_imag gave {} + {}i",
+                    real.value, imaginary.value,
+                ))
             }
+        }
 
-            #[quickcheck]
-            fn neg_4(x: Negative<Finite<f64>>, order: usize) -> TestResult {
-                if **x <= -10_f64 {
-                    return TestResult::discard();
-                }
-                if **x > -4_f64 {
-                    return TestResult::discard();
-                }
-                _ = le_neg_4(
-                    x,
-                    #[cfg(feature = "precision")]
-                    order,
-                );
+        // Just above and below the negative real axis, E1 straddles $-\text{Ei}$ by $\pi i$.
+        #[quickcheck]
+        fn cut(x: Positive<Finite<f64>>) -> TestResult {
+            let Ok(real) = Ei(NonZero::new(*x), Precision::Full) else {
+                return TestResult::discard();
+            };
+            let (Ok(above), Ok(below)) = (
+                complex::E1(Complex::new(Finite::new(-**x), Finite::new(0_f64))),
+                complex::E1(Complex::new(Finite::new(-**x), Finite::new(-0_f64))),
+            ) else {
+                return TestResult::discard();
+            };
+            let tolerance = 1e-13_f64 * real.value.abs().max(1_f64);
+            if (*above.value.re + *real.value).abs() < tolerance
+                && (*below.value.re + *real.value).abs() < tolerance
+                && (*above.value.im + PI).abs() < 1e-13_f64
+                && (*below.value.im - PI).abs() < 1e-13_f64
+            {
                 TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) = {real} but E1 on either side of -{x} gave {above} and {below}",
+                ))
             }
+        }
 
-            #[quickcheck]
-            fn neg_1(x: Negative<Finite<f64>>, order: usize) -> TestResult {
-                if **x <= -4_f64 {
+        // Each named side of Ei's cut should be the matching limit of $-E_{1}(-z)$.
+        #[quickcheck]
+        fn branch(x: NonZero<Finite<f64>>) -> TestResult {
+            for (side, im) in [
+                (Branch::UpperHalfPlane, 0_f64),
+                (Branch::LowerHalfPlane, -0_f64),
+            ] {
+                let Ok((real, imaginary)) = Ei_with_branch(x, side, Precision::Full) else {
                     return TestResult::discard();
-                }
-                if **x > -1_f64 {
+                };
+                // Negating `z` flips the side of the axis along with everything else:
+                let Ok(full) = complex::E1(Complex::new(Finite::new(-**x), Finite::new(-im)))
+                else {
                     return TestResult::discard();
+                };
+                let tolerance = 1e-13_f64 * real.value.abs().max(1_f64);
+                if (*full.value.re + *real.value).abs() >= tolerance
+                    || (*full.value.im + *imaginary).abs() >= 1e-13_f64
+                {
+                    return TestResult::error(format!(
+                        "Ei({x}) on the {side:?} gave {real} + {imaginary}i but -E1(-z) gave -({full})",
+                    ));
                 }
-                _ = le_neg_1(
-                    x,
-                    #[cfg(feature = "precision")]
-                    order,
-                );
+            }
+            TestResult::passed()
+        }
+
+        // On the positive real axis, En is real and matches the real implementation.
+        #[quickcheck]
+        fn real_order(n: u8, x: Positive<Finite<f64>>) -> TestResult {
+            let Ok(real) = En(usize::from(n), *x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(approx) = complex::En(usize::from(n), Complex::new(*x, Finite::new(0_f64)))
+            else {
+                return TestResult::error("Complex En failed where the real one didn't");
+            };
+            let relative = ((*approx.value.re - *real.value) / *real.value).abs();
+            if relative < 1e-12_f64 && *approx.value.im == 0_f64 {
                 TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E{n}({x}) = {real} but the complex implementation gave {approx}",
+                ))
             }
+        }
 
-            #[quickcheck]
-            fn pos_1(x: NonZero<Finite<f64>>, order: usize) -> TestResult {
-                if **x <= -1_f64 {
-                    return TestResult::discard();
-                }
-                if **x > 1_f64 {
-                    return TestResult::discard();
-                }
-                _ = le_pos_1(
-                    x,
-                    #[cfg(feature = "precision")]
-                    order,
-                );
+        // $n E_{n + 1}(z) + z E_{n}(z) = e^{-z}$ ties neighboring orders together.
+        #[quickcheck]
+        fn recurrence(n: u8, re: Finite<f64>, im: Finite<f64>) -> TestResult {
+            if n == 0 || re.abs() > 100_f64 || im.abs() > 100_f64 {
+                return TestResult::discard();
+            }
+            let z = Complex::new(re, im);
+            let (Ok(lower), Ok(higher)) = (
+                complex::En(usize::from(n), z),
+                complex::En(usize::from(n) + 1, z),
+            ) else {
+                return TestResult::discard();
+            };
+            let unwrapped = Complex::new(*re, *im);
+            let lower_value = Complex::new(*lower.value.re, *lower.value.im);
+            let higher_value = Complex::new(*higher.value.re, *higher.value.im);
+            let exponential = (-unwrapped).exp();
+            let order = f64::from(n);
+            let difference = (higher_value * order + unwrapped * lower_value - exponential).norm();
+            let scale = (higher_value * order).norm()
+                + (unwrapped * lower_value).norm()
+                + exponential.norm();
+            if difference <= 1e-12_f64 * scale {
                 TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "At order {n} and z = {re} + {im}i, E_n = {lower} and E_(n+1) = {higher} break the recurrence",
+                ))
             }
+        }
 
-            #[quickcheck]
-            fn pos_4(x: Positive<Finite<f64>>, order: usize) -> TestResult {
-                if **x <= 1_f64 {
-                    return TestResult::discard();
-                }
-                if **x > 4_f64 {
-                    return TestResult::discard();
-                }
-                _ = le_pos_4(
-                    x,
-                    #[cfg(feature = "precision")]
-                    order,
-                );
+        // On the positive real axis, E1 is real and matches the real implementation.
+        #[quickcheck]
+        fn real_axis(x: Positive<Finite<f64>>) -> TestResult {
+            let Ok(real) = E1(NonZero::new(*x), Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(approx) = complex::E1(Complex::new(*x, Finite::new(0_f64))) else {
+                return TestResult::error("Complex E1 failed where the real one didn't");
+            };
+            let relative = ((*approx.value.re - *real.value) / *real.value).abs();
+            if relative < 1e-13_f64 && *approx.value.im == 0_f64 {
                 TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {real} but the complex implementation gave {approx}",
+                ))
             }
+        }
+    }
 
-            #[quickcheck]
-            fn pos_max(x: Positive<Finite<f64>>, order: usize) -> TestResult {
-                if **x <= 4_f64 {
-                    return TestResult::discard();
-                }
-                if **x > constants::XMAX {
-                    return TestResult::discard();
+    mod consts {
+        extern crate alloc;
+
+        use {
+            crate::{
+                E1, Ei, Ein, Precision,
+                consts::{E1_1, EI_ZERO, EULER_GAMMA, GOMPERTZ, LI_2},
+                li,
+            },
+            alloc::{format, string::String},
+            core::f64::consts::E,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        /// Fifty digits from an arbitrary-precision evaluation (mpmath), parsed with correct rounding.
+        const ORACLE: [(&str, f64, &str); 5] = [
+            (
+                "E1_1",
+                E1_1,
+                "0.21938393439552027367716377546012164903104729340691",
+            ),
+            (
+                "EI_ZERO",
+                EI_ZERO,
+                "0.37250741078136663446199186658011913353568949777165",
+            ),
+            (
+                "EULER_GAMMA",
+                EULER_GAMMA,
+                "0.57721566490153286060651209008240243104215933593992",
+            ),
+            (
+                "GOMPERTZ",
+                GOMPERTZ,
+                "0.59634736232319407434107849936927937607417786015255",
+            ),
+            (
+                "LI_2",
+                LI_2,
+                "1.0451637801174927848445888891946131365226155781512",
+            ),
+        ];
+
+        #[test]
+        fn correctly_rounded() -> Result<(), String> {
+            for (name, value, digits) in ORACLE {
+                let expected = digits
+                    .parse::<f64>()
+                    .map_err(|e| format!("Couldn't parse {digits}: {e}"))?;
+                if value.to_bits() != expected.to_bits() {
+                    return Err(format!(
+                        "{name} = {value} but the oracle rounds to {expected}"
+                    ));
                 }
-                _ = le_pos_max(
-                    x,
-                    #[cfg(feature = "precision")]
-                    order,
-                );
-                TestResult::passed()
             }
+            Ok(())
         }
 
-        mod pos {
-            use {
-                crate::implementation::pos::*,
-                quickcheck_macros::quickcheck,
-                sigma_types::{Finite, Positive},
+        // Away from the power series, Ein is built from the same constant and the same E1,
+        // so composing the identity by hand should reproduce it bit for bit.
+        #[quickcheck]
+        fn ein_identity(x: NonZero<Finite<f64>>) -> TestResult {
+            if !(1_f64..=700_f64).contains(&x.abs()) {
+                return TestResult::discard();
+            }
+            let (Ok(ein), Ok(ei)) = (
+                Ein(*x, Precision::Full),
+                Ei(NonZero::new(Finite::new(-**x)), Precision::Full),
+            ) else {
+                return TestResult::discard();
             };
+            let composed = (EULER_GAMMA + libm::log(x.abs())) - *ei.value;
+            if ein.value.to_bits() == composed.to_bits() {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ein({x}) = {} but γ + ln|{x}| - Ei({}) = {composed}",
+                    ein.value, -**x,
+                ))
+            }
+        }
 
-            #[quickcheck]
-            fn e1(x: Positive<Finite<f64>>, order: usize) {
-                _ = E1(
-                    x,
-                    #[cfg(feature = "precision")]
-                    order,
-                );
+        // Each constant should also agree with the function that defines it.
+        #[test]
+        fn definitions() -> Result<(), String> {
+            let e1 = E1(NonZero::new(Finite::new(1_f64)), Precision::Full)
+                .map_err(|e| format!("E1(1) failed: {e}"))?;
+            let ei = Ei(NonZero::new(Finite::new(-1_f64)), Precision::Full)
+                .map_err(|e| format!("Ei(-1) failed: {e}"))?;
+            let li_2 = li(Finite::new(2_f64), Precision::Full)
+                .map_err(|e| format!("li(2) failed: {e}"))?;
+            let at_zero = Ei(NonZero::new(Finite::new(EI_ZERO)), Precision::Full)
+                .map_err(|e| format!("Ei({EI_ZERO}) failed: {e}"))?;
+            for (name, expected, actual) in [
+                ("E1(1)", E1_1, *e1.value),
+                ("-e Ei(-1)", GOMPERTZ, -E * *ei.value),
+                ("li(2)", LI_2, *li_2.value),
+            ] {
+                let relative = ((actual - expected) / expected).abs();
+                if relative >= 1e-14_f64 {
+                    return Err(format!(
+                        "{name} = {actual} but the constant is {expected} (relative difference {relative})"
+                    ));
+                }
             }
+            // Ei' = e^x / x, so rounding the zero moves Ei by at most half an ULP of it times that slope:
+            let slope = libm::exp(EI_ZERO) / EI_ZERO;
+            if at_zero.value.abs() > slope * EI_ZERO * f64::EPSILON {
+                return Err(format!("Ei({EI_ZERO}) = {}, not zero", at_zero.value));
+            }
+            Ok(())
         }
+    }
+
+    mod derivative {
+        extern crate alloc;
 
         use {
-            crate::implementation::*,
+            crate::{
+                Approx, E1_derivatives, Ei, Ei_derivative, Ei_derivatives, Ei_with_derivative,
+                Precision,
+            },
+            alloc::format,
+            quickcheck::TestResult,
             quickcheck_macros::quickcheck,
             sigma_types::{Finite, NonZero},
         };
 
+        // A central difference should match to about the square root of machine precision.
         #[quickcheck]
-        fn e1(x: NonZero<Finite<f64>>, order: usize) {
-            _ = E1(
-                x,
-                #[cfg(feature = "precision")]
-                order,
-            );
+        fn central_difference(x: NonZero<Finite<f64>>) -> TestResult {
+            if !(0.01_f64..=100_f64).contains(&x.abs()) {
+                return TestResult::discard();
+            }
+            let step = 1e-6_f64 * x.abs();
+            let (Ok(above), Ok(below)) = (
+                Ei(NonZero::new(Finite::new(**x + step)), Precision::Full),
+                Ei(NonZero::new(Finite::new(**x - step)), Precision::Full),
+            ) else {
+                return TestResult::error("Ei failed");
+            };
+            let Ok(derivative) = Ei_derivative(x) else {
+                return TestResult::error("Ei_derivative failed");
+            };
+            let difference = (*above.value - *below.value) / (2_f64 * step);
+            let relative = ((difference - *derivative.value) / *derivative.value).abs();
+            if relative < 1e-6_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei'({x}) = {derivative} but a central difference gave {difference} (relative difference {relative})",
+                ))
+            }
         }
-    }
 
-    use {
-        crate::{E1, Ei},
-        quickcheck::TestResult,
-        quickcheck_macros::quickcheck,
-        sigma_types::{Finite, NonZero},
-    };
+        // Differentiating $x \text{Ei}'(x) = e^{x}$ gives $x f_{m} + m f_{m - 1} = e^{x}$,
+        // which holds whichever way the entries were computed.
+        #[quickcheck]
+        fn higher_order(x: NonZero<Finite<f64>>) -> TestResult {
+            // Past this, $e^{x}$ is subnormal and has lost relative precision.
+            if **x < -700_f64 {
+                return TestResult::discard();
+            }
+            let mut derivatives = [Approx::default(); 16];
+            if Ei_derivatives(x, &mut derivatives).is_err() {
+                return TestResult::discard();
+            }
+            let exponential = libm::exp(**x);
+            for (m, pair) in derivatives.windows(2).enumerate() {
+                let [lower, higher] = *pair else {
+                    return TestResult::error("`windows(2)` gave something other than a pair");
+                };
+                #[expect(
+                    clippy::as_conversions,
+                    clippy::cast_precision_loss,
+                    reason = "tiny integer"
+                )]
+                let order = m as f64 + 1_f64;
+                let left = **x * *higher.value;
+                let right = order * *lower.value;
+                let scale = left.abs() + right.abs() + exponential;
+                if (left + right - exponential).abs() > 1e-12_f64 * scale {
+                    return TestResult::error(format!(
+                        "At {x}, the derivatives of orders {order} and {} were {lower} and {higher}, which don't satisfy the recurrence",
+                        order + 1_f64,
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
 
-    #[quickcheck]
-    fn e1(x: NonZero<Finite<f64>>, order: usize) {
-        _ = E1(
-            x,
-            #[cfg(feature = "precision")]
-            order,
-        );
-    }
+        // The derivatives of E1 are those of Ei reflected, alternating in sign.
+        #[quickcheck]
+        fn reflected(x: NonZero<Finite<f64>>) -> TestResult {
+            let mut e1 = [Approx::default(); 8];
+            let mut ei = [Approx::default(); 8];
+            #[expect(
+                clippy::arithmetic_side_effects,
+                reason = "negating a nonzero finite float can't fail"
+            )]
+            let reflected = -x;
+            if E1_derivatives(x, &mut e1).is_err() || Ei_derivatives(reflected, &mut ei).is_err() {
+                return TestResult::discard();
+            }
+            let mut odd = false;
+            for (m, (lhs, rhs)) in e1.iter().zip(ei.iter()).enumerate() {
+                let expected = if odd { -*rhs.value } else { *rhs.value };
+                if lhs.value != Finite::new(expected) {
+                    return TestResult::error(format!(
+                        "E1 derivative {m} at {x} was {lhs}, but Ei's at {reflected} was {rhs}",
+                    ));
+                }
+                odd = !odd;
+            }
+            TestResult::passed()
+        }
 
-    #[quickcheck]
+        // Sharing the exponential shouldn't change either result.
+        #[quickcheck]
+        fn together(x: NonZero<Finite<f64>>) -> TestResult {
+            let (Ok(value), Ok(derivative)) = (Ei(x, Precision::Full), Ei_derivative(x)) else {
+                return TestResult::discard();
+            };
+            let Ok((joint_value, joint_derivative)) = Ei_with_derivative(x, Precision::Full) else {
+                return TestResult::error(format!("Ei_with_derivative({x}) failed"));
+            };
+            let value_relative = ((*joint_value.value - *value.value) / *value.value).abs();
+            let derivative_relative =
+                ((*joint_derivative.value - *derivative.value) / *derivative.value).abs();
+            if value_relative < 1e-12_f64 && derivative_relative < 1e-15_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) = {value} and Ei'({x}) = {derivative}, but together they gave {joint_value} and {joint_derivative}",
+                ))
+            }
+        }
+    }
+
+    mod diff {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Ei_diff, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Swapping the endpoints expands about the other one, but should only flip the sign.
+        #[quickcheck]
+        fn antisymmetric(a: NonZero<Finite<f64>>, nudge: i16) -> TestResult {
+            let Some(b) = Finite::try_new(**a * f64::from(nudge).mul_add(2_f64.powi(-30), 1_f64))
+                .and_then(NonZero::try_new)
+            else {
+                return TestResult::discard();
+            };
+            let (Ok(forward), Ok(backward)) = (
+                Ei_diff(a, b, Precision::Full),
+                Ei_diff(b, a, Precision::Full),
+            ) else {
+                return TestResult::discard();
+            };
+            if (*forward.value + *backward.value).abs() <= 1e-12_f64 * forward.value.abs() {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({b}) - Ei({a}) = {forward}, but Ei({a}) - Ei({b}) = {backward}",
+                ))
+            }
+        }
+
+        // Far apart, subtracting by hand is accurate enough to compare against.
+        #[quickcheck]
+        fn subtraction(a: NonZero<Finite<f64>>, b: NonZero<Finite<f64>>) -> TestResult {
+            if (**b - **a).abs() < 0.5_f64 * a.abs().max(b.abs()) {
+                return TestResult::discard();
+            }
+            let (Ok(upper), Ok(lower)) = (Ei(b, Precision::Full), Ei(a, Precision::Full)) else {
+                return TestResult::discard();
+            };
+            let Ok(difference) = Ei_diff(a, b, Precision::Full) else {
+                return TestResult::error(format!("Ei_diff({a}, {b}) failed where Ei didn't"));
+            };
+            let expected = *upper.value - *lower.value;
+            let scale = upper.value.abs() + lower.value.abs();
+            if (*difference.value - expected).abs() <= 1e-12_f64 * scale {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({b}) - Ei({a}) = {upper} - {lower} = {expected}, but Ei_diff gave {difference}",
+                ))
+            }
+        }
+    }
+
+    mod dual {
+        extern crate alloc;
+
+        use {
+            crate::{
+                E1, E1_derivative, Ei, Ei_derivative, Precision,
+                dual::{self, Dual},
+            },
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Seeding the derivative should take it straight from the closed form, to the bit.
+        #[quickcheck]
+        fn seeds_e1(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(dual) = dual::E1(Dual::variable(**x), Precision::Full) else {
+                return TestResult::discard();
+            };
+            let (Ok(value), Ok(slope)) = (E1(x, Precision::Full), E1_derivative(x)) else {
+                return TestResult::error(format!("dual::E1({x}) = {dual} but E1 failed"));
+            };
+            if (dual.value.to_bits(), dual.derivative.to_bits())
+                == (value.value.to_bits(), slope.value.to_bits())
+            {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {value} and E1'({x}) = {slope} but dual::E1 gave {dual}",
+                ))
+            }
+        }
+
+        #[quickcheck]
+        fn seeds_ei(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(dual) = dual::Ei(Dual::variable(**x), Precision::Full) else {
+                return TestResult::discard();
+            };
+            let (Ok(value), Ok(slope)) = (Ei(x, Precision::Full), Ei_derivative(x)) else {
+                return TestResult::error(format!("dual::Ei({x}) = {dual} but Ei failed"));
+            };
+            if (dual.value.to_bits(), dual.derivative.to_bits())
+                == (value.value.to_bits(), slope.value.to_bits())
+            {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) = {value} and Ei'({x}) = {slope} but dual::Ei gave {dual}",
+                ))
+            }
+        }
+
+        // $\frac{\text{d}}{\text{d}x} \text{Ei}(2x + 1) = 2 \text{Ei}'(2x + 1)$, and doubling is exact.
+        #[quickcheck]
+        fn chain_rule(x: Finite<f64>) -> TestResult {
+            #[expect(
+                clippy::arithmetic_side_effects,
+                reason = "floating-point arithmetic saturates instead of panicking"
+            )]
+            let inner_dual = Dual::variable(*x) * 2_f64 + 1_f64;
+            let Ok(composed) = dual::Ei(inner_dual, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Some(inner) = Finite::try_new(2_f64.mul_add(*x, 1_f64)).and_then(NonZero::try_new)
+            else {
+                return TestResult::discard();
+            };
+            let Ok(derivative) = Ei_derivative(inner) else {
+                return TestResult::error(format!(
+                    "dual::Ei succeeded at 2 * {x} + 1 but Ei_derivative failed"
+                ));
+            };
+            let expected = 2_f64 * *derivative.value;
+            if composed.derivative.to_bits() == expected.to_bits() {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "d/dx Ei(2 * {x} + 1) = {expected} but dual::Ei gave {composed}",
+                ))
+            }
+        }
+    }
+
+    mod e10 {
+        extern crate alloc;
+
+        use {
+            crate::{E1, E1_e10, Ei_e10, Ei_ln, Precision},
+            alloc::format,
+            core::f64::consts::LN_10,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Wherever E1 itself works, nothing should change.
+        #[quickcheck]
+        fn unscaled(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(e1) = E1(x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(e10) = E1_e10(x, Precision::Full) else {
+                return TestResult::error(format!("E1_e10({x}) failed where E1 didn't"));
+            };
+            if e10.e10 == 0 && e10.value == e1.value {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!("E1({x}) = {e1}, but E1_e10 gave {e10}"))
+            }
+        }
+
+        // Taking the logarithm of mantissa and exponent should match the log-scale result.
+        #[quickcheck]
+        fn logarithm(x: NonZero<Finite<f64>>) -> TestResult {
+            let (Ok(e10), Ok((ln, sign))) = (Ei_e10(x, Precision::Full), Ei_ln(x, Precision::Full))
+            else {
+                return TestResult::discard();
+            };
+            let logarithm = f64::from(e10.e10).mul_add(LN_10, libm::log(e10.value.abs()));
+            if sign == Finite::new(libm::copysign(1_f64, *e10.value))
+                && (logarithm - *ln.value).abs() <= 1e-12_f64 * ln.value.abs().max(1_f64)
+            {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) = {e10}, whose logarithm is {logarithm}, but Ei_ln gave {ln} with sign {sign}",
+                ))
+            }
+        }
+    }
+
+    mod e1_const {
+        extern crate alloc;
+
+        use {
+            crate::{E1, E1_const, Ei, Ei_const, Precision},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        /// Evaluated at compile time.
+        const TAU0: f64 = E1_const(2_f64);
+
+        // The `const` exponential and logarithm should each be within a unit or two of `libm`'s.
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(approx) = E1(x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let value = E1_const(**x);
+            let ulps = ((value - *approx.value) / (approx.value.abs() * f64::EPSILON)).abs();
+            if ulps <= 4_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {approx} but E1_const gave {value} ({ulps} ULPs off)",
+                ))
+            }
+        }
+
+        #[quickcheck]
+        fn ei(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(approx) = Ei(x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let value = Ei_const(**x);
+            let ulps = ((value - *approx.value) / (approx.value.abs() * f64::EPSILON)).abs();
+            if ulps <= 4_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) = {approx} but Ei_const gave {value} ({ulps} ULPs off)",
+                ))
+            }
+        }
+
+        #[test]
+        fn in_const() -> Result<(), String> {
+            let approx = E1(NonZero::new(Finite::new(2_f64)), Precision::Full)
+                .map_err(|e| format!("{e}"))?;
+            if (TAU0 - *approx.value).abs() <= 4_f64 * f64::EPSILON * approx.value.abs() {
+                Ok(())
+            } else {
+                Err(format!(
+                    "E1(2) = {approx} but E1_const gave {TAU0} at compile time"
+                ))
+            }
+        }
+    }
+
+    mod e1_ei_pair {
+        extern crate alloc;
+
+        use {
+            crate::{E1, E1_Ei_pair, Ei, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Together, they should fail exactly where `E1` does and agree with each separately:
+        // to the bit up to 4 in magnitude, and to within a couple of roundings past that.
+        #[quickcheck]
+        fn separately(x: NonZero<Finite<f64>>) -> TestResult {
+            let pair = E1_Ei_pair(x, Precision::Full);
+            let alone = (E1(x, Precision::Full), Ei(x, Precision::Full));
+            let (Ok(e1), Ok(ei)) = alone else {
+                return if pair.map(|_| ()) == alone.0.map(|_| ()) {
+                    TestResult::passed()
+                } else {
+                    TestResult::error(format!(
+                        "E1({x}) = {:?} but together they gave {pair:?}",
+                        alone.0,
+                    ))
+                };
+            };
+            let Ok(together) = pair else {
+                return TestResult::error(format!("E1_Ei_pair({x}) failed: {pair:?}"));
+            };
+            let tolerance = if x.abs() <= 4_f64 { 0_f64 } else { 1e-15_f64 };
+            let relative = |joint: f64, separate: f64| ((joint - separate) / separate).abs();
+            if relative(*together.0.value, *e1.value) <= tolerance
+                && relative(*together.1.value, *ei.value) <= tolerance
+            {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {e1} and Ei({x}) = {ei}, but together they gave {} and {}",
+                    together.0, together.1,
+                ))
+            }
+        }
+    }
+
+    mod e1_inv {
+        extern crate alloc;
+
+        use {
+            crate::{E1, E1_inv, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero, Positive},
+        };
+
+        // Large `y` take the root straight from $e^{-\gamma - y}$,
+        // which should be within its own error of the root (from mpmath, rounded to the nearest `f64`).
+        #[test]
+        fn large() {
+            let expected = 1.082_914_893_567_529_6e-22_f64;
+            let x = E1_inv(Positive::new(Finite::new(50_f64)), Precision::Full);
+            assert!(
+                x.is_ok_and(|approx| {
+                    let difference = (*approx.value - expected).abs();
+                    difference <= 2_f64 * f64::EPSILON * expected
+                        && approx.error().is_none_or(|error| difference <= **error)
+                }),
+                "E1_inv(50) = {x:?} but the root is {expected}",
+            );
+        }
+
+        // Round trip through the inverse.
+        #[quickcheck]
+        fn round_trip(y: Positive<Finite<f64>>) -> TestResult {
+            let Ok(x) = E1_inv(y, Precision::Full) else {
+                return if **y > 700_f64 {
+                    // Root too close to zero to represent:
+                    TestResult::discard()
+                } else {
+                    TestResult::error("Inversion failed")
+                };
+            };
+            let Ok(back) = E1(NonZero::new(x.value), Precision::Full) else {
+                // Past `XMAX`, where E1 itself gives up:
+                return TestResult::discard();
+            };
+            // Allowing for rounding `x` itself, which E1 magnifies by $x E_{1}'(x) / E_{1}(x)$,
+            // roughly $x$ far from zero and $1 / \ln x$ near it:
+            let conditioning = 4_f64 * f64::EPSILON * (1_f64 + *x.value);
+            let relative = ((*back.value - **y) / **y).abs() - conditioning;
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1(E1_inv({y})) = E1({}) = {} (relative difference {relative})",
+                    x.value, back.value,
+                ))
+            }
+        }
+    }
+
+    mod e1_scaled {
+        extern crate alloc;
+
+        use {
+            crate::{E1, E1_scaled, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Wherever E1 itself is representable, scaling by hand should agree.
+        #[quickcheck]
+        fn unscaled(x: NonZero<Finite<f64>>) -> TestResult {
+            if x.abs() > 700_f64 {
+                return TestResult::discard();
+            }
+            let Ok(reference) = E1(x, Precision::Full) else {
+                return TestResult::error("Unscaled evaluation failed");
+            };
+            let scaled = E1_scaled(x, Precision::Full);
+            let expected = libm::exp(**x) * *reference.value;
+            let relative = ((*scaled.value - expected) / expected).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "e^{x} E1({x}) = {expected} but E1_scaled gave {} (relative difference {relative})",
+                    scaled.value,
+                ))
+            }
+        }
+    }
+
+    mod e1_slice {
+        extern crate alloc;
+
+        use {
+            crate::{Approx, BatchError, E1, E1_slice, E1_slice_approx, Precision},
+            alloc::{format, string::String, vec, vec::Vec},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        #[cfg(all(
+            feature = "std",
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(all(target_feature = "avx2", target_feature = "fma")),
+        ))]
+        use crate::implementation;
+
+        // Every entry should match `E1` to the bit, or the whole batch should fail at the first entry that doesn't,
+        // without writing anything.
+        #[quickcheck]
+        fn pointwise(xs: Vec<f64>) -> TestResult {
+            let mut out = vec![42_f64; xs.len()];
+            let result = E1_slice(&xs, &mut out, Precision::Full);
+            let mut expected = Ok(());
+            for (index, &x) in xs.iter().enumerate() {
+                let Some(nonzero) = Finite::try_new(x).and_then(NonZero::try_new) else {
+                    expected = Err(BatchError::Domain { index, value: x });
+                    break;
+                };
+                if let Err(error) = E1(nonzero, Precision::Full) {
+                    expected = Err(BatchError::Range { error, index });
+                    break;
+                }
+            }
+            // NaN entries don't equal themselves, so compare their bits:
+            let same = match (result, expected) {
+                (
+                    Err(BatchError::Domain { index, value }),
+                    Err(BatchError::Domain {
+                        index: expected_index,
+                        value: expected_value,
+                    }),
+                ) => index == expected_index && value.to_bits() == expected_value.to_bits(),
+                _ => result == expected,
+            };
+            if !same {
+                return TestResult::error(format!("Expected {expected:?} but got {result:?}"));
+            }
+            for (x, entry) in xs.into_iter().zip(out) {
+                let reference = if result.is_ok() {
+                    E1(NonZero::new(Finite::new(x)), Precision::Full)
+                        .map_or(f64::NAN, |approx| *approx.value)
+                } else {
+                    42_f64
+                };
+                if entry.to_bits() != reference.to_bits() {
+                    return TestResult::error(format!(
+                        "At {x}, expected {reference} but the batch gave {entry}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // Keeping the error estimates must give exactly what `E1` does.
+        #[quickcheck]
+        fn approx(xs: Vec<NonZero<Finite<f64>>>) -> TestResult {
+            let raw: Vec<f64> = xs.iter().map(|x| ***x).collect();
+            let mut out = vec![Approx::default(); xs.len()];
+            if E1_slice_approx(&raw, &mut out, Precision::Full).is_err() {
+                return TestResult::discard();
+            }
+            for (x, entry) in xs.into_iter().zip(out) {
+                let reference = E1(x, Precision::Full);
+                if reference != Ok(entry) {
+                    return TestResult::error(format!(
+                        "E1({x}) = {reference:?} but the batch gave {entry}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // The kernel compiled for AVX2 and FMA should give the same bits as the baseline,
+        // error estimates included.
+        #[quickcheck]
+        #[cfg(all(
+            feature = "std",
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(all(target_feature = "avx2", target_feature = "fma")),
+        ))]
+        fn kernels(xs: Vec<NonZero<Finite<f64>>>) -> TestResult {
+            if !(std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("fma")) {
+                return TestResult::discard();
+            }
+            let raw: Vec<f64> = xs.into_iter().map(|x| **x).collect();
+            if implementation::check_slice(&raw, false, 0).is_err() {
+                return TestResult::discard();
+            }
+            let mut portable = vec![Approx::default(); raw.len()];
+            let mut avx2_fma = portable.clone();
+            let portable_result = implementation::fill_slice_portable(
+                &raw,
+                &mut portable,
+                false,
+                |approx| approx,
+                0,
+                Precision::Full,
+            );
+            // SAFETY:
+            // Both features were just detected.
+            let avx2_fma_result = unsafe {
+                implementation::fill_slice_avx2_fma(
+                    &raw,
+                    &mut avx2_fma,
+                    false,
+                    |approx| approx,
+                    0,
+                    Precision::Full,
+                )
+            };
+            if portable_result != avx2_fma_result || portable != avx2_fma {
+                return TestResult::error(format!(
+                    "Baseline gave {portable_result:?} and {portable:?}, but AVX2 and FMA gave {avx2_fma_result:?} and {avx2_fma:?}"
+                ));
+            }
+            TestResult::passed()
+        }
+
+        #[test]
+        fn length_mismatch() -> Result<(), String> {
+            let result = E1_slice(&[1_f64, 2_f64], &mut [0_f64; 3], Precision::Full);
+            if result
+                == Err(BatchError::LengthMismatch {
+                    inputs: 2,
+                    outputs: 3,
+                })
+            {
+                Ok(())
+            } else {
+                Err(format!("Mismatched lengths gave {result:?}"))
+            }
+        }
+    }
+
+    mod e1_unchecked {
+        use {
+            crate::{E1, E1_unchecked, Ei, Ei_unchecked, Precision},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Wherever the checked versions succeed, the unchecked ones agree to the bit.
+        #[quickcheck]
+        fn same(x: NonZero<Finite<f64>>) -> TestResult {
+            let (Ok(e1), Ok(ei)) = (E1(x, Precision::Full), Ei(x, Precision::Full)) else {
+                return TestResult::discard();
+            };
+            // SAFETY:
+            // `E1` succeeded, so `x` is in range.
+            if unsafe { E1_unchecked(x, Precision::Full) } != e1 {
+                return TestResult::error("`E1_unchecked` disagrees with `E1`");
+            }
+            // SAFETY:
+            // `Ei` succeeded, so `x` is in range.
+            TestResult::from_bool(unsafe { Ei_unchecked(x, Precision::Full) } == ei)
+        }
+    }
+
+    mod e2 {
+        extern crate alloc;
+
+        use {
+            super::approx_compare::approx,
+            crate::{E1, E2, Precision},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Against mpmath at 50 digits, on either side of zero and past the asymptotic cutoff,
+        // with the error estimate covering the difference.
+        #[test]
+        fn reference() -> Result<(), String> {
+            for (x, expected) in [
+                (-5_f64, -52.513_217_676_439_28_f64),
+                (-1_f64, 0.823_164_012_103_108_5_f64),
+                (0.5_f64, 0.326_643_862_324_553_f64),
+                (1_f64, 0.148_495_506_775_922_05_f64),
+                (200_f64, 6.851_305_475_210_411e-90_f64),
+            ] {
+                let approx = E2(Finite::new(x), Precision::Full)
+                    .map_err(|e| format!("E2({x}) failed: {e}"))?;
+                let difference = (*approx.value - expected).abs();
+                if difference > 1e-14_f64 * expected.abs()
+                    || approx.error().is_some_and(|error| difference > **error)
+                {
+                    return Err(format!("E2({x}) = {approx} but mpmath gives {expected}"));
+                }
+            }
+            Ok(())
+        }
+
+        // $E_{2}(x) = e^{-x} - x E_{1}(x)$, carried through `Approx`'s own arithmetic,
+        // so that both the value and the error estimate have to agree.
+        #[quickcheck]
+        #[expect(
+            clippy::arithmetic_side_effects,
+            reason = "both terms are finite for arguments this small"
+        )]
+        fn recurrence(x: NonZero<Finite<f64>>) -> TestResult {
+            if x.abs() > 100_f64 {
+                return TestResult::discard();
+            }
+            let (Ok(e1), Ok(e2)) = (E1(x, Precision::Full), E2(*x, Precision::Full)) else {
+                return TestResult::discard();
+            };
+            let exponential = libm::exp(-**x);
+            let expected = approx(exponential, f64::EPSILON * exponential) - e1 * **x;
+            let tolerance = 1e-13_f64 * expected.value.abs();
+            if (*e2.value - *expected.value).abs() <= tolerance
+                && (e2.overlaps(&expected) || !cfg!(feature = "error"))
+            {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!("E2({x}) = {e2} but e^-x - x E1(x) = {expected}"))
+            }
+        }
+    }
+
+    mod e3 {
+        extern crate alloc;
+
+        use {
+            crate::{E3, En, Precision},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, Positive},
+        };
+
+        // Against mpmath at 50 digits, on either side of where the recurrence stops,
+        // with the error estimate covering the difference.
+        #[test]
+        fn reference() -> Result<(), String> {
+            for (x, expected) in [
+                (0_f64, 0.5_f64),
+                (1_f64, 0.109_691_967_197_760_13_f64),
+                (2_f64, 0.030_133_379_797_815_89_f64),
+                (3_f64, 0.008_930_646_556_022_725_f64),
+                (50_f64, 3.642_909_426_475_205e-24_f64),
+                (99_f64, 9.916_742_750_119_252e-46_f64),
+            ] {
+                let approx = E3(Finite::new(x), Precision::Full)
+                    .map_err(|e| format!("E3({x}) failed: {e}"))?;
+                let difference = (*approx.value - expected).abs();
+                if difference > 1e-15_f64 * expected
+                    || approx.error().is_some_and(|error| difference > **error)
+                {
+                    return Err(format!("E3({x}) = {approx} but mpmath gives {expected}"));
+                }
+            }
+            Ok(())
+        }
+
+        // Both ways of reaching the third order agree closely, however large the argument.
+        #[quickcheck]
+        fn en(x: Positive<Finite<f64>>) -> TestResult {
+            let (Ok(e3), Ok(en)) = (E3(*x, Precision::Full), En(3, *x, Precision::Full)) else {
+                return TestResult::discard();
+            };
+            if en.value.abs() < 1e-280_f64 {
+                return TestResult::discard();
+            }
+            let relative = ((*e3.value - *en.value) / *en.value).abs();
+            if relative < 1e-14_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E3({x}) = {} but En gave {} (relative difference {relative})",
+                    e3.value, en.value,
+                ))
+            }
+        }
+    }
+
+    mod ei_inv {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Ei_inv, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Round trip through the inverse.
+        #[quickcheck]
+        fn round_trip(y: Finite<f64>) -> TestResult {
+            if y.abs() > 1e6_f64 {
+                return TestResult::discard();
+            }
+            let Ok(x) = Ei_inv(y, Precision::Full) else {
+                return if *y < -700_f64 {
+                    // Root too close to zero to represent:
+                    TestResult::discard()
+                } else {
+                    TestResult::error("Inversion failed")
+                };
+            };
+            let Ok(back) = Ei(NonZero::new(x.value), Precision::Full) else {
+                return TestResult::error(format!(
+                    "Ei_inv({y}) = {} is out of Ei's range",
+                    x.value
+                ));
+            };
+            // Relative away from 0, absolute near it, and allowing for rounding `x` itself,
+            // which Ei magnifies by $e^{x}$:
+            let conditioning = 4_f64 * f64::EPSILON * libm::exp(*x.value);
+            let difference = (*back.value - *y).abs() - conditioning;
+            if difference < 1e-12_f64 * y.abs().max(1_f64) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei(Ei_inv({y})) = Ei({}) = {} (difference {difference})",
+                    x.value, back.value,
+                ))
+            }
+        }
+    }
+
+    mod ei_linspace {
+        extern crate alloc;
+
+        use {
+            crate::{Approx, Ei, Ei_linspace, Error, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Sharing the classification must never change a single bit.
+        #[quickcheck]
+        fn pointwise(start: Finite<f64>, stop: Finite<f64>, n: u8) -> TestResult {
+            let mut grid = [Approx::default(); 256];
+            let Some(prefix) = grid.get_mut(..usize::from(n)) else {
+                return TestResult::discard();
+            };
+            let result = Ei_linspace(start, stop, prefix, Precision::Full);
+            let intervals = f64::from(n.saturating_sub(1).max(1));
+            for (k, entry) in (0_u8..).zip(prefix.iter()) {
+                let t = f64::from(k) / intervals;
+                let x = Finite::new(start.mul_add(1_f64 - t, *stop * t));
+                let pointwise = NonZero::try_new(x).map_or(Err(Error::Domain(x)), |nonzero| {
+                    Ei(nonzero, Precision::Full)
+                });
+                match pointwise {
+                    Ok(reference) => {
+                        if entry.value.to_bits() != reference.value.to_bits() {
+                            return TestResult::error(format!(
+                                "Ei({x}) = {} but the grid gave {}",
+                                reference.value, entry.value,
+                            ));
+                        }
+                    }
+                    Err(e) => {
+                        return if result == Err(e) {
+                            TestResult::passed()
+                        } else {
+                            TestResult::error(format!(
+                                "Ei({x}) failed with {e:?} but the grid gave {result:?}",
+                            ))
+                        };
+                    }
+                }
+            }
+            if result.is_ok() {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Every point succeeded but the grid gave {result:?}"
+                ))
+            }
+        }
+    }
+
+    mod ei_with_branch {
+        extern crate alloc;
+
+        use {
+            crate::{Branch, Ei, Ei_with_branch, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // The principal value is Ei itself, sitting halfway between either side of the cut.
+        #[quickcheck]
+        fn principal_value(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(reference) = Ei(x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let sides = [
+                Branch::LowerHalfPlane,
+                Branch::PrincipalValue,
+                Branch::UpperHalfPlane,
+            ]
+            .map(|branch| Ei_with_branch(x, branch, Precision::Full));
+            let [Ok((lower, below)), Ok((principal, on)), Ok((upper, above))] = sides else {
+                return TestResult::error(format!("Ei({x}) = {reference} but a branch failed"));
+            };
+            if lower == reference
+                && principal == reference
+                && upper == reference
+                && *on == 0_f64
+                && below == Finite::new(-*above)
+                && (**x < 0_f64) == (*above == 0_f64)
+            {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) = {reference} but the branches gave {lower} + {below}i, {principal} + {on}i, and {upper} + {above}i",
+                ))
+            }
+        }
+    }
+
+    mod ei_ln {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Ei_ln, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Wherever Ei itself is representable, the two should agree.
+        #[quickcheck]
+        fn unscaled(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(ei) = Ei(x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            if !ei.value.is_normal() {
+                return TestResult::discard();
+            }
+            let Ok((ln, sign)) = Ei_ln(x, Precision::Full) else {
+                return TestResult::error(format!("Ei_ln({x}) failed where Ei didn't"));
+            };
+            let expected = libm::log(ei.value.abs());
+            let tolerance = 1e-12_f64 * expected.abs().max(1_f64);
+            if sign == Finite::new(libm::copysign(1_f64, *ei.value))
+                && (*ln.value - expected).abs() <= tolerance
+            {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) = {ei}, but Ei_ln gave {ln} with sign {sign}",
+                ))
+            }
+        }
+    }
+
+    mod ei_regular {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Ei_regular, Ein, Precision, consts},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Ein is the regular part of E1, so the two should mirror each other exactly.
+        #[quickcheck]
+        fn reflected_ein(x: Finite<f64>) -> TestResult {
+            let (Ok(regular), Ok(ein)) = (
+                Ei_regular(x, Precision::Full),
+                Ein(Finite::new(-*x), Precision::Full),
+            ) else {
+                return TestResult::discard();
+            };
+            if (*regular.value + *ein.value).abs() <= 1e-14_f64 * regular.value.abs() {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "The regular part of Ei({x}) is {regular}, but -Ein({}) = {}",
+                    -*x, -*ein.value,
+                ))
+            }
+        }
+
+        // Away from zero, subtracting the logarithm by hand is accurate enough to compare against.
+        #[quickcheck]
+        fn subtraction(x: NonZero<Finite<f64>>) -> TestResult {
+            if !(0.5_f64..=700_f64).contains(&x.abs()) {
+                return TestResult::discard();
+            }
+            let (Ok(regular), Ok(ei)) = (Ei_regular(*x, Precision::Full), Ei(x, Precision::Full))
+            else {
+                return TestResult::error("Evaluation failed");
+            };
+            let log_part = libm::log(x.abs()) + consts::EULER_GAMMA;
+            let expected = *ei.value - log_part;
+            if (*regular.value - expected).abs() <= 1e-13_f64 * (ei.value.abs() + log_part.abs()) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) - ln|{x}| - γ = {expected}, but the regular part came out {regular}",
+                ))
+            }
+        }
+    }
+
+    mod ei_scaled {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Ei_scaled, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Wherever Ei itself is representable, scaling by hand should agree.
+        #[quickcheck]
+        fn unscaled(x: NonZero<Finite<f64>>) -> TestResult {
+            if x.abs() > 700_f64 {
+                return TestResult::discard();
+            }
+            let Ok(reference) = Ei(x, Precision::Full) else {
+                return TestResult::error("Unscaled evaluation failed");
+            };
+            let scaled = Ei_scaled(x, Precision::Full);
+            let expected = libm::exp(-**x) * *reference.value;
+            let relative = ((*scaled.value - expected) / expected).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "e^(-{x}) Ei({x}) = {expected} but Ei_scaled gave {} (relative difference {relative})",
+                    scaled.value,
+                ))
+            }
+        }
+    }
+
+    mod ei_slice {
+        extern crate alloc;
+
+        use {
+            crate::{Approx, BatchError, Ei, Ei_slice, Ei_slice_approx, Precision},
+            alloc::{format, string::String, vec, vec::Vec},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Every entry should match `Ei` to the bit, or the whole batch should fail at the first entry that doesn't,
+        // without writing anything.
+        #[quickcheck]
+        fn pointwise(xs: Vec<f64>) -> TestResult {
+            let mut out = vec![42_f64; xs.len()];
+            let result = Ei_slice(&xs, &mut out, Precision::Full);
+            let mut expected = Ok(());
+            for (index, &x) in xs.iter().enumerate() {
+                let Some(nonzero) = Finite::try_new(x).and_then(NonZero::try_new) else {
+                    expected = Err(BatchError::Domain { index, value: x });
+                    break;
+                };
+                if let Err(error) = Ei(nonzero, Precision::Full) {
+                    expected = Err(BatchError::Range { error, index });
+                    break;
+                }
+            }
+            // NaN entries don't equal themselves, so compare their bits:
+            let same = match (result, expected) {
+                (
+                    Err(BatchError::Domain { index, value }),
+                    Err(BatchError::Domain {
+                        index: expected_index,
+                        value: expected_value,
+                    }),
+                ) => index == expected_index && value.to_bits() == expected_value.to_bits(),
+                _ => result == expected,
+            };
+            if !same {
+                return TestResult::error(format!("Expected {expected:?} but got {result:?}"));
+            }
+            for (x, entry) in xs.into_iter().zip(out) {
+                let reference = if result.is_ok() {
+                    Ei(NonZero::new(Finite::new(x)), Precision::Full)
+                        .map_or(f64::NAN, |approx| *approx.value)
+                } else {
+                    42_f64
+                };
+                if entry.to_bits() != reference.to_bits() {
+                    return TestResult::error(format!(
+                        "At {x}, expected {reference} but the batch gave {entry}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // Keeping the error estimates must give exactly what `Ei` does.
+        #[quickcheck]
+        fn approx(xs: Vec<NonZero<Finite<f64>>>) -> TestResult {
+            let raw: Vec<f64> = xs.iter().map(|x| ***x).collect();
+            let mut out = vec![Approx::default(); xs.len()];
+            if Ei_slice_approx(&raw, &mut out, Precision::Full).is_err() {
+                return TestResult::discard();
+            }
+            for (x, entry) in xs.into_iter().zip(out) {
+                let reference = Ei(x, Precision::Full);
+                if reference != Ok(entry) {
+                    return TestResult::error(format!(
+                        "Ei({x}) = {reference:?} but the batch gave {entry}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        #[test]
+        fn length_mismatch() -> Result<(), String> {
+            let result = Ei_slice(&[1_f64, 2_f64], &mut [0_f64; 3], Precision::Full);
+            if result
+                == Err(BatchError::LengthMismatch {
+                    inputs: 2,
+                    outputs: 3,
+                })
+            {
+                Ok(())
+            } else {
+                Err(format!("Mismatched lengths gave {result:?}"))
+            }
+        }
+    }
+
+    mod ein {
+        extern crate alloc;
+
+        use {
+            crate::{Ein, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::Finite,
+        };
+
+        // The series just inside |x| = 1 should meet the closed form just outside it.
+        #[quickcheck]
+        fn continuous_at_one(negative: bool) -> TestResult {
+            let sign = if negative { -1_f64 } else { 1_f64 };
+            let (Ok(inside), Ok(outside)) = (
+                Ein(Finite::new(sign), Precision::Full),
+                Ein(Finite::new(sign * (1_f64 + f64::EPSILON)), Precision::Full),
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let relative = ((*outside.value - *inside.value) / *inside.value).abs();
+            if relative < 1e-13_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ein({sign}) = {} by the series but {} by the closed form (relative difference {relative})",
+                    inside.value, outside.value,
+                ))
+            }
+        }
+    }
+
+    mod en {
+        extern crate alloc;
+
+        use {
+            crate::{Precision, implementation::en},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, Positive},
+        };
+
+        // Forward recurrence and the continued fraction overlap on [1/2, 1].
+        #[quickcheck]
+        fn forward_recurrence_vs_continued_fraction(n: u8, x: Positive<Finite<f64>>) -> TestResult {
+            if **x < 0.5_f64 || **x > 1_f64 || n < 3 || usize::from(n) > en::MAX_FORWARD_RECURRENCE
+            {
+                return TestResult::discard();
+            }
+            let Ok(recurrence) = en::forward_recurrence(usize::from(n), *x, false, Precision::Full)
+            else {
+                return TestResult::error("Forward recurrence failed");
+            };
+            let Ok(fraction) = en::continued_fraction(f64::from(n), x, false) else {
+                return TestResult::error("Continued fraction failed");
+            };
+            let relative = ((*fraction.approx.value - *recurrence.value) / *recurrence.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E{n}({x}): forward recurrence gave {} but the continued fraction gave {} (relative difference {relative})",
+                    recurrence.value, fraction.approx.value,
+                ))
+            }
+        }
+
+        // Both continued fractions converge for `x` at least 1, so dividing them should agree with the ratio's own.
+        #[quickcheck]
+        fn ratio_continued_fraction_vs_quotient(n: u8, x: Positive<Finite<f64>>) -> TestResult {
+            if **x < 1_f64 {
+                return TestResult::discard();
+            }
+            let (Ok(lower), Ok(upper)) = (
+                en::continued_fraction(f64::from(n), x, true),
+                en::continued_fraction(f64::from(n) + 1_f64, x, true),
+            ) else {
+                return TestResult::error("Continued fraction failed");
+            };
+            let expected = *upper.approx.value / *lower.approx.value;
+            let ratio = en::ratio_continued_fraction(f64::from(n), x);
+            let relative = ((*ratio.approx.value - expected) / expected).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E{}({x}) / E{n}({x}): dividing continued fractions gave {expected} but the ratio's own gave {} (relative difference {relative})",
+                    u16::from(n) + 1,
+                    ratio.approx.value,
+                ))
+            }
+        }
+    }
+
+    mod en_ratio {
+        extern crate alloc;
+
+        use {
+            crate::{En_ratio, En_scaled, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::Finite,
+        };
+
+        // Dividing scaled values is fine wherever both are comfortably normal.
+        // (For nonpositive `x`, `En_ratio` is exactly that quotient, so there's nothing to compare.)
+        #[quickcheck]
+        fn quotient(n: u8, x: Finite<f64>) -> TestResult {
+            if *x <= 0_f64 {
+                return TestResult::discard();
+            }
+            let Ok(lower) = En_scaled(usize::from(n), x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(upper) = En_scaled(usize::from(n) + 1, x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            if lower.value.abs() < 1e-280_f64 || upper.value.abs() < 1e-280_f64 {
+                return TestResult::discard();
+            }
+            let expected = *upper.value / *lower.value;
+            let Ok(ratio) = En_ratio(usize::from(n), x, Precision::Full) else {
+                return TestResult::error("Ratio failed where both orders succeeded");
+            };
+            let relative = ((*ratio.value - expected) / expected).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E{}({x}) / E{n}({x}) = {expected} but En_ratio gave {} (relative difference {relative})",
+                    u16::from(n) + 1,
+                    ratio.value,
+                ))
+            }
+        }
+
+        // Far past where both orders underflow, the ratio should still match its asymptotic form,
+        // $1 - \frac{1}{x + n + 2}$ up to a relative $O(\frac{n}{x^{3}})$.
+        #[quickcheck]
+        fn huge(n: u8, x: Finite<f64>) -> TestResult {
+            if *x <= 1e6_f64 {
+                return TestResult::discard();
+            }
+            let Ok(ratio) = En_ratio(usize::from(n), x, Precision::Full) else {
+                return TestResult::error("Ratio failed for huge positive x");
+            };
+            let expected = 1_f64 - (*x + f64::from(n) + 2_f64).recip();
+            let relative = ((*ratio.value - expected) / expected).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E{}({x}) / E{n}({x}) should be about {expected} but En_ratio gave {} (relative difference {relative})",
+                    u16::from(n) + 1,
+                    ratio.value,
+                ))
+            }
+        }
+    }
+
+    mod en_scaled {
+        extern crate alloc;
+
+        use {
+            crate::{En, En_scaled, Precision},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::Finite,
+        };
+
+        // Wherever En itself is representable, scaling by hand should agree.
+        #[quickcheck]
+        fn unscaled(n: u8, x: Finite<f64>) -> TestResult {
+            if x.abs() > 700_f64 {
+                return TestResult::discard();
+            }
+            let Ok(reference) = En(usize::from(n), x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(scaled) = En_scaled(usize::from(n), x, Precision::Full) else {
+                return TestResult::error("Scaled evaluation failed where unscaled succeeded");
+            };
+            let expected = libm::exp(*x) * *reference.value;
+            let relative = ((*scaled.value - expected) / expected).abs();
+            if relative < 1e-10_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "e^{x} E{n}({x}) = {expected} but En_scaled gave {} (relative difference {relative})",
+                    scaled.value,
+                ))
+            }
+        }
+
+        // Near `f64::MAX`, e^x E_n(x) is 1/(x + n) to within a rounding, though the result is subnormal.
+        #[test]
+        fn near_max() -> Result<(), String> {
+            for x in [
+                1.666_604_529_239_868e308_f64,
+                1.725_708_862_020_936_2e308_f64,
+                f64::MAX,
+            ] {
+                for n in [3_u8, 5_u8, 50_u8] {
+                    let scaled = En_scaled(usize::from(n), Finite::new(x), Precision::Full)
+                        .map_err(|e| format!("e^{x} E{n}({x}) failed: {e:?}"))?;
+                    let expected = (x + f64::from(n)).recip();
+                    if (*scaled.value - expected).abs() > f64::from_bits(1) {
+                        return Err(format!(
+                            "e^{x} E{n}({x}) = {expected} but En_scaled gave {}",
+                            scaled.value,
+                        ));
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    mod en_sequence {
+        extern crate alloc;
+
+        use {
+            crate::{Approx, En, En_scaled, En_scaled_sequence, En_sequence, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::Finite,
+        };
+
+        // Every entry should match its order computed on its own.
+        fn each_scaled(n: u8, x: Finite<f64>) -> TestResult {
+            let mut orders = [Approx::default(); 256];
+            let Some(prefix) = orders.get_mut(..usize::from(n)) else {
+                return TestResult::discard();
+            };
+            if En_scaled_sequence(x, prefix, Precision::Full).is_err() {
+                return TestResult::discard();
+            }
+            for (k, entry) in (1_usize..).zip(prefix.iter()) {
+                let Ok(reference) = En_scaled(k, x, Precision::Full) else {
+                    return TestResult::error(format!("E{k}({x}) failed on its own"));
+                };
+                let relative = ((*entry.value - *reference.value) / *reference.value).abs();
+                if relative >= 1e-12_f64 {
+                    return TestResult::error(format!(
+                        "e^{x} E{k}({x}) = {} but the sequence gave {} (relative difference {relative})",
+                        reference.value, entry.value,
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        #[quickcheck]
+        fn scaled(n: u8, x: Finite<f64>) -> TestResult {
+            each_scaled(n, x)
+        }
+
+        // Once caught by `scaled`: near `f64::MAX`, where the reference itself used to drift.
+        #[test]
+        fn scaled_near_max() {
+            assert!(!each_scaled(8_u8, Finite::new(1.725_708_862_020_936_2e308_f64)).is_failure());
+        }
+
+        // Same, unscaled, wherever the result is comfortably normal.
+        #[quickcheck]
+        fn unscaled(n: u8, x: Finite<f64>) -> TestResult {
+            let mut orders = [Approx::default(); 256];
+            let Some(prefix) = orders.get_mut(..usize::from(n)) else {
+                return TestResult::discard();
+            };
+            if En_sequence(x, prefix, Precision::Full).is_err() {
+                return TestResult::discard();
+            }
+            for (k, entry) in (1_usize..).zip(prefix.iter()) {
+                let Ok(reference) = En(k, x, Precision::Full) else {
+                    return TestResult::error(format!("E{k}({x}) failed on its own"));
+                };
+                if reference.value.abs() < 1e-280_f64 {
+                    continue;
+                }
+                let relative = ((*entry.value - *reference.value) / *reference.value).abs();
+                if relative >= 1e-12_f64 {
+                    return TestResult::error(format!(
+                        "E{k}({x}) = {} but the sequence gave {} (relative difference {relative})",
+                        reference.value, entry.value,
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+    }
+
+    mod enu {
+        extern crate alloc;
+
+        use {
+            crate::{Enu, Precision},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, Positive},
+        };
+
+        // $E_{\nu + 1}(x) = (e^{-x} - x E_{\nu}(x)) / \nu$ ties orders on either side
+        // of the crossover between the continued fraction and the incomplete gamma function.
+        #[quickcheck]
+        fn recurrence(nu: Positive<Finite<f64>>, x: Positive<Finite<f64>>) -> TestResult {
+            if **nu < 0.5_f64 || **nu > 4.5_f64 || **x > 2_f64 {
+                return TestResult::discard();
+            }
+            let (Ok(lower), Ok(upper)) = (
+                Enu(*nu, *x, Precision::Full),
+                Enu(Finite::new(**nu + 1_f64), *x, Precision::Full),
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let expected = (libm::exp(-**x) - **x * *lower.value) / **nu;
+            let relative = ((*upper.value - expected) / expected).abs();
+            if relative < 1e-10_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E_{{{nu} + 1}}({x}) = {} but the recurrence gave {expected} (relative difference {relative})",
+                    upper.value,
+                ))
+            }
+        }
+
+        // Above order 1, $E_{\nu}(x) \to \frac{1}{\nu - 1}$ as $x \to 0$,
+        // even where $\Gamma(1 - \nu, x)$ on its own would overflow.
+        #[test]
+        fn small_argument() -> Result<(), String> {
+            for (nu, x) in [
+                (2.5_f64, 1e-300_f64),
+                (10.5_f64, 1e-40_f64),
+                (20.5_f64, 1e-20_f64),
+            ] {
+                let expected = (nu - 1_f64).recip();
+                let approx = Enu(Finite::new(nu), Finite::new(x), Precision::Full)
+                    .map_err(|e| format!("E_{nu}({x}) failed: {e}"))?;
+                let relative = ((*approx.value - expected) / expected).abs();
+                if relative > 1e-14_f64 {
+                    return Err(format!(
+                        "E_{nu}({x}) = {approx} but expected {expected} (relative difference {relative})"
+                    ));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    mod enu_order_derivative {
+        extern crate alloc;
+
+        use {
+            crate::{Enu, Enu_order_derivative, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::Finite,
+        };
+
+        // A central difference in the order should match to about the square root of machine precision,
+        // on both sides of the switch from the series to quadrature at 1.
+        #[quickcheck]
+        fn central_difference(nu: Finite<f64>, x: Finite<f64>) -> TestResult {
+            if !(-20_f64..=20_f64).contains(&*nu) || !(0.01_f64..=50_f64).contains(&*x) {
+                return TestResult::discard();
+            }
+            let step = 1e-5_f64;
+            let (Ok(above), Ok(below)) = (
+                Enu(Finite::new(*nu + step), x, Precision::Full),
+                Enu(Finite::new(*nu - step), x, Precision::Full),
+            ) else {
+                return TestResult::discard();
+            };
+            let Ok(derivative) = Enu_order_derivative(nu, x, Precision::Full) else {
+                return TestResult::error("Enu_order_derivative failed where Enu succeeded");
+            };
+            let difference = (*above.value - *below.value) / (2_f64 * step);
+            let relative = ((difference - *derivative.value) / *derivative.value).abs();
+            if relative < 1e-6_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "dE{nu}({x})/dnu = {} but the central difference gave {difference} (relative difference {relative})",
+                    derivative.value,
+                ))
+            }
+        }
+
+        // As x goes to zero above order 1, the integral becomes $-\int_{1}^{\infty} t^{-\nu} \ln t \text{d}t$.
+        #[quickcheck]
+        fn limit(nu: Finite<f64>) -> TestResult {
+            if !(1.5_f64..=1e6_f64).contains(&*nu) {
+                return TestResult::discard();
+            }
+            let Ok(derivative) = Enu_order_derivative(nu, Finite::new(1e-300_f64), Precision::Full)
+            else {
+                return TestResult::error("Enu_order_derivative failed");
+            };
+            let expected = -(*nu - 1_f64).powi(2).recip();
+            let relative = ((*derivative.value - expected) / expected).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "dE{nu}(0)/dnu = {expected} but Enu_order_derivative gave {} (relative difference {relative})",
+                    derivative.value,
+                ))
+            }
+        }
+    }
+
+    mod error_trait {
+        extern crate alloc;
+
+        use {
+            crate::{BatchError, E1, E1_slice, E3, Error, Precision, e3, li, neg, pos},
+            alloc::{boxed::Box, string::ToString as _},
+            core::error,
+            sigma_types::{Finite, Negative, NonZero, Positive},
+        };
+
+        /// `E1` at `x`, or `neg::E1` or `pos::E1` if `by_sign`, through `?` into a boxed error.
+        fn boxed(x: Finite<f64>, by_sign: bool) -> Result<f64, Box<dyn error::Error>> {
+            if !by_sign {
+                return Ok(*E1(NonZero::new(x), Precision::Full)?.value);
+            }
+            if *x < 0_f64 {
+                Ok(*neg::E1(Negative::new(x), Precision::Full)?.value)
+            } else {
+                Ok(*pos::E1(Positive::new(x), Precision::Full)?.value)
+            }
+        }
+
+        // Every error survives `?` into `Box<dyn Error>` with its message intact.
+        #[test]
+        fn question_mark() {
+            let positive = Finite::new(800_f64);
+            let negative = Finite::new(-800_f64);
+            for (x, error, by_sign) in [
+                (
+                    positive,
+                    Error::Underflow,
+                    Error::ArgumentTooPositive(Positive::new(positive)),
+                ),
+                (
+                    negative,
+                    Error::ArgumentTooNegative(Negative::new(negative)),
+                    Error::ArgumentTooNegative(Negative::new(negative)),
+                ),
+            ] {
+                assert_eq!(
+                    boxed(x, false).map_err(|e| e.to_string()),
+                    Err(error.to_string())
+                );
+                assert_eq!(
+                    boxed(x, true).map_err(|e| e.to_string()),
+                    Err(by_sign.to_string())
+                );
+            }
+        }
+
+        // The errors of `E3`, `li`, and the slice functions box the same way, with nothing beneath them.
+        #[test]
+        fn others() {
+            let x = Finite::new(-1_f64);
+            let boxes: [(Option<Box<dyn error::Error>>, _); 3] = [
+                (
+                    E3(x, Precision::Full).err().map(Box::from),
+                    e3::Error::NegativeArgument(Negative::new(x)).to_string(),
+                ),
+                (
+                    li(x, Precision::Full).err().map(Box::from),
+                    li::Error::Domain(x).to_string(),
+                ),
+                (
+                    E1_slice(&[1_f64, 0_f64], &mut [0_f64; 2], Precision::Full)
+                        .err()
+                        .map(Box::from),
+                    BatchError::Domain {
+                        index: 1,
+                        value: 0_f64,
+                    }
+                    .to_string(),
+                ),
+            ];
+            for (boxed_error, message) in boxes {
+                assert_eq!(
+                    boxed_error.map(|e| (e.to_string(), e.source().is_none())),
+                    Some((message, true))
+                );
+            }
+        }
+    }
+
+    #[cfg(all(feature = "fast-poly", not(feature = "double-double")))]
+    mod estrin {
+        extern crate alloc;
+
+        use {
+            crate::{chebyshev, constants},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, usize::LessThan},
+        };
+
+        /// Estrin's scheme on the polynomial should match Clenshaw's recurrence on the series
+        /// to within rounding, i.e. a few units in the last place of the largest monomial coefficient.
+        fn agree<const N: usize>(
+            coefficients: &[f64; N],
+            monomials: &[f64; N],
+            y: Finite<f64>,
+        ) -> Result<(), String> {
+            let clenshaw = chebyshev::eval(
+                Finite::all(coefficients),
+                y,
+                LessThan::new(N.wrapping_sub(1)),
+            );
+            let estrin = chebyshev::eval_estrin(
+                Finite::all(coefficients),
+                monomials,
+                y,
+                LessThan::new(N.wrapping_sub(1)),
+            );
+            let magnitude: f64 = monomials.iter().map(|a| a.abs()).sum();
+            let difference = (*estrin.value - *clenshaw.value).abs();
+            if difference > 16_f64 * f64::EPSILON * magnitude {
+                return Err(format!(
+                    "At {y}, Clenshaw's recurrence gave {clenshaw} but Estrin's scheme gave {estrin}"
+                ));
+            }
+            #[cfg(feature = "error")]
+            if difference > **estrin.error + **clenshaw.error {
+                return Err(format!(
+                    "At {y}, Clenshaw's recurrence gave {clenshaw} but Estrin's scheme gave {estrin}, \
+                     outside both error estimates"
+                ));
+            }
+            Ok(())
+        }
+
+        #[quickcheck]
+        fn every_fit(x: Finite<f64>) -> TestResult {
+            let y = Finite::new(*x / (1_f64 + x.abs()));
+            let result = agree(
+                &constants::piecewise::AE11.coefficients,
+                &constants::monomial::AE11,
+                y,
+            )
+            .and_then(|()| {
+                agree(
+                    &constants::piecewise::AE12.coefficients,
+                    &constants::monomial::AE12,
+                    y,
+                )
+            })
+            .and_then(|()| {
+                agree(
+                    &constants::piecewise::AE13.coefficients,
+                    &constants::monomial::AE13,
+                    y,
+                )
+            })
+            .and_then(|()| {
+                agree(
+                    &constants::piecewise::AE14.coefficients,
+                    &constants::monomial::AE14,
+                    y,
+                )
+            })
+            .and_then(|()| {
+                agree(
+                    &constants::piecewise::E11.coefficients,
+                    &constants::monomial::E11,
+                    y,
+                )
+            })
+            .and_then(|()| {
+                agree(
+                    &constants::piecewise::E12.coefficients,
+                    &constants::monomial::E12,
+                    y,
+                )
+            });
+            match result {
+                Ok(()) => TestResult::passed(),
+                Err(e) => TestResult::error(e),
+            }
+        }
+    }
+
+    mod evaluator {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Ei, Precision, evaluator::Evaluator},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        /// Whether `evaluator` gives exactly what `E1` and `Ei` give at `max_precision`,
+        /// reporting the error estimate only if asked (and only with the `error` feature).
+        fn same(
+            evaluator: Evaluator,
+            x: NonZero<Finite<f64>>,
+            max_precision: Precision,
+            with_error: bool,
+        ) -> TestResult {
+            for (name, through, direct) in [
+                ("E1", evaluator.e1(x), E1(x, max_precision)),
+                ("Ei", evaluator.ei(x), Ei(x, max_precision)),
+            ] {
+                match (through, direct) {
+                    (Ok(evaluation), Ok(approx)) => {
+                        let error = if with_error { approx.error() } else { None };
+                        if evaluation.value.to_bits() != approx.value.to_bits()
+                            || evaluation.error != error
+                            || approx.error().is_some() != cfg!(feature = "error")
+                        {
+                            return TestResult::error(format!(
+                                "{name}({x}) through `Evaluator` was {evaluation:?}, but directly {approx:?}"
+                            ));
+                        }
+                    }
+                    (Err(through_error), Err(direct_error)) if through_error == direct_error => {}
+                    (through_result, direct_result) => {
+                        return TestResult::error(format!(
+                            "{name}({x}) through `Evaluator` was {through_result:?}, but directly {direct_result:?}"
+                        ));
+                    }
+                }
+            }
+            TestResult::passed()
+        }
+
+        // `max_terms(n)` sums n terms, i.e. up to order n - 1 (and at least the first term).
+        #[quickcheck]
+        fn max_terms(x: NonZero<Finite<f64>>, terms: u8, with_error: bool) -> TestResult {
+            let evaluator = Evaluator::new()
+                .max_terms(usize::from(terms))
+                .with_error(with_error);
+            let order = usize::from(terms).saturating_sub(1);
+            same(evaluator, x, Precision::Terms(order), with_error)
+        }
+
+        // `rel_tolerance` passes straight through, replacing any `max_terms` set before it.
+        #[quickcheck]
+        fn rel_tolerance(
+            x: NonZero<Finite<f64>>,
+            terms: u8,
+            exponent: u8,
+            with_error: bool,
+        ) -> TestResult {
+            let tolerance = libm::exp10(-f64::from(exponent) / 16_f64);
+            let evaluator = Evaluator::new()
+                .max_terms(usize::from(terms))
+                .rel_tolerance(tolerance)
+                .with_error(with_error);
+            same(evaluator, x, Precision::RelTolerance(tolerance), with_error)
+        }
+    }
+
+    mod expint_3 {
+        extern crate alloc;
+
+        use {
+            crate::{Enu, Precision, expint_3},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonNegative, Positive},
+        };
+
+        // Substituting $u = t^3$ turns the tail into a fractional-order `En`:
+        // $\int_{x}^{\infty} e^{-t^3} \text{d}t = x E_{2/3}(x^3) / 3$.
+        #[quickcheck]
+        fn enu_tail(x: Positive<Finite<f64>>) -> TestResult {
+            if **x < 0.5_f64 || **x > 3_f64 {
+                return TestResult::discard();
+            }
+            let Ok(tail) = Enu(
+                Finite::new(2_f64 / 3_f64),
+                Finite::new(**x * **x * **x),
+                Precision::Full,
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let expected = 0.892_979_511_569_249_2_f64 - **x * *tail.value / 3_f64;
+            let actual = expint_3(NonNegative::new(*x), Precision::Full);
+            let relative = ((*actual.value - expected) / expected).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "expint_3({x}) = {} but Γ(4/3) - x E_{{2/3}}(x³) / 3 = {expected} (relative difference {relative})",
+                    actual.value,
+                ))
+            }
+        }
+    }
+
+    mod expint_natural {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Ei, Precision, expint_E1, expint_Ei},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Wherever `E1` and `Ei` succeed, to the bit.
+        #[quickcheck]
+        fn in_range(x: NonZero<Finite<f64>>) -> TestResult {
+            if let Ok(approx) = E1(x, Precision::Full) {
+                let natural = expint_E1(**x);
+                if natural.to_bits() != approx.value.to_bits() {
+                    return TestResult::error(format!(
+                        "E1({x}) is {approx} but `expint_E1` gave {natural}"
+                    ));
+                }
+            }
+            if let Ok(approx) = Ei(x, Precision::Full) {
+                let natural = expint_Ei(**x);
+                if natural.to_bits() != approx.value.to_bits() {
+                    return TestResult::error(format!(
+                        "Ei({x}) is {approx} but `expint_Ei` gave {natural}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // What GSL leaves in `result.val` after each failure.
+        #[test]
+        fn failures() -> Result<(), String> {
+            let cases = [
+                (800_f64, 0_f64, f64::NEG_INFINITY),
+                (f64::INFINITY, 0_f64, f64::NEG_INFINITY),
+                (-800_f64, f64::INFINITY, -0_f64),
+                (f64::NEG_INFINITY, f64::INFINITY, -0_f64),
+                (f64::NAN, 0_f64, -0_f64),
+            ];
+            for (x, e1, ei) in cases {
+                for (name, expected, actual) in [("E1", e1, expint_E1(x)), ("Ei", ei, expint_Ei(x))]
+                {
+                    if actual.to_bits() != expected.to_bits() {
+                        return Err(format!(
+                            "Expected {name}({x}) = {expected} but got {actual}"
+                        ));
+                    }
+                }
+            }
+            if !expint_E1(0_f64).is_nan() || !expint_Ei(0_f64).is_nan() {
+                return Err("Expected NaN at zero".into());
+            }
+            Ok(())
+        }
+    }
+
+    mod extended {
+        use {
+            crate::{
+                Error, Precision,
+                extended::{self, NotNan},
+                with_zero::{self, Value},
+            },
+            quickcheck_macros::quickcheck,
+            sigma_types::Finite,
+        };
+
+        // Finite arguments, zero included, get exactly what `with_zero` gives.
+        #[quickcheck]
+        fn finite(x: Finite<f64>) -> bool {
+            NotNan::new(*x).is_some_and(|not_nan| {
+                extended::E1(not_nan, Precision::Full) == with_zero::E1(x, Precision::Full)
+                    && extended::Ei(not_nan, Precision::Full) == with_zero::Ei(x, Precision::Full)
+            })
+        }
+
+        // The limits at either infinity, to the sign of zero, and no argument at all from NaN.
+        #[test]
+        fn infinities() {
+            for (x, e1, ei) in [
+                (f64::INFINITY, 0_f64, f64::INFINITY),
+                (f64::NEG_INFINITY, f64::NEG_INFINITY, -0_f64),
+            ] {
+                let not_nan = NotNan::new(x);
+                let value = |f: fn(NotNan, Precision) -> Result<Value, Error>| {
+                    not_nan
+                        .and_then(|y| f(y, Precision::Full).ok())
+                        .map(|v| v.get().to_bits())
+                };
+                assert_eq!(value(extended::E1), Some(e1.to_bits()));
+                assert_eq!(value(extended::Ei), Some(ei.to_bits()));
+            }
+            assert_eq!(NotNan::new(f64::NAN), None);
+        }
+    }
+
+    mod exprel {
+        extern crate alloc;
+
+        use {
+            crate::exprel_n, alloc::format, quickcheck::TestResult, quickcheck_macros::quickcheck,
+            sigma_types::Finite,
+        };
+
+        // $\text{exprel}_{n + 1}(x) = \frac{n + 1}{x} (\text{exprel}_{n}(x) - 1)$
+        // ties the continued fraction to the asymptotic branches on either side of it.
+        #[quickcheck]
+        fn recurrence(n: u8, x: Finite<f64>) -> TestResult {
+            if !(3..=20).contains(&n) || x.abs() < 1_f64 || x.abs() > 50_f64 {
+                return TestResult::discard();
+            }
+            let (Ok(lower), Ok(upper)) =
+                (exprel_n(usize::from(n), x), exprel_n(usize::from(n) + 1, x))
+            else {
+                return TestResult::error("Evaluation failed");
+            };
+            let expected = (f64::from(n) + 1_f64) / *x * (*lower.value - 1_f64);
+            let relative = ((*upper.value - expected) / expected).abs();
+            if relative < 1e-10_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "exprel_{{{n} + 1}}({x}) = {} but the recurrence gave {expected} (relative difference {relative})",
+                    upper.value,
+                ))
+            }
+        }
+    }
+
+    mod fast {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Precision, fast},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        /// Worst relative error promised in the module documentation, with room for rounding.
+        const TOLERANCE: f64 = 2.1e-8_f64;
+
+        /// Compare against the full-precision path at `x`.
+        fn close(x: f64) -> Result<(), String> {
+            let fast = fast::Ei(x);
+            let precise = *Ei(NonZero::new(Finite::new(x)), Precision::Full)
+                .map_err(|e| format!("Ei({x}) failed: {e}"))?
+                .value;
+            let relative = ((fast - precise) / precise).abs();
+            if relative < TOLERANCE {
+                Ok(())
+            } else {
+                Err(format!(
+                    "At {x}, the fast path gave {fast} but Ei is {precise} (relative error {relative})"
+                ))
+            }
+        }
+
+        // Every region and every boundary between them, a few hundredths apart,
+        // everywhere the result is a normal `f64`.
+        #[test]
+        fn grid() -> Result<(), String> {
+            for k in -44_800_i32..=44_800_i32 {
+                if k != 0_i32 {
+                    close(f64::from(k) / 64_f64)?;
+                }
+            }
+            Ok(())
+        }
+
+        // Anywhere near the origin, where the pieces are narrowest.
+        #[quickcheck]
+        fn small(x: NonZero<Finite<f64>>) -> TestResult {
+            let y = **x / (1_f64 + x.abs() / 16_f64);
+            if y == 0_f64 {
+                return TestResult::discard();
+            }
+            match close(y) {
+                Ok(()) => TestResult::passed(),
+                Err(e) => TestResult::error(e),
+            }
+        }
+
+        #[test]
+        fn special() -> Result<(), String> {
+            let cases = [
+                (0_f64, f64::NEG_INFINITY),
+                (f64::INFINITY, f64::INFINITY),
+                (800_f64, f64::INFINITY),
+                // Ei is negative on this side, so it underflows to negative zero:
+                (f64::NEG_INFINITY, -0_f64),
+                (-800_f64, -0_f64),
+            ];
+            for (x, expected) in cases {
+                let actual = fast::Ei(x);
+                if actual.to_bits() != expected.to_bits() {
+                    return Err(format!("Expected {expected} at {x} but got {actual}"));
+                }
+            }
+            let nan = fast::Ei(f64::NAN);
+            if !nan.is_nan() {
+                return Err(format!("Expected NaN at NaN but got {nan}"));
+            }
+            Ok(())
+        }
+    }
+
+    mod gamma_inc_0 {
+        use {
+            crate::{E1, Error, Precision, gamma_inc_0},
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Exactly E1 on the positive axis, and a domain error everywhere else.
+        #[quickcheck]
+        fn e1(x: Finite<f64>) -> bool {
+            let alias = gamma_inc_0(x, Precision::Full);
+            if *x <= 0_f64 {
+                return alias == Err(Error::Domain(x));
+            }
+            alias == E1(NonZero::new(x), Precision::Full)
+        }
+    }
+
+    mod in_domain {
+        use {
+            crate::{
+                E1, Ei, Precision, constants,
+                in_domain::{self, InDomain},
+            },
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Rejected exactly where `E1` fails, with the same error, and the same to the bit everywhere else.
+        #[quickcheck]
+        fn same(x: NonZero<Finite<f64>>) -> bool {
+            let e1 = E1(x, Precision::Full);
+            match InDomain::new(x) {
+                Ok(checked) => {
+                    checked.get() == x
+                        && e1 == Ok(in_domain::E1(checked, Precision::Full))
+                        && Ei(x, Precision::Full) == Ok(in_domain::Ei(checked, Precision::Full))
+                }
+                Err(error) => e1 == Err(error),
+            }
+        }
+
+        #[test]
+        fn boundaries() {
+            for limit in [constants::NXMAX, constants::XMAX] {
+                let inside = limit - limit.signum() * limit.abs() * f64::EPSILON;
+                assert!(
+                    InDomain::new(NonZero::new(Finite::new(limit))).is_err(),
+                    "{limit} is out of range",
+                );
+                assert!(
+                    InDomain::new(NonZero::new(Finite::new(inside))).is_ok(),
+                    "{inside} is in range",
+                );
+            }
+        }
+    }
+
+    mod incomplete_gamma {
+        extern crate alloc;
+
+        use {
+            crate::{Error, Precision, incomplete_gamma::upper},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, Positive},
+        };
+
+        // $\Gamma(a + 1, x) = a \Gamma(a, x) + x^a e^{-x}$ ties the series to the continued fraction.
+        #[quickcheck]
+        fn recurrence(a: Positive<Finite<f64>>, x: Positive<Finite<f64>>) -> TestResult {
+            if **a < 0.1_f64 || **a > 6_f64 || **x > 10_f64 {
+                return TestResult::discard();
+            }
+            let (Ok(lower), Ok(higher)) = (
+                upper(*a, x, Precision::Full),
+                upper(Finite::new(**a + 1_f64), x, Precision::Full),
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let expected = **a * *lower.value + libm::pow(**x, **a) * libm::exp(-**x);
+            let relative = ((*higher.value - expected) / expected).abs();
+            if relative < 1e-10_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Γ({a} + 1, {x}) = {} but the recurrence gave {expected} (relative difference {relative})",
+                    higher.value,
+                ))
+            }
+        }
+
+        // Just below `x = a + 1` for huge `a`, successive terms of the lower series differ by a ULP,
+        // so it would take quadrillions of them to stop on its own: it has to give up instead.
+        #[test]
+        fn gives_up() {
+            let a = Finite::new(1e17_f64);
+            let x = Positive::new(Finite::new(1e17_f64.next_down()));
+            assert_eq!(upper(a, x, Precision::Full), Err(Error::Overflow));
+        }
+    }
+
+    mod integral {
+        extern crate alloc;
+
+        use {
+            crate::{Approx, E1, E1_integral, Ei, Ei_integral, Error, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        /// Compare a central difference of `antiderivative` against `integrand`,
+        /// which should match to about the square root of machine precision.
+        fn central_difference(
+            x: NonZero<Finite<f64>>,
+            antiderivative: fn(Finite<f64>, usize) -> Result<Approx, Error>,
+            integrand: fn(NonZero<Finite<f64>>, usize) -> Result<Approx, Error>,
+        ) -> TestResult {
+            if !(0.01_f64..=100_f64).contains(&x.abs()) {
+                return TestResult::discard();
+            }
+            let step = 1e-6_f64 * x.abs();
+            let (Ok(above), Ok(below), Ok(expected)) = (
+                antiderivative(Finite::new(**x + step), usize::MAX),
+                antiderivative(Finite::new(**x - step), usize::MAX),
+                integrand(x, usize::MAX),
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let difference = (*above.value - *below.value) / (2_f64 * step);
+            // Rounding in the antiderivative itself is magnified by the tiny step:
+            let tolerance = 1e-6_f64.mul_add(
+                expected.value.abs(),
+                16_f64 * f64::EPSILON * (above.value.abs() + below.value.abs()) / step,
+            );
+            if (difference - *expected.value).abs() < tolerance {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "The integrand at {x} is {expected}, but a central difference of its integral gave {difference}",
+                ))
+            }
+        }
+
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f64>>) -> TestResult {
+            central_difference(
+                x,
+                |t, order| E1_integral(t, Precision::Terms(order)),
+                |t, order| E1(t, Precision::Terms(order)),
+            )
+        }
+
+        #[quickcheck]
+        fn ei(x: NonZero<Finite<f64>>) -> TestResult {
+            central_difference(
+                x,
+                |t, order| Ei_integral(t, Precision::Terms(order)),
+                |t, order| Ei(t, Precision::Terms(order)),
+            )
+        }
+    }
+
+    mod interpolation {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Error, Precision, interpolation::TableEvaluator},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        /// Points per table, about a thousandth of a unit apart on `[1, 10]`.
+        const POINTS: usize = 2048;
+
+        /// A table from `start` to `stop`, which should build without trouble.
+        fn table(start: f64, stop: f64) -> Result<TableEvaluator<POINTS>, String> {
+            TableEvaluator::new(
+                NonZero::new(Finite::new(start)),
+                NonZero::new(Finite::new(stop)),
+                Precision::Full,
+            )
+            .map_err(|e| format!("Couldn't build a table from {start} to {stop}: {e}"))
+        }
+
+        // Two thousand points should be plenty for ten significant digits on either side of zero.
+        #[test]
+        fn bound() -> Result<(), String> {
+            for (start, stop) in [(1_f64, 10_f64), (-10_f64, -1_f64)] {
+                let relative_error = table(start, stop)?.relative_error();
+                if relative_error >= 1e-10_f64 {
+                    return Err(format!(
+                        "From {start} to {stop}, the interpolation is only good to {relative_error}"
+                    ));
+                }
+            }
+            Ok(())
+        }
+
+        // Anywhere in the table, interpolation should agree with `Ei` to within the table's own bound.
+        #[quickcheck]
+        fn within_bound(fraction: u32) -> TestResult {
+            let Ok(interpolator) = table(1_f64, 10_f64) else {
+                return TestResult::error("Couldn't build the table");
+            };
+            let x = 9_f64.mul_add(f64::from(fraction) / f64::from(u32::MAX), 1_f64);
+            let (Ok(interpolated), Ok(exact)) = (
+                interpolator.Ei(Finite::new(x)),
+                Ei(NonZero::new(Finite::new(x)), Precision::Full),
+            ) else {
+                return TestResult::error(format!("Evaluation failed at {x}"));
+            };
+            let actual = (*interpolated.value - *exact.value).abs();
+            // Neighbouring points differ by well under a percent, so this slack covers which end is larger:
+            let tolerance = 1.01_f64 * interpolator.relative_error() * exact.value.abs()
+                + 4_f64 * f64::EPSILON * exact.value.abs();
+            if actual > tolerance {
+                return TestResult::error(format!(
+                    "At {x}, interpolation gave {} but Ei is {} (difference {actual})",
+                    interpolated.value, exact.value,
+                ));
+            }
+            #[cfg(feature = "error")]
+            if actual > **interpolated.error + 2_f64 * **exact.error {
+                return TestResult::error(format!(
+                    "At {x}, interpolation gave {interpolated} but Ei is {exact} (difference {actual})",
+                ));
+            }
+            TestResult::passed()
+        }
+
+        #[test]
+        fn out_of_range() -> Result<(), String> {
+            let interpolator = table(1_f64, 10_f64)?;
+            for x in [0.5_f64, 10.5_f64] {
+                let result = interpolator.Ei(Finite::new(x));
+                if result != Err(Error::Domain(Finite::new(x))) {
+                    return Err(format!("Expected a domain error at {x} but got {result:?}"));
+                }
+            }
+            Ok(())
+        }
+
+        // Ei is undefined at zero, so no grid can reach across it, and grids must run left to right.
+        #[test]
+        fn rejected() -> Result<(), String> {
+            for (start, stop) in [(-1_f64, 1_f64), (10_f64, 1_f64), (1_f64, 1_f64)] {
+                if let Ok(interpolator) = table(start, stop) {
+                    return Err(format!(
+                        "Built a table from {start} to {stop}: {interpolator:?}"
+                    ));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "interval")]
+    mod interval {
+        extern crate alloc;
+
+        use {
+            crate::{
+                E1, Ei, Precision,
+                interval::{self, Interval},
+            },
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        #[cfg(feature = "quad")]
+        use crate::quad;
+
+        // Every point of the interval should map into the enclosure.
+        #[quickcheck]
+        fn encloses(a: NonZero<Finite<f64>>, b: NonZero<Finite<f64>>, t: u8) -> TestResult {
+            let x = Interval {
+                hi: a.max(**b),
+                lo: a.min(**b),
+            };
+            let (Ok(e1), Ok(ei)) = (interval::E1(x), interval::Ei(x)) else {
+                return TestResult::discard();
+            };
+            let fraction = f64::from(t) / f64::from(u8::MAX);
+            let point = x.lo.mul_add(1_f64 - fraction, x.hi * fraction);
+            let Some(inside) = Finite::try_new(point).and_then(NonZero::try_new) else {
+                return TestResult::discard();
+            };
+            let (Ok(e1_point), Ok(exponential)) =
+                (E1(inside, Precision::Full), Ei(inside, Precision::Full))
+            else {
+                return TestResult::error(format!("{x} evaluated but {point} inside it didn't"));
+            };
+            if e1.contains(*e1_point.value) && ei.contains(*exponential.value) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) is within {e1} and Ei({x}) within {ei}, but E1({point}) = {e1_point} and Ei({point}) = {exponential}",
+                ))
+            }
+        }
+
+        // A point's enclosure should contain the double-double value.
+        #[cfg(feature = "quad")]
+        #[quickcheck]
+        fn encloses_quad(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(enclosure) = interval::E1(Interval::point(**x)) else {
+                return TestResult::discard();
+            };
+            let Ok(exact) = quad::E1(x) else {
+                return TestResult::error(format!(
+                    "interval::E1({x}) = {enclosure} but quad::E1 failed"
+                ));
+            };
+            if enclosure.contains(exact.hi) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {exact} but interval::E1 gave {enclosure}"
+                ))
+            }
+        }
+
+        #[test]
+        fn rejects_zero() -> Result<(), String> {
+            let x = Interval {
+                hi: 1_f64,
+                lo: -1_f64,
+            };
+            match (interval::E1(x), interval::Ei(x)) {
+                (Err(interval::Error::Domain(e1)), Err(interval::Error::Domain(ei)))
+                    if e1 == x && ei == x =>
+                {
+                    Ok(())
+                }
+                (e1, ei) => Err(format!("E1({x}) = {e1:?} and Ei({x}) = {ei:?}")),
+            }
+        }
+    }
+
+    mod lenient {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Ei, Precision, lenient},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Wherever `E1` and `Ei` succeed, to the bit.
+        #[quickcheck]
+        fn in_range(x: NonZero<Finite<f64>>) -> TestResult {
+            if let Ok(approx) = E1(x, Precision::Full) {
+                let lenient = lenient::e1(**x);
+                if lenient.to_bits() != approx.value.to_bits() {
+                    return TestResult::error(format!(
+                        "E1({x}) is {approx} but `lenient` gave {lenient}"
+                    ));
+                }
+            }
+            if let Ok(approx) = Ei(x, Precision::Full) {
+                let lenient = lenient::ei(**x);
+                if lenient.to_bits() != approx.value.to_bits() {
+                    return TestResult::error(format!(
+                        "Ei({x}) is {approx} but `lenient` gave {lenient}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        #[test]
+        fn special() -> Result<(), String> {
+            let cases = [
+                (0_f64, f64::NEG_INFINITY, f64::INFINITY),
+                (-0_f64, f64::NEG_INFINITY, f64::INFINITY),
+                (f64::INFINITY, f64::INFINITY, 0_f64),
+                (800_f64, f64::INFINITY, 0_f64),
+                // Ei is negative on this side, so it underflows to negative zero:
+                (f64::NEG_INFINITY, -0_f64, f64::NEG_INFINITY),
+                (-800_f64, -0_f64, f64::NEG_INFINITY),
+            ];
+            for (x, ei, e1) in cases {
+                for (name, expected, actual) in
+                    [("Ei", ei, lenient::ei(x)), ("E1", e1, lenient::e1(x))]
+                {
+                    if actual.to_bits() != expected.to_bits() {
+                        return Err(format!(
+                            "Expected {name}({x}) = {expected} but got {actual}"
+                        ));
+                    }
+                }
+            }
+            if !lenient::ei(f64::NAN).is_nan() || !lenient::e1(f64::NAN).is_nan() {
+                return Err("Expected NaN at NaN".into());
+            }
+            // Past `XMAX` but not yet past the smallest subnormal, where both are nonzero (and Ei is negative):
+            for tiny in [-lenient::ei(-720_f64), lenient::e1(720_f64)] {
+                if !(tiny > 0_f64 && tiny < 1e-300_f64) {
+                    return Err(format!(
+                        "Expected a tiny nonzero magnitude at 720 but got {tiny}"
+                    ));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    mod li {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Precision, li, li_inv},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Round trip through the inverse.
+        #[quickcheck]
+        fn inverse(y: Finite<f64>) -> TestResult {
+            if y.abs() > 1e6_f64 {
+                return TestResult::discard();
+            }
+            let Ok(x) = li_inv(y, Precision::Full) else {
+                return TestResult::error("Inversion failed");
+            };
+            if *x.value - 1_f64 == 0_f64 {
+                // Rounded onto li's singularity: nothing to check.
+                return TestResult::discard();
+            }
+            let Ok(back) = li(x.value, Precision::Full) else {
+                return TestResult::error(format!(
+                    "li_inv({y}) = {} is outside li's domain",
+                    x.value
+                ));
+            };
+            // Relative away from 0, absolute near it, and allowing for rounding `x` itself,
+            // which li magnifies by $x / \ln x$ (huge just above 1):
+            let conditioning = 4_f64 * f64::EPSILON * *x.value / libm::log(*x.value);
+            let difference = (*back.value - *y).abs() - conditioning;
+            if difference < 1e-12_f64 * y.abs().max(1_f64) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "li(li_inv({y})) = li({}) = {} (difference {difference})",
+                    x.value, back.value,
+                ))
+            }
+        }
+
+        // Away from 1, composing `Ei` with a logarithm by hand is accurate enough.
+        #[quickcheck]
+        fn ei_of_ln(x: Finite<f64>) -> TestResult {
+            if *x <= 0_f64 || *x > 1e300_f64 {
+                return TestResult::discard();
+            }
+            let t = libm::log(*x);
+            if t.abs() < 0.5_f64 {
+                return TestResult::discard();
+            }
+            let (Ok(reference), Ok(actual)) = (
+                Ei(NonZero::new(Finite::new(t)), Precision::Full),
+                li(x, Precision::Full),
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let relative = ((*actual.value - *reference.value) / *reference.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "li({x}) = {} but Ei(ln {x}) = {} (relative difference {relative})",
+                    actual.value, reference.value,
+                ))
+            }
+        }
+    }
+
+    mod molecular {
+        extern crate alloc;
+
+        use {
+            crate::{
+                Approx,
+                molecular::{A, B, series},
+            },
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, Positive},
+        };
+
+        // Unrolling the recurrence gives a finite sum of positive terms,
+        // $A_{n}(x) = e^{-x} \sum_{j=0}^{n} \frac{n!}{j! x^{n - j + 1}}$.
+        #[quickcheck]
+        fn a_vs_sum(x: Positive<Finite<f64>>, n: u8) -> TestResult {
+            if !(0.01_f64..=100_f64).contains(&**x) {
+                return TestResult::discard();
+            }
+            let mut orders = [Approx::default(); 256];
+            let Some(prefix) = orders.get_mut(..usize::from(n)) else {
+                return TestResult::discard();
+            };
+            if A(x, prefix).is_err() {
+                return TestResult::discard();
+            }
+            for (k, entry) in (0_u16..).zip(prefix.iter()) {
+                let mut term = x.recip();
+                let mut sum = term;
+                for j in (1..=k).rev() {
+                    term *= f64::from(j) / **x;
+                    sum += term;
+                }
+                let expected = libm::exp(-**x) * sum;
+                let relative = ((*entry.value - expected) / expected).abs();
+                if relative >= 1e-12_f64 {
+                    return TestResult::error(format!(
+                        "A{k}({x}) = {expected} but the recurrence gave {} (relative difference {relative})",
+                        entry.value,
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // The power series converges for every order, just slowly where the recurrence is used instead.
+        #[quickcheck]
+        fn b_vs_series(x: Finite<f64>, n: u8) -> TestResult {
+            if x.abs() > 50_f64 {
+                return TestResult::discard();
+            }
+            let mut orders = [Approx::default(); 256];
+            let Some(prefix) = orders.get_mut(..usize::from(n)) else {
+                return TestResult::discard();
+            };
+            if B(x, prefix).is_err() {
+                return TestResult::error("B failed");
+            }
+            for (k, entry) in (0_u16..).zip(prefix.iter()) {
+                let reference = series(f64::from(k), *x);
+                let tolerance = 1e-12_f64 * reference.value.abs();
+                if (*entry.value - *reference.value).abs() > tolerance {
+                    return TestResult::error(format!(
+                        "B{k}({x}) = {} by recurrence but {} by series",
+                        entry.value, reference.value,
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+    }
+
+    mod moments {
+        extern crate alloc;
+
+        use {
+            crate::{
+                Approx, E1, Precision,
+                moments::{complete, partial, partials},
+            },
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero, Positive},
+        };
+
+        // Each side of the turning point, the recurrence should track the closed form.
+        #[quickcheck]
+        fn recurrence(x: Positive<Finite<f64>>) -> TestResult {
+            if **x > 100_f64 {
+                return TestResult::discard();
+            }
+            let mut moments = [Approx::default(); 64];
+            if partials(x, &mut moments, Precision::Full).is_err() {
+                return TestResult::error("Evaluation failed");
+            }
+            for (k, moment) in moments.iter().enumerate() {
+                let Ok(expected) = partial(k, x, Precision::Full) else {
+                    return TestResult::error("Evaluation failed");
+                };
+                if (*moment.value - *expected.value).abs() > 1e-12_f64 * expected.value.abs() {
+                    return TestResult::error(format!(
+                        "Moment {k} up to {x} is {expected}, but the recurrence gave {moment}",
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // The derivative of each moment is its integrand, $x^k E_{1}(x)$.
+        #[quickcheck]
+        fn central_difference(k: u8, x: Positive<Finite<f64>>) -> TestResult {
+            if k > 20 || !(0.01_f64..=100_f64).contains(&**x) {
+                return TestResult::discard();
+            }
+            let step = 1e-6_f64 * **x;
+            let (Ok(above), Ok(below), Ok(e1)) = (
+                partial(
+                    usize::from(k),
+                    Positive::new(Finite::new(**x + step)),
+                    Precision::Full,
+                ),
+                partial(
+                    usize::from(k),
+                    Positive::new(Finite::new(**x - step)),
+                    Precision::Full,
+                ),
+                E1(NonZero::new(*x), Precision::Full),
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let expected = libm::pow(**x, f64::from(k)) * *e1.value;
+            let difference = (*above.value - *below.value) / (2_f64 * step);
+            // Rounding in the moment itself is magnified by the tiny step:
+            let tolerance = 1e-6_f64.mul_add(
+                expected.abs(),
+                16_f64 * f64::EPSILON * (above.value.abs() + below.value.abs()) / step,
+            );
+            if (difference - expected).abs() < tolerance {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "The integrand at {x} is {expected}, but a central difference of moment {k} gave {difference}",
+                ))
+            }
+        }
+
+        // Far enough out, the tail is negligible next to the complete moment.
+        #[quickcheck]
+        fn limit(k: u8) -> TestResult {
+            if k > 100 {
+                return TestResult::discard();
+            }
+            let (Ok(far), Ok(expected)) = (
+                partial(
+                    usize::from(k),
+                    Positive::new(Finite::new(1_000_f64)),
+                    Precision::Full,
+                ),
+                complete(usize::from(k)),
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            if (*far.value - *expected.value).abs() <= 1e-12_f64 * *expected.value {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Moment {k} up to 1000 is {far}, but the complete moment is {expected}",
+                ))
+            }
+        }
+    }
+
+    #[cfg(feature = "mp")]
+    mod mp {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Precision, consts, mp},
+            alloc::{format, string::String},
+            core::num::NonZeroUsize,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        #[cfg(feature = "quad")]
+        use crate::quad;
+
+        /// Bits in an `f64` significand.
+        const DOUBLE: NonZeroUsize = NonZeroUsize::new(53).unwrap();
+
+        /// Bits in a double-double significand, plus a few to spare.
+        #[cfg(feature = "quad")]
+        const QUAD: NonZeroUsize = NonZeroUsize::new(128).unwrap();
+
+        // The tabulated constants are correctly rounded, so rounding to 53 bits should reproduce them exactly.
+        #[test]
+        fn e1_1() -> Result<(), String> {
+            let value = mp::E1(NonZero::new(Finite::new(1_f64)), DOUBLE)
+                .map_err(|e| format!("{e}"))?
+                .to_f64()
+                .value();
+            if value.to_bits() == consts::E1_1.to_bits() {
+                Ok(())
+            } else {
+                Err(format!("E1(1) = {} but mp::E1 gave {value}", consts::E1_1))
+            }
+        }
+
+        // As an oracle, the arbitrary-precision path should agree with the double-precision one
+        // to within its few units in the last place.
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(approx) = E1(x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(exact) = mp::E1(x, DOUBLE) else {
+                return TestResult::error(format!("E1({x}) = {approx} but mp::E1 failed"));
+            };
+            let reference = exact.to_f64().value();
+            let ulps = ((*approx.value - reference) / (reference.abs() * f64::EPSILON)).abs();
+            if ulps <= 8_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "mp::E1({x}) = {reference} but E1 gave {approx} ({ulps} ULPs off)",
+                ))
+            }
+        }
+
+        // The double-double backend claims about 30 digits.
+        #[cfg(feature = "quad")]
+        #[quickcheck]
+        fn quad(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(approx) = quad::E1(x) else {
+                return TestResult::discard();
+            };
+            let Ok(exact) = mp::E1(x, QUAD) else {
+                return TestResult::error(format!("quad::E1({x}) = {approx} but mp::E1 failed"));
+            };
+            let hi = exact.to_f64().value();
+            #[expect(
+                clippy::arithmetic_side_effects,
+                reason = "arbitrary-precision arithmetic doesn't overflow"
+            )]
+            let lo = (exact - mp::Float::try_from(hi).unwrap_or_default())
+                .to_f64()
+                .value();
+            let relative = (((approx.hi - hi) + (approx.lo - lo)) / hi).abs();
+            if relative < 1e-30_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "mp::E1({x}) = ({hi} + {lo}) but quad::E1 gave {approx} (relative difference {relative})",
+                ))
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod par {
+        extern crate alloc;
+
+        use {
+            crate::{BatchError, Ei_slice, Precision, par},
+            alloc::{format, string::String, vec, vec::Vec},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+        };
+
+        /// Enough points to span many chunks, and not a whole number of them,
+        /// but an even number, so that zero falls between the two in the middle.
+        const POINTS: usize = 100_002;
+
+        /// `POINTS` midpoints of equal intervals across $[-700, 700]$.
+        fn grid() -> Vec<f64> {
+            (0_u32..)
+                .take(POINTS)
+                .map(|k| -700_f64 + 1_400_f64 * (f64::from(k) + 0.5_f64) / 100_002_f64)
+                .collect()
+        }
+
+        // Splitting the work across threads must never change a single bit, nor which failure is reported.
+        #[quickcheck]
+        fn serial(xs: Vec<f64>) -> TestResult {
+            let mut parallel = vec![42_f64; xs.len()];
+            let mut reference = vec![42_f64; xs.len()];
+            let result = par::Ei_slice(&xs, &mut parallel, Precision::Full);
+            let expected = Ei_slice(&xs, &mut reference, Precision::Full);
+            // Compared by `Debug` so that NaN entries match themselves:
+            if format!("{result:?}") != format!("{expected:?}") {
+                return TestResult::error(format!("Expected {expected:?} but got {result:?}"));
+            }
+            for ((x, entry), expected_entry) in xs.into_iter().zip(parallel).zip(reference) {
+                if entry.to_bits() != expected_entry.to_bits() {
+                    return TestResult::error(format!(
+                        "At {x}, expected {expected_entry} but got {entry}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        #[test]
+        fn many_chunks() -> Result<(), String> {
+            let xs = grid();
+            let mut parallel = vec![0_f64; POINTS];
+            let mut reference = vec![0_f64; POINTS];
+            par::Ei_slice(&xs, &mut parallel, Precision::Full).map_err(|e| format!("{e}"))?;
+            Ei_slice(&xs, &mut reference, Precision::Full).map_err(|e| format!("{e}"))?;
+            for ((x, entry), expected) in xs.into_iter().zip(parallel).zip(reference) {
+                if entry.to_bits() != expected.to_bits() {
+                    return Err(format!("At {x}, expected {expected} but got {entry}"));
+                }
+            }
+            Ok(())
+        }
+
+        // Even if a later chunk fails first in time, the earliest failure by position is the one reported,
+        // and nothing is written.
+        #[test]
+        fn first_failure() -> Result<(), String> {
+            let mut xs = grid();
+            for (k, x) in xs.iter_mut().enumerate() {
+                match k {
+                    50_000 => *x = 0_f64,
+                    90_001.. => *x = f64::NAN,
+                    _ => {}
+                }
+            }
+            let mut out = vec![42_f64; POINTS];
+            let result = par::Ei_slice(&xs, &mut out, Precision::Full);
+            if result
+                != Err(BatchError::Domain {
+                    index: 50_000,
+                    value: 0_f64,
+                })
+            {
+                return Err(format!("Expected the zero at 50000 but got {result:?}"));
+            }
+            if out
+                .into_iter()
+                .any(|entry| entry.to_bits() != 42_f64.to_bits())
+            {
+                return Err("Output written despite the failure".into());
+            }
+            Ok(())
+        }
+    }
+
+    mod precision {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Ei, Precision, Si},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        use alloc::string::String;
+
+        // Every term is every term, however it's asked for.
+        #[quickcheck]
+        fn full(x: NonZero<Finite<f64>>) -> bool {
+            E1(x, Precision::Full) == E1(x, Precision::Terms(usize::MAX))
+                && E1(x, Precision::Full) == E1(x, usize::MAX.into())
+                && Ei(x, Precision::Full) == Ei(x, Precision::Terms(usize::MAX))
+        }
+
+        // Cutting each series short for a relative tolerance should keep within it (plus a few roundings),
+        // in every region of E1, Ei, and Si.
+        #[quickcheck]
+        fn rel_tolerance(x: NonZero<Finite<f64>>, digits: u8) -> TestResult {
+            let tolerance = libm::pow(10_f64, -f64::from(digits.wrapping_rem(14).wrapping_add(2)));
+            let cases = [
+                (
+                    "E1",
+                    E1(x, Precision::RelTolerance(tolerance)),
+                    E1(x, Precision::Full),
+                ),
+                (
+                    "Ei",
+                    Ei(x, Precision::RelTolerance(tolerance)),
+                    Ei(x, Precision::Full),
+                ),
+                (
+                    "Si",
+                    Ok(Si(*x, Precision::RelTolerance(tolerance))),
+                    Ok(Si(*x, Precision::Full)),
+                ),
+            ];
+            for (name, cut_result, full_result) in cases {
+                let (Ok(cut), Ok(full)) = (cut_result, full_result) else {
+                    continue;
+                };
+                if (*cut.value - *full.value).abs()
+                    > (tolerance + 16_f64 * f64::EPSILON) * full.value.abs()
+                {
+                    return TestResult::error(format!(
+                        "{name}({x}) = {full} in full but {cut} to a relative tolerance of {tolerance:e}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // Whatever a tolerance leaves out, the error estimate should still reach the full result.
+        #[quickcheck]
+        fn rel_tolerance_error(x: NonZero<Finite<f64>>, digits: u8) -> TestResult {
+            let tolerance = libm::pow(10_f64, -f64::from(digits.wrapping_rem(14).wrapping_add(2)));
+            let cases = [
+                (
+                    "E1",
+                    E1(x, Precision::RelTolerance(tolerance)),
+                    E1(x, Precision::Full),
+                ),
+                (
+                    "Ei",
+                    Ei(x, Precision::RelTolerance(tolerance)),
+                    Ei(x, Precision::Full),
+                ),
+                (
+                    "Si",
+                    Ok(Si(*x, Precision::RelTolerance(tolerance))),
+                    Ok(Si(*x, Precision::Full)),
+                ),
+            ];
+            for (name, cut_result, full_result) in cases {
+                let (Ok(cut), Ok(full)) = (cut_result, full_result) else {
+                    continue;
+                };
+                let Some(error) = cut.error() else {
+                    return TestResult::discard();
+                };
+                if (*cut.value - *full.value).abs() > **error {
+                    return TestResult::error(format!(
+                        "{name}({x}) = {full} in full but {cut} to a relative tolerance of {tolerance:e}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // Where a loose tolerance once left the estimate at the full table's last coefficient.
+        #[test]
+        fn rel_tolerance_error_cut_short() -> Result<(), String> {
+            for (x, tolerance) in [(-0.5_f64, 1e-8_f64), (-3_f64, 1e-3_f64)] {
+                let nonzero = NonZero::new(Finite::new(x));
+                let (Ok(cut), Ok(full)) = (
+                    E1(nonzero, Precision::RelTolerance(tolerance)),
+                    E1(nonzero, Precision::Full),
+                ) else {
+                    return Err(format!("E1({x}) failed"));
+                };
+                if let Some(error) = cut.error()
+                    && (*cut.value - *full.value).abs() > **error
+                {
+                    return Err(format!(
+                        "E1({x}) = {full} in full but {cut} to a relative tolerance of {tolerance:e}"
+                    ));
+                }
+            }
+            Ok(())
+        }
+
+        // A loose tolerance should actually sum fewer terms somewhere.
+        #[test]
+        #[cfg(not(feature = "double-double"))]
+        fn shorter() -> Result<(), String> {
+            let x = NonZero::new(Finite::new(-20_f64));
+            let cut = E1(x, Precision::RelTolerance(1e-6_f64));
+            let full = E1(x, Precision::Full);
+            if cut == full {
+                return Err(format!(
+                    "Expected a tolerance of 1e-6 to change E1(-20) = {full:?}"
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "quad")]
+    mod quad {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Precision, quad},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        /// $E_{1}(x)$ from mpmath at 60 digits, split into the nearest `f64` and what's left,
+        /// with at least one point in every piece.
+        const ORACLE: [(f64, f64, f64); 11] = [
+            (
+                -600.5_f64,
+                -1.037_644_068_648_833_4e258_f64,
+                -6.757_188_845_343_112e240_f64,
+            ),
+            (
+                -25.0_f64,
+                -3_005_950_906.525_548_5_f64,
+                -2.317_420_123_697_923_5e-7_f64,
+            ),
+            (
+                -7.5_f64,
+                -289.388_398_200_144_6_f64,
+                -1.499_538_956_979_993e-14_f64,
+            ),
+            (
+                -2.5_f64,
+                -7.073_765_894_578_6_f64,
+                -3.235_808_590_673_571e-16_f64,
+            ),
+            (
+                -0.375_f64,
+                -0.009_691_377_209_342_f64,
+                1.325_997_752_931_437_8e-19_f64,
+            ),
+            (
+                -0.5_f64,
+                -0.454_219_904_863_173_6_f64,
+                1.588_984_231_059_708e-17_f64,
+            ),
+            (
+                1e-10_f64,
+                22.448_635_265_138_925_f64,
+                -1.433_365_925_403_972_7e-15_f64,
+            ),
+            (
+                0.5_f64,
+                0.559_773_594_776_160_8_f64,
+                -3.152_032_504_186_642e-17_f64,
+            ),
+            (
+                2.5_f64,
+                0.024_914_917_870_269_736_f64,
+                -9.539_563_734_211_34e-19_f64,
+            ),
+            (
+                10.0_f64,
+                4.156_968_929_685_325e-6_f64,
+                -3.582_754_631_029_011e-22_f64,
+            ),
+            (
+                300.0_f64,
+                1.710_384_276_804_51e-133_f64,
+                8.318_207_009_977_34e-150_f64,
+            ),
+        ];
+
+        // The leading word should be the double-precision E1, give or take its own error.
+        #[quickcheck]
+        fn leading_word(x: NonZero<Finite<f64>>) -> TestResult {
+            let (Ok(wide), Ok(reference)) = (quad::E1(x), E1(x, Precision::Full)) else {
+                return TestResult::discard();
+            };
+            // Past this, the low word falls into the subnormals:
+            if reference.value.abs() < 1e-280_f64 {
+                return TestResult::discard();
+            }
+            let relative = ((wide.hi - *reference.value) / *reference.value).abs();
+            if relative < 1e-14_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {reference} but the quad backend gave {wide} (relative difference {relative})",
+                ))
+            }
+        }
+
+        // With double-double internals, the ordinary `E1` should round the oracle correctly.
+        #[cfg(feature = "double-double")]
+        #[test]
+        fn correctly_rounded() -> Result<(), String> {
+            for (x, hi, _) in ORACLE {
+                let approx = E1(NonZero::new(Finite::new(x)), Precision::Full)
+                    .map_err(|e| format!("E1({x}) failed: {e}"))?;
+                if approx.value.to_bits() != hi.to_bits() {
+                    return Err(format!("E1({x}) rounds to {hi} but gave {approx}"));
+                }
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn oracle() -> Result<(), String> {
+            for (x, hi, lo) in ORACLE {
+                let value = quad::E1(NonZero::new(Finite::new(x)))
+                    .map_err(|e| format!("E1({x}) failed: {e}"))?;
+                let relative = (((value.hi - hi) + (value.lo - lo)) / hi).abs();
+                if relative > 1e-30_f64 {
+                    return Err(format!(
+                        "E1({x}) = ({hi} + {lo}) but the quad backend gave {value} (relative difference {relative})",
+                    ));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    mod real {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Ei, Precision, real},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // In `f64`, the generic path runs the full fits, so it should match the crate root to within a few roundings
+        // (the most where a fit's leading terms cancel).
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(generic) = real::E1(x) else {
+                return TestResult::discard();
+            };
+            let Ok(reference) = E1(x, Precision::Full) else {
+                return TestResult::error(format!("real::E1({x}) = {generic} but E1 failed"));
+            };
+            let ulps =
+                ((*generic - *reference.value) / (reference.value.abs() * f64::EPSILON)).abs();
+            if ulps <= 8_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {reference} but real::E1 gave {generic} ({ulps} ULPs off)",
+                ))
+            }
+        }
+
+        #[quickcheck]
+        fn ei(x: NonZero<Finite<f64>>) -> TestResult {
+            let Ok(generic) = real::Ei(x) else {
+                return TestResult::discard();
+            };
+            let Ok(reference) = Ei(x, Precision::Full) else {
+                return TestResult::error(format!("real::Ei({x}) = {generic} but Ei failed"));
+            };
+            let ulps =
+                ((*generic - *reference.value) / (reference.value.abs() * f64::EPSILON)).abs();
+            if ulps <= 8_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}) = {reference} but real::Ei gave {generic} ({ulps} ULPs off)",
+                ))
+            }
+        }
+    }
+
+    mod root {
+        extern crate alloc;
+
+        use {
+            crate::{
+                Precision,
+                root::{EI_ZERO, find_root_near},
+            },
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, Positive},
+        };
+
+        // Wherever it starts, Newton's method should land on the precomputed zero.
+        #[quickcheck]
+        fn newton(guess: Positive<Finite<f64>>) -> TestResult {
+            let root = find_root_near(guess, Precision::Full);
+            let difference = (*root.value - EI_ZERO).abs();
+            if difference <= 2_f64 * f64::EPSILON * EI_ZERO {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Starting from {guess}, Newton's method found {} instead of {EI_ZERO}",
+                    root.value,
+                ))
+            }
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    mod simd {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Ei, Precision, simd},
+            alloc::{format, string::String},
+            core::simd::Simd,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Each lane runs the full fits, so it should match the crate root to within a few roundings,
+        // as in `real`, whichever piece its neighbours fall into.
+        #[quickcheck]
+        fn e1(
+            a: NonZero<Finite<f64>>,
+            b: NonZero<Finite<f64>>,
+            c: NonZero<Finite<f64>>,
+            d: NonZero<Finite<f64>>,
+        ) -> TestResult {
+            let Ok(batch) = simd::E1(Simd::from_array([**a, **b, **c, **d])) else {
+                return TestResult::discard();
+            };
+            for (&x, &lane) in [a, b, c, d].iter().zip(batch.as_array()) {
+                let Ok(reference) = E1(x, Precision::Full) else {
+                    return TestResult::error(format!("simd::E1 gave {lane} at {x} but E1 failed"));
+                };
+                let ulps =
+                    ((lane - *reference.value) / (reference.value.abs() * f64::EPSILON)).abs();
+                if ulps > 8_f64 {
+                    return TestResult::error(format!(
+                        "E1({x}) = {reference} but simd::E1 gave {lane} ({ulps} ULPs off)",
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        #[quickcheck]
+        fn ei(
+            a: NonZero<Finite<f64>>,
+            b: NonZero<Finite<f64>>,
+            c: NonZero<Finite<f64>>,
+            d: NonZero<Finite<f64>>,
+        ) -> TestResult {
+            let Ok(batch) = simd::Ei(Simd::from_array([**a, **b, **c, **d])) else {
+                return TestResult::discard();
+            };
+            for (&x, &lane) in [a, b, c, d].iter().zip(batch.as_array()) {
+                let Ok(reference) = Ei(x, Precision::Full) else {
+                    return TestResult::error(format!("simd::Ei gave {lane} at {x} but Ei failed"));
+                };
+                let ulps =
+                    ((lane - *reference.value) / (reference.value.abs() * f64::EPSILON)).abs();
+                if ulps > 8_f64 {
+                    return TestResult::error(format!(
+                        "Ei({x}) = {reference} but simd::Ei gave {lane} ({ulps} ULPs off)",
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // Random lanes rarely share a vector with every piece, so make sure one does.
+        #[test]
+        fn every_piece() -> Result<(), String> {
+            let points = [
+                -20_f64, -5_f64, -2_f64, -0.375_f64, 0.5_f64, 2_f64, 10_f64, 700_f64,
+            ];
+            let batch = simd::E1(Simd::from_array(points)).map_err(|e| format!("{e}"))?;
+            for (&x, &lane) in points.iter().zip(batch.as_array()) {
+                let reference = E1(NonZero::new(Finite::new(x)), Precision::Full)
+                    .map_err(|e| format!("{e}"))?;
+                let ulps =
+                    ((lane - *reference.value) / (reference.value.abs() * f64::EPSILON)).abs();
+                if ulps > 8_f64 {
+                    return Err(format!(
+                        "E1({x}) = {reference} but simd::E1 gave {lane} ({ulps} ULPs off)",
+                    ));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    mod single {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Precision, single},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Truncating the fits to single precision should cost only a few units in the last place.
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f32>>) -> TestResult {
+            let Ok(approx) = single::E1(x) else {
+                return TestResult::discard();
+            };
+            let Ok(reference) = E1(NonZero::new(Finite::new(f64::from(**x))), Precision::Full)
+            else {
+                return TestResult::error(format!("single::E1({x}) = {approx} but E1 failed"));
+            };
+            let ulps = ((f64::from(*approx) - *reference.value)
+                / (reference.value.abs() * f64::from(f32::EPSILON)))
+            .abs();
+            if ulps <= 8_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {reference} but single::E1 gave {approx} ({ulps} ULPs off)",
+                ))
+            }
+        }
+    }
+
+    mod taylor {
+        extern crate alloc;
+
+        use {
+            crate::{Approx, E1, E1_taylor, Ei, Ei_derivatives, Ei_taylor, Precision},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Well within the radius of convergence, summing the series should give Ei back.
+        #[quickcheck]
+        fn summed(x: NonZero<Finite<f64>>) -> TestResult {
+            if !(0.5_f64..=100_f64).contains(&x.abs()) {
+                return TestResult::discard();
+            }
+            // Ei is roughly exponential for large `x`, so cap the step too:
+            let step = (0.125_f64 * **x).clamp(-1_f64, 1_f64);
+            let mut coefficients = [Approx::default(); 32];
+            let (Ok(()), Ok(expected)) = (
+                Ei_taylor(x, &mut coefficients, Precision::Full),
+                Ei(NonZero::new(Finite::new(**x + step)), Precision::Full),
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let mut sum = 0_f64;
+            let mut magnitude = 0_f64;
+            for coefficient in coefficients.iter().rev() {
+                sum = sum.mul_add(step, *coefficient.value);
+                magnitude = magnitude.mul_add(step.abs(), coefficient.value.abs());
+            }
+            if (sum - *expected.value).abs() <= 1e-12_f64 * magnitude {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x} + {step}) = {expected}, but the Taylor series about {x} gave {sum}",
+                ))
+            }
+        }
+
+        // Past the constant term, each coefficient is a derivative over a factorial.
+        #[quickcheck]
+        fn derivatives(x: NonZero<Finite<f64>>) -> TestResult {
+            let mut coefficients = [Approx::default(); 9];
+            let mut derivatives = [Approx::default(); 8];
+            if Ei_taylor(x, &mut coefficients, Precision::Full).is_err()
+                || Ei_derivatives(x, &mut derivatives).is_err()
+            {
+                return TestResult::discard();
+            }
+            let mut factorial = 1_f64;
+            for (k, (coefficient, derivative)) in coefficients
+                .iter()
+                .skip(1)
+                .zip(derivatives.iter())
+                .enumerate()
+            {
+                #[expect(
+                    clippy::as_conversions,
+                    clippy::cast_precision_loss,
+                    reason = "tiny integer"
+                )]
+                let order = k as f64 + 1_f64;
+                factorial *= order;
+                let expected = *derivative.value / factorial;
+                if (*coefficient.value - expected).abs() > 1e-12_f64 * expected.abs() {
+                    return TestResult::error(format!(
+                        "Taylor coefficient {order} of Ei about {x} was {coefficient}, but its derivative over {order}! was {expected}",
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        // The constant term is the function itself.
+        #[quickcheck]
+        fn constant(x: NonZero<Finite<f64>>) -> TestResult {
+            let mut coefficients = [Approx::default(); 1];
+            let Ok(expected) = E1(x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            if E1_taylor(x, &mut coefficients, Precision::Full).is_err() {
+                return TestResult::error(format!("E1_taylor({x}) failed where E1 didn't"));
+            }
+            let [constant] = coefficients;
+            if constant.value == expected.value {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}) = {expected}, but its Taylor series started with {constant}",
+                ))
+            }
+        }
+    }
+
+    mod theis {
+        extern crate alloc;
+
+        use {
+            crate::{Precision, consts, theis::drawdown},
+            alloc::format,
+            core::f64::consts::PI,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, Positive},
+        };
+
+        // For small $u$, the Cooper-Jacob straight line $W(u) \approx -\gamma - \ln u$ is off by only about $u$.
+        #[quickcheck]
+        fn cooper_jacob(
+            transmissivity: Positive<Finite<f64>>,
+            radius: Positive<Finite<f64>>,
+            time: Positive<Finite<f64>>,
+        ) -> TestResult {
+            if !(1e-3_f64..1e3_f64).contains(&**transmissivity)
+                || !(1e-2_f64..1e3_f64).contains(&**radius)
+                || !(1e-2_f64..1e6_f64).contains(&**time)
+            {
+                return TestResult::discard();
+            }
+            let storativity = 1e-4_f64;
+            let u = **radius * **radius * storativity / (4_f64 * **transmissivity * **time);
+            if u > 1e-2_f64 {
+                return TestResult::discard();
+            }
+            let Ok(approx) = drawdown(
+                Finite::new(4_f64 * PI * **transmissivity),
+                transmissivity,
+                Positive::new(Finite::new(storativity)),
+                radius,
+                time,
+                Precision::Full,
+            ) else {
+                return TestResult::error("Evaluation failed");
+            };
+            let straight_line = -consts::EULER_GAMMA - libm::log(u);
+            if (*approx.value - straight_line).abs() <= 1.01_f64 * u + 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "At u = {u}, drawdown (in units of Q / 4πT) was {approx} but Cooper-Jacob gave {straight_line}",
+                ))
+            }
+        }
+    }
+
+    mod underflow {
+        use {
+            crate::{BatchError, E1, E1_scaled, E1_slice, Error, Precision, constants, expint_E1},
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Past `XMAX`, GSL's last `else` reports an underflow, which `expint_E1` takes as zero.
+        #[test]
+        fn past_xmax() {
+            let x = NonZero::new(Finite::new(705_f64));
+            for max_precision in [Precision::Full, Precision::Terms(0)] {
+                assert_eq!(E1(x, max_precision), Err(Error::Underflow));
+                assert!(*E1_scaled(x, max_precision).value > 0_f64);
+            }
+            assert!(
+                E1(NonZero::new(Finite::new(-705_f64)), Precision::Full)
+                    .is_err_and(|error| matches!(error, Error::ArgumentTooNegative(_)))
+            );
+            assert_eq!(expint_E1(705_f64).to_bits(), 0_f64.to_bits());
+            let mut out = [0_f64; 2];
+            assert_eq!(
+                E1_slice(&[1_f64, 705_f64], &mut out, Precision::Full),
+                Err(BatchError::Range {
+                    error: Error::Underflow,
+                    index: 1,
+                })
+            );
+        }
+
+        // GSL checks its largest positive piece for an exact zero,
+        // but `XMAX` stops just short of one: the last argument it allows still gives a positive value.
+        #[test]
+        fn not_before_xmax() {
+            let x = NonZero::new(Finite::new(constants::XMAX.next_down()));
+            for max_precision in [Precision::Full, Precision::Terms(0)] {
+                assert!(E1(x, max_precision).is_ok_and(|approx| *approx.value > 0_f64));
+            }
+        }
+    }
+
+    mod with_zero {
+        use {
+            crate::{E1, Ei, Precision, with_zero},
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Away from zero, exactly `E1` and `Ei`, errors included.
+        #[quickcheck]
+        fn nonzero(x: NonZero<Finite<f64>>) -> bool {
+            with_zero::E1(*x, Precision::Full)
+                == E1(x, Precision::Full).map(with_zero::Value::Finite)
+                && with_zero::Ei(*x, Precision::Full)
+                    == Ei(x, Precision::Full).map(with_zero::Value::Finite)
+        }
+
+        // Both signs of zero give the limit from either side.
+        #[test]
+        fn zero() {
+            for x in [0_f64, -0_f64].map(Finite::new) {
+                assert_eq!(
+                    with_zero::E1(x, Precision::Full).map(with_zero::Value::get),
+                    Ok(f64::INFINITY)
+                );
+                assert_eq!(
+                    with_zero::Ei(x, Precision::Full).map(with_zero::Value::get),
+                    Ok(f64::NEG_INFINITY)
+                );
+            }
+        }
+    }
+
+    mod sici {
+        //! The Maclaurin series, summed directly, cancel only mildly through both
+        //! the small-argument tables and the first pair of auxiliary tables.
+
+        extern crate alloc;
+
+        use {
+            crate::{Ci, Cin, E1_imag, Precision, Si, consts},
+            alloc::format,
+            core::f64::consts::FRAC_PI_2,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero, Positive},
+        };
+
+        #[quickcheck]
+        fn ci_series(x: Positive<Finite<f64>>) -> TestResult {
+            if **x > 8_f64 {
+                return TestResult::discard();
+            }
+            let square = **x * **x;
+            let mut term = 1_f64;
+            let mut expected = consts::EULER_GAMMA + libm::log(**x);
+            // Sixty terms leave the tail far below rounding for `x <= 8`.
+            for index in 1_u8..60 {
+                let k = f64::from(index);
+                term *= -square / ((2_f64 * k - 1_f64) * (2_f64 * k));
+                expected += term / (2_f64 * k);
+            }
+            let actual = Ci(x, Precision::Full);
+            let difference = (*actual.value - expected).abs();
+            if difference < 1e-12_f64 * expected.abs().max(1_f64) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ci({x}) = {} but the power series gave {expected} (difference {difference})",
+                    actual.value,
+                ))
+            }
+        }
+
+        // Away from zero, subtracting Ci by hand cancels harmlessly,
+        // so it should agree with Cin's own series on both sides of the switch.
+        #[quickcheck]
+        fn cin_closed_form(x: Positive<Finite<f64>>) -> TestResult {
+            if **x < 1_f64 || **x > 8_f64 {
+                return TestResult::discard();
+            }
+            let ci = Ci(x, Precision::Full);
+            let expected = consts::EULER_GAMMA + libm::log(**x) - *ci.value;
+            let actual = Cin(*x, Precision::Full);
+            let relative = ((*actual.value - expected) / expected).abs();
+            if relative < 1e-13_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Cin({x}) = {} but γ + ln x - Ci(x) = {expected} (relative difference {relative})",
+                    actual.value,
+                ))
+            }
+        }
+
+        #[quickcheck]
+        fn si_series(x: Finite<f64>) -> TestResult {
+            if x.abs() > 8_f64 {
+                return TestResult::discard();
+            }
+            let square = *x * *x;
+            let mut term = *x;
+            let mut expected = term;
+            // Sixty terms leave the tail far below rounding for `|x| <= 8`.
+            for index in 1_u8..60 {
+                let k = f64::from(index);
+                term *= -square / ((2_f64 * k) * (2_f64 * k + 1_f64));
+                expected += term / (2_f64 * k + 1_f64);
+            }
+            let actual = Si(x, Precision::Full);
+            let difference = (*actual.value - expected).abs();
+            if difference < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Si({x}) = {} but the power series gave {expected} (difference {difference})",
+                    actual.value,
+                ))
+            }
+        }
+
+        // Sharing the auxiliary functions shouldn't change either part beyond rounding.
+        #[quickcheck]
+        fn e1_imag(y: NonZero<Finite<f64>>) -> TestResult {
+            let (real, imaginary) = E1_imag(y, Precision::Full);
+            let ci = Ci(Positive::new(Finite::new(y.abs())), Precision::Full);
+            let si = Si(*y, Precision::Full);
+            let expected = *si.value - y.signum() * FRAC_PI_2;
+            let real_difference = (*real.value + *ci.value).abs();
+            let imaginary_difference = (*imaginary.value - expected).abs();
+            if real_difference <= 1e-14_f64 * ci.value.abs().max(1_f64)
+                && imaginary_difference <= 1e-14_f64
+            {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({y}i) = {} + {}i, but -Ci(|{y}|) = {} and Si({y}) - sgn({y}) π/2 = {expected}",
+                    real.value, imaginary.value, -*ci.value,
+                ))
+            }
+        }
+    }
+
+    mod asymptotic {
+        extern crate alloc;
+
+        use {
+            crate::{E1, E1_scaled, Precision, algorithms::asymptotic},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero, Positive},
+        };
+
+        // Past 40, the smallest term is already below double precision.
+        #[quickcheck]
+        fn e1(x: Positive<Finite<f64>>) -> TestResult {
+            if **x < 40_f64 {
+                return TestResult::discard();
+            }
+            let Ok(reference) = E1(NonZero::new(*x), Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(expansion) = asymptotic::E1(x) else {
+                return TestResult::error("Asymptotic evaluation failed where Chebyshev didn't");
+            };
+            let relative = ((*expansion.approx.value - *reference.value) / *reference.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}): the asymptotic expansion gave {} after {} terms but Chebyshev gave {} (relative difference {relative})",
+                    expansion.approx.value, expansion.terms, reference.value,
+                ))
+            }
+        }
+
+        #[quickcheck]
+        fn e1_scaled(x: Positive<Finite<f64>>) -> TestResult {
+            if **x < 40_f64 {
+                return TestResult::discard();
+            }
+            let reference = E1_scaled(NonZero::new(*x), Precision::Full);
+            let expansion = asymptotic::E1_scaled(x);
+            let relative = ((*expansion.approx.value - *reference.value) / *reference.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "e^x E1({x}): the asymptotic expansion gave {} after {} terms but Chebyshev gave {} (relative difference {relative})",
+                    expansion.approx.value, expansion.terms, reference.value,
+                ))
+            }
+        }
+    }
+
+    mod continued_fraction {
+        extern crate alloc;
+
+        use {
+            crate::{E1, En, Precision, algorithms::continued_fraction},
+            alloc::format,
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero, Positive},
+        };
+
+        #[quickcheck]
+        fn e1(x: Positive<Finite<f64>>) -> TestResult {
+            if **x < 1_f64 {
+                return TestResult::discard();
+            }
+            let Ok(reference) = E1(NonZero::new(*x), Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(fraction) = continued_fraction::E1(x) else {
+                return TestResult::error("Continued fraction failed where Chebyshev didn't");
+            };
+            let relative = ((*fraction.approx.value - *reference.value) / *reference.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}): the continued fraction gave {} after {} terms but Chebyshev gave {} (relative difference {relative})",
+                    fraction.approx.value, fraction.terms, reference.value,
+                ))
+            }
+        }
+
+        #[quickcheck]
+        fn en(n: u8, x: Positive<Finite<f64>>) -> TestResult {
+            if **x < 1_f64 {
+                return TestResult::discard();
+            }
+            let Ok(reference) = En(usize::from(n), *x, Precision::Full) else {
+                return TestResult::discard();
+            };
+            let Ok(fraction) = continued_fraction::En(usize::from(n), x) else {
+                return TestResult::error("Continued fraction failed where Chebyshev didn't");
+            };
+            let relative = ((*fraction.approx.value - *reference.value) / *reference.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E{n}({x}): the continued fraction gave {} after {} terms but Chebyshev gave {} (relative difference {relative})",
+                    fraction.approx.value, fraction.terms, reference.value,
+                ))
+            }
+        }
+    }
+
+    mod ramanujan {
+        extern crate alloc;
+
+        use {
+            crate::{Ei, Precision, algorithms::ramanujan},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, Positive},
+        };
+
+        #[quickcheck]
+        fn ei(x: Positive<Finite<f64>>) -> TestResult {
+            if **x > 40_f64 {
+                return TestResult::discard();
+            }
+            let Ok(reference) = Ei(x.also(), Precision::Full) else {
+                return TestResult::error("Chebyshev evaluation failed");
+            };
+            let Ok(series) = ramanujan::Ei(x, 1_000) else {
+                return TestResult::error("Ramanujan evaluation failed");
+            };
+            let relative = ((*series.approx.value - *reference.value) / *reference.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}): Ramanujan's series gave {} after {} terms but Chebyshev gave {} (relative difference {relative})",
+                    series.approx.value, series.terms, reference.value,
+                ))
+            }
+        }
+
+        // However few terms it stops at, the error estimate still reaches the true value.
+        #[test]
+        fn truncated() -> Result<(), String> {
+            if !cfg!(feature = "error") {
+                return Ok(());
+            }
+            for x in [
+                0.1_f64, 0.5_f64, 1_f64, 2_f64, 5_f64, 10_f64, 20_f64, 40_f64,
+            ] {
+                let positive = Positive::new(Finite::new(x));
+                let reference = Ei(positive.also(), Precision::Full)
+                    .map_err(|e| format!("Ei({x}) failed: {e:?}"))?;
+                for max_terms in 0_usize..=60_usize {
+                    let series = ramanujan::Ei(positive, max_terms)
+                        .map_err(|e| format!("Ramanujan's series failed at {x}: {e:?}"))?;
+                    if !series.approx.overlaps(&reference) {
+                        return Err(format!(
+                            "Ei({x}): Ramanujan's series gave {} after {} terms but Chebyshev gave {}",
+                            series.approx, series.terms, reference,
+                        ));
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    mod series {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Ei, Precision, algorithms::series},
+            alloc::{format, string::String},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero, Positive},
+        };
+
+        // Below 2, the terms of E1's series barely cancel.
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f64>>) -> TestResult {
+            if x.abs() > 2_f64 {
+                return TestResult::discard();
+            }
+            let Ok(reference) = E1(x, Precision::Full) else {
+                return TestResult::error("Chebyshev evaluation failed");
+            };
+            let Ok(series) = series::E1(x, 1_000) else {
+                return TestResult::error("Series evaluation failed");
+            };
+            let relative = ((*series.approx.value - *reference.value) / *reference.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "E1({x}): the power series gave {} after {} terms but Chebyshev gave {} (relative difference {relative})",
+                    series.approx.value, series.terms, reference.value,
+                ))
+            }
+        }
+
+        #[quickcheck]
+        fn ei(x: Positive<Finite<f64>>) -> TestResult {
+            if **x > 40_f64 {
+                return TestResult::discard();
+            }
+            let nonzero = NonZero::new(*x);
+            let Ok(reference) = Ei(nonzero, Precision::Full) else {
+                return TestResult::error("Chebyshev evaluation failed");
+            };
+            let Ok(series) = series::Ei(nonzero, 1_000) else {
+                return TestResult::error("Series evaluation failed");
+            };
+            let relative = ((*series.approx.value - *reference.value) / *reference.value).abs();
+            if relative < 1e-12_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Ei({x}): the power series gave {} after {} terms but Chebyshev gave {} (relative difference {relative})",
+                    series.approx.value, series.terms, reference.value,
+                ))
+            }
+        }
+
+        // However few terms it stops at, the error estimate still reaches the true value.
+        #[test]
+        fn truncated() -> Result<(), String> {
+            if !cfg!(feature = "error") {
+                return Ok(());
+            }
+            for x in [
+                -40_f64, -10_f64, -1_f64, 0.1_f64, 1_f64, 5_f64, 10_f64, 30_f64, 40_f64,
+            ] {
+                let nonzero = NonZero::new(Finite::new(x));
+                let reference =
+                    Ei(nonzero, Precision::Full).map_err(|e| format!("Ei({x}) failed: {e:?}"))?;
+                for max_terms in 0_usize..=60_usize {
+                    let series = series::Ei(nonzero, max_terms)
+                        .map_err(|e| format!("The power series failed at {x}: {e:?}"))?;
+                    if !series.approx.overlaps(&reference) {
+                        return Err(format!(
+                            "Ei({x}): the power series gave {} after {} terms but Chebyshev gave {}",
+                            series.approx, series.terms, reference,
+                        ));
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+mod doesnt_crash {
+    mod algorithms {
+        mod asymptotic {
+            use {
+                crate::algorithms::asymptotic,
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, Positive},
+            };
+
+            #[quickcheck]
+            fn e1(x: Positive<Finite<f64>>) {
+                _ = asymptotic::E1(x);
+            }
+
+            #[quickcheck]
+            fn e1_scaled(x: Positive<Finite<f64>>) {
+                _ = asymptotic::E1_scaled(x);
+            }
+        }
+
+        mod continued_fraction {
+            use {
+                crate::algorithms::continued_fraction,
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, Positive},
+            };
+
+            #[quickcheck]
+            fn e1(x: Positive<Finite<f64>>) {
+                _ = continued_fraction::E1(x);
+            }
+
+            #[quickcheck]
+            fn en(n: usize, x: Positive<Finite<f64>>) {
+                _ = continued_fraction::En(n, x);
+            }
+        }
+
+        mod ramanujan {
+            use {
+                crate::algorithms::ramanujan,
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, Positive},
+            };
+
+            #[quickcheck]
+            fn ei(x: Positive<Finite<f64>>, max_terms: u16) {
+                _ = ramanujan::Ei(x, usize::from(max_terms));
+            }
+        }
+
+        mod series {
+            use {
+                crate::algorithms::series,
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, NonZero},
+            };
+
+            #[quickcheck]
+            fn e1(x: NonZero<Finite<f64>>, max_terms: u16) {
+                _ = series::E1(x, usize::from(max_terms));
+            }
+
+            #[quickcheck]
+            fn ei(x: NonZero<Finite<f64>>, max_terms: u16) {
+                _ = series::Ei(x, usize::from(max_terms));
+            }
+        }
+    }
+
+    #[cfg(feature = "arrow")]
+    mod arrow {
+        extern crate alloc;
+
+        use {
+            crate::{E1, Ei, Precision, arrow},
+            alloc::{format, vec::Vec},
+            arrow_array::{Array as _, Float64Array},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        // Every valid entry should agree with the scalar API,
+        // and every null entry should stay null.
+
+        #[quickcheck]
+        fn e1(xs: Vec<Option<f64>>, order: usize) -> TestResult {
+            let array = Float64Array::from(xs.clone());
+            let evaluated = arrow::E1(&array, Precision::Terms(order));
+            if evaluated.len() != xs.len() {
+                return TestResult::error("Length changed");
+            }
+            for (i, x) in xs.into_iter().enumerate() {
+                let expected = x
+                    .and_then(Finite::try_new)
+                    .and_then(NonZero::try_new)
+                    .and_then(|nonzero| E1(nonzero, Precision::Terms(order)).ok())
+                    .map(|approx| *approx.value);
+                let actual = evaluated.is_valid(i).then(|| evaluated.value(i));
+                if expected != actual {
+                    return TestResult::error(format!(
+                        "Entry #{i}: expected {expected:?} but got {actual:?}"
+                    ));
+                }
+            }
+            TestResult::passed()
+        }
+
+        #[quickcheck]
+        fn ei_in_place(xs: Vec<f64>, order: usize) -> TestResult {
+            let array = Float64Array::from(xs.clone());
+            let Ok(result) = arrow::Ei_in_place(array, Precision::Terms(order)) else {
+                return TestResult::error("Freshly built array reported as shared");
+            };
+            let all_valid = xs.iter().all(|&x| {
+                Finite::try_new(x)
+                    .and_then(NonZero::try_new)
+                    .is_some_and(|nonzero| Ei(nonzero, Precision::Terms(order)).is_ok())
+            });
+            match result {
+                Ok(evaluated) if all_valid => {
+                    for (i, x) in xs.into_iter().enumerate() {
+                        let expected = Ei(NonZero::new(Finite::new(x)), Precision::Terms(order))
+                            .map(|approx| *approx.value);
+                        if expected != Ok(evaluated.value(i)) {
+                            return TestResult::error(format!(
+                                "Entry #{i}: expected {expected:?} but got {:?}",
+                                evaluated.value(i),
+                            ));
+                        }
+                    }
+                    TestResult::passed()
+                }
+                Err(_) if !all_valid => TestResult::passed(),
+                Ok(_) => TestResult::error("Invalid entry evaluated without error"),
+                Err(e) => TestResult::error(format!("Valid entries reported an error: {e}")),
+            }
+        }
+
+        // Null slots should stay null, and the values underneath them shouldn't be touched.
+
+        #[quickcheck]
+        fn ei_in_place_nulls(xs: Vec<(f64, bool)>, order: usize) -> TestResult {
+            let (_, values, _) =
+                Float64Array::from(xs.iter().map(|&(x, _)| x).collect::<Vec<_>>()).into_parts();
+            let (_, _, nulls) = Float64Array::from(
+                xs.iter()
+                    .map(|&(x, valid)| valid.then_some(x))
+                    .collect::<Vec<_>>(),
+            )
+            .into_parts();
+            let Ok(result) =
+                arrow::Ei_in_place(Float64Array::new(values, nulls), Precision::Terms(order))
+            else {
+                return TestResult::error("Freshly built array reported as shared");
+            };
+            let all_valid = xs.iter().all(|&(x, valid)| {
+                !valid
+                    || Finite::try_new(x)
+                        .and_then(NonZero::try_new)
+                        .is_some_and(|nonzero| Ei(nonzero, Precision::Terms(order)).is_ok())
+            });
+            match result {
+                Ok(evaluated) if all_valid => {
+                    for (i, (x, valid)) in xs.into_iter().enumerate() {
+                        if !valid {
+                            if evaluated.is_valid(i) {
+                                return TestResult::error(format!("Entry #{i}: null became valid"));
+                            }
+                            if evaluated.value(i).to_bits() != x.to_bits() {
+                                return TestResult::error(format!(
+                                    "Entry #{i}: null slot changed from {x:?} to {:?}",
+                                    evaluated.value(i),
+                                ));
+                            }
+                            continue;
+                        }
+                        let expected = Ei(NonZero::new(Finite::new(x)), Precision::Terms(order))
+                            .map(|approx| *approx.value);
+                        if !evaluated.is_valid(i) || expected != Ok(evaluated.value(i)) {
+                            return TestResult::error(format!(
+                                "Entry #{i}: expected {expected:?} but got {:?}",
+                                evaluated.is_valid(i).then(|| evaluated.value(i)),
+                            ));
+                        }
+                    }
+                    TestResult::passed()
+                }
+                Err(_) if !all_valid => TestResult::passed(),
+                Ok(_) => TestResult::error("Invalid entry evaluated without error"),
+                Err(e) => TestResult::error(format!("Valid entries reported an error: {e}")),
+            }
+        }
+    }
+
+    #[cfg(feature = "num-complex")]
+    mod complex {
+        use {
+            crate::complex, num_complex::Complex, quickcheck_macros::quickcheck,
+            sigma_types::Finite,
+        };
+
+        #[quickcheck]
+        fn e1(re: Finite<f64>, im: Finite<f64>) {
+            _ = complex::E1(Complex::new(re, im));
+        }
+
+        #[quickcheck]
+        fn en(n: usize, re: Finite<f64>, im: Finite<f64>) {
+            _ = complex::En(n, Complex::new(re, im));
+        }
+    }
+
+    mod chebyshev {
+        extern crate alloc;
+
+        use {
+            crate::{
+                Approx, BatchError, E1, Error, Precision,
+                chebyshev::{self, gsl},
+                constants,
+            },
+            alloc::{format, string::String, vec, vec::Vec},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero, usize::LessThan},
+        };
+
+        #[cfg(feature = "num-complex")]
+        use num_complex::Complex;
+
+        #[cfg(any(feature = "compensated", feature = "rigorous-error"))]
+        use crate::double_double::DoubleDouble;
+
+        // Chebyshev approximation can balloon out of control,
+        // so it doesn't need to succeed for all inputs,
+        // but only on those we give it.
+
+        // Summing in complex numbers along the real axis
+        // should match summing in real numbers bit for bit.
+        #[cfg(feature = "num-complex")]
+        #[quickcheck]
+        fn complex_real_axis(x: Finite<f64>) -> TestResult {
+            let y = *x / (1_f64 + x.abs());
+            let real = chebyshev::eval_scalar(&constants::E12, y);
+            let complex = chebyshev::eval_scalar(&constants::E12, Complex::new(y, 0_f64));
+            if complex.re.to_bits() == real.to_bits() && complex.im == 0_f64 {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "At {y}: summed to {real} in real numbers but {complex} in complex numbers"
+                ))
+            }
+        }
+
+        // The generic recurrence in `f64` should match `eval` bit for bit
+        // (unless `eval` is compensated, which is the point).
+        #[cfg(not(feature = "compensated"))]
+        #[quickcheck]
+        fn scalar(x: Finite<f64>) -> TestResult {
+            let y = *x / (1_f64 + x.abs());
+            let expected = *chebyshev::eval(
+                Finite::all(&constants::E12),
+                Finite::new(y),
+                LessThan::new(const { constants::size::E12 - 1 }),
+            )
+            .value;
+            let actual = chebyshev::eval_scalar(&constants::E12, y);
+            if actual.to_bits() == expected.to_bits() {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "At {y}: `eval_scalar` summed to {actual}, but `eval` to {expected}"
+                ))
+            }
+        }
+
+        // On $[-1, 1]$, mapping is the identity, so `Series::eval` should match `eval_mapped` bit for bit,
+        // and outside it, refuse.
+        #[quickcheck]
+        fn series(x: Finite<f64>) -> TestResult {
+            let series = constants::piecewise::E12;
+            let checked = series.eval(x, usize::MAX);
+            if x.abs() > 1_f64 {
+                return if checked == Err(Error::Domain(x)) {
+                    TestResult::passed()
+                } else {
+                    TestResult::error(format!("At {x}, outside the series' interval: {checked:?}"))
+                };
+            }
+            let mapped = series.eval_mapped(x, usize::MAX);
+            if checked == Ok(mapped) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "At {x}: `Series::eval` gave {checked:?}, but `eval_mapped` {mapped}"
+                ))
+            }
+        }
+
+        // Compensated, `eval` should land within half a unit in the last place
+        // of the same series summed in double-double arithmetic, everywhere across `AE11`, the longest table,
+        // where the plain recurrence strays more than four units (at least eight times as far).
+        #[cfg(feature = "compensated")]
+        #[test]
+        fn compensated() -> Result<(), String> {
+            /// Points across $[-1, 1]$.
+            const POINTS: u32 = 1 << 14;
+            /// The series crosses zero once, where nothing can be relative,
+            /// so error is measured relative to at least this much.
+            const FLOOR: f64 = 0.01_f64;
+
+            let exact_coefficients = constants::AE11.map(|c| (c, 0_f64));
+            let mut worst_plain = 0_f64;
+            let mut worst_compensated = 0_f64;
+            for k in 0..=POINTS {
+                let y = 2_f64 * f64::from(k) / f64::from(POINTS) - 1_f64;
+                let exact = chebyshev::eval_scalar(&exact_coefficients, DoubleDouble::from(y));
+                let ulps = |sum: f64| {
+                    ((DoubleDouble::from(sum) - exact).hi
+                        / (f64::EPSILON * exact.hi.abs().max(FLOOR)))
+                    .abs()
+                };
+                let plain = ulps(chebyshev::eval_scalar(&constants::AE11, y));
+                let compensated = ulps(
+                    *chebyshev::eval(
+                        Finite::all(&constants::AE11),
+                        Finite::new(y),
+                        LessThan::new(const { constants::size::AE11 - 1 }),
+                    )
+                    .value,
+                );
+                worst_plain = worst_plain.max(plain);
+                worst_compensated = worst_compensated.max(compensated);
+            }
+            if worst_compensated <= 0.5_f64 && worst_plain >= 8_f64 * worst_compensated {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Compensated, `AE11` was off by up to {worst_compensated} units in the last place, \
+                     and plain, {worst_plain}"
+                ))
+            }
+        }
+
+        // With `rigorous-error`, the error `eval` reports, less the last coefficient (its stand-in for the tail),
+        // should cover the distance to the same series summed in double-double arithmetic,
+        // across every published fit, on $[-1, 1]$ and past it.
+        #[cfg(feature = "rigorous-error")]
+        #[test]
+        fn rigorous() -> Result<(), String> {
+            /// Points across $[-4, 4]$.
+            const POINTS: u32 = 1 << 12;
+
+            fn covers<const N: usize>(
+                name: &str,
+                series: &chebyshev::Series<N>,
+            ) -> Result<(), String> {
+                let exact_coefficients = series.coefficients.map(|c| (c, 0_f64));
+                let last = series.coefficients.last().map_or(0_f64, |c| c.abs());
+                for k in 0..=POINTS {
+                    let y = 8_f64 * f64::from(k) / f64::from(POINTS) - 4_f64;
+                    let exact = chebyshev::eval_scalar(&exact_coefficients, DoubleDouble::from(y));
+                    let approx = chebyshev::eval(
+                        Finite::all(&series.coefficients),
+                        Finite::new(y),
+                        LessThan::new(const { N - 1 }),
+                    );
+                    let distance = (DoubleDouble::from(*approx.value) - exact).hi.abs();
+                    if distance + last > **approx.error {
+                        return Err(format!(
+                            "`{name}` at {y} was {approx}, {distance} from {exact:?}, past the bound"
+                        ));
+                    }
+                }
+                Ok(())
+            }
+
+            covers("AE11", &gsl::AE11)?;
+            covers("AE12", &gsl::AE12)?;
+            covers("AE13", &gsl::AE13)?;
+            covers("AE14", &gsl::AE14)?;
+            covers("E11", &gsl::E11)?;
+            covers("E12", &gsl::E12)
+        }
+
+        // Each published fit, through the formula beside it, should give E1 on its interval
+        // (away from the zero of Ei, where E1 itself vanishes and nothing can be relative).
+        #[quickcheck]
+        fn gsl(x: Finite<f64>) -> TestResult {
+            let z = *x;
+            if z == 0_f64 || z.abs() > 700_f64 || (z + constants::EI_ZERO).abs() < 0.0625_f64 {
+                return TestResult::discard();
+            }
+            let decaying = |sum: Approx| libm::exp(-z) / z * (1_f64 + *sum.value);
+            let (name, through) = if z <= -10_f64 {
+                let y = Finite::new(20_f64 / z + 1_f64);
+                let sum = gsl::AE11.eval(y, usize::MAX);
+                ("AE11", sum.map(decaying))
+            } else if z <= -4_f64 {
+                let y = Finite::new((40_f64 / z + 7_f64) / 3_f64);
+                let sum = gsl::AE12.eval(y, usize::MAX);
+                ("AE12", sum.map(decaying))
+            } else if z <= -1_f64 {
+                let y = Finite::new((2_f64 * z + 5_f64) / 3_f64);
+                let sum = gsl::E11.eval(y, usize::MAX);
+                ("E11", sum.map(|approx| -libm::log(-z) + *approx.value))
+            } else if z <= 1_f64 {
+                let sum = gsl::E12.eval(x, usize::MAX);
+                (
+                    "E12",
+                    sum.map(|approx| -libm::log(z.abs()) - 0.6875_f64 + z + *approx.value),
+                )
+            } else if z <= 4_f64 {
+                let y = Finite::new((8_f64 / z - 5_f64) / 3_f64);
+                let sum = gsl::AE13.eval(y, usize::MAX);
+                ("AE13", sum.map(decaying))
+            } else {
+                let y = Finite::new(8_f64 / z - 1_f64);
+                let sum = gsl::AE14.eval(y, usize::MAX);
+                ("AE14", sum.map(decaying))
+            };
+            let actual = match through {
+                Ok(actual) => actual,
+                Err(error) => return TestResult::error(format!("`{name}` at {z}: {error:?}")),
+            };
+            let expected = match E1(NonZero::new(x), Precision::Full) {
+                Ok(expected) => *expected.value,
+                Err(error) => return TestResult::error(format!("`E1` at {z}: {error:?}")),
+            };
+            if (actual - expected).abs() <= 1e-13_f64 * expected.abs() {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "At {z}: E1 through `{name}` is {actual}, but `E1` gives {expected}"
+                ))
+            }
+        }
+
+        // Summed in a `const`, a series should come out with the same bits as at runtime,
+        // whether mapped from its interval or already on $[-1, 1]$.
+        #[test]
+        fn in_const() -> Result<(), String> {
+            const MAPPED: Result<Approx, Error> =
+                gsl::AE13.eval(chebyshev::finite(-0.25_f64), usize::MAX);
+            const ON_UNIT: Approx = chebyshev::eval(
+                chebyshev::finite_all(&gsl::AE11.coefficients),
+                chebyshev::finite(0.5_f64),
+                chebyshev::less_than(const { constants::size::AE11 - 1 }),
+            );
+            let mapped = gsl::AE13
+                .eval(Finite::new(-0.25_f64), usize::MAX)
+                .map_err(|e| format!("{e:?}"))?;
+            match MAPPED {
+                Ok(baked) if identical(baked, mapped) => {}
+                _ => {
+                    return Err(format!(
+                        "`AE13` at -1/4 was {MAPPED:?} at compile time but {mapped:?} at runtime"
+                    ));
+                }
+            }
+            let on_unit = gsl::AE11.eval_mapped(Finite::new(0.5_f64), usize::MAX);
+            if identical(ON_UNIT, on_unit) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "`AE11` at 1/2 was {ON_UNIT:?} at compile time but {on_unit:?} at runtime"
+                ))
+            }
+        }
+
+        // Each single-precision order should stop at the last coefficient above `f32::EPSILON`,
+        // never past GSL's own, and the copy in `f32` should round exactly those coefficients.
+        #[test]
+        fn single() -> Result<(), String> {
+            fn check<const N: usize, const M: usize>(
+                name: &str,
+                series: &chebyshev::Series<N>,
+                single: [f32; M],
+            ) -> Result<(), String> {
+                let order = series.single_order();
+                if order > series.order_sp || M != order + 1 {
+                    return Err(format!(
+                        "`{name}` cut to order {order} in {M} terms, past GSL's {}",
+                        series.order_sp
+                    ));
+                }
+                let epsilon = f64::from(f32::EPSILON);
+                for (k, &c) in series
+                    .coefficients
+                    .iter()
+                    .enumerate()
+                    .take(series.order_sp + 1)
+                {
+                    if (k == order && c.abs() <= epsilon) || (k > order && c.abs() > epsilon) {
+                        return Err(format!(
+                            "`{name}` cut to order {order}, but coefficient {k} is {c}"
+                        ));
+                    }
+                }
+                for ((k, &rounded), &c) in single.iter().enumerate().zip(&series.coefficients) {
+                    #[expect(
+                        clippy::as_conversions,
+                        clippy::cast_possible_truncation,
+                        reason = "rounding is the point"
+                    )]
+                    if rounded.to_bits() != (c as f32).to_bits() {
+                        return Err(format!(
+                            "`{name}` coefficient {k} is {c}, but {rounded} in `f32`"
+                        ));
+                    }
+                }
+                Ok(())
+            }
+
+            check("AE11", &gsl::AE11, gsl::AE11.to_f32::<10>())?;
+            check("AE12", &gsl::AE12, gsl::AE12.to_f32::<10>())?;
+            check("AE13", &gsl::AE13, gsl::AE13.to_f32::<9>())?;
+            check("AE14", &gsl::AE14, gsl::AE14.to_f32::<8>())?;
+            check("E11", &gsl::E11, gsl::E11.to_f32::<10>())?;
+            check("E12", &gsl::E12, gsl::E12.to_f32::<8>())
+        }
+
+        /// Whether two results have the same bits, error estimate included.
+        fn identical(actual: Approx, expected: Approx) -> bool {
+            #[cfg(feature = "error")]
+            if actual.error.to_bits() != expected.error.to_bits() {
+                return false;
+            }
+            actual.value.to_bits() == expected.value.to_bits()
+        }
+
+        // In lockstep, each point should sum exactly as `eval` would sum it alone,
+        // over $[-1, 1]$ and (through `Series::eval_many`) over any other interval,
+        // and mismatched lengths or points outside the interval should be refused.
+        #[quickcheck]
+        fn many(xs: Vec<Finite<f64>>) -> TestResult {
+            let ys: Vec<Finite<f64>> = xs
+                .into_iter()
+                .map(|x| Finite::new(*x / (1_f64 + x.abs())))
+                .collect();
+            let mut out = vec![Approx::default(); ys.len()];
+            if let Err(error) = chebyshev::eval_many(
+                Finite::all(&constants::E12),
+                &ys,
+                &mut out,
+                LessThan::new(const { constants::size::E12 - 1 }),
+            ) {
+                return TestResult::error(format!("`eval_many` refused: {error:?}"));
+            }
+            for (&y, &actual) in ys.iter().zip(&out) {
+                let expected = chebyshev::eval(
+                    Finite::all(&constants::E12),
+                    y,
+                    LessThan::new(const { constants::size::E12 - 1 }),
+                );
+                if !identical(actual, expected) {
+                    return TestResult::error(format!(
+                        "At {y}: `eval_many` gave {actual:?}, but `eval` {expected:?}"
+                    ));
+                }
+            }
+
+            let shifted = chebyshev::Series {
+                a: 0_f64,
+                b: 2_f64,
+                ..constants::piecewise::E12
+            };
+            let mut zs: Vec<Finite<f64>> = ys.iter().map(|&y| Finite::new(*y + 1_f64)).collect();
+            if let Err(error) = shifted.eval_many(&zs, &mut out, usize::MAX) {
+                return TestResult::error(format!("`Series::eval_many` refused: {error:?}"));
+            }
+            for (&z, &actual) in zs.iter().zip(&out) {
+                let expected = shifted.eval(z, usize::MAX);
+                if expected.is_ok_and(|alone| identical(actual, alone)) {
+                    continue;
+                }
+                return TestResult::error(format!(
+                    "At {z}: `Series::eval_many` gave {actual:?}, but `Series::eval` {expected:?}"
+                ));
+            }
+
+            out.push(Approx::default());
+            let mismatched = shifted.eval_many(&zs, &mut out, usize::MAX);
+            let lengths = BatchError::LengthMismatch {
+                inputs: zs.len(),
+                outputs: out.len(),
+            };
+            if mismatched != Err(lengths) {
+                return TestResult::error(format!(
+                    "Mismatched lengths gave {mismatched:?}, not {lengths:?}"
+                ));
+            }
+            zs.push(Finite::new(3_f64));
+            let outside = shifted.eval_many(&zs, &mut out, usize::MAX);
+            let domain = BatchError::Domain {
+                index: ys.len(),
+                value: 3_f64,
+            };
+            if outside == Err(domain) {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "Past the interval gave {outside:?}, not {domain:?}"
+                ))
+            }
+        }
+
+        // Over a slice, `eval` should sum the same, and refuse an empty one.
+        #[quickcheck]
+        fn slice(x: Finite<f64>) -> TestResult {
+            let y = Finite::new(*x / (1_f64 + x.abs()));
+            let expected = chebyshev::eval(
+                Finite::all(&constants::E12),
+                y,
+                LessThan::new(const { constants::size::E12 - 1 }),
+            );
+            let Some(actual) = chebyshev::eval_slice(
+                Finite::all(&constants::E12),
+                y,
+                const { constants::size::E12 - 1 },
+            ) else {
+                return TestResult::error("`eval_slice` refused the whole of `E12`");
+            };
+            if chebyshev::eval_slice(&[], y, 0).is_some() {
+                return TestResult::error("`eval_slice` summed an empty series");
+            }
+            if actual.value.to_bits() != expected.value.to_bits() {
+                return TestResult::error(format!(
+                    "At {y}: `eval_slice` summed to {actual}, but `eval` to {expected}"
+                ));
+            }
+            #[cfg(feature = "error")]
+            if actual.error.to_bits() != expected.error.to_bits() {
+                return TestResult::error(format!(
+                    "At {y}: `eval_slice` estimated an error of {}, but `eval` {}",
+                    actual.error, expected.error,
+                ));
+            }
+            TestResult::passed()
+        }
+
+        // Sums, multiples, products, and truncations of series should sum to what they say they do,
+        // to within rounding and the bound each reports.
+        #[quickcheck]
+        fn arithmetic(x: Finite<f64>, multiple: Finite<f64>) -> TestResult {
+            let y = Finite::new(*x / (1_f64 + x.abs()));
+            let factor = *multiple / (1_f64 + multiple.abs());
+            let series = constants::piecewise::E12;
+            let sum = |s: &chebyshev::Series<{ constants::piecewise::E12.coefficients.len() }>| {
+                *s.eval_mapped(y, usize::MAX).value
+            };
+            let value = sum(&series);
+            let shifted = chebyshev::Series {
+                a: -2_f64,
+                ..series
+            };
+            let (Some(combined), Some(squared)) =
+                (series.scale(factor).sum(&series), series.product(&series))
+            else {
+                return TestResult::error("Refused to combine series on the same interval");
+            };
+            if series.sum(&shifted).is_some() || series.product(&shifted).is_some() {
+                return TestResult::error("Combined series on different intervals");
+            }
+            let truncated = series.truncate::<8>();
+            let short = *truncated.series.eval_mapped(y, usize::MAX).value;
+            let tolerance = 1e-14_f64 * value.abs().max(1_f64);
+            let combined_value = sum(&combined);
+            if (combined_value - (factor + 1_f64) * value).abs() > tolerance {
+                return TestResult::error(format!(
+                    "At {y}: {factor} times the series plus itself gave {combined_value}, not {}",
+                    (factor + 1_f64) * value,
+                ));
+            }
+            let squared_value = sum(&squared.series);
+            if (squared_value - value * value).abs() > squared.bound + tolerance * value.abs() {
+                return TestResult::error(format!(
+                    "At {y}: the series squared gave {squared_value}, not {} (bound {})",
+                    value * value,
+                    squared.bound,
+                ));
+            }
+            if (short - value).abs() > truncated.bound + tolerance {
+                return TestResult::error(format!(
+                    "At {y}: the series cut to 8 terms gave {short}, not {value} (bound {})",
+                    truncated.bound,
+                ));
+            }
+            TestResult::passed()
+        }
+
+        // Fitting a series should recover a series exactly (to within rounding),
+        // and fit a smooth function off the nodes too.
+        #[quickcheck]
+        fn fit(x: Finite<f64>) -> TestResult {
+            let series = constants::piecewise::E12;
+            let sum = |z: f64| *series.eval_mapped(Finite::new(z), usize::MAX).value;
+            let refit = chebyshev::Series::<{ constants::piecewise::E12.coefficients.len() }>::fit(
+                sum, -1_f64, 1_f64,
+            );
+            for (k, (&expected, &actual)) in series
+                .coefficients
+                .iter()
+                .zip(refit.coefficients.iter())
+                .enumerate()
+            {
+                if (actual - expected).abs() > 1e-15_f64 {
+                    return TestResult::error(format!(
+                        "Refitting E12 gave {actual} for coefficient {k}, not {expected}"
+                    ));
+                }
+            }
+            let y = 0.5_f64 + 0.5_f64 * x.abs() / (1_f64 + x.abs());
+            let Ok(approx) = chebyshev::Series::<20>::fit(libm::exp, 0.5_f64, 1_f64)
+                .eval(Finite::new(y), usize::MAX)
+            else {
+                return TestResult::error(format!("At {y}: outside [0.5, 1]"));
+            };
+            let expected = libm::exp(y);
+            if (*approx.value - expected).abs() > 1e-15_f64 * expected {
+                return TestResult::error(format!(
+                    "At {y}: a fit to exp gave {approx}, not {expected}"
+                ));
+            }
+            TestResult::passed()
+        }
+
+        // The derivative series should match central differences of the original,
+        // both on $[-1, 1]$ (E12) and, to check the scaling, on a wider interval (E11 on $[-4, -1]$).
+        #[quickcheck]
+        fn derivative(x: Finite<f64>) -> TestResult {
+            let y = 0.9_f64 * *x / (1_f64 + x.abs());
+            let wide = chebyshev::Series {
+                a: -4_f64,
+                b: -1_f64,
+                ..constants::piecewise::E11
+            };
+            match differentiates(constants::piecewise::E12, y)
+                .and_then(|()| differentiates(wide, 1.5_f64 * y - 2.5_f64))
+            {
+                Ok(()) => TestResult::passed(),
+                Err(e) => TestResult::error(e),
+            }
+        }
+
+        /// Whether `series.derivative()` matches central differences of `series` at `at`.
+        fn differentiates<const N: usize>(
+            series: chebyshev::Series<N>,
+            at: f64,
+        ) -> Result<(), String> {
+            const STEP: f64 = 1e-5_f64;
+            let sum = |z: f64| {
+                series
+                    .eval(Finite::new(z), usize::MAX)
+                    .map(|approx| *approx.value)
+            };
+            let (Ok(above), Ok(below), Ok(slope)) = (
+                sum(at + STEP),
+                sum(at - STEP),
+                series.derivative().eval(Finite::new(at), usize::MAX),
+            ) else {
+                return Err(format!("At {at}: outside [{}, {}]", series.a, series.b));
+            };
+            let difference = (above - below) / (2_f64 * STEP);
+            if (*slope.value - difference).abs() > 1e-7_f64 * difference.abs().max(1_f64) {
+                return Err(format!(
+                    "At {at} on [{}, {}]: the derivative series gave {slope}, but central differences {difference}",
+                    series.a, series.b,
+                ));
+            }
+            Ok(())
+        }
+
+        // In one pass, the value should match `eval` (to the bit, unless that's compensated)
+        // and the derivative should match the derivative series,
+        // both on $[-1, 1]$ (E12) and, to check the scaling, on a wider interval (E11 on $[-4, -1]$).
+        #[quickcheck]
+        fn with_derivative(x: Finite<f64>) -> TestResult {
+            let y = *x / (1_f64 + x.abs());
+            let wide = chebyshev::Series {
+                a: -4_f64,
+                b: -1_f64,
+                ..gsl::E11
+            };
+            match differentiates_in_one_pass(gsl::E12, y)
+                .and_then(|()| differentiates_in_one_pass(wide, 1.5_f64 * y - 2.5_f64))
+            {
+                Ok(()) => TestResult::passed(),
+                Err(e) => TestResult::error(e),
+            }
+        }
+
+        /// Whether `series.eval_with_derivative` matches `series.eval` and `series.derivative()` at `at`.
+        fn differentiates_in_one_pass<const N: usize>(
+            series: chebyshev::Series<N>,
+            at: f64,
+        ) -> Result<(), String> {
+            let z = Finite::new(at);
+            let (Ok((value, slope)), Ok(expected_value), Ok(expected_slope)) = (
+                series.eval_with_derivative(z, usize::MAX),
+                series.eval(z, usize::MAX),
+                series.derivative().eval(z, usize::MAX),
+            ) else {
+                return Err(format!("At {at}: outside [{}, {}]", series.a, series.b));
+            };
+            // Compensated, `eval` is off from the plain recurrence by no more than its rounding,
+            // and with `rigorous-error`, only its error estimate differs:
+            let same = if cfg!(feature = "compensated") {
+                (*value.value - *expected_value.value).abs()
+                    <= 1e-14_f64 * expected_value.value.abs().max(1_f64)
+            } else if cfg!(feature = "rigorous-error") {
+                value.value.to_bits() == expected_value.value.to_bits()
+            } else {
+                identical(value, expected_value)
+            };
+            if !same {
+                return Err(format!(
+                    "At {at} on [{}, {}]: the value was {value:?}, but `eval` gave {expected_value:?}",
+                    series.a, series.b,
+                ));
+            }
+            if (*slope.value - *expected_slope.value).abs()
+                > 1e-12_f64 * expected_slope.value.abs().max(1_f64)
+            {
+                return Err(format!(
+                    "At {at} on [{}, {}]: the derivative was {slope}, but the derivative series gave {expected_slope}",
+                    series.a, series.b,
+                ));
+            }
+            Ok(())
+        }
+
+        // The antiderivative series should start at the constant asked for,
+        // and its central differences should match the original,
+        // both on $[-1, 1]$ (E12) and, to check the scaling, on a wider interval (E11 on $[-4, -1]$).
+        #[quickcheck]
+        fn integral(x: Finite<f64>, constant: Finite<f64>) -> TestResult {
+            let y = 0.9_f64 * *x / (1_f64 + x.abs());
+            let start = *constant / (1_f64 + constant.abs());
+            let wide = chebyshev::Series {
+                a: -4_f64,
+                b: -1_f64,
+                ..constants::piecewise::E11
+            };
+            match integrates(constants::piecewise::E12, y, start)
+                .and_then(|()| integrates(wide, 1.5_f64 * y - 2.5_f64, start))
+            {
+                Ok(()) => TestResult::passed(),
+                Err(e) => TestResult::error(e),
+            }
+        }
+
+        /// Whether `series.integral(constant)` starts at `constant`
+        /// and has central differences matching `series` at `at`.
+        fn integrates<const N: usize>(
+            series: chebyshev::Series<N>,
+            at: f64,
+            constant: f64,
+        ) -> Result<(), String> {
+            const STEP: f64 = 1e-5_f64;
+            let antiderivative = series.integral(constant);
+            let sum = |s: &chebyshev::Series<N>, z: f64| {
+                s.eval(Finite::new(z), usize::MAX)
+                    .map(|approx| *approx.value)
+            };
+            let (Ok(start), Ok(above), Ok(below), Ok(value)) = (
+                sum(&antiderivative, series.a),
+                sum(&antiderivative, at + STEP),
+                sum(&antiderivative, at - STEP),
+                sum(&series, at),
+            ) else {
+                return Err(format!("At {at}: outside [{}, {}]", series.a, series.b));
+            };
+            if (start - constant).abs() > 1e-14_f64 {
+                return Err(format!(
+                    "On [{}, {}]: the antiderivative should start at {constant}, but starts at {start}",
+                    series.a, series.b,
+                ));
+            }
+            let difference = (above - below) / (2_f64 * STEP);
+            if (value - difference).abs() > 1e-7_f64 * value.abs().max(1_f64) {
+                return Err(format!(
+                    "At {at} on [{}, {}]: the series gave {value}, but central differences of its antiderivative {difference}",
+                    series.a, series.b,
+                ));
+            }
+            Ok(())
+        }
+
+        // Cutting a series to a tolerance should drop as much as it can without going over,
+        // and move the sum by no more than it says.
+        #[quickcheck]
+        fn truncate_to_tolerance(x: Finite<f64>, exponent: u8) -> TestResult {
+            let y = Finite::new(*x / (1_f64 + x.abs()));
+            let tolerance = libm::exp10(-f64::from(exponent.rem_euclid(20)));
+            let series = constants::piecewise::E12;
+            let truncated = series.truncate_to_tolerance(tolerance);
+            if truncated.bound > tolerance {
+                return TestResult::error(format!(
+                    "Cut to a tolerance of {tolerance}, but reported a bound of {}",
+                    truncated.bound,
+                ));
+            }
+            let Some(last) = truncated.terms.checked_sub(1) else {
+                return TestResult::error("Cut every coefficient");
+            };
+            if last > 0
+                && series
+                    .coefficients
+                    .get(last)
+                    .is_some_and(|c| truncated.bound + c.abs() <= tolerance)
+            {
+                return TestResult::error(format!(
+                    "Cut to a tolerance of {tolerance}, but could have dropped coefficient {last} too"
+                ));
+            }
+            if truncated
+                .series
+                .coefficients
+                .iter()
+                .skip(truncated.terms)
+                .any(|&c| c != 0_f64)
+            {
+                return TestResult::error("Left a dropped coefficient nonzero");
+            }
+            let sum = |s: &chebyshev::Series<{ constants::piecewise::E12.coefficients.len() }>| {
+                *s.eval_mapped(y, usize::MAX).value
+            };
+            let (full, short) = (sum(&series), sum(&truncated.series));
+            if (short - full).abs() > truncated.bound + 1e-15_f64 * full.abs().max(1_f64) {
+                return TestResult::error(format!(
+                    "At {y}: the series cut to {tolerance} gave {short}, not {full} (bound {})",
+                    truncated.bound,
+                ));
+            }
+            TestResult::passed()
+        }
+
+        // Summing to a tolerance should sum exactly the terms `truncate_to_tolerance` keeps,
+        // count what it left out in its error estimate, and refuse points off the interval.
+        #[quickcheck]
+        fn eval_to_tolerance(x: Finite<f64>, exponent: u8) -> TestResult {
+            let y = Finite::new(*x / (1_f64 + x.abs()));
+            let tolerance = libm::exp10(-f64::from(exponent.rem_euclid(20)));
+            let series = gsl::AE11;
+            let truncated = series.truncate_to_tolerance(tolerance);
+            let Ok(adaptive) = series.eval_to_tolerance(y, tolerance) else {
+                return TestResult::error(format!("Refused {y}, inside [-1, 1]"));
+            };
+            if adaptive.terms != truncated.terms
+                || adaptive.bound.to_bits() != truncated.bound.to_bits()
+            {
+                return TestResult::error(format!(
+                    "To a tolerance of {tolerance}: summed {} terms within {}, but `truncate_to_tolerance` keeps {} within {}",
+                    adaptive.terms, adaptive.bound, truncated.terms, truncated.bound,
+                ));
+            }
+            let Ok(expected) = truncated.series.eval(y, usize::MAX) else {
+                return TestResult::error(format!("The cut series refused {y}, inside [-1, 1]"));
+            };
+            if adaptive.approx.value.to_bits() != expected.value.to_bits() {
+                return TestResult::error(format!(
+                    "At {y} to a tolerance of {tolerance}: summed {}, but the cut series sums to {}",
+                    adaptive.approx.value, expected.value,
+                ));
+            }
+            #[cfg(feature = "error")]
+            if **adaptive.approx.error < adaptive.bound {
+                return TestResult::error(format!(
+                    "At {y} to a tolerance of {tolerance}: estimated an error of {}, under the bound {}",
+                    adaptive.approx.error, adaptive.bound,
+                ));
+            }
+            if series
+                .eval_to_tolerance(Finite::new(2_f64), tolerance)
+                .is_ok()
+            {
+                return TestResult::error("Summed at 2, outside [-1, 1]");
+            }
+            TestResult::passed()
+        }
+
+        // Test our `const` implementation of `min`
+        // again the standard library.
+        #[quickcheck]
+        fn min(a: usize, b: usize) -> TestResult {
+            let c = chebyshev::min(a, b);
+            let m = a.min(b);
+            if c == m {
+                TestResult::passed()
+            } else {
+                TestResult::error(format!(
+                    "`chebyshev::min({a}, {b}) = {c}`, but `{a}.min({b}) = {m}`"
+                ))
+            }
+        }
+    }
+
+    #[cfg(feature = "mp")]
+    mod mp {
+        use {
+            crate::mp,
+            core::num::NonZeroUsize,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f64>>, precision_bits: u8) {
+            if let Some(bits) = NonZeroUsize::new(usize::from(precision_bits)) {
+                drop(mp::E1(x, bits));
+            }
+        }
+
+        #[quickcheck]
+        fn ei(x: NonZero<Finite<f64>>, precision_bits: u8) {
+            if let Some(bits) = NonZeroUsize::new(usize::from(precision_bits)) {
+                drop(mp::Ei(x, bits));
+            }
+        }
+    }
+
+    #[cfg(feature = "quad")]
+    mod quad {
+        use {
+            crate::quad,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonZero},
+        };
+
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f64>>) {
+            _ = quad::E1(x);
+        }
+
+        #[quickcheck]
+        fn ei(x: NonZero<Finite<f64>>) {
+            _ = quad::Ei(x);
+        }
+    }
+
+    #[cfg(feature = "interval")]
+    mod interval {
+        use {
+            crate::interval::{self, Interval},
+            quickcheck_macros::quickcheck,
+        };
+
+        #[quickcheck]
+        fn e1(hi: f64, lo: f64) {
+            _ = interval::E1(Interval { hi, lo });
+        }
+
+        #[quickcheck]
+        fn ei(hi: f64, lo: f64) {
+            _ = interval::Ei(Interval { hi, lo });
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    mod simd {
+        use {crate::simd, core::simd::Simd, quickcheck_macros::quickcheck};
+
+        #[quickcheck]
+        fn e1(a: f64, b: f64, c: f64, d: f64) {
+            _ = simd::E1(Simd::from_array([a, b, c, d]));
+        }
+
+        #[quickcheck]
+        fn ei(a: f64, b: f64, c: f64, d: f64) {
+            _ = simd::Ei(Simd::from_array([a, b, c, d]));
+        }
+    }
+
+    mod implementation {
+
+        mod en {
+            use {
+                crate::{Precision, implementation::en::*},
+                quickcheck::TestResult,
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, Positive},
+            };
+
+            #[quickcheck]
+            fn fraction(n: u8, x: Positive<Finite<f64>>, scale: bool) -> TestResult {
+                if n < 3 {
+                    return TestResult::discard();
+                }
+                _ = continued_fraction(f64::from(n), x, scale);
+                TestResult::passed()
+            }
+
+            #[quickcheck]
+            fn recurrence(
+                n: u8,
+                x: Positive<Finite<f64>>,
+                scale: bool,
+                order: usize,
+            ) -> TestResult {
+                if **x > 1_f64 || usize::from(n) > MAX_FORWARD_RECURRENCE {
+                    return TestResult::discard();
+                }
+                _ = forward_recurrence(usize::from(n), *x, scale, Precision::Terms(order));
+                TestResult::passed()
+            }
+        }
+
+        mod exprel {
+            use {
+                crate::implementation::exprel::*, quickcheck::TestResult,
+                quickcheck_macros::quickcheck, sigma_types::Finite,
+            };
+
+            #[quickcheck]
+            fn fraction(n: u8, x: Finite<f64>) -> TestResult {
+                let order = f64::from(n);
+                if n < 3 || *x <= -10_f64 * order || *x > order {
+                    return TestResult::discard();
+                }
+                _ = continued_fraction(order, *x);
+                TestResult::passed()
+            }
+
+            #[quickcheck]
+            fn power_series(n: u8, x: Finite<f64>) -> TestResult {
+                if x.abs() > 1_f64 {
+                    return TestResult::discard();
+                }
+                _ = series(f64::from(n), *x);
+                TestResult::passed()
+            }
+        }
+
+        mod neg {
+            use {
+                crate::{Precision, implementation::neg::*},
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, Negative},
+            };
+
+            #[quickcheck]
+            fn e1(x: Negative<Finite<f64>>, order: usize) {
+                _ = E1(x, Precision::Terms(order));
+            }
+        }
+
+        mod piecewise {
+            use {
+                crate::{Precision, constants, implementation::piecewise::*},
+                quickcheck::TestResult,
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, Negative, NonZero, Positive},
+            };
+
+            #[quickcheck]
+            fn neg_10(x: Negative<Finite<f64>>, scale: bool, order: usize) -> TestResult {
+                if **x < constants::NXMAX {
+                    return TestResult::discard();
+                }
+                if **x > -10_f64 {
+                    return TestResult::discard();
+                }
+                _ = le_neg_10(x, scale, Precision::Terms(order));
+                TestResult::passed()
+            }
+
+            #[quickcheck]
+            fn neg_4(x: Negative<Finite<f64>>, scale: bool, order: usize) -> TestResult {
+                if **x <= -10_f64 {
+                    return TestResult::discard();
+                }
+                if **x > -4_f64 {
+                    return TestResult::discard();
+                }
+                _ = le_neg_4(x, scale, Precision::Terms(order));
+                TestResult::passed()
+            }
+
+            #[quickcheck]
+            fn neg_1(x: Negative<Finite<f64>>, scale: bool, order: usize) -> TestResult {
+                if **x <= -4_f64 {
+                    return TestResult::discard();
+                }
+                if **x > -1_f64 {
+                    return TestResult::discard();
+                }
+                _ = le_neg_1(x, scale, Precision::Terms(order));
+                TestResult::passed()
+            }
+
+            #[quickcheck]
+            fn pos_1(x: NonZero<Finite<f64>>, scale: bool, order: usize) -> TestResult {
+                if **x <= -1_f64 {
+                    return TestResult::discard();
+                }
+                if **x > 1_f64 {
+                    return TestResult::discard();
+                }
+                _ = le_pos_1(x, scale, Precision::Terms(order));
+                TestResult::passed()
+            }
+
+            // Away from the zero itself, the Taylor series should match the Chebyshev fit it replaces.
+            #[quickcheck]
+            fn root(x: Negative<Finite<f64>>, scale: bool) -> TestResult {
+                let distance = (**x + constants::EI_ZERO).abs();
+                if !(ROOT_RADIUS * 0.5_f64..ROOT_RADIUS).contains(&distance) {
+                    return TestResult::discard();
+                }
+                let near = near_root(x, scale);
+                let far = le_pos_1(NonZero::new(*x), scale, Precision::Full);
+                if (*near.value - *far.value).abs() <= 1e-14_f64 {
+                    TestResult::passed()
+                } else {
+                    TestResult::failed()
+                }
+            }
+
+            #[quickcheck]
+            fn pos_4(x: Positive<Finite<f64>>, scale: bool, order: usize) -> TestResult {
+                if **x <= 1_f64 {
+                    return TestResult::discard();
+                }
+                if **x > 4_f64 {
+                    return TestResult::discard();
+                }
+                _ = le_pos_4(x, scale, Precision::Terms(order));
+                TestResult::passed()
+            }
+
+            #[quickcheck]
+            fn pos_max(x: Positive<Finite<f64>>, scale: bool, order: usize) -> TestResult {
+                if **x <= 4_f64 {
+                    return TestResult::discard();
+                }
+                if **x > constants::XMAX {
+                    return TestResult::discard();
+                }
+                _ = le_pos_max(x, scale, Precision::Terms(order));
+                TestResult::passed()
+            }
+        }
+
+        mod pos {
+            use {
+                crate::{Precision, implementation::pos::*},
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, Positive},
+            };
+
+            #[quickcheck]
+            fn e1(x: Positive<Finite<f64>>, order: usize) {
+                _ = E1(x, Precision::Terms(order));
+            }
+        }
+
+        mod sici {
+            use {
+                crate::{Precision, implementation::sici::*},
+                quickcheck::TestResult,
+                quickcheck_macros::quickcheck,
+                sigma_types::{Finite, Positive},
+            };
+
+            #[quickcheck]
+            fn auxiliary(x: Positive<Finite<f64>>, order: usize) -> TestResult {
+                if **x < 4_f64 {
+                    return TestResult::discard();
+                }
+                _ = fg_asymp(x, Precision::Terms(order));
+                TestResult::passed()
+            }
+        }
+
+        use {
+            crate::{Precision, implementation::*},
+            quickcheck::TestResult,
+            quickcheck_macros::quickcheck,
+            sigma_types::{Finite, NonNegative, NonZero, Positive},
+        };
+
+        #[quickcheck]
+        fn e1(x: NonZero<Finite<f64>>, order: usize) {
+            _ = E1(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn e1_scaled(x: NonZero<Finite<f64>>, order: usize) {
+            _ = E1_scaled(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn e2(x: Finite<f64>, scale: bool, order: usize) {
+            _ = E2(x, scale, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn e3(x: Finite<f64>, order: usize) {
+            _ = E3(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn ein(x: Finite<f64>, order: usize) {
+            _ = Ein(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn ei_regular(x: Finite<f64>, order: usize) {
+            _ = Ei_regular(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn ein_power_series(x: Finite<f64>) -> TestResult {
+            if x.abs() > 1_f64 {
+                return TestResult::discard();
+            }
+            _ = ein_series(*x);
+            TestResult::passed()
+        }
+
+        #[quickcheck]
+        fn en(n: u8, x: Finite<f64>, scale: bool, order: usize) {
+            _ = En(usize::from(n), x, scale, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn enu(nu: Finite<f64>, x: Finite<f64>, order: usize) {
+            _ = Enu(nu, x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn root_of_ei(guess: Positive<Finite<f64>>, order: usize) {
+            _ = find_root_near(guess, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn inverse_e1(y: Positive<Finite<f64>>, order: usize) {
+            _ = E1_inv(y, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn inverse_exponential_integral(y: Finite<f64>, order: usize) {
+            _ = Ei_inv(y, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn inverse_logarithmic_integral(y: Finite<f64>, order: usize) {
+            _ = li_inv(y, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn offset_logarithmic_integral(x: Finite<f64>, order: usize) {
+            _ = Li(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn logarithmic_integral(x: Finite<f64>, order: usize) {
+            _ = li(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn relative_exponential(n: usize, x: Finite<f64>) {
+            _ = exprel_n(n, x);
+        }
+
+        #[quickcheck]
+        fn third_order_integral(x: NonNegative<Finite<f64>>, order: usize) {
+            _ = expint_3(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn entire_cosine_integral(x: Finite<f64>, order: usize) {
+            _ = Cin(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn cosine_integral(x: Positive<Finite<f64>>, order: usize) {
+            _ = Ci(x, Precision::Terms(order));
+        }
+
+        #[quickcheck]
+        fn sine_integral(x: Finite<f64>, order: usize) {
+            _ = Si(x, Precision::Terms(order));
+        }
+    }
+
+    use {
+        crate::{
+            Approx, Branch, E1, E1_Ei_pair, E1_derivative, E1_derivatives, E1_e10, E1_imag,
+            E1_integral, E1_scaled, E1_taylor, E2, E3, Ei, Ei_derivative, Ei_derivatives, Ei_diff,
+            Ei_e10, Ei_integral, Ei_linspace, Ei_ln, Ei_regular, Ei_scaled, Ei_taylor,
+            Ei_with_branch, Ei_with_derivative, Ein, En, En_ratio, En_scaled, En_scaled_sequence,
+            En_sequence, Enu, Enu_order_derivative, Precision, bickley,
+            dual::{self, Dual},
+            incomplete_gamma, molecular, moments, real, root, single, theis,
+        },
+        quickcheck::TestResult,
+        quickcheck_macros::quickcheck,
+        sigma_types::{Finite, NonNegative, NonZero, Positive},
+    };
+
+    #[quickcheck]
+    fn e1(x: NonZero<Finite<f64>>, order: usize) {
+        _ = E1(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn e1_scaled(x: NonZero<Finite<f64>>, order: usize) {
+        _ = E1_scaled(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn e1_e10(x: NonZero<Finite<f64>>, order: usize) {
+        _ = E1_e10(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_e10(x: NonZero<Finite<f64>>, order: usize) {
+        _ = Ei_e10(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn e1_derivative(x: NonZero<Finite<f64>>) {
+        _ = E1_derivative(x);
+    }
+
+    #[quickcheck]
+    fn e1_derivatives(x: NonZero<Finite<f64>>, n: u8) {
+        let mut derivatives = [Approx::default(); 256];
+        if let Some(prefix) = derivatives.get_mut(..usize::from(n)) {
+            _ = E1_derivatives(x, prefix);
+        }
+    }
+
+    #[quickcheck]
+    fn e1_imag(y: NonZero<Finite<f64>>, order: usize) {
+        _ = E1_imag(y, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn e1_integral(x: Finite<f64>, order: usize) {
+        _ = E1_integral(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn e1_taylor(x: NonZero<Finite<f64>>, n: u8, order: usize) {
+        let mut coefficients = [Approx::default(); 256];
+        if let Some(prefix) = coefficients.get_mut(..usize::from(n)) {
+            _ = E1_taylor(x, prefix, Precision::Terms(order));
+        }
+    }
+
+    #[quickcheck]
+    fn e2(x: Finite<f64>, order: usize) {
+        _ = E2(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn e3(x: Finite<f64>, order: usize) {
+        _ = E3(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
     fn ei(x: NonZero<Finite<f64>>, order: usize) {
-        _ = Ei(
-            x,
-            #[cfg(feature = "precision")]
-            order,
+        _ = Ei(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_linspace(start: Finite<f64>, stop: Finite<f64>, n: u8, order: usize) {
+        let mut grid = [Approx::default(); 256];
+        if let Some(prefix) = grid.get_mut(..usize::from(n)) {
+            _ = Ei_linspace(start, stop, prefix, Precision::Terms(order));
+        }
+    }
+
+    #[quickcheck]
+    fn ei_ln(x: NonZero<Finite<f64>>, order: usize) {
+        _ = Ei_ln(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_regular(x: Finite<f64>, order: usize) {
+        _ = Ei_regular(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_scaled(x: NonZero<Finite<f64>>, order: usize) {
+        _ = Ei_scaled(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_with_branch(x: NonZero<Finite<f64>>, order: usize) {
+        for branch in [
+            Branch::LowerHalfPlane,
+            Branch::PrincipalValue,
+            Branch::UpperHalfPlane,
+        ] {
+            _ = Ei_with_branch(x, branch, Precision::Terms(order));
+        }
+    }
+
+    #[quickcheck]
+    fn ei_derivative(x: NonZero<Finite<f64>>) {
+        _ = Ei_derivative(x);
+    }
+
+    #[quickcheck]
+    fn ei_derivatives(x: NonZero<Finite<f64>>, n: u8) {
+        let mut derivatives = [Approx::default(); 256];
+        if let Some(prefix) = derivatives.get_mut(..usize::from(n)) {
+            _ = Ei_derivatives(x, prefix);
+        }
+    }
+
+    #[quickcheck]
+    fn ei_with_derivative(x: NonZero<Finite<f64>>, order: usize) {
+        _ = Ei_with_derivative(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn e1_ei_pair(x: NonZero<Finite<f64>>, order: usize) {
+        _ = E1_Ei_pair(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_diff(a: NonZero<Finite<f64>>, b: NonZero<Finite<f64>>, order: usize) {
+        _ = Ei_diff(a, b, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_integral(x: Finite<f64>, order: usize) {
+        _ = Ei_integral(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_taylor(x: NonZero<Finite<f64>>, n: u8, order: usize) {
+        let mut coefficients = [Approx::default(); 256];
+        if let Some(prefix) = coefficients.get_mut(..usize::from(n)) {
+            _ = Ei_taylor(x, prefix, Precision::Terms(order));
+        }
+    }
+
+    #[quickcheck]
+    fn ein(x: Finite<f64>, order: usize) {
+        _ = Ein(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn en(n: u8, x: Finite<f64>, order: usize) {
+        _ = En(usize::from(n), x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn en_scaled(n: u8, x: Finite<f64>, order: usize) {
+        _ = En_scaled(usize::from(n), x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn en_ratio(n: u8, x: Finite<f64>, order: usize) {
+        _ = En_ratio(usize::from(n), x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn en_sequence(x: Finite<f64>, n: u8, order: usize) {
+        let mut orders = [Approx::default(); 256];
+        if let Some(prefix) = orders.get_mut(..usize::from(n)) {
+            _ = En_sequence(x, prefix, Precision::Terms(order));
+        }
+    }
+
+    #[quickcheck]
+    fn en_scaled_sequence(x: Finite<f64>, n: u8, order: usize) {
+        let mut orders = [Approx::default(); 256];
+        if let Some(prefix) = orders.get_mut(..usize::from(n)) {
+            _ = En_scaled_sequence(x, prefix, Precision::Terms(order));
+        }
+    }
+
+    #[quickcheck]
+    fn enu(nu: Finite<f64>, x: Finite<f64>, order: usize) {
+        _ = Enu(nu, x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn enu_order_derivative(nu: Finite<f64>, x: Finite<f64>, order: usize) {
+        _ = Enu_order_derivative(nu, x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn dual_e1(value: f64, derivative: f64) {
+        _ = dual::E1(Dual { derivative, value }, Precision::Full);
+    }
+
+    #[quickcheck]
+    fn dual_ei(value: f64, derivative: f64) {
+        _ = dual::Ei(Dual { derivative, value }, Precision::Full);
+    }
+
+    #[quickcheck]
+    fn real_e1(x: NonZero<Finite<f64>>) {
+        _ = real::E1(x);
+    }
+
+    #[quickcheck]
+    fn real_ei(x: NonZero<Finite<f32>>) {
+        _ = real::Ei(x);
+    }
+
+    #[quickcheck]
+    fn single_e1(x: NonZero<Finite<f32>>) {
+        _ = single::E1(x);
+    }
+
+    #[quickcheck]
+    fn single_ei(x: NonZero<Finite<f32>>) {
+        _ = single::Ei(x);
+    }
+
+    #[quickcheck]
+    fn well_function(u: Positive<Finite<f64>>, order: usize) {
+        _ = theis::W(u, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn drawdown(
+        discharge: Finite<f64>,
+        transmissivity: Positive<Finite<f64>>,
+        storativity: Positive<Finite<f64>>,
+        radius: Positive<Finite<f64>>,
+        time: Positive<Finite<f64>>,
+        order: usize,
+    ) {
+        _ = theis::drawdown(
+            discharge,
+            transmissivity,
+            storativity,
+            radius,
+            time,
+            Precision::Terms(order),
         );
     }
 
+    #[quickcheck]
+    fn find_root_near(guess: Positive<Finite<f64>>, order: usize) {
+        _ = root::find_root_near(guess, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn e1_inv(y: Positive<Finite<f64>>, order: usize) {
+        _ = crate::E1_inv(y, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ei_inv(y: Finite<f64>, order: usize) {
+        _ = crate::Ei_inv(y, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn li_inv(y: Finite<f64>, order: usize) {
+        _ = crate::li_inv(y, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn offset_li(x: Finite<f64>, order: usize) {
+        _ = crate::Li(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn li(x: Finite<f64>, order: usize) {
+        _ = crate::li(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn partial_moment(k: usize, x: Positive<Finite<f64>>, order: usize) {
+        _ = moments::partial(k, x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn partial_moments(x: Positive<Finite<f64>>, n: u8, order: usize) {
+        let mut moments = [Approx::default(); 256];
+        if let Some(prefix) = moments.get_mut(..usize::from(n)) {
+            _ = moments::partials(x, prefix, Precision::Terms(order));
+        }
+    }
+
+    #[quickcheck]
+    fn bickley(n: usize, x: Finite<f64>) {
+        _ = bickley::Ki(n, x);
+    }
+
+    #[quickcheck]
+    fn molecular_a(x: Positive<Finite<f64>>, n: u8) {
+        let mut orders = [Approx::default(); 256];
+        if let Some(prefix) = orders.get_mut(..usize::from(n)) {
+            _ = molecular::A(x, prefix);
+        }
+    }
+
+    #[quickcheck]
+    fn molecular_b(x: Finite<f64>, n: u8) {
+        let mut orders = [Approx::default(); 256];
+        if let Some(prefix) = orders.get_mut(..usize::from(n)) {
+            _ = molecular::B(x, prefix);
+        }
+    }
+
+    #[quickcheck]
+    fn complete_moment(k: usize) {
+        _ = moments::complete(k);
+    }
+
+    #[quickcheck]
+    fn exprel(x: Finite<f64>) {
+        _ = crate::exprel(x);
+    }
+
+    #[quickcheck]
+    fn exprel_2(x: Finite<f64>) {
+        _ = crate::exprel_2(x);
+    }
+
+    #[quickcheck]
+    fn exprel_n(n: usize, x: Finite<f64>) {
+        _ = crate::exprel_n(n, x);
+    }
+
+    #[quickcheck]
+    fn gamma_inc(a: Finite<f64>, x: Positive<Finite<f64>>, order: usize) {
+        _ = incomplete_gamma::upper(a, x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn gamma_inc_0(x: Finite<f64>, order: usize) {
+        _ = crate::gamma_inc_0(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn expint_3(x: NonNegative<Finite<f64>>, order: usize) {
+        _ = crate::expint_3(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn cin(x: Finite<f64>, order: usize) {
+        _ = crate::Cin(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn ci(x: Positive<Finite<f64>>, order: usize) {
+        _ = crate::Ci(x, Precision::Terms(order));
+    }
+
+    #[quickcheck]
+    fn si(x: Finite<f64>, order: usize) {
+        _ = crate::Si(x, Precision::Terms(order));
+    }
+
     #[quickcheck]
     fn ei_near_zero(x: NonZero<Finite<f64>>, order: usize) -> TestResult {
         let Some(smaller) = Finite::try_new(**x / 1_000_000_000_000_f64).and_then(NonZero::try_new)
         else {
             return TestResult::discard();
         };
-        _ = Ei(
-            smaller,
-            #[cfg(feature = "precision")]
-            order,
-        );
+        _ = Ei(smaller, Precision::Terms(order));
         TestResult::passed()
     }
 }
+
+mod tuning {
+    //! `build.rs`'s choice of orders for the `tuned` feature.
+
+    extern crate alloc;
+
+    #[expect(
+        clippy::single_call_fn,
+        dead_code,
+        reason = "one step each, as in `build.rs`, and only some shapes are needed to test the cut"
+    )]
+    mod build {
+        include!("tuning.rs");
+    }
+
+    use {
+        crate::constants,
+        alloc::{format, string::String},
+        build::{Shape, order},
+    };
+
+    #[cfg(feature = "quad")]
+    use build::{RADII, root_radius};
+
+    /// Coefficients falling tenfold each term, from 1 down to $10^{-9}$.
+    const GEOMETRIC: [(f64, f64); 10] = [
+        (1e0_f64, 0_f64),
+        (1e-1_f64, 0_f64),
+        (1e-2_f64, 0_f64),
+        (1e-3_f64, 0_f64),
+        (1e-4_f64, 0_f64),
+        (1e-5_f64, 0_f64),
+        (1e-6_f64, 0_f64),
+        (1e-7_f64, 0_f64),
+        (1e-8_f64, 0_f64),
+        (1e-9_f64, 0_f64),
+    ];
+
+    #[test]
+    fn within_reach() -> Result<(), String> {
+        let (terms, bound) = order(
+            &GEOMETRIC,
+            Shape::Decaying,
+            constants::ROOT_RADIUS,
+            1e-4_f64,
+        )
+        .map_err(|closest| {
+            format!("A tolerance of 1e-4 should be reachable, but got to {closest:e}")
+        })?;
+        if terms < GEOMETRIC.len() && bound > 0_f64 && bound <= 1e-4_f64 {
+            Ok(())
+        } else {
+            Err(format!("Cut to {terms} terms for a bound of {bound:e}"))
+        }
+    }
+
+    // Past what the table can reach, the build should fail, not claim a bound of zero.
+    #[test]
+    fn out_of_reach() -> Result<(), String> {
+        match order(
+            &GEOMETRIC,
+            Shape::Decaying,
+            constants::ROOT_RADIUS,
+            1e-40_f64,
+        ) {
+            Ok((terms, bound)) => Err(format!(
+                "A tolerance of 1e-40 shouldn't be reachable, but cut to {terms} terms for a bound of {bound:e}"
+            )),
+            Err(closest) if closest > 1e-40_f64 => Ok(()),
+            Err(closest) => Err(format!("Reported a closest bound of {closest:e}")),
+        }
+    }
+
+    // Wherever the Taylor series takes over, both it and `E12` should meet the tolerance there.
+    #[cfg(feature = "quad")]
+    #[test]
+    fn root_radius_meets_tolerance() -> Result<(), String> {
+        for tolerance in [1e-8_f64, f64::EPSILON, 1e-17_f64, 1e-25_f64] {
+            let radius = root_radius(&constants::quad::E12, &constants::quad::EI_ROOT, tolerance);
+            if !RADII.contains(&radius) {
+                return Err(format!("Picked a radius of {radius} for {tolerance:e}"));
+            }
+            _ = order(&constants::quad::E12, Shape::Log, radius, tolerance).map_err(|closest| {
+                format!("`E12` only got to {closest:e} of {tolerance:e} at {radius}")
+            })?;
+            _ = order(&constants::quad::EI_ROOT, Shape::Taylor, radius, tolerance).map_err(
+                |closest| format!("`EI_ROOT` only got to {closest:e} of {tolerance:e} at {radius}"),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+/// Ei's zero, as in `constants::EI_ZERO`.
+const EI_ZERO: f64 = 0.372_507_410_781_366_6;
+
+/// Half-widths of the neighbourhood of the zero to choose between, the untuned `constants::ROOT_RADIUS` first.
+/// None is over half the zero, so that `near_root` still takes its distance from the zero exactly.
+pub(crate) const RADII: [f64; 4] = [0.0625, 0.031_25, 0.125, 0.015_625];
+
+/// Points at which to sample each piece for the smallest value its series is added to.
+const SAMPLES: u32 = 1 << 12;
+
+/// How a table's series enters E1, which decides what its truncation error is relative to.
+#[derive(Clone, Copy)]
+pub(crate) enum Shape {
+    /// $\frac{e^{-x}}{x} (1 + c(t))$, relative to $1 + c$.
+    Decaying,
+    /// $-\ln |x| - 0.6875 + x + c(x)$ on $[-1, 1]$ away from the zero of Ei, relative to all of it.
+    Log,
+    /// $-\ln |x| + c(t)$ at $x = \frac{3 t - 5}{2}$, relative to all of it.
+    LogShifted,
+    /// $-d \sum_{k} a_{k} d^{k}$ at distance $d$ from the zero of Ei, relative to the sum.
+    Taylor,
+}
+
+/// The series, in double precision (plenty to find its smallest magnitude),
+/// at `t` in $[-1, 1]$, with GSL's convention of halving the first Chebyshev coefficient.
+fn series(coefficients: &[(f64, f64)], shape: Shape, radius: f64, t: f64) -> f64 {
+    if matches!(shape, Shape::Taylor) {
+        let distance = t * radius;
+        return coefficients
+            .iter()
+            .rev()
+            .fold(0_f64, |sum, &(hi, _)| sum.mul_add(distance, hi));
+    }
+    let mut d = 0_f64;
+    let mut dd = 0_f64;
+    let Some((&(first, _), rest)) = coefficients.split_first() else {
+        return d;
+    };
+    for &(hi, _) in rest.iter().rev() {
+        let tmp = d;
+        d = (2_f64 * t).mul_add(d, -dd) + hi;
+        dd = tmp;
+    }
+    0.5_f64.mul_add(first, t.mul_add(d, -dd))
+}
+
+/// The magnitude of what the series is added to at `t` in $[-1, 1]$,
+/// or infinity where the piece isn't used, given the neighbourhood of the zero of Ei is `radius` either side.
+fn magnitude(coefficients: &[(f64, f64)], shape: Shape, radius: f64, t: f64) -> f64 {
+    let c = series(coefficients, shape, radius, t);
+    match shape {
+        Shape::Decaying => (1_f64 + c).abs(),
+        Shape::Log => {
+            if t == 0_f64 || (t + EI_ZERO).abs() < radius {
+                f64::INFINITY
+            } else {
+                (-t.abs().ln() - 0.6875_f64 + t + c).abs()
+            }
+        }
+        Shape::LogShifted => {
+            let x = 3_f64.mul_add(t, -5_f64) / 2_f64;
+            (-x.abs().ln() + c).abs()
+        }
+        Shape::Taylor => c.abs(),
+    }
+}
+
+/// The most that cutting the series after `terms` terms can change it by.
+fn tail(coefficients: &[(f64, f64)], shape: Shape, radius: f64, terms: usize) -> f64 {
+    let mut power = 1_f64;
+    let mut sum = 0_f64;
+    for (k, &(hi, _)) in coefficients.iter().enumerate() {
+        if k >= terms {
+            sum = hi.abs().mul_add(power, sum);
+        }
+        if matches!(shape, Shape::Taylor) {
+            power *= radius;
+        }
+    }
+    sum
+}
+
+/// The fewest terms of `coefficients` that keep the relative truncation error under `tolerance`,
+/// alongside the bound on that error they actually reach.
+/// Only cuts that leave something out have a bound to speak of, since nothing says what the table itself leaves out,
+/// so if even the last term alone is too much, this is the bound without it.
+pub(crate) fn order(
+    coefficients: &[(f64, f64)],
+    shape: Shape,
+    radius: f64,
+    tolerance: f64,
+) -> Result<(usize, f64), f64> {
+    let smallest = (0..=SAMPLES)
+        .map(|k| {
+            magnitude(
+                coefficients,
+                shape,
+                radius,
+                2_f64 * f64::from(k) / f64::from(SAMPLES) - 1_f64,
+            )
+        })
+        .fold(f64::INFINITY, f64::min);
+    let mut closest = f64::INFINITY;
+    for terms in 1..coefficients.len() {
+        let bound = tail(coefficients, shape, radius, terms) / smallest;
+        if bound <= tolerance {
+            return Ok((terms, bound));
+        }
+        closest = bound;
+    }
+    Err(closest)
+}
+
+/// Where the Taylor series about the zero of Ei takes over from `E12`:
+/// the closer in, the fewer terms the Taylor series needs, but the more `E12` does,
+/// since E1 itself gets smaller relative to what `E12` is off by.
+/// Of the `RADII` at which both meet `tolerance`, this is the one at which the longer of the two is shortest,
+/// or the first if none is (so the orders it's cut to say why).
+pub(crate) fn root_radius(e12: &[(f64, f64)], ei_root: &[(f64, f64)], tolerance: f64) -> f64 {
+    RADII
+        .iter()
+        .filter_map(|&radius| {
+            let (e12_terms, _) = order(e12, Shape::Log, radius, tolerance).ok()?;
+            let (root_terms, _) = order(ei_root, Shape::Taylor, radius, tolerance).ok()?;
+            Some((radius, e12_terms.max(root_terms)))
+        })
+        .min_by_key(|&(_, terms)| terms)
+        .map_or(RADII[0], |(radius, _)| radius)
+}
@@ -0,0 +1,62 @@
+//! E1 and Ei on every finite argument, zero included, e.g. for data that can legitimately hit zero
+//! without a `NonZero` check ahead of every call.
+//!
+//! Both diverge at zero, from either side: $E_{1}(x) \to +\infty$ and $\text{Ei}(x) \to -\infty$
+//! (each like $\mp \ln |x|$), so zero gives that limit as a `Value` instead of an error.
+//! Everywhere else, values and errors are exactly what `crate::E1` and `crate::Ei` give.
+//! For the limits at either infinity as well, see `extended`.
+//!
+//! No original C code: GSL reports a domain error at zero.
+
+use {
+    crate::{Approx, Error, Precision},
+    sigma_types::{Finite, NonZero},
+};
+
+/// E1 or Ei at a finite argument: an approximate value, or the infinity it diverges to at zero.
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "a real function either has a value or diverges to one side"
+)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum Value {
+    /// Anywhere but zero, as `crate::E1` or `crate::Ei` gives it.
+    Finite(Approx),
+    /// Ei at zero.
+    NegativeInfinity,
+    /// E1 at zero.
+    PositiveInfinity,
+}
+
+impl Value {
+    /// The value as a plain `f64`, infinite at zero.
+    #[inline]
+    #[must_use]
+    pub fn get(self) -> f64 {
+        match self {
+            Self::Finite(approx) => *approx.value,
+            Self::NegativeInfinity => f64::NEG_INFINITY,
+            Self::PositiveInfinity => f64::INFINITY,
+        }
+    }
+}
+
+/// $E_{1}(x)$, or $+\infty$ at zero.
+/// # Errors
+/// See `crate::E1`.
+#[inline]
+pub fn E1(x: Finite<f64>, max_precision: Precision) -> Result<Value, Error> {
+    NonZero::try_new(x).map_or(Ok(Value::PositiveInfinity), |nonzero| {
+        crate::E1(nonzero, max_precision).map(Value::Finite)
+    })
+}
+
+/// $\text{Ei}(x)$, or $-\infty$ at zero.
+/// # Errors
+/// See `crate::E1`.
+#[inline]
+pub fn Ei(x: Finite<f64>, max_precision: Precision) -> Result<Value, Error> {
+    NonZero::try_new(x).map_or(Ok(Value::NegativeInfinity), |nonzero| {
+        crate::Ei(nonzero, max_precision).map(Value::Finite)
+    })
+}